@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use winit::window::CursorIcon;
+
+/// A cursor made of an image.
+///
+/// Because `winit` does not support custom cursors yet, the system cursor
+/// is hidden and the image is drawn on top of the app instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomCursor {
+    /// Encoded image, e.g PNG bytes.
+    pub image: Arc<Vec<u8>>,
+    /// Point of the image that represents the cursor position.
+    pub hotspot: (f32, f32),
+    /// Icon to use when the image can't be decoded.
+    pub fallback: CursorIcon,
+}
+
+impl CustomCursor {
+    /// Create a new custom cursor from the given encoded image bytes.
+    pub fn new(image: impl Into<Vec<u8>>) -> Self {
+        Self {
+            image: Arc::new(image.into()),
+            hotspot: (0.0, 0.0),
+            fallback: CursorIcon::default(),
+        }
+    }
+
+    /// Set the point of the image that represents the cursor position.
+    pub fn hotspot(mut self, x: f32, y: f32) -> Self {
+        self.hotspot = (x, y);
+        self
+    }
+
+    /// Set the icon to use when the image can't be decoded.
+    pub fn fallback(mut self, fallback: CursorIcon) -> Self {
+        self.fallback = fallback;
+        self
+    }
+}
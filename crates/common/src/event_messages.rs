@@ -1,14 +1,21 @@
 use accesskit::NodeId;
 use accesskit_winit::ActionRequestEvent;
 use dioxus_core::Template;
+use tokio::sync::oneshot;
 use uuid::Uuid;
-use winit::window::CursorIcon;
+use winit::window::{CursorIcon, WindowId};
+
+use crate::{CustomCursor, ExportNode, NewWindow};
 
 /// Custom EventLoop messages
 #[derive(Debug)]
 pub enum EventMessage {
     /// Update the given template
     UpdateTemplate(Template),
+    /// Replace the stylesheet with the given source
+    UpdateStyleSheet(String),
+    /// Process the next event sent by a plugin
+    DispatchPluginEvent,
     /// Pull the VirtualDOM
     PollVDOM,
     /// Request a rerender
@@ -17,10 +24,34 @@ pub enum EventMessage {
     RemeasureTextGroup(Uuid),
     /// Change the cursor icon
     SetCursorIcon(CursorIcon),
+    /// Change the cursor to a custom image, or go back to the system cursor
+    SetCustomCursor(Option<CustomCursor>),
+    /// Stop sending the pointer events to the node that captured the pointer
+    ReleasePointerCapture,
     /// Accessibility action request event
     ActionRequestEvent(ActionRequestEvent),
     /// Focus the given accessibility NodeID
     FocusAccessibilityNode(NodeId),
+    /// Change the title of the window
+    SetWindowTitle(String),
+    /// Minimize the window
+    MinimizeWindow,
+    /// Maximize the window, or restore it if already maximized
+    ToggleMaximizeWindow,
+    /// Enter or exit the fullscreen mode of the window
+    ToggleFullscreenWindow,
+    /// Close the window. Closing the main window exits the app
+    CloseWindow,
+    /// Open a new window
+    NewWindow(NewWindow),
+    /// Capture the last frame of the window encoded as a PNG
+    Screenshot(oneshot::Sender<Option<Vec<u8>>>),
+    /// Export a Node and its descendants to a vector format
+    ExportNode(ExportNode),
+    /// An item of the system tray menu was clicked
+    TrayMenuClicked(String),
+    /// Message sent from a specific window
+    WindowMessage(WindowId, Box<EventMessage>),
 }
 
 impl From<ActionRequestEvent> for EventMessage {
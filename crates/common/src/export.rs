@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use tokio::sync::{oneshot, watch};
+
+use crate::NodeReferenceLayout;
+
+/// Vector formats a Node can be exported to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    /// SVG document, raster images are embedded as base64 encoded PNGs.
+    Svg,
+    /// Single page PDF document with the size of the Node, raster images are embedded compressed.
+    Pdf,
+    /// Single page PDF document with the given page size, the Node is scaled down to fit inside the margins.
+    PdfPage(PageOptions),
+}
+
+/// Size of a printed page.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PageSize {
+    /// 210 x 297 mm.
+    #[default]
+    A4,
+    /// 8.5 x 11 inches.
+    Letter,
+    /// 8.5 x 14 inches.
+    Legal,
+    /// Width and height in points, 1/72 of an inch.
+    Custom { width: f32, height: f32 },
+}
+
+impl PageSize {
+    /// Width and height in points, 1/72 of an inch.
+    pub fn size(&self) -> (f32, f32) {
+        match self {
+            Self::A4 => (595.0, 842.0),
+            Self::Letter => (612.0, 792.0),
+            Self::Legal => (612.0, 1008.0),
+            Self::Custom { width, height } => (*width, *height),
+        }
+    }
+}
+
+/// Layout of a printed page.
+///
+/// Layout pixels are printed at 96 DPI, like in browsers, so `96` pixels take an inch of the page.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageOptions {
+    pub size: PageSize,
+    /// Portrait or landscape orientation.
+    pub landscape: bool,
+    /// Space around the content in points, 1/72 of an inch.
+    pub margin: f32,
+}
+
+impl Default for PageOptions {
+    fn default() -> Self {
+        Self {
+            size: PageSize::default(),
+            landscape: false,
+            margin: 36.0,
+        }
+    }
+}
+
+impl PageOptions {
+    /// Specify the page size.
+    pub fn with_size(mut self, size: PageSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Specify whether the page is in landscape orientation.
+    pub fn with_landscape(mut self, landscape: bool) -> Self {
+        self.landscape = landscape;
+        self
+    }
+
+    /// Specify the space around the content in points, 1/72 of an inch.
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Width and height of the page in points, in its orientation.
+    pub fn page_size(&self) -> (f32, f32) {
+        let (width, height) = self.size.size();
+        if self.landscape {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+}
+
+/// Request to export the Node that has the given `reference`, or the whole window if there is none.
+///
+/// See `use_node_export`.
+#[derive(Debug)]
+pub struct ExportNode {
+    pub reference: Option<Arc<watch::Sender<NodeReferenceLayout>>>,
+    pub format: ExportFormat,
+    /// Receives the encoded document, or `None` if the Node could not be exported.
+    pub sender: oneshot::Sender<Option<Vec<u8>>>,
+}
@@ -0,0 +1,307 @@
+use chrono::{Locale, NaiveDate, NaiveDateTime, NaiveTime};
+use pure_rust_locales::locale_match;
+
+/// Locale used when the given one is unknown.
+const FALLBACK_LOCALE: Locale = Locale::en_US;
+
+/// Territory of the languages whose main locale is not `<language>_<LANGUAGE>`, e.g `ja_JP`.
+const LANGUAGE_TERRITORIES: &[(&str, &str)] = &[
+    ("ar", "SA"),
+    ("ca", "ES"),
+    ("cs", "CZ"),
+    ("da", "DK"),
+    ("el", "GR"),
+    ("en", "US"),
+    ("fa", "IR"),
+    ("he", "IL"),
+    ("hi", "IN"),
+    ("ja", "JP"),
+    ("ko", "KR"),
+    ("nb", "NO"),
+    ("sv", "SE"),
+    ("uk", "UA"),
+    ("vi", "VN"),
+    ("zh", "CN"),
+];
+
+/// Find the locale data of a locale name like `de_DE`, `de-DE` or `de`.
+///
+/// Unknown locales fall back to `en_US`.
+pub fn resolve_locale(locale: &str) -> Locale {
+    // Ignore the encoding, e.g `de_DE.UTF-8`
+    let locale = locale
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .replace('-', "_");
+
+    if let Ok(locale) = Locale::try_from(locale.as_str()) {
+        return locale;
+    }
+
+    let language = locale.split('_').next().unwrap_or_default().to_lowercase();
+    let territory = LANGUAGE_TERRITORIES
+        .iter()
+        .find(|(lang, _)| *lang == language)
+        .map(|(_, territory)| territory.to_string())
+        .unwrap_or_else(|| language.to_uppercase());
+
+    Locale::try_from(format!("{language}_{territory}").as_str()).unwrap_or(FALLBACK_LOCALE)
+}
+
+/// Group the digits of an integer following a POSIX grouping, where the last size is repeated.
+fn group_digits(digits: &str, grouping: &[i64], separator: &str) -> String {
+    if separator.is_empty() || grouping.is_empty() {
+        return digits.to_string();
+    }
+
+    let mut groups = Vec::new();
+    let mut rest = digits;
+    let mut sizes = grouping.iter();
+    let mut size = 0;
+    loop {
+        // A size of -1 or 0 stops the grouping, a missing size repeats the last one
+        if let Some(next_size) = sizes.next() {
+            size = *next_size;
+        }
+        if size <= 0 || rest.len() <= size as usize {
+            groups.push(rest);
+            break;
+        }
+        let (head, tail) = rest.split_at(rest.len() - size as usize);
+        groups.push(tail);
+        rest = head;
+    }
+
+    groups.reverse();
+    groups.join(separator)
+}
+
+fn format_decimal(
+    value: f64,
+    decimals: usize,
+    decimal_point: &str,
+    thousands_separator: &str,
+    grouping: &[i64],
+) -> String {
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+    let mut result = group_digits(integer, grouping, thousands_separator);
+    if !fraction.is_empty() {
+        result.push_str(decimal_point);
+        result.push_str(fraction);
+    }
+    result
+}
+
+/// Whether a value is shown as negative once formatted, values rounded to zero are not.
+fn is_negative(value: f64, formatted: &str) -> bool {
+    value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0')
+}
+
+/// Format a number with the decimal point and the digit grouping of the given locale.
+///
+/// ```rust
+/// # use freya_common::format_number;
+/// assert_eq!(format_number(1234567.891, 2, "en_US"), "1,234,567.89");
+/// assert_eq!(format_number(1234567.891, 2, "de-DE"), "1.234.567,89");
+/// ```
+pub fn format_number(value: f64, decimals: usize, locale: &str) -> String {
+    let locale = resolve_locale(locale);
+    let number = format_decimal(
+        value,
+        decimals,
+        locale_match!(locale => LC_NUMERIC::DECIMAL_POINT),
+        locale_match!(locale => LC_NUMERIC::THOUSANDS_SEP),
+        locale_match!(locale => LC_NUMERIC::GROUPING),
+    );
+
+    if is_negative(value, &number) {
+        format!("-{number}")
+    } else {
+        number
+    }
+}
+
+/// Format an amount of money in the currency of the given locale.
+///
+/// ```rust
+/// # use freya_common::format_currency;
+/// assert_eq!(format_currency(1234.5, "en_US"), "$1,234.50");
+/// assert_eq!(format_currency(1234.5, "de_DE"), "1.234,50 €");
+/// assert_eq!(format_currency(-0.001, "en_US"), "$0.00");
+/// ```
+pub fn format_currency(value: f64, locale: &str) -> String {
+    let resolved = resolve_locale(locale);
+    format_currency_with_symbol(
+        value,
+        locale_match!(resolved => LC_MONETARY::CURRENCY_SYMBOL),
+        locale,
+    )
+}
+
+/// Format an amount of money with the given currency symbol, placed where the given locale places it.
+///
+/// ```rust
+/// # use freya_common::format_currency_with_symbol;
+/// assert_eq!(format_currency_with_symbol(-5.0, "$", "de_DE"), "-5,00 $");
+/// ```
+pub fn format_currency_with_symbol(value: f64, symbol: &str, locale: &str) -> String {
+    let locale = resolve_locale(locale);
+    let frac_digits = locale_match!(locale => LC_MONETARY::FRAC_DIGITS);
+    let amount = format_decimal(
+        value,
+        frac_digits.clamp(0, 10) as usize,
+        locale_match!(locale => LC_MONETARY::MON_DECIMAL_POINT),
+        locale_match!(locale => LC_MONETARY::MON_THOUSANDS_SEP),
+        locale_match!(locale => LC_MONETARY::MON_GROUPING),
+    );
+
+    if is_negative(value, &amount) {
+        let negative_sign = locale_match!(locale => LC_MONETARY::NEGATIVE_SIGN);
+        place_currency(
+            &amount,
+            symbol,
+            if negative_sign.is_empty() {
+                "-"
+            } else {
+                negative_sign
+            },
+            locale_match!(locale => LC_MONETARY::N_CS_PRECEDES) == 1,
+            locale_match!(locale => LC_MONETARY::N_SEP_BY_SPACE),
+            locale_match!(locale => LC_MONETARY::N_SIGN_POSN),
+        )
+    } else {
+        // Positive amounts are never wrapped in parentheses
+        let sign_posn = locale_match!(locale => LC_MONETARY::P_SIGN_POSN);
+        place_currency(
+            &amount,
+            symbol,
+            locale_match!(locale => LC_MONETARY::POSITIVE_SIGN),
+            locale_match!(locale => LC_MONETARY::P_CS_PRECEDES) == 1,
+            locale_match!(locale => LC_MONETARY::P_SEP_BY_SPACE),
+            if sign_posn == 0 { 1 } else { sign_posn },
+        )
+    }
+}
+
+/// Place the currency symbol and the sign around an amount, following the POSIX `sep_by_space` and `sign_posn` values.
+fn place_currency(
+    amount: &str,
+    symbol: &str,
+    sign: &str,
+    cs_precedes: bool,
+    sep_by_space: i64,
+    sign_posn: i64,
+) -> String {
+    // A `sep_by_space` of 1 separates the symbol from the amount, and 2 separates the sign from what is next to it
+    let space = if sep_by_space == 1 { " " } else { "" };
+    let sign_space = if sep_by_space == 2 && !sign.is_empty() {
+        " "
+    } else {
+        ""
+    };
+
+    let symbol = match sign_posn {
+        3 => format!("{sign}{sign_space}{symbol}"),
+        4 => format!("{symbol}{sign_space}{sign}"),
+        _ => symbol.to_string(),
+    };
+
+    let money = if cs_precedes {
+        format!("{symbol}{space}{amount}")
+    } else {
+        format!("{amount}{space}{symbol}")
+    };
+
+    match sign_posn {
+        0 => format!("({money})"),
+        2 => format!("{money}{sign_space}{sign}"),
+        3 | 4 => money,
+        _ => format!("{sign}{sign_space}{money}"),
+    }
+}
+
+/// Format a date with the short date format of the given locale.
+///
+/// ```rust
+/// # use freya_common::{format_date, NaiveDate};
+/// let date = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+/// assert_eq!(format_date(&date, "en_US"), "03/09/2024");
+/// assert_eq!(format_date(&date, "de_DE"), "09.03.2024");
+/// ```
+pub fn format_date(date: &NaiveDate, locale: &str) -> String {
+    let locale = resolve_locale(locale);
+    date.format_localized(locale_match!(locale => LC_TIME::D_FMT), locale)
+        .to_string()
+}
+
+/// Remove the time zone from a time format, as naive times don't have one.
+fn without_time_zone(format: &str) -> String {
+    format
+        .replace("%Z", "")
+        .replace("  ", " ")
+        .trim()
+        .to_string()
+}
+
+/// Format a time with the time format of the given locale.
+pub fn format_time(time: &NaiveTime, locale: &str) -> String {
+    let locale = resolve_locale(locale);
+    let format = without_time_zone(locale_match!(locale => LC_TIME::T_FMT));
+    // Only dates can be formatted with the localized formatter of chrono
+    NaiveDate::default()
+        .and_time(*time)
+        .and_utc()
+        .format_localized(&format, locale)
+        .to_string()
+}
+
+/// Format a date and a time with the date and time format of the given locale.
+pub fn format_datetime(datetime: &NaiveDateTime, locale: &str) -> String {
+    let locale = resolve_locale(locale);
+    let format = without_time_zone(locale_match!(locale => LC_TIME::D_T_FMT));
+    datetime
+        .and_utc()
+        .format_localized(&format, locale)
+        .to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn unknown_locales_fall_back() {
+        assert_eq!(resolve_locale("xx_YY"), Locale::en_US);
+        assert_eq!(resolve_locale(""), Locale::en_US);
+        assert_eq!(resolve_locale("de"), Locale::de_DE);
+        assert_eq!(resolve_locale("ja-JP.UTF-8"), Locale::ja_JP);
+
+        assert_eq!(format_number(-1234.5, 1, "xx_YY"), "-1,234.5");
+        assert_eq!(format_currency(1234.5, "klingon"), "$1,234.50");
+        assert_eq!(
+            format_date(&NaiveDate::from_ymd_opt(2024, 3, 9).unwrap(), "xx"),
+            "03/09/2024"
+        );
+    }
+
+    #[test]
+    pub fn negative_sign_position() {
+        // Before the symbol and the amount
+        assert_eq!(format_currency(-5.0, "en_US"), "-$5.00");
+        assert_eq!(format_currency(-5.0, "de_DE"), "-5,00 €");
+        // After the symbol, separated from it
+        assert_eq!(format_currency(-5.0, "nl_NL"), "€ -5,00");
+        assert_eq!(format_currency(5.0, "nl_NL"), "€ 5,00");
+        // Rounded to zero
+        assert_eq!(format_currency(-0.001, "en_US"), "$0.00");
+        assert_eq!(format_currency(-0.001, "nl_NL"), "€ 0,00");
+
+        assert_eq!(place_currency("5", "$", "-", true, 0, 0), "($5)");
+        assert_eq!(place_currency("5", "$", "-", false, 1, 2), "5 $-");
+        assert_eq!(place_currency("5", "$", "-", true, 0, 3), "-$5");
+        assert_eq!(place_currency("5", "$", "-", true, 2, 1), "- $5");
+    }
+}
@@ -6,6 +6,8 @@ use torin::geometry::{Area, Size2D};
 pub struct NodeReferenceLayout {
     pub area: Area,
     pub inner: Size2D,
+    /// Portion of the Node that is visible in the viewport, from `0.0` to `1.0`.
+    pub visible_ratio: f32,
 }
 
 impl NodeReferenceLayout {
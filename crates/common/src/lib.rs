@@ -1,5 +1,18 @@
+mod cursor;
 mod event_messages;
+mod export;
+#[cfg(feature = "format")]
+mod format;
 mod layout;
+mod window;
 
+pub use cursor::*;
 pub use event_messages::*;
+pub use export::*;
+#[cfg(feature = "format")]
+pub use format::*;
 pub use layout::*;
+pub use window::*;
+
+#[cfg(feature = "format")]
+pub use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
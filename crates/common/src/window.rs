@@ -0,0 +1,62 @@
+use std::any::Any;
+use std::fmt;
+
+use dioxus_core::Element;
+
+/// Configuration of a window opened from within the app, see `UsePlatform::new_window`.
+///
+/// The window gets its own root component, it doesn't share any state with the rest of the app
+/// unless passed with [`NewWindow::with_context`].
+pub struct NewWindow {
+    /// Root component of the window.
+    pub app: fn() -> Element,
+    /// Title of the window.
+    pub title: &'static str,
+    /// Width of the window.
+    pub width: f64,
+    /// Height of the window.
+    pub height: f64,
+    /// Values provided as root contexts of the window.
+    pub contexts: Vec<Box<dyn Any + Send>>,
+}
+
+impl NewWindow {
+    pub fn new(app: fn() -> Element) -> Self {
+        Self {
+            app,
+            title: "Freya app",
+            width: 600.0,
+            height: 600.0,
+            contexts: Vec::new(),
+        }
+    }
+
+    /// Specify the window title.
+    pub fn with_title(mut self, title: &'static str) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Specify the window size.
+    pub fn with_size(mut self, width: f64, height: f64) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Pass a value that the root component of the window can consume with `use_context`.
+    pub fn with_context<T: Clone + Send + 'static>(mut self, value: T) -> Self {
+        self.contexts.push(Box::new(value));
+        self
+    }
+}
+
+impl fmt::Debug for NewWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NewWindow")
+            .field("title", &self.title)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,248 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::{router, Outlet, Routable};
+use freya_elements::elements as dioxus_elements;
+use freya_hooks::{use_animation_with_dependencies, use_node, AnimNum, Ease};
+
+/// Animation used by [`AnimatedOutlet`] when the route changes.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum RouteTransition {
+    /// Swap the views without any animation.
+    #[default]
+    None,
+    /// Cross-fade the outgoing view with the incoming view.
+    Fade,
+    /// Slide the incoming view from the right, pushing the outgoing view to the left.
+    SlideLeft,
+    /// Slide the incoming view from the bottom, pushing the outgoing view to the top.
+    SlideUp,
+}
+
+/// [`AnimatedOutlet`] component properties.
+#[derive(Props, Clone, PartialEq)]
+pub struct AnimatedOutletProps {
+    /// Animation to run when the route changes.
+    #[props(default)]
+    pub transition: RouteTransition,
+    /// Duration of the animation in milliseconds.
+    #[props(default = 250)]
+    pub duration: u64,
+    /// Nesting level of the outlet, this is `1` for an outlet in a `#[layout]`, `2` for an outlet in a nested layout, etc.
+    #[props(default = 1)]
+    pub level: usize,
+}
+
+/// Same as [`Outlet`], but animating the route changes with a [`RouteTransition`].
+///
+/// The outgoing view is kept mounted until the animation finishes.
+/// When navigating again in the middle of a transition, the outgoing view is dropped
+/// and the animation starts over from the view that was entering.
+///
+/// # Props
+/// See [`AnimatedOutletProps`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// # #[derive(Routable, Clone, PartialEq)]
+/// # #[rustfmt::skip]
+/// # enum Route {
+/// #     #[layout(AppLayout)]
+/// #     #[route("/")]
+/// #     Home,
+/// # }
+/// # #[component]
+/// # fn Home() -> Element { None }
+/// #[component]
+/// fn AppLayout() -> Element {
+///     rsx!(
+///         Link {
+///             to: Route::Home,
+///             label { "Home" }
+///         }
+///         AnimatedOutlet::<Route> {
+///             transition: RouteTransition::Fade,
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn AnimatedOutlet<R: Routable + Clone + PartialEq>(props: AnimatedOutletProps) -> Element {
+    let AnimatedOutletProps {
+        transition,
+        duration,
+        level,
+    } = props;
+    let route = router().current::<R>();
+    let mut last_route = use_hook(|| CopyValue::new(route.clone()));
+    // The outgoing route, and whether its animation has already started
+    let mut outgoing = use_hook(|| CopyValue::new(None::<(R, bool)>));
+    let (node_ref, size) = use_node();
+    let animation = use_animation_with_dependencies(&duration, move |ctx, duration| {
+        ctx.with(AnimNum::new(0., 1.).time(duration).ease(Ease::InOut))
+    });
+
+    // Keep the previous route around as the outgoing view when the route changes
+    let _ = use_memo_with_dependencies(&(route.clone(), transition), move |(route, transition)| {
+        let previous = std::mem::replace(&mut *last_route.write(), route.clone());
+        if transition == RouteTransition::None || previous == route {
+            outgoing.set(None);
+            return;
+        }
+        outgoing.set(Some((previous, false)));
+        // Starting the animation writes to signals, so it can't be done while rendering
+        spawn(async move {
+            animation.read().start();
+            if let Some((_, started)) = &mut *outgoing.write() {
+                *started = true;
+            }
+        });
+    });
+
+    if transition == RouteTransition::None {
+        return rsx!(Outlet::<R> {});
+    }
+
+    let is_running = animation.read().is_running();
+    let (outgoing_route, progress) = match &*outgoing.peek() {
+        // Waiting for the animation to start
+        Some((route, false)) => (Some(route.clone()), 0.),
+        Some((route, true)) if is_running => {
+            (Some(route.clone()), animation.read().get().read().as_f32())
+        }
+        // The outgoing view is dropped once the animation finishes
+        _ => (None, 1.),
+    };
+
+    let (width, height) = (size.area.width(), size.area.height());
+    let (outgoing_opacity, incoming_opacity) = match transition {
+        RouteTransition::Fade => (1. - progress, progress),
+        _ => (1., 1.),
+    };
+    let (outgoing_left, incoming_left) = match transition {
+        RouteTransition::SlideLeft => (-width * progress, width * (1. - progress)),
+        _ => (0., 0.),
+    };
+    let (outgoing_top, incoming_top) = match transition {
+        RouteTransition::SlideUp => (-height * progress, height * (1. - progress)),
+        _ => (0., 0.),
+    };
+
+    rsx!(
+        rect {
+            reference: node_ref,
+            width: "100%",
+            height: "100%",
+            overflow: "clip",
+            if let Some(outgoing_route) = outgoing_route {
+                rect {
+                    position: "absolute",
+                    position_top: "{outgoing_top}",
+                    position_left: "{outgoing_left}",
+                    width: "100%",
+                    height: "100%",
+                    opacity: "{outgoing_opacity}",
+                    {outgoing_route.render(level)}
+                }
+            }
+            rect {
+                position: "absolute",
+                position_top: "{incoming_top}",
+                position_left: "{incoming_left}",
+                width: "100%",
+                height: "100%",
+                opacity: "{incoming_opacity}",
+                Outlet::<R> {}
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use dioxus_router::prelude::{Routable, Router};
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[tokio::test(start_paused = true)]
+    pub async fn animated_outlet() {
+        #[derive(Routable, Clone, PartialEq)]
+        #[rustfmt::skip]
+        enum Route {
+            #[layout(Layout)]
+            #[route("/")]
+            Home,
+            #[route("/somewhere")]
+            Somewhere,
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Home() -> Element {
+            rsx!(label { "Home" })
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Somewhere() -> Element {
+            rsx!(label { "Somewhere" })
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Layout() -> Element {
+            rsx!(
+                Link {
+                    to: Route::Somewhere,
+                    rect {
+                        width: "100%",
+                        height: "50",
+                    }
+                }
+                AnimatedOutlet::<Route> {
+                    transition: RouteTransition::Fade,
+                    duration: 50,
+                }
+            )
+        }
+
+        fn animated_outlet_app() -> Element {
+            rsx!(Router::<Route> {})
+        }
+
+        let mut utils = launch_test(animated_outlet_app);
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The outgoing view is a placeholder
+        let outlet = utils.root().get(1);
+        assert!(outlet.get(0).children_ids().is_empty());
+        assert_eq!(outlet.get(1).get(0).get(0).text(), Some("Home"));
+
+        // Go to the "Somewhere" route
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Both views are shown during the transition
+        let outlet = utils.root().get(1);
+        assert_eq!(outlet.get(0).get(0).get(0).text(), Some("Home"));
+        assert_eq!(outlet.get(1).get(0).get(0).text(), Some("Somewhere"));
+
+        utils.advance_time(Duration::from_millis(100)).await;
+
+        // The outgoing view is dropped after the transition
+        let outlet = utils.root().get(1);
+        assert!(outlet.get(0).children_ids().is_empty());
+        assert_eq!(outlet.get(1).get(0).get(0).text(), Some("Somewhere"));
+    }
+}
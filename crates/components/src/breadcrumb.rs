@@ -0,0 +1,537 @@
+use std::ops::Range;
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::{navigator, IntoRoutable};
+use freya_elements::elements as dioxus_elements;
+use freya_elements::events::MouseEvent;
+use freya_hooks::{
+    use_applied_theme, use_node_signal, use_platform, use_text_measurer, BreadcrumbTheme,
+    BreadcrumbThemeWith, TextMeasureOptions,
+};
+use winit::window::CursorIcon;
+
+use crate::ButtonStatus;
+
+/// Horizontal padding of the segments.
+const SEGMENT_PADDING: f32 = 12.0;
+
+/// Horizontal margin of the separators.
+const SEPARATOR_MARGIN: f32 = 8.0;
+
+/// A segment of a [`Breadcrumb`] trail.
+#[derive(Clone, PartialEq)]
+pub struct BreadcrumbItem {
+    /// Text shown for this segment.
+    pub label: String,
+    /// Route or external URL to navigate to when this segment is clicked.
+    pub to: Option<IntoRoutable>,
+    /// Handler for the `onclick` event of this segment.
+    pub onclick: Option<EventHandler<()>>,
+}
+
+impl BreadcrumbItem {
+    /// Create a new segment with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            to: None,
+            onclick: None,
+        }
+    }
+
+    /// Navigate to the given route when this segment is clicked.
+    ///
+    /// Make sure the [`Breadcrumb`] is descendant of a [`Router`](dioxus_router::components::Router) component.
+    pub fn to(mut self, to: impl Into<IntoRoutable>) -> Self {
+        self.to = Some(to.into());
+        self
+    }
+
+    /// Call the given handler when this segment is clicked.
+    pub fn onclick(mut self, onclick: impl FnMut(()) + 'static) -> Self {
+        self.onclick = Some(EventHandler::new(onclick));
+        self
+    }
+}
+
+/// What is rendered in every position of a [`Breadcrumb`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum BreadcrumbSegment {
+    /// The item at the given index.
+    Item(usize),
+    /// The items in the given range, collapsed into a menu.
+    Collapsed(Range<usize>),
+}
+
+/// Compute the segments to show for a trail of `len` items when only `max_items` fit.
+///
+/// The first item and the last ones are always visible, and the middle ones get collapsed.
+pub fn breadcrumb_segments(len: usize, max_items: Option<usize>) -> Vec<BreadcrumbSegment> {
+    let max_items = max_items.unwrap_or(len).max(2);
+
+    if len <= max_items {
+        return (0..len).map(BreadcrumbSegment::Item).collect();
+    }
+
+    let tail = max_items - 1;
+    let collapsed = 1..len - tail;
+
+    let mut segments = vec![
+        BreadcrumbSegment::Item(0),
+        BreadcrumbSegment::Collapsed(collapsed.clone()),
+    ];
+    segments.extend((collapsed.end..len).map(BreadcrumbSegment::Item));
+    segments
+}
+
+/// Compute the segments to show for a trail of items with the given widths, collapsing the middle ones
+/// until the trail fits in `max_width` and there are at most `max_items`, see [`breadcrumb_segments`].
+///
+/// The separator width is added to every segment but the first one. A `max_width` of `0` or less is ignored.
+pub fn breadcrumb_segments_fitting(
+    item_widths: &[f32],
+    separator_width: f32,
+    collapsed_width: f32,
+    max_width: f32,
+    max_items: Option<usize>,
+) -> Vec<BreadcrumbSegment> {
+    let len = item_widths.len();
+    let max_items = max_items.unwrap_or(len).min(len).max(2);
+
+    let segments_width = |segments: &[BreadcrumbSegment]| -> f32 {
+        segments
+            .iter()
+            .enumerate()
+            .map(|(i, segment)| {
+                let width = match segment {
+                    BreadcrumbSegment::Item(index) => item_widths[*index],
+                    BreadcrumbSegment::Collapsed(_) => collapsed_width,
+                };
+                if i == 0 {
+                    width
+                } else {
+                    width + separator_width
+                }
+            })
+            .sum()
+    };
+
+    // Show as many items as possible, the first and the last ones are always shown
+    (2..=max_items)
+        .rev()
+        .map(|visible_items| breadcrumb_segments(len, Some(visible_items)))
+        .find(|segments| max_width <= 0.0 || segments_width(segments) <= max_width)
+        .unwrap_or_else(|| breadcrumb_segments(len, Some(2)))
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn BreadcrumbLink(
+    theme: Option<BreadcrumbThemeWith>,
+    item: BreadcrumbItem,
+    is_current: bool,
+) -> Element {
+    let BreadcrumbTheme {
+        hover_color,
+        hover_background,
+        font_theme,
+        ..
+    } = use_applied_theme!(&theme, breadcrumb);
+    let mut status = use_signal(ButtonStatus::default);
+    let platform = use_platform();
+
+    use_drop(move || {
+        if *status.peek() == ButtonStatus::Hovering {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onmouseenter = move |_| {
+        if !is_current {
+            platform.set_cursor(CursorIcon::Pointer);
+            status.set(ButtonStatus::Hovering);
+        }
+    };
+
+    let onmouseleave = move |_| {
+        if *status.peek() == ButtonStatus::Hovering {
+            platform.set_cursor(CursorIcon::default());
+            status.set(ButtonStatus::default());
+        }
+    };
+
+    let onclick = {
+        to_owned![item];
+        move |_: MouseEvent| {
+            if is_current {
+                return;
+            }
+
+            if let Some(onclick) = &item.onclick {
+                onclick.call(());
+            }
+
+            if let Some(to) = &item.to {
+                navigator().push(to.clone());
+            }
+        }
+    };
+
+    let (color, background) = match *status.read() {
+        ButtonStatus::Hovering => (hover_color, hover_background),
+        ButtonStatus::Idle => (font_theme.color, "transparent".into()),
+    };
+
+    rsx!(
+        rect {
+            onmouseenter,
+            onmouseleave,
+            onclick,
+            padding: "2 6",
+            corner_radius: "6",
+            color: "{color}",
+            background: "{background}",
+            label {
+                max_lines: "1",
+                "{item.label}"
+            }
+        }
+    )
+}
+
+/// Navigation trail showing the location of the current page in a hierarchy.
+///
+/// The last item represents the current location and is not clickable.
+/// When the items don't fit in the width of the trail or there are more than `max_items`,
+/// the middle ones get collapsed into a menu that can be opened by clicking the ellipsis.
+///
+/// # Styling
+///
+/// Inherits the [`BreadcrumbTheme`](freya_hooks::BreadcrumbTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         Breadcrumb {
+///             max_items: 3,
+///             items: vec![
+///                 BreadcrumbItem::new("Home").onclick(|_| println!("Home")),
+///                 BreadcrumbItem::new("Documents"),
+///                 BreadcrumbItem::new("Freya"),
+///                 BreadcrumbItem::new("README.md"),
+///             ]
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Breadcrumb(
+    /// Theme override.
+    theme: Option<BreadcrumbThemeWith>,
+    /// Segments of the trail, from the root to the current location.
+    items: Vec<BreadcrumbItem>,
+    /// Text rendered between two segments.
+    #[props(default = "/".to_string(), into)]
+    separator: String,
+    /// Width of the trail, the middle items get collapsed if they don't fit in it. Defaults to `100%`.
+    #[props(default = "100%".to_string(), into)]
+    width: String,
+    /// Maximum number of segments to show before collapsing the middle ones. Defaults to showing all of them.
+    #[props(optional)]
+    max_items: Option<usize>,
+) -> Element {
+    let BreadcrumbTheme {
+        separator_color,
+        menu_background,
+        border_fill,
+        font_size,
+        font_family,
+        font_theme,
+        ..
+    } = use_applied_theme!(&theme, breadcrumb);
+    let mut menu_opened = use_signal(|| false);
+    let platform = use_platform();
+    let measurer = use_text_measurer();
+    let (reference, size) = use_node_signal();
+
+    // Measure the segments like they are laid out, to know how many fit
+    let mut measure_options = TextMeasureOptions::default().with_font_family(font_family.as_ref());
+    if let Ok(font_size) = font_size.parse() {
+        measure_options = measure_options.with_font_size(font_size);
+    }
+    let measure = |text: &str| measurer.measure(text, &measure_options).width;
+    let item_widths = items
+        .iter()
+        .map(|item| measure(&item.label) + SEGMENT_PADDING)
+        .collect::<Vec<_>>();
+    let segments = breadcrumb_segments_fitting(
+        &item_widths,
+        measure(&separator) + SEPARATOR_MARGIN,
+        measure("…") + SEGMENT_PADDING,
+        size.read().area.width(),
+        max_items,
+    );
+    let last_index = items.len().saturating_sub(1);
+
+    let segments = segments.into_iter().enumerate().map(|(i, segment)| {
+        let content = match segment {
+            BreadcrumbSegment::Item(index) => rsx!(BreadcrumbLink {
+                theme: theme.clone(),
+                item: items[index].clone(),
+                is_current: index == last_index,
+            }),
+            BreadcrumbSegment::Collapsed(range) => rsx!(
+                rect {
+                    rect {
+                        onclick: move |_| menu_opened.toggle(),
+                        onmouseenter: move |_| platform.set_cursor(CursorIcon::Pointer),
+                        onmouseleave: move |_| platform.set_cursor(CursorIcon::default()),
+                        padding: "2 6",
+                        label {
+                            "…"
+                        }
+                    }
+                    if *menu_opened.read() {
+                        rect {
+                            height: "0",
+                            rect {
+                                // Close the collapsed items menu if clicked anywhere
+                                onglobalclick: move |_| menu_opened.set(false),
+                                layer: "-99",
+                                margin: "4 0",
+                                padding: "4",
+                                corner_radius: "8",
+                                border: "1 solid {border_fill}",
+                                background: "{menu_background}",
+                                shadow: "0 4 5 0 rgb(0, 0, 0, 0.3)",
+                                for index in range {
+                                    BreadcrumbLink {
+                                        key: "{index}",
+                                        theme: theme.clone(),
+                                        item: items[index].clone(),
+                                        is_current: false,
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            ),
+        };
+
+        if i == 0 {
+            rsx!(
+                rect {
+                    key: "{i}",
+                    {content}
+                }
+            )
+        } else {
+            rsx!(
+                rect {
+                    key: "{i}",
+                    direction: "horizontal",
+                    cross_align: "center",
+                    label {
+                        margin: "0 4",
+                        color: "{separator_color}",
+                        "{separator}"
+                    }
+                    {content}
+                }
+            )
+        }
+    });
+
+    rsx!(
+        rect {
+            reference,
+            width: "{width}",
+            direction: "horizontal",
+            cross_align: "center",
+            color: "{font_theme.color}",
+            font_size: "{font_size}",
+            font_family: "{font_family}",
+            {segments}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[test]
+    pub fn breadcrumb_segments_collapse() {
+        assert_eq!(
+            breadcrumb_segments(3, None),
+            vec![
+                BreadcrumbSegment::Item(0),
+                BreadcrumbSegment::Item(1),
+                BreadcrumbSegment::Item(2),
+            ]
+        );
+        assert_eq!(
+            breadcrumb_segments(6, Some(3)),
+            vec![
+                BreadcrumbSegment::Item(0),
+                BreadcrumbSegment::Collapsed(1..4),
+                BreadcrumbSegment::Item(4),
+                BreadcrumbSegment::Item(5),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn breadcrumb_segments_fit() {
+        let widths = [50.0, 20.0, 20.0, 20.0, 50.0];
+
+        // Unknown width
+        assert_eq!(
+            breadcrumb_segments_fitting(&widths, 10.0, 20.0, 0.0, None).len(),
+            5
+        );
+        // Everything fits
+        assert_eq!(
+            breadcrumb_segments_fitting(&widths, 10.0, 20.0, 200.0, None).len(),
+            5
+        );
+        assert_eq!(
+            breadcrumb_segments_fitting(&widths, 10.0, 20.0, 180.0, None),
+            vec![
+                BreadcrumbSegment::Item(0),
+                BreadcrumbSegment::Collapsed(1..3),
+                BreadcrumbSegment::Item(3),
+                BreadcrumbSegment::Item(4),
+            ]
+        );
+        assert_eq!(
+            breadcrumb_segments_fitting(&widths, 10.0, 20.0, 150.0, None),
+            vec![
+                BreadcrumbSegment::Item(0),
+                BreadcrumbSegment::Collapsed(1..4),
+                BreadcrumbSegment::Item(4),
+            ]
+        );
+        // The first and last items are kept even if they don't fit
+        assert_eq!(
+            breadcrumb_segments_fitting(&widths, 10.0, 20.0, 50.0, None).len(),
+            3
+        );
+        // The maximum items still apply
+        assert_eq!(
+            breadcrumb_segments_fitting(&widths, 10.0, 20.0, 1000.0, Some(3)),
+            breadcrumb_segments(5, Some(3))
+        );
+    }
+
+    #[tokio::test]
+    pub async fn breadcrumb() {
+        fn breadcrumb_app() -> Element {
+            let mut clicked = use_signal(|| false);
+
+            rsx!(
+                Breadcrumb {
+                    max_items: 3,
+                    items: vec![
+                        BreadcrumbItem::new("Home").onclick(move |_| clicked.set(true)),
+                        BreadcrumbItem::new("A"),
+                        BreadcrumbItem::new("B"),
+                        BreadcrumbItem::new("C"),
+                        BreadcrumbItem::new("D"),
+                    ]
+                }
+                label {
+                    "{clicked}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(breadcrumb_app);
+        utils.wait_for_update().await;
+
+        let root = utils.root();
+        let breadcrumb = root.get(0);
+
+        // The first item, the collapsed menu and the last two items
+        assert_eq!(breadcrumb.children_ids().len(), 4);
+        assert_eq!(breadcrumb.get(0).get(0).get(0).get(0).text(), Some("Home"));
+        assert_eq!(breadcrumb.get(2).get(1).get(0).get(0).text(), Some("C"));
+        assert_eq!(breadcrumb.get(3).get(1).get(0).get(0).text(), Some("D"));
+        assert_eq!(root.get(1).get(0).text(), Some("false"));
+
+        // Click the first item
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(root.get(1).get(0).text(), Some("true"));
+    }
+
+    #[tokio::test]
+    pub async fn breadcrumb_collapses_to_fit() {
+        fn breadcrumb_app() -> Element {
+            rsx!(Breadcrumb {
+                width: "190",
+                items: vec![
+                    BreadcrumbItem::new("Home"),
+                    BreadcrumbItem::new("Alpha"),
+                    BreadcrumbItem::new("Beta"),
+                    BreadcrumbItem::new("Gamma"),
+                    BreadcrumbItem::new("Delta"),
+                ]
+            })
+        }
+
+        let mut utils = launch_test(breadcrumb_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let breadcrumb = utils.root().get(0);
+
+        // Only the first item, the collapsed menu and the last item fit
+        assert_eq!(breadcrumb.area().unwrap().width(), 190.0);
+        assert_eq!(breadcrumb.children_ids().len(), 3);
+        assert_eq!(breadcrumb.get(0).get(0).get(0).get(0).text(), Some("Home"));
+        assert_eq!(breadcrumb.get(2).get(1).get(0).get(0).text(), Some("Delta"));
+        assert!(breadcrumb.get(2).area().unwrap().max_x() <= 190.0);
+    }
+
+    #[tokio::test]
+    pub async fn breadcrumb_measures_with_theme_font() {
+        fn breadcrumb_app() -> Element {
+            rsx!(Breadcrumb {
+                width: "260",
+                theme: theme_with!(BreadcrumbTheme {
+                    font_size: "20".into(),
+                }),
+                items: vec![
+                    BreadcrumbItem::new("Home"),
+                    BreadcrumbItem::new("Alpha"),
+                    BreadcrumbItem::new("Beta"),
+                    BreadcrumbItem::new("Gamma"),
+                    BreadcrumbItem::new("Delta"),
+                ]
+            })
+        }
+
+        let mut utils = launch_test(breadcrumb_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let breadcrumb = utils.root().get(0);
+        let last = breadcrumb.get(breadcrumb.children_ids().len() - 1);
+
+        // The segments are measured with the font size they are drawn with, so they don't overflow
+        assert_eq!(last.get(1).get(0).get(0).text(), Some("Delta"));
+        assert!(last.area().unwrap().max_x() <= 260.0);
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_engine::prelude::*;
+use freya_hooks::{use_local_canvas, use_platform, UseCamera};
+
+/// How the camera frames fit in the [`CameraView`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CameraFit {
+    /// Show the whole frame, keeping its aspect ratio.
+    #[default]
+    Contain,
+    /// Fill the view, keeping the aspect ratio and cropping the frame if needed.
+    Cover,
+    /// Stretch the frame to fill the view.
+    Fill,
+}
+
+impl CameraFit {
+    /// Get where to draw a frame of the given size in a view of the given size.
+    fn rect(&self, view: (f32, f32), frame: (f32, f32)) -> Rect {
+        let (view_width, view_height) = view;
+        let (frame_width, frame_height) = frame;
+
+        let scale = match self {
+            Self::Fill => return Rect::new(0.0, 0.0, view_width, view_height),
+            Self::Contain => (view_width / frame_width).min(view_height / frame_height),
+            Self::Cover => (view_width / frame_width).max(view_height / frame_height),
+        };
+
+        let (width, height) = (frame_width * scale, frame_height * scale);
+        let (x, y) = ((view_width - width) / 2.0, (view_height - height) / 2.0);
+        Rect::new(x, y, x + width, y + height)
+    }
+}
+
+/// [`CameraView`] component properties.
+#[derive(Props, Clone, PartialEq)]
+pub struct CameraViewProps {
+    /// The camera created with `use_camera_stream`.
+    pub camera: UseCamera,
+    /// Width of the view.
+    #[props(default = "100%".to_string(), into)]
+    pub width: String,
+    /// Height of the view.
+    #[props(default = "100%".to_string(), into)]
+    pub height: String,
+    /// Flip the frames horizontally, like a mirror.
+    #[props(default = false)]
+    pub mirror: bool,
+    /// How the frames fit in the view.
+    #[props(default)]
+    pub fit: CameraFit,
+    /// Handler called with every new frame, as given by the camera.
+    pub onframe: Option<EventHandler<Bytes>>,
+}
+
+/// Render the frames of a camera.
+///
+/// Each frame is decoded once and drawn straight into the canvas, so repainting the view doesn't decode it again.
+///
+/// # Props
+/// See [`CameraViewProps`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let camera = use_camera_stream(CameraSettings::default());
+///
+///     rsx!(
+///         CameraView {
+///             camera,
+///             mirror: true,
+///             fit: CameraFit::Cover,
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn CameraView(
+    CameraViewProps {
+        camera,
+        width,
+        height,
+        mirror,
+        fit,
+        onframe,
+    }: CameraViewProps,
+) -> Element {
+    let platform = use_platform();
+    let frame_reference = camera.frame_reference();
+
+    // Notify about the new frames
+    use_hook({
+        let camera = camera.clone();
+        move || {
+            spawn(async move {
+                let Some(onframe) = onframe else {
+                    return;
+                };
+                let mut ticker = platform.new_ticker();
+                let mut last_frame: Option<Bytes> = None;
+
+                loop {
+                    ticker.tick().await;
+
+                    let frame = camera.frame();
+                    if let Some(frame) = frame {
+                        let is_new = last_frame
+                            .as_ref()
+                            .map(|last| last.as_ptr() != frame.as_ptr())
+                            .unwrap_or(true);
+                        if is_new {
+                            last_frame = Some(frame.clone());
+                            onframe.call(frame);
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    let canvas = use_local_canvas((mirror, fit), move |(mirror, fit)| {
+        let frame_reference = frame_reference.clone();
+        let decoded: Arc<Mutex<Option<(Bytes, Image)>>> = Arc::default();
+
+        Box::new(move |canvas, _, area| {
+            let Some(frame) = frame_reference.0.lock().unwrap().clone() else {
+                return;
+            };
+
+            // Only decode the frame if it's a new one
+            let mut decoded = decoded.lock().unwrap();
+            let is_new = decoded
+                .as_ref()
+                .map(|(last, _)| last.as_ptr() != frame.as_ptr())
+                .unwrap_or(true);
+            if is_new {
+                *decoded = Image::from_encoded(Data::new_copy(&frame)).map(|image| (frame, image));
+            }
+
+            let Some((_, image)) = &*decoded else {
+                return;
+            };
+
+            let rect = fit.rect(
+                (area.width(), area.height()),
+                (image.width() as f32, image.height() as f32),
+            );
+
+            canvas.save();
+            if mirror {
+                canvas.translate((area.width(), 0.0));
+                canvas.scale((-1.0, 1.0));
+            }
+
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+            canvas.draw_image_rect(image, None, rect, &paint);
+
+            canvas.restore();
+        })
+    });
+
+    rsx!(rect {
+        overflow: "clip",
+        canvas_reference: canvas.attribute(),
+        width: "{width}",
+        height: "{height}"
+    })
+}
@@ -0,0 +1,207 @@
+use std::time::{Duration, Instant};
+
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_engine::prelude::*;
+
+use freya_hooks::{
+    use_applied_theme, use_local_canvas, use_platform, CircularProgressTheme,
+    CircularProgressThemeWith,
+};
+use freya_node_state::Parse;
+
+/// Time an indeterminate [`CircularProgress`] takes to spin a full turn.
+const SPIN_DURATION: Duration = Duration::from_millis(1000);
+
+/// [`CircularProgress`] component properties.
+#[derive(Props, Clone, PartialEq)]
+pub struct CircularProgressProps {
+    /// Theme override.
+    pub theme: Option<CircularProgressThemeWith>,
+    /// Show a label with the current progress in the center. Default to false.
+    #[props(default = false)]
+    pub show_progress: bool,
+    /// Percentage of the progress.
+    pub progress: f32,
+    /// Spin an arc instead of showing the progress, for when it's unknown. Default to false.
+    #[props(default = false)]
+    pub indeterminate: bool,
+    /// Thickness of the ring. Default to 6.
+    #[props(default = 6.0)]
+    pub thickness: f32,
+}
+
+/// `CircularProgress` component, a compact ring alternative to the [`ProgressBar`](crate::ProgressBar).
+///
+/// # Props
+/// See [`CircularProgressProps`].
+///
+/// # Styling
+/// Inherits the [`CircularProgressTheme`](freya_hooks::CircularProgressTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         CircularProgress {
+///             progress: 75.0,
+///             show_progress: true,
+///         }
+///         CircularProgress {
+///             progress: 0.0,
+///             indeterminate: true,
+///             thickness: 3.0,
+///         }
+///     )
+/// }
+/// ```
+///
+#[allow(non_snake_case)]
+pub fn CircularProgress(
+    CircularProgressProps {
+        theme,
+        show_progress,
+        progress,
+        indeterminate,
+        thickness,
+    }: CircularProgressProps,
+) -> Element {
+    let CircularProgressTheme {
+        color,
+        track_color,
+        progress_color,
+        size,
+    } = use_applied_theme!(&theme, circular_progress);
+    let platform = use_platform();
+    let mut spinning = use_signal(|| indeterminate);
+    let mut rotation = use_signal(|| 0.0);
+
+    // Keep spinning while in the indeterminate mode
+    let _ = use_memo_with_dependencies(&indeterminate, move |indeterminate| {
+        spinning.set(indeterminate);
+    });
+
+    use_hook(move || {
+        spawn(async move {
+            let start = Instant::now();
+            let mut ticker = platform.new_ticker();
+
+            loop {
+                ticker.tick().await;
+
+                if *spinning.peek() {
+                    let elapsed = start.elapsed().as_secs_f32();
+                    let duration = SPIN_DURATION.as_secs_f32();
+                    rotation.set((elapsed % duration) / duration * 360.0);
+
+                    platform.request_animation_frame();
+                }
+            }
+        });
+    });
+
+    let canvas = use_local_canvas(
+        (
+            progress,
+            *rotation.read(),
+            indeterminate,
+            thickness,
+            track_color.to_string(),
+            progress_color.to_string(),
+        ),
+        |(progress, rotation, indeterminate, thickness, track_color, progress_color)| {
+            Box::new(move |canvas, _, area| {
+                let inset = thickness / 2.0;
+                let oval = Rect::new(inset, inset, area.width() - inset, area.height() - inset);
+
+                let mut paint = Paint::default();
+                paint.set_anti_alias(true);
+                paint.set_style(PaintStyle::Stroke);
+                paint.set_stroke_width(thickness);
+                paint.set_stroke_cap(PaintCap::Round);
+
+                // Draw the track
+                paint.set_color(Color::parse(&track_color).unwrap_or(Color::TRANSPARENT));
+                let mut track = Path::new();
+                track.add_arc(&oval, 0.0, 360.0);
+                canvas.draw_path(&track, &paint);
+
+                // Draw the progress arc, starting from the top
+                let (start_angle, sweep_angle) = if indeterminate {
+                    (rotation - 90.0, 90.0)
+                } else {
+                    (-90.0, progress.clamp(0.0, 100.0) / 100.0 * 360.0)
+                };
+
+                if sweep_angle > 0.0 {
+                    paint.set_color(Color::parse(&progress_color).unwrap_or(Color::TRANSPARENT));
+                    let mut arc = Path::new();
+                    arc.add_arc(&oval, start_angle, sweep_angle);
+                    canvas.draw_path(&arc, &paint);
+                }
+            })
+        },
+    );
+
+    rsx!(
+        rect {
+            canvas_reference: canvas.attribute(),
+            width: "{size}",
+            height: "{size}",
+            main_align: "center",
+            cross_align: "center",
+            font_size: "12",
+            role: "progressIndicator",
+            if show_progress && !indeterminate {
+                label {
+                    color: "{color}",
+                    max_lines: "1",
+                    "{progress.floor()}%"
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[tokio::test]
+    pub async fn circular_progress() {
+        fn circular_progress_app() -> Element {
+            let mut indeterminate = use_signal(|| false);
+
+            rsx!(
+                rect {
+                    onclick: move |_| indeterminate.toggle(),
+                    CircularProgress {
+                        progress: 42.5,
+                        show_progress: true,
+                        indeterminate: *indeterminate.read()
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(circular_progress_app);
+        let ring = utils.root().get(0).get(0);
+        utils.wait_for_update().await;
+
+        assert_eq!(ring.area().unwrap().width(), 48.0);
+        assert_eq!(ring.get(0).get(0).text(), Some("42%"));
+
+        // The label is hidden while spinning
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert!(ring.child(0).and_then(|label| label.child(0)).is_none());
+    }
+}
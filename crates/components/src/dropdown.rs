@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::time::{Duration, Instant};
 
 use crate::icons::ArrowIcon;
 use dioxus::prelude::*;
@@ -8,7 +9,7 @@ use freya_elements::events::{KeyboardEvent, MouseEvent};
 
 use freya_hooks::{
     theme_with, use_applied_theme, use_focus, use_platform, ArrowIconThemeWith,
-    DropdownItemThemeWith, DropdownTheme, DropdownThemeWith,
+    DropdownItemThemeWith, DropdownTheme, DropdownThemeWith, UseFocus,
 };
 use winit::window::CursorIcon;
 
@@ -25,6 +26,39 @@ pub struct DropdownItemProps<T: 'static + Clone + PartialEq> {
     pub onclick: Option<EventHandler<()>>,
 }
 
+/// Time after which the typed characters are forgotten by the type-ahead.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// A [`DropdownItem`] that can be highlighted with the keyboard.
+struct DropdownOption<T> {
+    value: T,
+    focus: UseFocus,
+}
+
+/// Characters typed recently to jump to an item.
+#[derive(Default)]
+struct TypeAhead {
+    buffer: String,
+    last_typed: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// Add a character and get the whole typed text.
+    fn push(&mut self, text: &str) -> String {
+        let now = Instant::now();
+        let expired = self
+            .last_typed
+            .map(|last_typed| now.duration_since(last_typed) > TYPE_AHEAD_TIMEOUT)
+            .unwrap_or(true);
+        if expired {
+            self.buffer.clear();
+        }
+        self.buffer.push_str(&text.to_lowercase());
+        self.last_typed = Some(now);
+        self.buffer.clone()
+    }
+}
+
 /// Current status of the DropdownItem.
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub enum DropdownItemStatus {
@@ -55,6 +89,7 @@ where
     T: PartialEq + 'static,
 {
     let selected = use_context::<Signal<T>>();
+    let mut options = use_context::<Signal<Vec<DropdownOption<T>>>>();
     let theme = use_applied_theme!(&theme, dropdown_item);
     let focus = use_focus();
     let mut status = use_signal(DropdownItemStatus::default);
@@ -72,10 +107,20 @@ where
     };
     let color = theme.font_theme.color;
 
+    // Register the item so it can be highlighted with the keyboard
+    use_hook({
+        to_owned![value];
+        move || options.write().push(DropdownOption { value, focus })
+    });
+
     use_drop(move || {
         if *status.peek() == DropdownItemStatus::Hovering {
             platform.set_cursor(CursorIcon::default());
         }
+        // The dropdown might have been dropped already
+        if let Ok(mut options) = options.try_write() {
+            options.retain(|option| option.focus.id() != focus.id());
+        }
     });
 
     let onmouseenter = move |_| {
@@ -153,6 +198,10 @@ pub enum DropdownStatus {
 /// # Styling
 /// Inherits the [`DropdownTheme`](freya_hooks::DropdownTheme) theme.
 ///
+/// # Keyboard
+/// When focused, it opens with `Enter`, `Space` or the arrow keys. The arrow keys move the highlight,
+/// `Enter` selects the highlighted item and `Escape` closes it. Typing jumps to the first item that starts with the typed text.
+///
 /// # Example
 /// ```no_run
 /// # use freya::prelude::*;
@@ -183,6 +232,8 @@ where
     T: PartialEq + Clone + Display + 'static,
 {
     let mut selected = use_context_provider(|| Signal::new(props.value.clone()));
+    let options = use_context_provider(|| Signal::new(Vec::<DropdownOption<T>>::new()));
+    let mut type_ahead = use_signal(TypeAhead::default);
     let theme = use_applied_theme!(&props.theme, dropdown);
     let mut focus = use_focus();
     let mut status = use_signal(DropdownStatus::default);
@@ -215,15 +266,56 @@ where
     };
 
     let onkeydown = move |e: KeyboardEvent| {
-        match e.key {
-            // Close when `Escape` key is pressed
-            Key::Escape => {
+        let options = options.read();
+        let highlighted = options.iter().position(|option| option.focus.is_focused());
+
+        // Ignore the keys meant for other elements
+        if !is_focused && highlighted.is_none() {
+            return;
+        }
+
+        match &e.key {
+            // Close and focus the dropdown back when `Escape` is pressed
+            Key::Escape if is_opened => {
                 opened.set(false);
+                focus.focus();
             }
-            // Open the dropdown items when the `Enter` key is pressed
-            Key::Enter if is_focused && !is_opened => {
+            // Open the dropdown items when the `Enter`, `Space` or arrow keys are pressed
+            Key::Enter | Key::ArrowDown | Key::ArrowUp if !is_opened => {
                 opened.set(true);
             }
+            Key::Character(c) if c == " " && !is_opened => {
+                opened.set(true);
+            }
+            // The highlighted item is selected by itself, close and focus the dropdown back
+            Key::Enter if highlighted.is_some() => {
+                opened.set(false);
+                focus.focus();
+            }
+            // Move the highlight, the selected item is highlighted first
+            Key::ArrowDown | Key::ArrowUp if !options.is_empty() => {
+                let next = match (highlighted, &e.key) {
+                    (None, _) => options
+                        .iter()
+                        .position(|option| option.value == *selected.peek())
+                        .unwrap_or_default(),
+                    (Some(current), Key::ArrowDown) => (current + 1) % options.len(),
+                    (Some(current), _) => (current + options.len() - 1) % options.len(),
+                };
+                let mut focus = options[next].focus;
+                focus.focus();
+            }
+            // Jump to the first item that starts with the typed text
+            Key::Character(c) if is_opened => {
+                let prefix = type_ahead.write().push(c);
+                let option = options
+                    .iter()
+                    .find(|option| option.value.to_string().to_lowercase().starts_with(&prefix));
+                if let Some(option) = option {
+                    let mut focus = option.focus;
+                    focus.focus();
+                }
+            }
             _ => {}
         }
     };
@@ -288,7 +380,6 @@ where
                 height: "0",
                 rect {
                     onglobalclick,
-                    onkeydown,
                     layer: "-99",
                     margin: "4",
                     border: "1 solid {border_fill}",
@@ -395,4 +486,96 @@ mod test {
         // The second optio was selected
         assert_eq!(label.get(0).text(), Some("Value B"));
     }
+
+    #[tokio::test]
+    pub async fn dropdown_keyboard() {
+        fn dropdown_app() -> Element {
+            let values = use_hook(|| {
+                vec![
+                    "Apple".to_string(),
+                    "Banana".to_string(),
+                    "Blueberry".to_string(),
+                ]
+            });
+            let mut selected_dropdown = use_signal(|| "Apple".to_string());
+
+            rsx!(
+                Dropdown {
+                    value: selected_dropdown.read().clone(),
+                    for ch in values {
+                        DropdownItem {
+                            value: ch.clone(),
+                            onclick: {
+                                to_owned![ch];
+                                move |_| selected_dropdown.set(ch.clone())
+                            },
+                            label { "{ch}" }
+                        }
+                    }
+                }
+            )
+        }
+
+        fn key(key: Key, code: Code) -> PlatformEvent {
+            PlatformEvent::Keyboard {
+                name: EventName::KeyDown,
+                key,
+                code,
+                modifiers: Modifiers::default(),
+            }
+        }
+
+        let mut utils = launch_test(dropdown_app);
+        let root = utils.root();
+        let label = root.get(0).get(0);
+        utils.wait_for_update().await;
+
+        let start_size = utils.sdom().get().layout().size();
+
+        // Focus the dropdown
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        let dropdown_focus = utils.focus_id();
+
+        // Close it with `Escape`
+        utils.push_event(key(Key::Escape, Code::Escape));
+        utils.wait_for_update().await;
+        assert_eq!(utils.sdom().get().layout().size(), start_size);
+
+        // Open it with `Enter`
+        utils.push_event(key(Key::Enter, Code::Enter));
+        utils.wait_for_update().await;
+        assert!(utils.sdom().get().layout().size() > start_size);
+
+        // Highlight the selected item and then the next one
+        utils.push_event(key(Key::ArrowDown, Code::ArrowDown));
+        utils.wait_for_update().await;
+        utils.push_event(key(Key::ArrowDown, Code::ArrowDown));
+        utils.wait_for_update().await;
+        assert_ne!(utils.focus_id(), dropdown_focus);
+
+        // Select it, the dropdown is closed and focused back
+        utils.push_event(key(Key::Enter, Code::Enter));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("Banana"));
+        assert_eq!(utils.sdom().get().layout().size(), start_size);
+        assert_eq!(utils.focus_id(), dropdown_focus);
+
+        // Open it again and jump to "Blueberry" by typing
+        utils.push_event(key(Key::Character(" ".to_string()), Code::Space));
+        utils.wait_for_update().await;
+        utils.push_event(key(Key::Character("b".to_string()), Code::KeyB));
+        utils.wait_for_update().await;
+        utils.push_event(key(Key::Character("l".to_string()), Code::KeyL));
+        utils.wait_for_update().await;
+        utils.push_event(key(Key::Enter, Code::Enter));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("Blueberry"));
+    }
 }
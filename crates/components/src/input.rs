@@ -4,8 +4,8 @@ use freya_elements::events::keyboard::Key;
 use freya_elements::events::{KeyboardData, MouseEvent};
 use freya_hooks::use_platform;
 use freya_hooks::{
-    use_applied_theme, use_editable, use_focus, EditableConfig, EditableEvent, EditableMode,
-    FontTheme, InputTheme, InputThemeWith, TextEditor,
+    use_applied_theme, use_caret_blink, use_editable, use_focus, EditableConfig, EditableEvent,
+    EditableMode, FontTheme, InputTheme, InputThemeWith, TextEditor,
 };
 
 use winit::window::CursorIcon;
@@ -48,6 +48,16 @@ pub struct InputProps {
     /// Display mode for Input. By default, input text is shown as it is provided.
     #[props(default = InputMode::Shown, into)]
     pub mode: InputMode,
+    /// Maximum amount of characters the Input accepts.
+    pub max_length: Option<usize>,
+    /// Only the characters accepted by this filter can be written into the Input.
+    pub filter: Option<fn(char) -> bool>,
+    /// Grow the Input horizontally to fit its text. The theme `width` is used as the minimum width.
+    #[props(default = false)]
+    pub auto_size: bool,
+    /// Maximum width the Input can grow to when `auto_size` is enabled.
+    #[props(default = "100%".to_string(), into)]
+    pub max_width: String,
 }
 
 /// `Input` component.
@@ -78,6 +88,26 @@ pub struct InputProps {
 ///     )
 /// }
 /// ```
+///
+/// Restrict the Input to at most 4 digits:
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut value = use_signal(String::new);
+///
+///     rsx!(
+///         Input {
+///             value: value.read().clone(),
+///             max_length: 4,
+///             filter: |ch: char| ch.is_ascii_digit(),
+///             onchange: move |e| {
+///                  value.set(e)
+///             }
+///         }
+///     )
+/// }
+/// ```
 #[allow(non_snake_case)]
 pub fn Input(
     InputProps {
@@ -85,6 +115,10 @@ pub fn Input(
         value,
         onchange,
         mode,
+        max_length,
+        filter,
+        auto_size,
+        max_width,
     }: InputProps,
 ) -> Element {
     let platform = use_platform();
@@ -95,6 +129,7 @@ pub fn Input(
     );
     let theme = use_applied_theme!(&theme, input);
     let mut focus = use_focus();
+    let caret = use_caret_blink(focus.is_focused());
 
     if &value != editable.editor().read().rope() {
         editable.editor_mut().write().set(&value);
@@ -113,14 +148,31 @@ pub fn Input(
 
     let onkeydown = move |e: Event<KeyboardData>| {
         if focus.is_focused() && e.data.key != Key::Enter {
+            let restricted = max_length.is_some() || filter.is_some();
+            let previous = restricted.then(|| editable.editor().peek().clone());
+
             editable.process_event(&EditableEvent::KeyDown(e.data));
-            onchange.call(editable.editor().peek().to_string());
+            caret.reset();
+
+            let new_value = editable.editor().peek().to_string();
+
+            // Rejected edits are reverted, including the cursor and the history
+            if let Some(previous) = previous {
+                let previous_value = previous.to_string();
+                if !is_edit_allowed(&previous_value, &new_value, max_length, filter) {
+                    editable.editor_mut().set(previous);
+                    return;
+                }
+            }
+
+            onchange.call(new_value);
         }
     };
 
     let onmousedown = move |e: MouseEvent| {
         editable.process_event(&EditableEvent::MouseDown(e.data, 0));
         focus.focus();
+        caret.reset();
     };
 
     let onmouseover = move |e: MouseEvent| {
@@ -167,10 +219,25 @@ pub fn Input(
         font_theme: FontTheme { color },
         ..
     } = theme;
+    // The caret is hidden with a transparent color so the text doesn't need to be laid out again
+    let cursor_color = if caret.is_visible() {
+        color.as_ref()
+    } else {
+        "transparent"
+    };
+    // Auto sized inputs grow with the text, between the theme width and the max width
+    let (min_width, max_width, text_width) = if auto_size {
+        (width.to_string(), max_width, "auto")
+    } else {
+        (width.to_string(), width.to_string(), "100%")
+    };
+    let width = if auto_size { "auto" } else { width.as_ref() };
 
     rsx!(
         rect {
             width: "{width}",
+            min_width: "{min_width}",
+            max_width: "{max_width}",
             direction: "vertical",
             color: "{color}",
             background: "{background}",
@@ -191,11 +258,11 @@ pub fn Input(
                 onmouseleave,
                 onmousedown,
                 onmouseover,
-                width: "100%",
+                width: text_width,
                 cursor_id: "0",
                 cursor_index: "{cursor_char}",
                 cursor_mode: "editable",
-                cursor_color: "{color}",
+                cursor_color,
                 max_lines: "1",
                 highlights,
                 text {
@@ -206,6 +273,31 @@ pub fn Input(
     )
 }
 
+/// Check if an edit respects the `max_length` and `filter` of an [`Input`].
+/// Edits that don't make an already invalid value worse, like removing characters, are always allowed.
+fn is_edit_allowed(
+    previous: &str,
+    new: &str,
+    max_length: Option<usize>,
+    filter: Option<fn(char) -> bool>,
+) -> bool {
+    if let Some(max_length) = max_length {
+        let (previous_len, new_len) = (previous.chars().count(), new.chars().count());
+        if new_len > max_length && new_len > previous_len {
+            return false;
+        }
+    }
+
+    if let Some(filter) = filter {
+        let rejected = |text: &str| text.chars().filter(|ch| !filter(*ch)).count();
+        if rejected(new) > rejected(previous) {
+            return false;
+        }
+    }
+
+    true
+}
+
 #[cfg(test)]
 mod test {
     use freya::prelude::*;
@@ -257,4 +349,195 @@ mod test {
         // Check that "d" has been written into the input.
         assert_eq!(text.get(0).text(), Some("Hello, World"));
     }
+
+    #[tokio::test]
+    pub async fn input_type_text() {
+        fn input_app() -> Element {
+            let mut value = use_signal(String::new);
+            let mut changes = use_signal(|| 0);
+
+            rsx!(
+                Input {
+                    value: value.read().clone(),
+                    onchange: move |new_value| {
+                        value.set(new_value);
+                        changes += 1;
+                    }
+                }
+                label {
+                    "{changes}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0);
+        let changes = root.get(1);
+        utils.wait_for_update().await;
+
+        // Focus the input
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (15., 10.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        utils.type_text("Hello World").await;
+
+        assert_eq!(text.get(0).text(), Some("Hello World"));
+        assert_eq!(changes.get(0).text(), Some("11"));
+    }
+
+    #[tokio::test]
+    pub async fn input_max_length() {
+        fn input_app() -> Element {
+            let mut value = use_signal(String::new);
+            let mut changes = use_signal(|| 0);
+
+            rsx!(
+                Input {
+                    value: value.read().clone(),
+                    max_length: 5,
+                    onchange: move |new_value| {
+                        value.set(new_value);
+                        changes += 1;
+                    }
+                }
+                label {
+                    "{changes}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0);
+        let changes = root.get(1);
+        utils.wait_for_update().await;
+
+        // Focus the input
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (15., 10.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        utils.type_text("Hello World").await;
+
+        // The characters beyond the limit are dropped
+        assert_eq!(text.get(0).text(), Some("Hello"));
+        assert_eq!(changes.get(0).text(), Some("5"));
+
+        // Removing characters is still possible
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Backspace,
+            code: Code::Backspace,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(text.get(0).text(), Some("Hell"));
+
+        utils.type_text("!?").await;
+
+        assert_eq!(text.get(0).text(), Some("Hell!"));
+    }
+
+    #[tokio::test]
+    pub async fn input_filter() {
+        fn input_app() -> Element {
+            let mut value = use_signal(String::new);
+            let mut changes = use_signal(|| 0);
+
+            rsx!(
+                Input {
+                    value: value.read().clone(),
+                    filter: |ch: char| ch.is_ascii_digit(),
+                    onchange: move |new_value| {
+                        value.set(new_value);
+                        changes += 1;
+                    }
+                }
+                label {
+                    "{changes}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0);
+        let changes = root.get(1);
+        utils.wait_for_update().await;
+
+        // Focus the input
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (15., 10.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        utils.type_text("1a2 b3").await;
+
+        // Only the digits are written
+        assert_eq!(text.get(0).text(), Some("123"));
+        assert_eq!(changes.get(0).text(), Some("3"));
+    }
+
+    #[tokio::test]
+    pub async fn input_auto_size() {
+        fn input_app() -> Element {
+            let mut value = use_signal(String::new);
+
+            rsx!(Input {
+                value: value.read().clone(),
+                auto_size: true,
+                max_width: "300",
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            })
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let input = root.get(0);
+        utils.wait_for_update().await;
+
+        // Empty inputs use the theme width as minimum width
+        let initial_width = input.layout().unwrap().visible_area().width();
+        assert_eq!(initial_width, 150.0);
+
+        // Focus the input
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (15., 10.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        utils.type_text("Hello World, this is Freya").await;
+        utils.wait_for_update().await;
+
+        // The input grows with the text
+        let grown_width = input.layout().unwrap().visible_area().width();
+        assert!(grown_width > initial_width);
+
+        utils
+            .type_text(" and it keeps growing until it reaches the max width")
+            .await;
+        utils.wait_for_update().await;
+
+        // But never beyond the max width
+        assert_eq!(input.layout().unwrap().visible_area().width(), 300.0);
+    }
 }
@@ -2,9 +2,14 @@
 //! A collection of basic components to be used in Freya.
 
 mod accordion;
+mod animated_outlet;
 mod body;
+mod breadcrumb;
 mod button;
+#[cfg(feature = "use_camera")]
+mod camera_view;
 mod canvas;
+mod circular_progress;
 mod cursor_area;
 mod drag_drop;
 mod dropdown;
@@ -14,20 +19,29 @@ mod icons;
 mod input;
 mod link;
 mod loader;
+#[cfg(feature = "network_image")]
 mod network_image;
+mod pagination;
 mod progress_bar;
+mod range_slider;
 mod scroll_views;
 mod sidebar;
 mod slider;
+mod split_pane;
 mod switch;
 mod table;
 mod theme;
 mod tooltip;
 
 pub use accordion::*;
+pub use animated_outlet::*;
 pub use body::*;
+pub use breadcrumb::*;
 pub use button::*;
+#[cfg(feature = "use_camera")]
+pub use camera_view::*;
 pub use canvas::*;
+pub use circular_progress::*;
 pub use cursor_area::*;
 pub use drag_drop::*;
 pub use dropdown::*;
@@ -37,11 +51,15 @@ pub use icons::*;
 pub use input::*;
 pub use link::*;
 pub use loader::*;
+#[cfg(feature = "network_image")]
 pub use network_image::*;
+pub use pagination::*;
 pub use progress_bar::*;
+pub use range_slider::*;
 pub use scroll_views::*;
 pub use sidebar::*;
 pub use slider::*;
+pub use split_pane::*;
 pub use switch::*;
 pub use table::*;
 pub use theme::*;
@@ -1,9 +1,9 @@
 use crate::Tooltip;
 use dioxus::prelude::*;
-use dioxus_router::prelude::{navigator, IntoRoutable};
+use dioxus_router::prelude::IntoRoutable;
 use freya_elements::elements as dioxus_elements;
 use freya_elements::events::MouseEvent;
-use freya_hooks::{use_applied_theme, LinkThemeWith};
+use freya_hooks::{use_applied_theme, use_navigation, LinkThemeWith};
 use std::borrow::Cow;
 use winit::event::MouseButton;
 
@@ -23,6 +23,7 @@ pub enum LinkTooltip {
 /// Similar to [`Link`](dioxus_router::components::Link), but you can use it in Freya.
 /// Both internal routes (dioxus-router) and external links are supported. When using internal routes
 /// make sure the Link is descendant of a [`Router`](dioxus_router::components::Router) component.
+/// Internal routes go through the navigation guards and history of [`use_navigation`](freya_hooks::use_navigation).
 ///
 /// # Styling
 ///
@@ -97,6 +98,7 @@ pub fn Link(
 ) -> Element {
     let theme = use_applied_theme!(&theme, link);
     let mut is_hovering = use_signal(|| false);
+    let navigation = use_navigation();
 
     let url = if let IntoRoutable::FromStr(ref url) = to {
         Some(url.clone())
@@ -130,8 +132,7 @@ pub fn Link(
 
                 // TODO(marc2332): Log unhandled errors
             } else {
-                let router = navigator();
-                router.push(to.clone());
+                navigation.push(to.clone());
             }
         }
     };
@@ -1,9 +1,14 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+};
+
 use crate::Loader;
 use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
 
 use freya_hooks::{use_applied_theme, use_focus, NetworkImageTheme, NetworkImageThemeWith};
-use freya_node_state::bytes_to_data;
+use freya_node_state::shared_bytes_to_data;
 use reqwest::Url;
 
 /// [`NetworkImage`] component properties.
@@ -58,8 +63,15 @@ pub enum ImageStatus {
 #[allow(non_snake_case)]
 pub fn NetworkImage(props: NetworkImageProps) -> Element {
     let focus = use_focus();
-    let mut status = use_signal(|| ImageStatus::Loading);
-    let mut image_bytes = use_signal::<Option<Vec<u8>>>(|| None);
+    // Images that were already fetched are shown right away
+    let mut image_bytes = use_signal(|| cached_image(&props.url));
+    let mut status = use_signal(|| {
+        if image_bytes.peek().is_some() {
+            ImageStatus::Loaded
+        } else {
+            ImageStatus::Loading
+        }
+    });
 
     let focus_id = focus.attribute();
     let NetworkImageTheme { width, height } = use_applied_theme!(&props.theme, network_image);
@@ -68,14 +80,24 @@ pub fn NetworkImage(props: NetworkImageProps) -> Element {
     // TODO: Waiting for a dependency-based use_effect
     let _ = use_memo_with_dependencies(&props.url, move |url| {
         spawn(async move {
+            // Reuse the image if it was already fetched
+            if let Some(bytes) = cached_image(&url) {
+                image_bytes.set(Some(bytes));
+                status.set(ImageStatus::Loaded);
+                return;
+            }
+
             // Loading image
             status.set(ImageStatus::Loading);
-            let img = fetch_image(url).await;
-            if let Ok(img) = img {
+            // Fetch it in the background runtime so the UI thread is not blocked
+            let img = tokio::spawn(fetch_image(url.clone())).await;
+            if let Ok(Ok(img)) = img {
                 // Image loaded
+                let img = Arc::new(img);
+                cache_image(url, img.clone());
                 image_bytes.set(Some(img));
                 status.set(ImageStatus::Loaded)
-            } else if let Err(_err) = img {
+            } else {
                 // Image errored
                 image_bytes.set(None);
                 status.set(ImageStatus::Errored)
@@ -117,7 +139,7 @@ pub fn NetworkImage(props: NetworkImageProps) -> Element {
     } else {
         rsx!({
             image_bytes.as_ref().map(|bytes| {
-                let image_data = bytes_to_data(&bytes);
+                let image_data = shared_bytes_to_data(bytes.clone());
                 rsx!(image {
                     height: "{height}",
                     width: "{width}",
@@ -131,8 +153,172 @@ pub fn NetworkImage(props: NetworkImageProps) -> Element {
     }
 }
 
+/// Memory budget of the images fetched by the [`NetworkImage`]s, 64MB.
+pub const NETWORK_IMAGES_CACHE_SIZE: usize = 64 * 1024 * 1024;
+
+struct CachedImage {
+    bytes: Arc<Vec<u8>>,
+    last_used: u64,
+}
+
+/// Least recently used cache of the encoded images fetched by the [`NetworkImage`]s, keyed by their URL.
+///
+/// Images are evicted once the cache goes over its memory budget.
+struct NetworkImagesCache {
+    images: HashMap<Url, CachedImage>,
+    capacity: usize,
+    bytes: usize,
+    clock: u64,
+}
+
+impl NetworkImagesCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            images: HashMap::new(),
+            capacity,
+            bytes: 0,
+            clock: 0,
+        }
+    }
+
+    fn get(&mut self, url: &Url) -> Option<Arc<Vec<u8>>> {
+        self.clock += 1;
+        let image = self.images.get_mut(url)?;
+        image.last_used = self.clock;
+        Some(image.bytes.clone())
+    }
+
+    fn insert(&mut self, url: Url, bytes: Arc<Vec<u8>>) {
+        // Images bigger than the whole budget are not cached
+        if bytes.len() > self.capacity {
+            return;
+        }
+
+        self.clock += 1;
+        self.bytes += bytes.len();
+        let image = CachedImage {
+            bytes,
+            last_used: self.clock,
+        };
+        if let Some(previous) = self.images.insert(url, image) {
+            self.bytes -= previous.bytes.len();
+        }
+        self.evict();
+    }
+
+    fn clear(&mut self) {
+        self.images.clear();
+        self.bytes = 0;
+    }
+
+    /// Remove the least recently used images until the cache is under its memory budget.
+    fn evict(&mut self) {
+        while self.bytes > self.capacity {
+            let Some(url) = self
+                .images
+                .iter()
+                .min_by_key(|(_, image)| image.last_used)
+                .map(|(url, _)| url.clone())
+            else {
+                break;
+            };
+
+            if let Some(image) = self.images.remove(&url) {
+                self.bytes -= image.bytes.len();
+            }
+        }
+    }
+}
+
+/// Images that were already fetched, shared by all the [`NetworkImage`]s.
+static IMAGES_CACHE: OnceLock<Mutex<NetworkImagesCache>> = OnceLock::new();
+
+fn images_cache() -> &'static Mutex<NetworkImagesCache> {
+    IMAGES_CACHE.get_or_init(|| Mutex::new(NetworkImagesCache::new(NETWORK_IMAGES_CACHE_SIZE)))
+}
+
+fn cached_image(url: &Url) -> Option<Arc<Vec<u8>>> {
+    images_cache().lock().unwrap().get(url)
+}
+
+fn cache_image(url: Url, bytes: Arc<Vec<u8>>) {
+    images_cache().lock().unwrap().insert(url, bytes);
+}
+
+/// Remove the images fetched by the [`NetworkImage`]s, so they are fetched again the next time they are shown.
+pub fn clear_network_images_cache() {
+    images_cache().lock().unwrap().clear();
+}
+
 async fn fetch_image(url: Url) -> Result<Vec<u8>, reqwest::Error> {
     let res = reqwest::get(url).await?;
     let data = res.bytes().await?;
     Ok(data.to_vec())
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use freya::prelude::*;
+    use freya_testing::*;
+    use reqwest::Url;
+
+    use super::NetworkImagesCache;
+
+    #[test]
+    pub fn network_images_cache_evicts_least_recently_used() {
+        let url = |name: &str| Url::parse(&format!("https://example.com/{name}.png")).unwrap();
+        let image = |size: usize| Arc::new(vec![0; size]);
+
+        let mut cache = NetworkImagesCache::new(100);
+        cache.insert(url("a"), image(40));
+        cache.insert(url("b"), image(40));
+
+        // Using an image makes it the most recently used one
+        assert!(cache.get(&url("a")).is_some());
+
+        // Going over the budget removes the least recently used image
+        cache.insert(url("c"), image(40));
+        assert!(cache.get(&url("a")).is_some());
+        assert!(cache.get(&url("b")).is_none());
+        assert!(cache.get(&url("c")).is_some());
+        assert_eq!(cache.bytes, 80);
+
+        // Replacing an image doesn't count it twice
+        cache.insert(url("c"), image(20));
+        assert_eq!(cache.bytes, 60);
+
+        // Images bigger than the whole budget are not cached
+        cache.insert(url("d"), image(200));
+        assert!(cache.get(&url("d")).is_none());
+        assert_eq!(cache.bytes, 60);
+    }
+
+    #[tokio::test]
+    pub async fn network_image_fallback() {
+        fn network_image_app() -> Element {
+            rsx!(NetworkImage {
+                // Nothing listens in this port, so the request fails
+                url: "http://127.0.0.1:1/image.png".parse().unwrap(),
+                fallback: rsx!(label { "Could not load" })
+            })
+        }
+
+        let mut utils = launch_test(network_image_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+
+        // Wait for the request to fail
+        for _ in 0..20 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            utils.wait_for_update().await;
+            if root.get(0).get(0).text().is_some() {
+                break;
+            }
+        }
+
+        assert_eq!(root.get(0).get(0).text(), Some("Could not load"));
+    }
+}
@@ -0,0 +1,395 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_elements::events::keyboard::Key;
+use freya_elements::events::{KeyboardEvent, MouseEvent};
+use freya_hooks::{
+    use_applied_theme, use_focus, use_platform, PaginationTheme, PaginationThemeWith,
+};
+use winit::window::CursorIcon;
+
+use crate::ButtonStatus;
+
+/// An entry of the [`Pagination`] controls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaginationItem {
+    /// A button for the page with the given index.
+    Page(usize),
+    /// A placeholder for the hidden pages.
+    Ellipsis,
+}
+
+/// Compute which page buttons to show for the given `page` out of `pages`.
+///
+/// The first and last pages are always shown, together with `siblings` pages on each side of the current one.
+/// The rest of pages are replaced by [`PaginationItem::Ellipsis`]. The amount of items is always the same
+/// so the controls don't jump around while navigating.
+pub fn pagination_items(page: usize, pages: usize, siblings: usize) -> Vec<PaginationItem> {
+    // First, last, current and two ellipsis
+    let slots = siblings * 2 + 5;
+
+    if pages <= slots {
+        return (0..pages).map(PaginationItem::Page).collect();
+    }
+
+    let page = page.min(pages - 1);
+    let left = page.saturating_sub(siblings);
+    let right = (page + siblings).min(pages - 1);
+    let edge_count = siblings * 2 + 3;
+
+    let mut items = Vec::with_capacity(slots);
+
+    match (left > 2, right < pages - 3) {
+        (false, _) => {
+            items.extend((0..edge_count).map(PaginationItem::Page));
+            items.push(PaginationItem::Ellipsis);
+            items.push(PaginationItem::Page(pages - 1));
+        }
+        (true, false) => {
+            items.push(PaginationItem::Page(0));
+            items.push(PaginationItem::Ellipsis);
+            items.extend((pages - edge_count..pages).map(PaginationItem::Page));
+        }
+        (true, true) => {
+            items.push(PaginationItem::Page(0));
+            items.push(PaginationItem::Ellipsis);
+            items.extend((left..=right).map(PaginationItem::Page));
+            items.push(PaginationItem::Ellipsis);
+            items.push(PaginationItem::Page(pages - 1));
+        }
+    }
+
+    items
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn PaginationButton(
+    theme: Option<PaginationThemeWith>,
+    is_active: bool,
+    is_disabled: bool,
+    onclick: EventHandler<()>,
+    children: Element,
+) -> Element {
+    let PaginationTheme {
+        background,
+        hover_background,
+        active_background,
+        active_color,
+        disabled_color,
+        border_fill,
+        corner_radius,
+        font_theme,
+        ..
+    } = use_applied_theme!(&theme, pagination);
+    let mut status = use_signal(ButtonStatus::default);
+    let platform = use_platform();
+
+    use_drop(move || {
+        if *status.peek() == ButtonStatus::Hovering {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onmouseenter = move |_| {
+        if !is_disabled {
+            platform.set_cursor(CursorIcon::Pointer);
+            status.set(ButtonStatus::Hovering);
+        }
+    };
+
+    let onmouseleave = move |_| {
+        if *status.peek() == ButtonStatus::Hovering {
+            platform.set_cursor(CursorIcon::default());
+            status.set(ButtonStatus::default());
+        }
+    };
+
+    let onclick = move |_: MouseEvent| {
+        if !is_disabled {
+            onclick.call(());
+        }
+    };
+
+    let (background, color) = match *status.read() {
+        _ if is_active => (active_background, active_color),
+        _ if is_disabled => (background, disabled_color),
+        ButtonStatus::Hovering => (hover_background, font_theme.color),
+        ButtonStatus::Idle => (background, font_theme.color),
+    };
+
+    rsx!(
+        rect {
+            onmouseenter,
+            onmouseleave,
+            onclick,
+            min_width: "32",
+            height: "32",
+            margin: "0 2",
+            padding: "0 8",
+            main_align: "center",
+            cross_align: "center",
+            corner_radius: "{corner_radius}",
+            border: "1 solid {border_fill}",
+            background: "{background}",
+            color: "{color}",
+            {children}
+        }
+    )
+}
+
+/// Controls to navigate through a list of pages.
+///
+/// Pages are zero-indexed, so `page: 0` is displayed as "1".
+/// When focused, the arrow keys go to the previous or next page, and `Home`/`End` go to the first or last page.
+///
+/// # Styling
+/// Inherits the [`PaginationTheme`](freya_hooks::PaginationTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut page = use_signal(|| 0);
+///
+///     rsx!(
+///         Pagination {
+///             page: *page.read(),
+///             pages: 20,
+///             onchange: move |p| page.set(p)
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Pagination(
+    /// Theme override.
+    theme: Option<PaginationThemeWith>,
+    /// Index of the current page.
+    page: usize,
+    /// Total number of pages.
+    pages: usize,
+    /// Number of pages shown on each side of the current page.
+    #[props(default = 1)]
+    siblings: usize,
+    /// Handler for the `onchange` event, called with the index of the new page.
+    onchange: EventHandler<usize>,
+) -> Element {
+    let PaginationTheme {
+        focus_border_fill,
+        font_theme,
+        ..
+    } = use_applied_theme!(&theme, pagination);
+    let mut focus = use_focus();
+
+    let focus_id = focus.attribute();
+    let last_page = pages.saturating_sub(1);
+    let has_previous = page > 0;
+    let has_next = page < last_page;
+
+    let go_to = move |new_page: usize| {
+        focus.focus();
+        let new_page = new_page.min(last_page);
+        if new_page != page {
+            onchange.call(new_page);
+        }
+    };
+
+    let onkeydown = {
+        to_owned![go_to];
+        move |e: KeyboardEvent| {
+            if !focus.is_focused() {
+                return;
+            }
+            match e.key {
+                Key::ArrowLeft if has_previous => go_to(page - 1),
+                Key::ArrowRight if has_next => go_to(page + 1),
+                Key::Home => go_to(0),
+                Key::End => go_to(last_page),
+                _ => {}
+            }
+        }
+    };
+
+    let border = if focus.is_selected() {
+        format!("2 solid {focus_border_fill}")
+    } else {
+        "none".to_string()
+    };
+
+    let items = pagination_items(page, pages, siblings);
+
+    rsx!(
+        rect {
+            onkeydown,
+            focus_id,
+            direction: "horizontal",
+            cross_align: "center",
+            padding: "2",
+            corner_radius: "8",
+            border: "{border}",
+            color: "{font_theme.color}",
+            PaginationButton {
+                theme: theme.clone(),
+                is_active: false,
+                is_disabled: !has_previous,
+                onclick: {
+                    to_owned![go_to];
+                    move |_| go_to(page.saturating_sub(1))
+                },
+                label { "‹" }
+            }
+            for (i, item) in items.into_iter().enumerate() {
+                if let PaginationItem::Page(item_page) = item {
+                    PaginationButton {
+                        key: "{i}",
+                        theme: theme.clone(),
+                        is_active: item_page == page,
+                        is_disabled: false,
+                        onclick: {
+                            to_owned![go_to];
+                            move |_| go_to(item_page)
+                        },
+                        label { "{item_page + 1}" }
+                    }
+                } else {
+                    label {
+                        key: "{i}",
+                        margin: "0 6",
+                        "…"
+                    }
+                }
+            }
+            PaginationButton {
+                theme: theme.clone(),
+                is_active: false,
+                is_disabled: !has_next,
+                onclick: {
+                    to_owned![go_to];
+                    move |_| go_to(page + 1)
+                },
+                label { "›" }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[test]
+    pub fn pagination_items_ellipsis() {
+        use PaginationItem::*;
+
+        assert_eq!(
+            pagination_items(0, 4, 1),
+            vec![Page(0), Page(1), Page(2), Page(3)]
+        );
+        assert_eq!(
+            pagination_items(0, 10, 1),
+            vec![
+                Page(0),
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Ellipsis,
+                Page(9)
+            ]
+        );
+        assert_eq!(
+            pagination_items(5, 10, 1),
+            vec![
+                Page(0),
+                Ellipsis,
+                Page(4),
+                Page(5),
+                Page(6),
+                Ellipsis,
+                Page(9)
+            ]
+        );
+        assert_eq!(
+            pagination_items(9, 10, 1),
+            vec![
+                Page(0),
+                Ellipsis,
+                Page(5),
+                Page(6),
+                Page(7),
+                Page(8),
+                Page(9)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    pub async fn pagination() {
+        fn pagination_app() -> Element {
+            let mut page = use_signal(|| 0);
+
+            rsx!(
+                Pagination {
+                    page: *page.read(),
+                    pages: 10,
+                    onchange: move |p| page.set(p)
+                }
+                label {
+                    "{page}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(pagination_app);
+        let root = utils.root();
+        let label = root.get(1);
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("0"));
+
+        // The previous button is disabled in the first page
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (10.0, 10.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("0"));
+
+        // Click the second page
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (80.0, 10.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("1"));
+
+        // Go to the next page with the keyboard
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::ArrowRight,
+            code: Code::ArrowRight,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("2"));
+
+        // Go to the last page
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::End,
+            code: Code::End,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("9"));
+    }
+}
@@ -1,7 +1,14 @@
+use std::time::{Duration, Instant};
+
 use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
 
-use freya_hooks::{use_applied_theme, ProgressBarTheme, ProgressBarThemeWith};
+use freya_hooks::{
+    use_applied_theme, use_node_signal, use_platform, ProgressBarTheme, ProgressBarThemeWith,
+};
+
+/// Time the stripe of an indeterminate [`ProgressBar`] takes to cross the bar.
+const INDETERMINATE_DURATION: Duration = Duration::from_millis(1500);
 
 /// [`ProgressBar`] component properties.
 #[derive(Props, Clone, PartialEq)]
@@ -13,6 +20,9 @@ pub struct ProgressBarProps {
     pub show_progress: bool,
     /// Percentage of the progress bar.
     pub progress: f32,
+    /// Animate a moving stripe instead of the progress, for when it's unknown. Default to false.
+    #[props(default = false)]
+    pub indeterminate: bool,
 }
 
 /// `ProgressBar` component.
@@ -32,6 +42,10 @@ pub struct ProgressBarProps {
 ///         ProgressBar {
 ///             progress: 75.0
 ///         }
+///         ProgressBar {
+///             progress: 0.0,
+///             indeterminate: true
+///         }
 ///     )
 /// }
 /// ```
@@ -42,6 +56,7 @@ pub fn ProgressBar(
         theme,
         show_progress,
         progress,
+        indeterminate,
     }: ProgressBarProps,
 ) -> Element {
     let ProgressBarTheme {
@@ -65,21 +80,27 @@ pub fn ProgressBar(
                 background: "{background}",
                 font_size: "13",
                 direction: "horizontal",
-                rect {
-                    corner_radius: "999",
-                    width: "{progress}%",
-                    height: "100%",
-                    background: "{progress_background}",
-                    main_align: "center",
-                    cross_align: "center",
-                    overflow: "clip",
-                    if show_progress {
-                        label {
-                            text_align: "center",
-                            width: "100%",
-                            color: "{color}",
-                            max_lines: "1",
-                            "{progress.floor()}%"
+                if indeterminate {
+                    IndeterminateStripe {
+                        background: "{progress_background}"
+                    }
+                } else {
+                    rect {
+                        corner_radius: "999",
+                        width: "{progress}%",
+                        height: "100%",
+                        background: "{progress_background}",
+                        main_align: "center",
+                        cross_align: "center",
+                        overflow: "clip",
+                        if show_progress {
+                            label {
+                                text_align: "center",
+                                width: "100%",
+                                color: "{color}",
+                                max_lines: "1",
+                                "{progress.floor()}%"
+                            }
                         }
                     }
                 }
@@ -87,3 +108,107 @@ pub fn ProgressBar(
         }
     )
 }
+
+/// Stripe moving across an indeterminate [`ProgressBar`], driven by the renderer ticks.
+#[allow(non_snake_case)]
+#[component]
+fn IndeterminateStripe(background: String) -> Element {
+    let platform = use_platform();
+    let (reference, size) = use_node_signal();
+    let mut position = use_signal(|| 0.0);
+
+    use_hook(move || {
+        spawn(async move {
+            let start = Instant::now();
+            let mut ticker = platform.new_ticker();
+
+            loop {
+                ticker.tick().await;
+
+                let elapsed = start.elapsed().as_secs_f32();
+                let duration = INDETERMINATE_DURATION.as_secs_f32();
+                position.set((elapsed % duration) / duration);
+
+                platform.request_animation_frame();
+            }
+        });
+    });
+
+    // The stripe enters from the left and leaves by the right
+    let width = size.read().area.width();
+    let offset_x = (-0.3 + *position.read() * 1.3) * width;
+
+    rsx!(
+        rect {
+            reference,
+            width: "100%",
+            height: "100%",
+            corner_radius: "999",
+            overflow: "clip",
+            rect {
+                corner_radius: "999",
+                offset_x: "{offset_x}",
+                width: "30%",
+                height: "100%",
+                background: "{background}",
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[tokio::test]
+    pub async fn progress_bar_modes() {
+        fn progress_bar_app() -> Element {
+            let mut indeterminate = use_signal(|| true);
+
+            rsx!(
+                rect {
+                    onclick: move |_| indeterminate.toggle(),
+                    ProgressBar {
+                        progress: 50.0,
+                        indeterminate: *indeterminate.read()
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(progress_bar_app);
+        let track = utils.root().get(0).get(0).get(0);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The stripe moves across the bar
+        let stripe = track.get(0).get(0);
+        let start_x = stripe.area().unwrap().min_x();
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(stripe.area().unwrap().min_x() > start_x);
+
+        // Switch to the determinate mode
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        let progress = track.get(0);
+        assert_eq!(progress.area().unwrap().width(), 248.0);
+
+        // And back to the indeterminate mode
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(track.get(0).area().unwrap().width(), 496.0);
+    }
+}
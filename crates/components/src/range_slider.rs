@@ -0,0 +1,397 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_elements::events::keyboard::Key;
+use freya_elements::events::{KeyboardEvent, MouseEvent};
+
+use freya_hooks::{
+    use_applied_theme, use_focus, use_node, use_platform, SliderTheme, SliderThemeWith, UseFocus,
+};
+use winit::window::CursorIcon;
+
+use crate::slider::{ensure_correct_slider_range, snap_to_step, SliderStatus};
+
+/// [`RangeSlider`] component properties.
+#[derive(Props, Clone, PartialEq)]
+pub struct RangeSliderProps {
+    /// Theme override.
+    pub theme: Option<SliderThemeWith>,
+    /// Handler for the `onchange` event, called continuously while the range changes, e.g while dragging.
+    pub onchange: EventHandler<(f64, f64)>,
+    /// Handler for the `onchangecommitted` event, called once the range is done changing, e.g when the thumb is released.
+    pub onchangecommitted: Option<EventHandler<(f64, f64)>>,
+    /// Width of the RangeSlider.
+    #[props(into, default = "100%".to_string())]
+    pub width: String,
+    /// Selected range as `(low, high)`, from `min` to `max`.
+    pub value: (f64, f64),
+    /// Minimum value.
+    #[props(default = 0.0)]
+    pub min: f64,
+    /// Maximum value.
+    #[props(default = 100.0)]
+    pub max: f64,
+    /// Values are snapped to multiples of the step, starting from `min`.
+    /// The arrow keys move the thumbs by the step, or by 1% of the range if not specified.
+    pub step: Option<f64>,
+}
+
+/// Thumb of the [`RangeSlider`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum RangeThumb {
+    Low,
+    High,
+}
+
+/// Controlled `RangeSlider` component, like a [`Slider`](crate::Slider) with two thumbs to select a range.
+///
+/// The thumbs can't cross each other and clicking the track moves the nearest one.
+///
+/// # Keyboard
+/// `Tab` moves the focus between the thumbs, the arrow keys move the focused thumb by the step,
+/// `Home` and `End` move it to the minimum and maximum.
+///
+/// # Props
+/// See [`RangeSliderProps`].
+///
+/// # Styling
+/// Inherits a [`SliderTheme`](freya_hooks::SliderTheme) theme, the selected range is filled with `thumb_inner_background`.
+///
+/// # Example
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut range = use_signal(|| (20.0, 80.0));
+///     let (low, high) = *range.read();
+///
+///     rsx!(
+///         label {
+///             "From {low} to {high}"
+///         }
+///         RangeSlider {
+///             width: "50%",
+///             value: (low, high),
+///             onchange: move |r| {
+///                 range.set(r);
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn RangeSlider(
+    RangeSliderProps {
+        value,
+        onchange,
+        onchangecommitted,
+        theme,
+        width,
+        min,
+        max,
+        step,
+    }: RangeSliderProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, slider);
+    let mut low_focus = use_focus();
+    let mut high_focus = use_focus();
+    let mut status = use_signal(SliderStatus::default);
+    let mut dragging = use_signal(|| None);
+    let mut dragged_value = use_signal(|| None);
+    let platform = use_platform();
+    let (node_reference, size) = use_node();
+
+    let low = ensure_correct_slider_range(value.0, min, max);
+    let high = ensure_correct_slider_range(value.1, low, max);
+    let low_focus_id = low_focus.attribute();
+    let high_focus_id = high_focus.attribute();
+    let is_low_focused = low_focus.is_focused();
+    let is_high_focused = high_focus.is_focused();
+
+    // Keyboard step, 1% of the range by default
+    let key_step = step.unwrap_or((max - min) / 100.0);
+
+    // Get the value under the cursor, relative to the slider
+    let value_at = move |x: f64| {
+        let ratio = (x - 9.0) / (size.area.width() as f64 - 18.0);
+        snap_to_step(min + ratio * (max - min), min, max, step)
+    };
+
+    // Move a thumb without crossing the other one
+    let move_thumb = move |thumb: RangeThumb, new_value: f64| match thumb {
+        RangeThumb::Low => (new_value.min(high), high),
+        RangeThumb::High => (low, new_value.max(low)),
+    };
+
+    use_drop(move || {
+        if *status.peek() == SliderStatus::Hovering {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onmouseleave = move |e: MouseEvent| {
+        e.stop_propagation();
+        *status.write() = SliderStatus::Idle;
+        platform.set_cursor(CursorIcon::default());
+    };
+
+    let onmouseenter = move |e: MouseEvent| {
+        e.stop_propagation();
+        *status.write() = SliderStatus::Hovering;
+        platform.set_cursor(CursorIcon::Pointer);
+    };
+
+    let onmouseover = {
+        to_owned![onchange];
+        move |e: MouseEvent| {
+            e.stop_propagation();
+            if let Some(thumb) = *dragging.peek() {
+                let coordinates = e.get_element_coordinates();
+                let new_value = value_at(coordinates.x - size.area.min_x() as f64);
+                let range = move_thumb(thumb, new_value);
+
+                dragged_value.set(Some(range));
+                onchange.call(range);
+            }
+        }
+    };
+
+    // Move the nearest thumb to the clicked point
+    let onmousedown = {
+        to_owned![onchange];
+        move |e: MouseEvent| {
+            e.stop_propagation();
+            let coordinates = e.get_element_coordinates();
+            let new_value = value_at(coordinates.x);
+            let thumb = if (new_value - low).abs() < (new_value - high).abs()
+                || (new_value == low && new_value < high)
+            {
+                low_focus.focus();
+                RangeThumb::Low
+            } else {
+                high_focus.focus();
+                RangeThumb::High
+            };
+            let range = move_thumb(thumb, new_value);
+
+            dragging.set(Some(thumb));
+            dragged_value.set(Some(range));
+            onchange.call(range);
+        }
+    };
+
+    let onclick = {
+        to_owned![onchangecommitted];
+        move |_: MouseEvent| {
+            if dragging.write().take().is_some() {
+                let range = dragged_value.write().take().unwrap_or((low, high));
+                if let Some(onchangecommitted) = &onchangecommitted {
+                    onchangecommitted.call(range);
+                }
+            }
+        }
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        let (thumb, current) = if is_low_focused {
+            (RangeThumb::Low, low)
+        } else if is_high_focused {
+            (RangeThumb::High, high)
+        } else {
+            return;
+        };
+        let new_value = match e.key {
+            Key::ArrowRight | Key::ArrowUp => current + key_step,
+            Key::ArrowLeft | Key::ArrowDown => current - key_step,
+            Key::Home => min,
+            Key::End => max,
+            _ => return,
+        };
+        let range = move_thumb(thumb, snap_to_step(new_value, min, max, step));
+
+        onchange.call(range);
+        if let Some(onchangecommitted) = &onchangecommitted {
+            onchangecommitted.call(range);
+        }
+    };
+
+    let track_width = size.area.width() - 18.0;
+    let (low_ratio, high_ratio) = if max > min {
+        (
+            ((low - min) / (max - min)) as f32,
+            ((high - min) / (max - min)) as f32,
+        )
+    } else {
+        (0.0, 0.0)
+    };
+    let low_offset = track_width * low_ratio;
+    let range_width = track_width * (high_ratio - low_ratio);
+    let thumb_border = |focus: &UseFocus| {
+        if focus.is_selected() {
+            format!("2 solid {}", theme.border_fill)
+        } else {
+            "none".to_string()
+        }
+    };
+    let low_border = thumb_border(&low_focus);
+    let high_border = thumb_border(&high_focus);
+
+    rsx!(
+        rect {
+            reference: node_reference,
+            width: "{width}",
+            height: "20",
+            onmousedown,
+            onglobalclick: onclick,
+            onkeydown,
+            onmouseenter,
+            onglobalmouseover: onmouseover,
+            onmouseleave,
+            main_align: "center",
+            corner_radius: "8",
+            padding: "0 9",
+            rect {
+                background: "{theme.background}",
+                width: "100%",
+                height: "6",
+                direction: "horizontal",
+                corner_radius: "50",
+                rect {
+                    width: "{low_offset}",
+                    height: "100%",
+                }
+                RangeSliderThumb {
+                    focus_id: low_focus_id,
+                    value: low,
+                    border: low_border,
+                    theme: theme.clone(),
+                }
+                rect {
+                    background: "{theme.thumb_inner_background}",
+                    width: "{range_width}",
+                    height: "100%",
+                }
+                RangeSliderThumb {
+                    focus_id: high_focus_id,
+                    value: high,
+                    border: high_border,
+                    theme: theme.clone(),
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn RangeSliderThumb(
+    focus_id: AttributeValue,
+    value: f64,
+    border: String,
+    theme: SliderTheme,
+) -> Element {
+    rsx!(
+        rect {
+            width: "0",
+            height: "100%",
+            rect {
+                focus_id,
+                role: "slider",
+                alt: "{value}",
+                offset_x: "-9",
+                offset_y: "-6",
+                background: "{theme.thumb_background}",
+                border: "{border}",
+                width: "18",
+                height: "18",
+                corner_radius: "50",
+                padding: "4",
+                rect {
+                    height: "100%",
+                    width: "100%",
+                    background: "{theme.thumb_inner_background}",
+                    corner_radius: "50"
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use dioxus::prelude::use_signal;
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[tokio::test]
+    pub async fn range_slider() {
+        fn range_slider_app() -> Element {
+            let mut range = use_signal(|| (20.0, 80.0));
+            let (low, high) = *range.read();
+
+            rsx!(
+                RangeSlider {
+                    value: (low, high),
+                    step: 1.0,
+                    onchange: move |r| {
+                        range.set(r);
+                    }
+                }
+                label {
+                    "{low} {high}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(range_slider_app);
+        let root = utils.root();
+        let label = root.get(1);
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("20 80"));
+
+        // Clicking near the low thumb moves it
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (58.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (58.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("10 80"));
+
+        // Dragging the low thumb past the high thumb stops at it
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (58.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (490.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (490.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("80 80"));
+
+        // Move the focused thumb with the keyboard
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Home,
+            code: Code::Home,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("0 80"));
+    }
+}
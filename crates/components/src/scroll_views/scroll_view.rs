@@ -25,6 +25,8 @@ pub struct ScrollViewProps {
     /// Enable scrolling with arrow keys.
     #[props(default = true, into)]
     pub scroll_with_arrows: bool,
+    /// Handler for the `onscroll` event, called with the `(x, y)` scroll offsets when they change.
+    pub onscroll: Option<EventHandler<(f32, f32)>>,
 }
 
 /// `ScrollView` component.
@@ -91,6 +93,16 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
         *scrolled_x.read() as f32,
     );
 
+    let onscroll = props.onscroll.clone();
+    let _ = use_memo_with_dependencies(
+        &(corrected_scrolled_x as i32, corrected_scrolled_y as i32),
+        move |(x, y)| {
+            if let Some(onscroll) = &onscroll {
+                onscroll.call((x as f32, y as f32));
+            }
+        },
+    );
+
     let (scrollbar_y, scrollbar_height) =
         get_scrollbar_pos_and_size(size.inner.height, size.area.height(), corrected_scrolled_y);
     let (scrollbar_x, scrollbar_width) =
@@ -485,4 +497,57 @@ mod test {
         assert!(content.get(2).is_visible());
         assert!(content.get(3).is_visible());
     }
+
+    #[tokio::test]
+    pub async fn scroll_view_rtl_scrollbar() {
+        fn scroll_view_rtl_app() -> Element {
+            rsx!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    direction_mode: "rtl",
+                    ScrollView {
+                        rect {
+                            height: "800",
+                            width: "200",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_rtl_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let scroll_view = utils.root().get(0).get(0);
+        let container = scroll_view.get(0);
+        let vertical_scrollbar = scroll_view.get(1);
+
+        // The vertical scrollbar is on the left side
+        assert_eq!(vertical_scrollbar.area().unwrap().min_x(), 0.0);
+        assert_eq!(vertical_scrollbar.area().unwrap().width(), 15.0);
+        assert_eq!(container.area().unwrap().min_x(), 15.0);
+
+        // Dragging it scrolls the content
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (5., 20.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (5., 320.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 320.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        let content = container.get(0);
+        assert_eq!(content.get(0).area().unwrap().min_y(), -300.0);
+    }
 }
@@ -38,6 +38,12 @@ pub struct VirtualScrollViewProps<
     /// Enable scrolling with arrow keys.
     #[props(default = true, into)]
     pub scroll_with_arrows: bool,
+    /// Called when the scroll gets close to the end, useful to load the next page of items.
+    /// It is not called again until the length changes or the scroll moves away from the end.
+    pub onreachend: Option<EventHandler<()>>,
+    /// Distance in pixels from the end at which `onreachend` is called, `200` by default.
+    #[props(default = 200.0, into)]
+    pub reach_end_threshold: f32,
 }
 
 impl<
@@ -53,6 +59,8 @@ impl<
             && self.show_scrollbar == other.show_scrollbar
             && self.scroll_with_arrows == other.scroll_with_arrows
             && self.builder_args == other.builder_args
+            && self.onreachend == other.onreachend
+            && self.reach_end_threshold == other.reach_end_threshold
     }
 }
 
@@ -75,6 +83,12 @@ fn get_render_range(
     render_index_start as usize..(render_index_end as usize)
 }
 
+/// Whether the scroll is closer to the end than the threshold.
+fn is_near_end(inner_size: f32, viewport_size: f32, scroll_position: f32, threshold: f32) -> bool {
+    let remaining = inner_size - viewport_size + scroll_position;
+    viewport_size > 0.0 && remaining <= threshold
+}
+
 /// `VirtualScrollView` component.
 ///
 /// # Props
@@ -117,6 +131,7 @@ pub fn VirtualScrollView<
     let mut clicking_alt = use_signal(|| false);
     let mut scrolled_y = use_signal(|| 0);
     let mut scrolled_x = use_signal(|| 0);
+    let mut reached_end_length = use_hook(|| CopyValue::new(None::<usize>));
     let (node_ref, size) = use_node();
     let mut focus = use_focus();
     let theme = use_applied_theme!(&props.theme, scroll_view);
@@ -317,6 +332,23 @@ pub fn VirtualScrollView<
         (size.area.width(), corrected_scrolled_x)
     };
 
+    // Notify that the end is close, only once per length so it doesn't fire again while the next items are loading
+    if is_near_end(
+        inner_size,
+        viewport_size,
+        scroll_position,
+        props.reach_end_threshold,
+    ) {
+        if *reached_end_length.peek() != Some(items_length) {
+            reached_end_length.set(Some(items_length));
+            if let Some(onreachend) = props.onreachend.clone() {
+                spawn(async move { onreachend.call(()) });
+            }
+        }
+    } else if reached_end_length.peek().is_some() {
+        reached_end_length.set(None);
+    }
+
     // Calculate from what to what items must be rendered
     let render_range = get_render_range(
         viewport_size,
@@ -587,4 +619,108 @@ mod test {
             );
         }
     }
+
+    #[tokio::test]
+    pub async fn virtual_scroll_view_reach_end() {
+        fn virtual_scroll_view_reach_end_app() -> Element {
+            let mut length = use_signal(|| 30);
+            let mut pages = use_signal(|| 1);
+
+            rsx!(
+                VirtualScrollView {
+                    length: *length.read(),
+                    item_size: 50.0,
+                    direction: "vertical",
+                    onreachend: move |_| {
+                        *length.write() += 30;
+                        *pages.write() += 1;
+                    },
+                    builder: move |index, _: &Option<()>| {
+                        rsx! {
+                            label {
+                                key: "{index}",
+                                height: "50",
+                                "{index}"
+                            }
+                        }
+                    }
+                }
+                label {
+                    "{pages}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(virtual_scroll_view_reach_end_app);
+        let root = utils.root();
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let pages = root.get(1).get(0);
+        assert_eq!(pages.text(), Some("1"));
+
+        // Scroll to the end
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -1200.).into(),
+            cursor: (5., 5.).into(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The next page was loaded once
+        assert_eq!(root.get(1).get(0).text(), Some("2"));
+
+        // Scrolling again far from the end doesn't load more pages
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -100.).into(),
+            cursor: (5., 5.).into(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(root.get(1).get(0).text(), Some("2"));
+    }
+
+    #[tokio::test]
+    pub async fn virtual_scroll_view_rtl_scrollbar() {
+        fn virtual_scroll_view_rtl_app() -> Element {
+            rsx!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    direction_mode: "rtl",
+                    VirtualScrollView {
+                        length: 30,
+                        item_size: 50.0,
+                        direction: "vertical",
+                        builder: move |index, _: &Option<()>| {
+                            rsx! {
+                                label {
+                                    key: "{index}",
+                                    height: "50",
+                                    "{index}"
+                                }
+                            }
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(virtual_scroll_view_rtl_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let scroll_view = utils.root().get(0).get(0);
+        let container = scroll_view.get(0);
+        let vertical_scrollbar = scroll_view.get(1);
+
+        // The vertical scrollbar is on the left side
+        assert_eq!(vertical_scrollbar.area().unwrap().min_x(), 0.0);
+        assert_eq!(vertical_scrollbar.area().unwrap().width(), 15.0);
+        assert_eq!(container.area().unwrap().min_x(), 15.0);
+    }
 }
@@ -1,6 +1,7 @@
 use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
-use freya_elements::events::{MouseEvent, WheelEvent};
+use freya_elements::events::keyboard::Key;
+use freya_elements::events::{KeyboardEvent, MouseEvent, WheelEvent};
 
 use freya_hooks::{use_applied_theme, use_focus, use_node, use_platform, SliderThemeWith};
 use tracing::info;
@@ -11,28 +12,55 @@ use winit::window::CursorIcon;
 pub struct SliderProps {
     /// Theme override.
     pub theme: Option<SliderThemeWith>,
-    /// Handler for the `onmoved` event.
-    pub onmoved: EventHandler<f64>,
-    /// Width of the Slider.
+    /// Handler for the `onchange` event, called continuously while the value changes, e.g while dragging.
+    pub onchange: EventHandler<f64>,
+    /// Handler for the `onchangecommitted` event, called once the value is done changing, e.g when the thumb is released.
+    pub onchangecommitted: Option<EventHandler<f64>>,
+    /// Width of the Slider, used when horizontal.
     #[props(into, default = "100%".to_string())]
     pub width: String,
-    /// Height of the Slider.
+    /// Height of the Slider, used when vertical.
+    #[props(into, default = "100%".to_string())]
+    pub height: String,
+    /// Value of the Slider, from `min` to `max`.
     pub value: f64,
+    /// Minimum value.
+    #[props(default = 0.0)]
+    pub min: f64,
+    /// Maximum value.
+    #[props(default = 100.0)]
+    pub max: f64,
+    /// Values are snapped to multiples of the step, starting from `min`.
+    /// The arrow keys move the value by the step, or by 1% of the range if not specified.
+    pub step: Option<f64>,
+    /// Direction of the Slider, `horizontal` or `vertical`.
+    #[props(default = "horizontal".to_string(), into)]
+    pub direction: String,
 }
 
 #[inline]
-fn ensure_correct_slider_range(value: f64) -> f64 {
-    if value < 0.0 {
-        info!("Slider value is less than 0.0, setting to 0.0");
-        0.0
-    } else if value > 100.0 {
-        info!("Slider value is greater than 100.0, setting to 100.0");
-        100.0
+pub(crate) fn ensure_correct_slider_range(value: f64, min: f64, max: f64) -> f64 {
+    if value < min {
+        info!("Slider value is less than {min}, setting to {min}");
+        min
+    } else if value > max {
+        info!("Slider value is greater than {max}, setting to {max}");
+        max
     } else {
         value
     }
 }
 
+/// Clamp a value to the range and snap it to the step.
+#[inline]
+pub(crate) fn snap_to_step(value: f64, min: f64, max: f64, step: Option<f64>) -> f64 {
+    let value = value.clamp(min, max);
+    match step {
+        Some(step) if step > 0.0 => (min + ((value - min) / step).round() * step).clamp(min, max),
+        _ => value,
+    }
+}
+
 /// Describes the current status of the Slider.
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub enum SliderStatus {
@@ -45,9 +73,12 @@ pub enum SliderStatus {
 
 /// Controlled `Slider` component.
 ///
-/// You must pass a percentage from 0.0 to 100.0 and listen for value changes with `onmoved` and then decide if this changes are applicable,
+/// You must pass a value from `min` to `max` (`0.0` to `100.0` by default) and listen for value changes with `onchange` and then decide if this changes are applicable,
 /// and if so, apply them.
 ///
+/// # Keyboard
+/// When focused, the arrow keys move the value by the step, `Home` and `End` set it to the minimum and maximum.
+///
 /// # Props
 /// See [`SliderProps`].
 ///
@@ -67,7 +98,8 @@ pub enum SliderStatus {
 ///         Slider {
 ///             width: "50%",
 ///             value: *percentage.read(),
-///             onmoved: move |p| {
+///             step: 5.0,
+///             onchange: move |p| {
 ///                 percentage.set(p);
 ///             }
 ///         }
@@ -78,20 +110,53 @@ pub enum SliderStatus {
 pub fn Slider(
     SliderProps {
         value,
-        onmoved,
+        onchange,
+        onchangecommitted,
         theme,
         width,
+        height,
+        min,
+        max,
+        step,
+        direction,
     }: SliderProps,
 ) -> Element {
     let theme = use_applied_theme!(&theme, slider);
     let mut focus = use_focus();
     let mut status = use_signal(SliderStatus::default);
     let mut clicking = use_signal(|| false);
+    let mut dragged_value = use_signal(|| None);
     let platform = use_platform();
     let (node_reference, size) = use_node();
 
-    let value = ensure_correct_slider_range(value);
+    let value = ensure_correct_slider_range(value, min, max);
     let focus_id = focus.attribute();
+    let is_vertical = direction == "vertical";
+    let is_focused = focus.is_focused();
+
+    // Keyboard step, 1% of the range by default
+    let key_step = step.unwrap_or((max - min) / 100.0);
+
+    // Get the value under the cursor, relative to the slider
+    let value_at = move |x: f64, y: f64| {
+        let ratio = if is_vertical {
+            (size.area.height() as f64 - 6.0 - y) / (size.area.height() as f64 - 15.0)
+        } else {
+            (x - 6.0) / (size.area.width() as f64 - 15.0)
+        };
+        snap_to_step(min + ratio * (max - min), min, max, step)
+    };
+
+    // Emit the change continuously and the committed change at once
+    let commit = {
+        to_owned![onchange, onchangecommitted];
+        move |new_value: f64| {
+            onchange.call(new_value);
+            if let Some(onchangecommitted) = &onchangecommitted {
+                onchangecommitted.call(new_value);
+            }
+        }
+    };
 
     use_drop(move || {
         if *status.peek() == SliderStatus::Hovering {
@@ -112,105 +177,200 @@ pub fn Slider(
     };
 
     let onmouseover = {
-        to_owned![onmoved];
+        to_owned![onchange];
         move |e: MouseEvent| {
             e.stop_propagation();
             if *clicking.peek() {
                 let coordinates = e.get_element_coordinates();
-                let x = coordinates.x - size.area.min_x() as f64 - 6.0;
-                let percentage = x / (size.area.width() as f64 - 15.0) * 100.0;
-                let percentage = percentage.clamp(0.0, 100.0);
+                let new_value = value_at(
+                    coordinates.x - size.area.min_x() as f64,
+                    coordinates.y - size.area.min_y() as f64,
+                );
 
-                onmoved.call(percentage);
+                dragged_value.set(Some(new_value));
+                onchange.call(new_value);
             }
         }
     };
 
     let onmousedown = {
-        to_owned![onmoved];
+        to_owned![onchange];
         move |e: MouseEvent| {
             e.stop_propagation();
             focus.focus();
             clicking.set(true);
             let coordinates = e.get_element_coordinates();
-            let x = coordinates.x - 6.0;
-            let percentage = x / (size.area.width() as f64 - 15.0) * 100.0;
-            let percentage = percentage.clamp(0.0, 100.0);
+            let new_value = value_at(coordinates.x, coordinates.y);
 
-            onmoved.call(percentage);
+            dragged_value.set(Some(new_value));
+            onchange.call(new_value);
         }
     };
 
-    let onclick = move |_: MouseEvent| {
-        clicking.set(false);
+    let onclick = {
+        to_owned![onchangecommitted];
+        move |_: MouseEvent| {
+            if *clicking.peek() {
+                clicking.set(false);
+                let new_value = dragged_value.write().take().unwrap_or(value);
+                if let Some(onchangecommitted) = &onchangecommitted {
+                    onchangecommitted.call(new_value);
+                }
+            }
+        }
     };
 
-    let onwheel = move |e: WheelEvent| {
-        e.stop_propagation();
-        let wheel_y = e.get_delta_y().clamp(-1.0, 1.0);
-        let percentage = value + (wheel_y * 2.0);
-        let percentage = percentage.clamp(0.0, 100.0);
+    let onwheel = {
+        to_owned![commit];
+        move |e: WheelEvent| {
+            e.stop_propagation();
+            let wheel_y = e.get_delta_y().clamp(-1.0, 1.0);
+            let new_value = value + (wheel_y * 2.0) * (max - min) / 100.0;
+
+            commit(snap_to_step(new_value, min, max, step));
+        }
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        if !is_focused {
+            return;
+        }
+        let new_value = match e.key {
+            Key::ArrowRight | Key::ArrowUp => value + key_step,
+            Key::ArrowLeft | Key::ArrowDown => value - key_step,
+            Key::Home => min,
+            Key::End => max,
+            _ => return,
+        };
 
-        onmoved.call(percentage);
+        commit(snap_to_step(new_value, min, max, step));
     };
 
-    let inner_width = (size.area.width() - 15.0) * (value / 100.0) as f32;
+    let ratio = if max > min {
+        ((value - min) / (max - min)) as f32
+    } else {
+        0.0
+    };
     let border = if focus.is_selected() {
         format!("2 solid {}", theme.border_fill)
     } else {
         "none".to_string()
     };
 
-    rsx!(
-        rect {
-            reference: node_reference,
-            width: "{width}",
-            height: "20",
-            onmousedown,
-            onglobalclick: onclick,
-            focus_id,
-            onmouseenter,
-            onglobalmouseover: onmouseover,
-            onmouseleave,
-            onwheel: onwheel,
-            main_align: "center",
-            cross_align: "center",
-            border: "{border}",
-            corner_radius: "8",
+    if is_vertical {
+        let inner_height = (size.area.height() - 15.0) * ratio;
+
+        rsx!(
             rect {
-                background: "{theme.background}",
-                width: "100%",
-                height: "6",
-                direction: "horizontal",
-                corner_radius: "50",
+                reference: node_reference,
+                width: "20",
+                height: "{height}",
+                onmousedown,
+                onglobalclick: onclick,
+                onkeydown,
+                focus_id,
+                role: "slider",
+                alt: "{value}",
+                onmouseenter,
+                onglobalmouseover: onmouseover,
+                onmouseleave,
+                onwheel: onwheel,
+                main_align: "center",
+                cross_align: "center",
+                border: "{border}",
+                corner_radius: "8",
                 rect {
-                    background: "{theme.thumb_inner_background}",
-                    width: "{inner_width}",
+                    background: "{theme.background}",
+                    width: "6",
                     height: "100%",
-                    corner_radius: "50"
+                    direction: "vertical",
+                    corner_radius: "50",
+                    rect {
+                        width: "100%",
+                        height: "fill",
+                        main_align: "end",
+                        offset_x: "-6",
+                        offset_y: "3",
+                        rect {
+                            background: "{theme.thumb_background}",
+                            width: "18",
+                            height: "18",
+                            corner_radius: "50",
+                            padding: "4",
+                            rect {
+                                height: "100%",
+                                width: "100%",
+                                background: "{theme.thumb_inner_background}",
+                                corner_radius: "50"
+                            }
+                        }
+                    }
+                    rect {
+                        background: "{theme.thumb_inner_background}",
+                        width: "100%",
+                        height: "{inner_height}",
+                        corner_radius: "50"
+                    }
                 }
+            }
+        )
+    } else {
+        let inner_width = (size.area.width() - 15.0) * ratio;
+
+        rsx!(
+            rect {
+                reference: node_reference,
+                width: "{width}",
+                height: "20",
+                onmousedown,
+                onglobalclick: onclick,
+                onkeydown,
+                focus_id,
+                role: "slider",
+                alt: "{value}",
+                onmouseenter,
+                onglobalmouseover: onmouseover,
+                onmouseleave,
+                onwheel: onwheel,
+                main_align: "center",
+                cross_align: "center",
+                border: "{border}",
+                corner_radius: "8",
                 rect {
-                    width: "fill",
-                    height: "100%",
-                    offset_y: "-6",
-                    offset_x: "-3",
+                    background: "{theme.background}",
+                    width: "100%",
+                    height: "6",
+                    direction: "horizontal",
+                    corner_radius: "50",
+                    rect {
+                        background: "{theme.thumb_inner_background}",
+                        width: "{inner_width}",
+                        height: "100%",
+                        corner_radius: "50"
+                    }
                     rect {
-                        background: "{theme.thumb_background}",
-                        width: "18",
-                        height: "18",
-                        corner_radius: "50",
-                        padding: "4",
+                        width: "fill",
+                        height: "100%",
+                        offset_y: "-6",
+                        offset_x: "-3",
                         rect {
-                            height: "100%",
-                            width: "100%",
-                            background: "{theme.thumb_inner_background}",
-                            corner_radius: "50"
+                            background: "{theme.thumb_background}",
+                            width: "18",
+                            height: "18",
+                            corner_radius: "50",
+                            padding: "4",
+                            rect {
+                                height: "100%",
+                                width: "100%",
+                                background: "{theme.thumb_inner_background}",
+                                corner_radius: "50"
+                            }
                         }
                     }
                 }
             }
-        }
-    )
+        )
+    }
 }
 
 #[cfg(test)]
@@ -227,7 +387,7 @@ mod test {
             rsx!(
                 Slider {
                     value: *value.read(),
-                    onmoved: move |p| {
+                    onchange: move |p| {
                         value.set(p);
                     }
                 }
@@ -269,4 +429,131 @@ mod test {
 
         assert_eq!(label.get(0).text(), Some("100"));
     }
+
+    #[tokio::test]
+    pub async fn slider_keyboard() {
+        fn slider_app() -> Element {
+            let mut value = use_signal(|| 4.);
+            let mut committed = use_signal(|| 0.);
+
+            rsx!(
+                Slider {
+                    value: *value.read(),
+                    min: 2.0,
+                    max: 10.0,
+                    step: 2.0,
+                    onchange: move |p| {
+                        value.set(p);
+                    },
+                    onchangecommitted: move |p| {
+                        committed.set(p);
+                    }
+                }
+                label {
+                    "{value} {committed}"
+                }
+            )
+        }
+
+        fn key(key: Key, code: Code) -> PlatformEvent {
+            PlatformEvent::Keyboard {
+                name: EventName::KeyDown,
+                key,
+                code,
+                modifiers: Modifiers::default(),
+            }
+        }
+
+        let mut utils = launch_test(slider_app);
+        let root = utils.root();
+        let label = root.get(1);
+        utils.wait_for_update().await;
+
+        // Focus the slider, the value is snapped to the step
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (165.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (165.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("4 4"));
+
+        utils.push_event(key(Key::ArrowRight, Code::ArrowRight));
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("6 6"));
+
+        utils.push_event(key(Key::End, Code::End));
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("10 10"));
+
+        // Clamped to the maximum
+        utils.push_event(key(Key::ArrowUp, Code::ArrowUp));
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("10 10"));
+
+        utils.push_event(key(Key::Home, Code::Home));
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("2 2"));
+    }
+
+    #[tokio::test]
+    pub async fn vertical_slider() {
+        fn slider_app() -> Element {
+            let mut value = use_signal(|| 50.);
+
+            rsx!(
+                Slider {
+                    value: *value.read(),
+                    direction: "vertical",
+                    height: "200",
+                    onchange: move |p| {
+                        value.set(p);
+                    }
+                }
+                label {
+                    "{value}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(slider_app);
+        let root = utils.root();
+        let label = root.get(1);
+        utils.wait_for_update().await;
+
+        // The bottom is the minimum
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (10.0, 199.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("0"));
+
+        // The top is the maximum
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (10.0, 1.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (10.0, 1.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("100"));
+    }
 }
@@ -0,0 +1,304 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_elements::events::MouseEvent;
+use freya_hooks::{
+    use_applied_theme, use_node_signal, use_platform, SplitPaneTheme, SplitPaneThemeWith,
+};
+use winit::window::CursorIcon;
+
+/// Size in pixels of the [`SplitPane`] divider.
+const DIVIDER_SIZE: f32 = 4.0;
+
+/// Clamp the size of the first pane given the size available for both panes and their limits.
+fn clamp_first_size(
+    size: f32,
+    available: f32,
+    (first_min, first_max): (f32, Option<f32>),
+    (second_min, second_max): (f32, Option<f32>),
+) -> f32 {
+    let min = first_min.max(second_max.map(|max| available - max).unwrap_or(0.0));
+    let max = first_max.unwrap_or(available).min(available - second_min);
+    size.min(max).max(min).max(0.0)
+}
+
+/// Two panes next to each other separated by a divider that can be dragged to resize them.
+///
+/// Use `direction: "vertical"` to stack the panes instead. Split panes can be nested.
+///
+/// # Styling
+/// Inherits the [`SplitPaneTheme`](freya_hooks::SplitPaneTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         SplitPane {
+///             ratio: 0.3,
+///             first_min_size: 100.0,
+///             first: rsx!(label { "Sidebar" }),
+///             second: rsx!(label { "Content" }),
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn SplitPane(
+    /// Theme override.
+    theme: Option<SplitPaneThemeWith>,
+    /// Direction in which the panes are placed, `horizontal` or `vertical`.
+    #[props(default = "horizontal".to_string(), into)]
+    direction: String,
+    /// Content of the first pane.
+    first: Element,
+    /// Content of the second pane.
+    second: Element,
+    /// Portion of the space taken by the first pane, from `0.0` to `1.0`.
+    #[props(default = 0.5)]
+    ratio: f32,
+    /// Minimum size in pixels of the first pane.
+    #[props(default = 0.0)]
+    first_min_size: f32,
+    /// Maximum size in pixels of the first pane.
+    first_max_size: Option<f32>,
+    /// Minimum size in pixels of the second pane.
+    #[props(default = 0.0)]
+    second_min_size: f32,
+    /// Maximum size in pixels of the second pane.
+    second_max_size: Option<f32>,
+    /// Handler for the `onresize` event, called with the new ratio after dragging the divider.
+    onresize: Option<EventHandler<f32>>,
+) -> Element {
+    let SplitPaneTheme {
+        divider_fill,
+        hover_divider_fill,
+    } = use_applied_theme!(&theme, split_pane);
+    let mut ratio_state = use_signal(|| ratio);
+    let mut dragging = use_signal(|| false);
+    let mut hovering = use_signal(|| false);
+    let (node_ref, layout) = use_node_signal();
+    let platform = use_platform();
+
+    let is_vertical = direction == "vertical";
+    let cursor_icon = if is_vertical {
+        CursorIcon::RowResize
+    } else {
+        CursorIcon::ColResize
+    };
+
+    // Update the ratio if the passed one changes
+    let _ = use_memo_with_dependencies(&ratio, move |ratio| {
+        ratio_state.set(ratio);
+    });
+
+    use_drop(move || {
+        if *hovering.peek() || *dragging.peek() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let limits = (
+        (first_min_size, first_max_size),
+        (second_min_size, second_max_size),
+    );
+
+    // Resize the panes while dragging the divider
+    let onglobalmouseover = move |e: MouseEvent| {
+        if !*dragging.peek() {
+            return;
+        }
+        let area = layout.peek().area;
+        let cursor = e.get_screen_coordinates();
+        let (position, size) = if is_vertical {
+            (cursor.y as f32 - area.min_y(), area.height())
+        } else {
+            (cursor.x as f32 - area.min_x(), area.width())
+        };
+        let available = size - DIVIDER_SIZE;
+        if available > 0.0 {
+            let first_size =
+                clamp_first_size(position - DIVIDER_SIZE / 2.0, available, limits.0, limits.1);
+            ratio_state.set(first_size / available);
+        }
+    };
+
+    // Stop dragging when the mouse is released
+    let onglobalclick = move |_: MouseEvent| {
+        if *dragging.peek() {
+            dragging.set(false);
+            if !*hovering.peek() {
+                platform.set_cursor(CursorIcon::default());
+            }
+            if let Some(onresize) = &onresize {
+                onresize.call(*ratio_state.peek());
+            }
+        }
+    };
+
+    let onmousedown = move |e: MouseEvent| {
+        e.stop_propagation();
+        dragging.set(true);
+    };
+
+    let onmouseenter = move |_| {
+        platform.set_cursor(cursor_icon);
+        hovering.set(true);
+    };
+
+    let onmouseleave = move |_| {
+        if !*dragging.peek() {
+            platform.set_cursor(CursorIcon::default());
+        }
+        hovering.set(false);
+    };
+
+    let area = layout.read().area;
+    let size = if is_vertical {
+        area.height()
+    } else {
+        area.width()
+    };
+    let available = size - DIVIDER_SIZE;
+    let ratio = *ratio_state.read();
+
+    // Use a percentage until the container has been measured
+    let first_size = if available > 0.0 {
+        clamp_first_size(ratio * available, available, limits.0, limits.1).to_string()
+    } else {
+        format!("{}%", ratio * 100.0)
+    };
+
+    let (first_width, first_height, divider_width, divider_height, second_width, second_height) =
+        if is_vertical {
+            (
+                "100%".to_string(),
+                first_size,
+                "100%".to_string(),
+                DIVIDER_SIZE.to_string(),
+                "100%",
+                "fill",
+            )
+        } else {
+            (
+                first_size,
+                "100%".to_string(),
+                DIVIDER_SIZE.to_string(),
+                "100%".to_string(),
+                "fill",
+                "100%",
+            )
+        };
+
+    let divider_background = if *hovering.read() || *dragging.read() {
+        hover_divider_fill
+    } else {
+        divider_fill
+    };
+
+    rsx!(
+        rect {
+            reference: node_ref,
+            onglobalmouseover,
+            onglobalclick,
+            width: "100%",
+            height: "100%",
+            direction: "{direction}",
+            rect {
+                overflow: "clip",
+                width: "{first_width}",
+                height: "{first_height}",
+                {first}
+            }
+            rect {
+                onmousedown,
+                onmouseenter,
+                onmouseleave,
+                width: "{divider_width}",
+                height: "{divider_height}",
+                background: "{divider_background}",
+            }
+            rect {
+                overflow: "clip",
+                width: "{second_width}",
+                height: "{second_height}",
+                {second}
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[tokio::test]
+    pub async fn split_pane() {
+        fn split_pane_app() -> Element {
+            let mut ratio = use_signal(|| 0.5);
+
+            rsx!(SplitPane {
+                second_min_size: 100.0,
+                onresize: move |r| ratio.set(r),
+                first: rsx!(
+                    label {
+                        "{ratio}"
+                    }
+                ),
+                second: rsx!(rect {}),
+            })
+        }
+
+        let mut utils = launch_test(split_pane_app);
+        let root = utils.root();
+        let first = root.get(0).get(0);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(first.area().unwrap().width(), 248.0);
+
+        // Drag the divider to the left
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (250.0, 100.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (100.0, 100.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (100.0, 100.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(first.area().unwrap().width(), 98.0);
+
+        // The second pane can't be smaller than its minimum size
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (100.0, 100.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (490.0, 100.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (490.0, 100.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(first.area().unwrap().width(), 396.0);
+    }
+}
@@ -63,6 +63,7 @@ pub fn Switch(props: SwitchProps) -> Element {
     let mut focus = use_focus();
 
     let focus_id = focus.attribute();
+    let enabled = props.enabled;
 
     use_drop(move || {
         if *status.read() == SwitchStatus::Hovering {
@@ -149,6 +150,8 @@ pub fn Switch(props: SwitchProps) -> Element {
             onkeydown,
             onclick,
             focus_id,
+            role: "switch",
+            alt: "{enabled}",
             rect {
                 width: "100%",
                 height: "100%",
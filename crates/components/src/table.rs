@@ -1,8 +1,17 @@
+use std::cmp::Ordering;
+
 use crate::icons::ArrowIcon;
 use dioxus::prelude::*;
+use freya_common::NodeReferenceLayout;
 use freya_elements::elements as dioxus_elements;
 use freya_elements::events::MouseEvent;
-use freya_hooks::{use_applied_theme, use_get_theme, FontTheme, TableTheme, TableThemeWith};
+use freya_hooks::{
+    theme_with, use_applied_theme, use_get_theme, use_node, use_node_signal, use_platform,
+    FontTheme, ScrollViewThemeWith, TableTheme, TableThemeWith,
+};
+use winit::window::CursorIcon;
+
+use crate::ScrollView;
 
 #[allow(non_snake_case)]
 #[component]
@@ -20,6 +29,97 @@ fn TableArrow(order_direction: OrderDirection) -> Element {
     })
 }
 
+#[allow(non_snake_case)]
+#[component]
+fn TableCheckbox(
+    theme: Option<TableThemeWith>,
+    checked: bool,
+    onclick: EventHandler<()>,
+) -> Element {
+    let TableTheme {
+        checkbox_fill,
+        divider_fill,
+        ..
+    } = use_applied_theme!(&theme, table);
+    let background = if checked {
+        checkbox_fill.as_ref()
+    } else {
+        "transparent"
+    };
+
+    rsx!(
+        rect {
+            width: "40",
+            height: "fill",
+            main_align: "center",
+            cross_align: "center",
+            onclick: move |_| onclick.call(()),
+            rect {
+                width: "16",
+                height: "16",
+                corner_radius: "4",
+                border: "1 solid {divider_fill}",
+                background: "{background}",
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn TableColumnDivider(column: usize) -> Element {
+    let mut config = consume_context::<TableConfig>();
+    let resizing = config.resizing;
+    let TableTheme { divider_fill, .. } = use_get_theme().table;
+    let mut hovering = use_signal(|| false);
+    let platform = use_platform();
+
+    use_drop(move || {
+        if *hovering.peek() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onmousedown = move |e: MouseEvent| {
+        e.stop_propagation();
+        config.start_resizing(column, e.get_screen_coordinates().x);
+    };
+
+    let onmouseenter = move |_| {
+        platform.set_cursor(CursorIcon::ColResize);
+        hovering.set(true);
+    };
+
+    let onmouseleave = move |_| {
+        if resizing.peek().is_none() {
+            platform.set_cursor(CursorIcon::default());
+        }
+        hovering.set(false);
+    };
+
+    let is_resizing = resizing
+        .read()
+        .map(|resizing| resizing.column == column)
+        .unwrap_or_default();
+    let background = if *hovering.read() || is_resizing {
+        divider_fill.as_ref()
+    } else {
+        "transparent"
+    };
+
+    rsx!(rect {
+        onmousedown,
+        onmouseenter,
+        onmouseleave,
+        position: "absolute",
+        position_top: "0",
+        position_right: "0",
+        width: "4",
+        height: "100%",
+        background: "{background}",
+    })
+}
+
 /// [`TableHead`] component properties.
 #[derive(Props, Clone, PartialEq)]
 pub struct TableHeadProps {
@@ -34,8 +134,30 @@ pub struct TableHeadProps {
 ///
 #[allow(non_snake_case)]
 pub fn TableHead(TableHeadProps { children }: TableHeadProps) -> Element {
+    let config = consume_context::<TableConfig>();
+    provide_context(TableHeadContext);
+
+    if !config.sticky_header {
+        return rsx!(
+            rect { width: "100%", {children} }
+        );
+    }
+
+    // Follow the width and horizontal scroll of the body so the columns stay aligned
+    let width = config
+        .body_width
+        .read()
+        .map(|width| width.to_string())
+        .unwrap_or_else(|| "100%".to_string());
+    let offset_x = *config.scroll_x.read();
+
     rsx!(
-        rect { width: "100%", {children} }
+        rect {
+            width: "100%",
+            overflow: "clip",
+            offset_x: "{offset_x}",
+            rect { width: "{width}", {children} }
+        }
     )
 }
 
@@ -53,8 +175,38 @@ pub struct TableBodyProps {
 ///
 #[allow(non_snake_case)]
 pub fn TableBody(TableBodyProps { children }: TableBodyProps) -> Element {
+    let config = consume_context::<TableConfig>();
+    let (node_ref, size) = use_node();
+
+    let mut body_width = config.body_width;
+    let _ = use_memo_with_dependencies(&size.area.width(), move |width| {
+        if width > 0.0 && *body_width.peek() != Some(width) {
+            body_width.set(Some(width));
+        }
+    });
+
+    if !config.sticky_header {
+        return rsx!(
+            rect { width: "100%", {children} }
+        );
+    }
+
+    let mut scroll_x = config.scroll_x;
+    let width = config.row_width();
+
     rsx!(
-        rect { width: "100%", {children} }
+        ScrollView {
+            theme: theme_with!(ScrollViewTheme {
+                height: "fill".into(),
+            }),
+            onscroll: move |(x, _)| scroll_x.set(x),
+            rect {
+                reference: node_ref,
+                width: "{width}",
+                min_width: "100%",
+                {children}
+            }
+        }
     )
 }
 
@@ -68,6 +220,13 @@ pub struct TableRowProps {
     /// Show the row with a different background, this allows to have a zebra-style table.
     #[props(default = false)]
     alternate_colors: bool,
+    /// Show a checkbox at the start of the row, checked when `true`.
+    ///
+    /// Use it in the row of the [`TableHead`] to have a select-all checkbox.
+    /// Make sure all the rows of the table have it so the columns stay aligned.
+    pub selected: Option<bool>,
+    /// Handler for the `onselect` event, called with the new selection state when the checkbox is clicked.
+    pub onselect: Option<EventHandler<bool>>,
 }
 
 /// `TableRow` component.
@@ -84,16 +243,23 @@ pub fn TableRow(
         theme,
         children,
         alternate_colors,
+        selected,
+        onselect,
     }: TableRowProps,
 ) -> Element {
-    let theme = use_applied_theme!(&theme, table);
     let TableTheme {
         divider_fill,
         alternate_row_background,
         row_background,
+        selected_row_background,
         ..
-    } = theme;
-    let background = if alternate_colors {
+    } = use_applied_theme!(&theme, table);
+    let config = consume_context::<TableConfig>();
+    let width = config.row_width();
+    let inner_width = if config.is_resized() { "auto" } else { "fill" };
+    let background = if selected == Some(true) {
+        selected_row_background
+    } else if alternate_colors {
         alternate_row_background
     } else {
         row_background
@@ -102,9 +268,27 @@ pub fn TableRow(
     rsx!(
         rect {
             direction: "horizontal",
-            width: "100%",
+            width: "{width}",
+            min_width: "100%",
             background: "{background}",
-            {children}
+            if let Some(selected) = selected {
+                TableCheckbox {
+                    theme: theme.clone(),
+                    checked: selected,
+                    onclick: move |_| {
+                        if let Some(onselect) = &onselect {
+                            onselect.call(!selected);
+                        }
+                    }
+                }
+                rect {
+                    width: "{inner_width}",
+                    direction: "horizontal",
+                    {children}
+                }
+            } else {
+                {children}
+            }
         }
         rect {
             height: "1",
@@ -123,6 +307,57 @@ pub enum OrderDirection {
     Down,
 }
 
+impl OrderDirection {
+    /// Get the opposite direction.
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Down => Self::Up,
+        }
+    }
+}
+
+/// Column and direction a [`Table`] is sorted by.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TableSort {
+    /// Index of the sorted column.
+    pub column: usize,
+    /// Direction of the sorting.
+    pub direction: OrderDirection,
+}
+
+impl TableSort {
+    /// Get the sorting after clicking the head of the given `column`.
+    ///
+    /// The direction is toggled if the table was already sorted by this column,
+    /// otherwise the column is sorted in the default direction.
+    pub fn toggle(current: Option<Self>, column: usize) -> Self {
+        match current {
+            Some(current) if current.column == column => Self {
+                column,
+                direction: current.direction.toggled(),
+            },
+            _ => Self {
+                column,
+                direction: OrderDirection::default(),
+            },
+        }
+    }
+
+    /// Sort the given rows with `compare`, which receives two rows and the index of the sorted column.
+    ///
+    /// `compare` must return the ascending order, it gets reversed when sorting in the [`OrderDirection::Up`] direction.
+    pub fn sort_by<T>(&self, rows: &mut [T], mut compare: impl FnMut(&T, &T, usize) -> Ordering) {
+        rows.sort_by(|a, b| {
+            let ordering = compare(a, b, self.column);
+            match self.direction {
+                OrderDirection::Down => ordering,
+                OrderDirection::Up => ordering.reverse(),
+            }
+        });
+    }
+}
+
 /// [`TableCell`] component properties.
 #[derive(Props, Clone, PartialEq)]
 pub struct TableCellProps {
@@ -135,6 +370,16 @@ pub struct TableCellProps {
     /// **This is only a visual change (it changes the icon), you need to sort stuff yourself.**
     #[props(into)]
     pub order_direction: Option<Option<OrderDirection>>,
+    /// Index of the column this cell belongs to.
+    ///
+    /// In the cells of the [`TableHead`] it makes the column sortable by clicking the cell,
+    /// and the sorting direction icon is shown based on the current [`TableSort`] of the [`Table`]
+    /// unless `order_direction` is specified. It is also needed for the cell to follow the width of its column
+    /// when the columns are resizable.
+    pub column: Option<usize>,
+    /// Allow sorting the table by clicking this cell, only used in the cells of the [`TableHead`] with a `column`.
+    #[props(default = true)]
+    pub sortable: bool,
     /// The padding of the cell.
     #[props(default = "5 25".to_string(), into)]
     pub padding: String,
@@ -150,27 +395,39 @@ pub struct TableCellProps {
 ///
 #[allow(non_snake_case)]
 pub fn TableCell(props: TableCellProps) -> Element {
-    let config = consume_context::<TableConfig>();
-    let width = 100.0 / config.columns as f32;
+    let mut config = consume_context::<TableConfig>();
+    let is_head = try_consume_context::<TableHeadContext>().is_some();
     let TableCellProps {
         children,
         order_direction,
         padding,
         height,
+        column,
+        sortable,
         ..
     } = &props;
+    let column = *column;
+    let width = config.column_width(column);
+    let sort_column = column.filter(|_| is_head && *sortable);
+    let resize_column = column.filter(|_| is_head && config.resizable);
+    let order_direction =
+        order_direction.or_else(|| sort_column.map(|column| config.order_direction(column)));
 
     rsx!(
         rect {
             overflow: "clip",
             padding: "{padding}",
-            width: "{width}%",
+            width: "{width}",
             main_align: "center",
             cross_align: "center",
             height: "{height}",
             text_align: "right",
             direction: "horizontal",
             onclick: move |e| {
+                // Releasing the mouse after resizing a column must not sort it
+                if let (Some(column), None) = (sort_column, *config.resizing.peek()) {
+                    config.sort_by(column);
+                }
                 if let Some(onclick) = &props.onclick {
                     onclick.call(e);
                 }
@@ -188,6 +445,11 @@ pub fn TableCell(props: TableCellProps) -> Element {
                 }
             }
             {children}
+            if let Some(column) = resize_column {
+                TableColumnDivider {
+                    column
+                }
+            }
         }
     )
 }
@@ -201,6 +463,29 @@ pub struct TableProps {
     pub columns: usize,
     /// The content of the table.
     pub children: Element,
+    /// Current sorting of the table.
+    ///
+    /// When not specified, the table keeps track of the sorting by itself.
+    pub sort: Option<TableSort>,
+    /// Handler for the `onsort` event, called when a sortable [`TableCell`] is clicked.
+    pub onsort: Option<EventHandler<TableSort>>,
+    /// Keep the [`TableHead`] pinned while the [`TableBody`] scrolls.
+    ///
+    /// The body becomes scrollable by itself, so the table needs a fixed height.
+    #[props(default = false)]
+    pub sticky_header: bool,
+    /// Allow resizing the columns by dragging the dividers between the cells of the [`TableHead`].
+    ///
+    /// The cells need a `column` to follow the width of their column.
+    #[props(default = false)]
+    pub resizable_columns: bool,
+    /// Width in pixels of each column, columns share the available width evenly when not specified.
+    pub column_widths: Option<Vec<f32>>,
+    /// Minimum width in pixels of a column when resizing it.
+    #[props(default = 50.0)]
+    pub min_column_width: f32,
+    /// Handler for the `onresize` event, called with the width of every column after resizing one of them.
+    pub onresize: Option<EventHandler<Vec<f32>>>,
 }
 
 /// `Table` component.
@@ -211,12 +496,24 @@ pub struct TableProps {
 /// # Styling
 /// Inherits the [`TableTheme`](freya_hooks::TableTheme) theme.
 ///
+/// # Sorting
+/// Give the head cells a `column` to sort the table when they are clicked.
+/// The table emits the new [`TableSort`] with `onsort`, which can be used to sort the rows,
+/// for example with [`TableSort::sort_by`].
+///
 #[allow(non_snake_case)]
 pub fn Table(
     TableProps {
         theme,
         columns,
         children,
+        sort,
+        onsort,
+        sticky_header,
+        resizable_columns,
+        column_widths,
+        min_column_width,
+        onresize,
     }: TableProps,
 ) -> Element {
     let TableTheme {
@@ -227,9 +524,73 @@ pub fn Table(
         font_theme: FontTheme { color },
         ..
     } = use_applied_theme!(&theme, table);
-    provide_context(TableConfig { columns });
+    let mut sort_state = use_signal(|| sort);
+    let scroll_x = use_signal(|| 0.0);
+    let body_width = use_signal(|| None);
+    let mut widths_state = use_signal(|| column_widths.clone().unwrap_or_default());
+    let mut resizing = use_signal::<Option<ColumnResizing>>(|| None);
+    let (node_ref, layout) = use_node_signal();
+    let platform = use_platform();
+
+    // Update the column widths if the passed ones change
+    let _ = use_memo_with_dependencies(&column_widths, move |column_widths| {
+        if let Some(column_widths) = column_widths {
+            widths_state.set(column_widths);
+        }
+    });
+
+    // Update the sorting if the passed one changes, it's cleared when it stops being passed
+    let _ = use_memo_with_dependencies(&sort, move |sort| {
+        if *sort_state.peek() != sort {
+            sort_state.set(sort);
+        }
+    });
+
+    provide_context(TableConfig {
+        columns,
+        sort: sort_state,
+        controlled: sort.is_some(),
+        onsort,
+        sticky_header,
+        scroll_x,
+        body_width,
+        resizable: resizable_columns,
+        column_widths: widths_state,
+        min_column_width,
+        resizing,
+        layout,
+    });
+
+    // Resize the column while dragging its divider
+    let onglobalmouseover = move |e: MouseEvent| {
+        if let Some(ColumnResizing {
+            column,
+            start_x,
+            start_width,
+        }) = *resizing.peek()
+        {
+            let width = start_width + (e.get_screen_coordinates().x - start_x) as f32;
+            if let Some(current) = widths_state.write().get_mut(column) {
+                *current = width.max(min_column_width);
+            }
+        }
+    };
+
+    // Stop resizing when the mouse is released
+    let onglobalclick = move |_: MouseEvent| {
+        if resizing.peek().is_some() {
+            resizing.set(None);
+            platform.set_cursor(CursorIcon::default());
+            if let Some(onresize) = &onresize {
+                onresize.call(widths_state.peek().clone());
+            }
+        }
+    };
 
     rsx!(rect {
+        reference: node_ref,
+        onglobalmouseover,
+        onglobalclick,
         overflow: "clip",
         color: "{color}",
         background: "{background}",
@@ -240,7 +601,398 @@ pub fn Table(
     })
 }
 
+#[derive(Clone)]
+struct TableHeadContext;
+
+#[derive(Clone, Copy, PartialEq)]
+struct ColumnResizing {
+    column: usize,
+    start_x: f64,
+    start_width: f32,
+}
+
 #[derive(Clone)]
 pub struct TableConfig {
     columns: usize,
+    sort: Signal<Option<TableSort>>,
+    controlled: bool,
+    onsort: Option<EventHandler<TableSort>>,
+    sticky_header: bool,
+    scroll_x: Signal<f32>,
+    body_width: Signal<Option<f32>>,
+    resizable: bool,
+    column_widths: Signal<Vec<f32>>,
+    min_column_width: f32,
+    resizing: Signal<Option<ColumnResizing>>,
+    layout: Signal<NodeReferenceLayout>,
+}
+
+impl TableConfig {
+    fn is_resized(&self) -> bool {
+        !self.column_widths.read().is_empty()
+    }
+
+    fn column_width(&self, column: Option<usize>) -> String {
+        match column.and_then(|column| self.column_widths.read().get(column).copied()) {
+            Some(width) => width.to_string(),
+            None => format!("{}%", 100.0 / self.columns as f32),
+        }
+    }
+
+    fn row_width(&self) -> &'static str {
+        if self.is_resized() {
+            "auto"
+        } else {
+            "100%"
+        }
+    }
+
+    fn start_resizing(&mut self, column: usize, start_x: f64) {
+        // Start with the columns sharing the table width evenly
+        if self.column_widths.peek().len() != self.columns {
+            let width = self.layout.peek().area.width() / self.columns as f32;
+            self.column_widths.set(vec![width; self.columns]);
+        }
+        let Some(start_width) = self.column_widths.peek().get(column).copied() else {
+            return;
+        };
+        self.resizing.set(Some(ColumnResizing {
+            column,
+            start_x,
+            start_width,
+        }));
+    }
+
+    fn order_direction(&self, column: usize) -> Option<OrderDirection> {
+        self.sort
+            .read()
+            .filter(|sort| sort.column == column)
+            .map(|sort| sort.direction)
+    }
+
+    fn sort_by(&mut self, column: usize) {
+        let sort = TableSort::toggle(*self.sort.peek(), column);
+        if !self.controlled {
+            self.sort.set(Some(sort));
+        }
+        if let Some(onsort) = &self.onsort {
+            onsort.call(sort);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[test]
+    pub fn toggle_sort() {
+        let sort = TableSort::toggle(None, 1);
+        assert_eq!(sort.column, 1);
+        assert_eq!(sort.direction, OrderDirection::Down);
+
+        let sort = TableSort::toggle(Some(sort), 1);
+        assert_eq!(sort.direction, OrderDirection::Up);
+
+        let sort = TableSort::toggle(Some(sort), 1);
+        assert_eq!(sort.direction, OrderDirection::Down);
+
+        let sort = TableSort::toggle(Some(TableSort::toggle(Some(sort), 1)), 0);
+        assert_eq!(sort.column, 0);
+        assert_eq!(sort.direction, OrderDirection::Down);
+
+        let mut rows = vec![2, 3, 1];
+        sort.sort_by(&mut rows, |a, b, _| a.cmp(b));
+        assert_eq!(rows, vec![1, 2, 3]);
+        let sort = TableSort {
+            direction: OrderDirection::Up,
+            ..sort
+        };
+        sort.sort_by(&mut rows, |a, b, _| a.cmp(b));
+        assert_eq!(rows, vec![3, 2, 1]);
+    }
+
+    #[tokio::test]
+    pub async fn table_sort() {
+        fn table_app() -> Element {
+            let mut sort = use_signal(|| None);
+            let sort_text = format!("{:?}", sort.read());
+
+            rsx!(
+                Table {
+                    columns: 2,
+                    onsort: move |s| sort.set(Some(s)),
+                    TableHead {
+                        TableRow {
+                            TableCell {
+                                column: 0,
+                                label { "A" }
+                            }
+                            TableCell {
+                                column: 1,
+                                label { "B" }
+                            }
+                        }
+                    }
+                }
+                label {
+                    "{sort_text}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(table_app);
+        let root = utils.root();
+        let label = root.get(1);
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("None"));
+
+        // Sort by the first column
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (50.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            label.get(0).text(),
+            Some("Some(TableSort { column: 0, direction: Down })")
+        );
+
+        // Toggle the direction of the first column
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (50.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            label.get(0).text(),
+            Some("Some(TableSort { column: 0, direction: Up })")
+        );
+
+        // Sort by the second column
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (300.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            label.get(0).text(),
+            Some("Some(TableSort { column: 1, direction: Down })")
+        );
+    }
+
+    #[tokio::test]
+    pub async fn table_controlled_sort_reset() {
+        fn table_app() -> Element {
+            let mut sort = use_signal(|| {
+                Some(TableSort {
+                    column: 0,
+                    direction: OrderDirection::Down,
+                })
+            });
+            let mut emitted = use_signal(|| None);
+            let emitted_text = format!("{:?}", emitted.read());
+
+            rsx!(
+                rect {
+                    width: "100%",
+                    height: "50",
+                    onclick: move |_| sort.set(None),
+                }
+                Table {
+                    columns: 1,
+                    sort: sort(),
+                    onsort: move |s| emitted.set(Some(s)),
+                    TableHead {
+                        TableRow {
+                            TableCell {
+                                column: 0,
+                                label { "A" }
+                            }
+                        }
+                    }
+                }
+                label {
+                    "{emitted_text}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(table_app);
+        let root = utils.root();
+        let label = root.get(2);
+        utils.wait_for_update().await;
+
+        // Stop passing the sorting
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // The table is no longer sorted, so the column starts in the default direction
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (50.0, 65.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            label.get(0).text(),
+            Some("Some(TableSort { column: 0, direction: Down })")
+        );
+
+        // And it keeps track of the sorting by itself
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (50.0, 65.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            label.get(0).text(),
+            Some("Some(TableSort { column: 0, direction: Up })")
+        );
+    }
+
+    #[tokio::test]
+    pub async fn table_sticky_header() {
+        fn table_app() -> Element {
+            rsx!(
+                Table {
+                    theme: theme_with!(TableTheme {
+                        height: "200".into(),
+                    }),
+                    columns: 1,
+                    sticky_header: true,
+                    TableHead {
+                        TableRow {
+                            TableCell {
+                                label { "Head" }
+                            }
+                        }
+                    }
+                    TableBody {
+                        for i in 0..20 {
+                            TableRow {
+                                key: "{i}",
+                                TableCell {
+                                    label { "{i}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(table_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let head = root.get(0).get(0);
+        let first_row = root.get(0).get(1).get(0).get(0).get(0).get(0);
+        let first_row_y = first_row.area().unwrap().min_y();
+
+        // Scroll the body
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -50.).into(),
+            cursor: (5., 100.).into(),
+        });
+        utils.wait_for_update().await;
+
+        // The head stays in place while the rows move
+        assert_eq!(head.area().unwrap().min_y(), 0.0);
+        assert!(first_row.area().unwrap().min_y() < first_row_y);
+    }
+
+    #[tokio::test]
+    pub async fn table_resize_columns() {
+        fn table_app() -> Element {
+            let mut widths = use_signal(Vec::new);
+            let widths_text = format!("{:?}", widths.read());
+
+            rsx!(
+                Table {
+                    columns: 2,
+                    resizable_columns: true,
+                    onresize: move |w| widths.set(w),
+                    TableHead {
+                        TableRow {
+                            TableCell {
+                                column: 0,
+                                label { "A" }
+                            }
+                            TableCell {
+                                column: 1,
+                                label { "B" }
+                            }
+                        }
+                    }
+                }
+                label {
+                    "{widths_text}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(table_app);
+        let root = utils.root();
+        let label = root.get(1);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Drag the divider of the first column to the right
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (248.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (298.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (298.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("[300.0, 250.0]"));
+
+        // Columns can't be smaller than the minimum width
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (298.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (0.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (0.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("[50.0, 250.0]"));
+    }
 }
@@ -1,7 +1,14 @@
+use std::time::Duration;
+
 use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
+use freya_elements::events::MouseEvent;
+
+use freya_hooks::{use_applied_theme, use_node_signal, TooltipTheme, TooltipThemeWith};
+use tokio::time::sleep;
 
-use freya_hooks::{use_applied_theme, TooltipTheme, TooltipThemeWith};
+/// Distance in pixels between the pointer and the [`Tooltip`] when following the cursor.
+const CURSOR_OFFSET: f64 = 12.0;
 
 /// [`Tooltip`] component properties.
 #[derive(Props, Clone, PartialEq)]
@@ -10,6 +17,19 @@ pub struct TooltipProps {
     pub theme: Option<TooltipThemeWith>,
     /// Url as the Tooltip destination.
     pub url: String,
+    /// Element that shows the Tooltip when hovered.
+    /// Without it, the Tooltip is always shown.
+    pub children: Element,
+    /// Milliseconds to wait before showing the Tooltip once the children are hovered.
+    #[props(default = 0)]
+    pub delay_ms: u64,
+    /// Milliseconds to wait before hiding the Tooltip once the pointer leaves.
+    /// Useful to move the pointer from the children to the Tooltip without it disappearing.
+    #[props(default = 0)]
+    pub hide_delay_ms: u64,
+    /// Show the Tooltip next to the pointer and follow it while it moves over the children.
+    #[props(default = false)]
+    pub follow_cursor: bool,
 }
 
 /// `Tooltip` component
@@ -20,16 +40,74 @@ pub struct TooltipProps {
 /// # Styling
 /// Inherits the [`TooltipTheme`](freya_hooks::TooltipTheme)
 ///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         Tooltip {
+///             url: "Open settings",
+///             delay_ms: 500,
+///             follow_cursor: true,
+///             Button {
+///                 label { "Settings" }
+///             }
+///         }
+///     )
+/// }
+/// ```
 #[allow(non_snake_case)]
-pub fn Tooltip(TooltipProps { url, theme }: TooltipProps) -> Element {
+pub fn Tooltip(
+    TooltipProps {
+        url,
+        theme,
+        children,
+        delay_ms,
+        hide_delay_ms,
+        follow_cursor,
+    }: TooltipProps,
+) -> Element {
     let theme = use_applied_theme!(&theme, tooltip);
     let TooltipTheme {
         background,
         color,
         border_fill,
     } = theme;
+    let mut is_visible = use_signal(|| false);
+    let mut pending_task = use_signal::<Option<Task>>(|| None);
+    let mut cursor_position = use_signal(|| (0.0, 0.0));
+    let (node_ref, layout) = use_node_signal();
 
-    rsx!(
+    let mut set_visible = move |visible: bool, delay_ms: u64| {
+        if let Some(task) = pending_task.write().take() {
+            task.cancel();
+        }
+
+        if delay_ms == 0 {
+            is_visible.set(visible);
+        } else if *is_visible.peek() != visible {
+            let task = spawn(async move {
+                sleep(Duration::from_millis(delay_ms)).await;
+                is_visible.set(visible);
+                pending_task.set(None);
+            });
+            pending_task.set(Some(task));
+        }
+    };
+
+    let onmouseenter = move |_: MouseEvent| set_visible(true, delay_ms);
+
+    let onmouseleave = move |_: MouseEvent| set_visible(false, hide_delay_ms);
+
+    let onmouseover = move |e: MouseEvent| {
+        if follow_cursor {
+            let coordinates = e.get_element_coordinates();
+            cursor_position.set((coordinates.x, coordinates.y));
+        }
+    };
+
+    let tooltip = rsx!(
         rect {
             padding: "4 10",
             shadow: "0 4 5 0 rgb(0, 0, 0, 0.1)",
@@ -39,5 +117,123 @@ pub fn Tooltip(TooltipProps { url, theme }: TooltipProps) -> Element {
             main_align: "center",
             label { max_lines: "1", color: "{color}", "{url}" }
         }
+    );
+
+    if children.is_none() {
+        return tooltip;
+    }
+
+    // The Tooltip is placed right below the children unless it follows the cursor
+    let (top, left) = if follow_cursor {
+        let (x, y) = *cursor_position.read();
+        let height = layout.read().area.height() as f64;
+        (y - height + CURSOR_OFFSET, x + CURSOR_OFFSET)
+    } else {
+        (0.0, 0.0)
+    };
+
+    rsx!(
+        rect {
+            rect {
+                reference: node_ref,
+                onmouseenter,
+                onmouseleave,
+                onmouseover,
+                {children}
+            }
+            if *is_visible.read() {
+                rect {
+                    height: "0",
+                    width: "0",
+                    layer: "-999",
+                    rect {
+                        onmouseenter,
+                        onmouseleave,
+                        position: "absolute",
+                        position_top: "{top}",
+                        position_left: "{left}",
+                        {tooltip}
+                    }
+                }
+            }
+        }
     )
 }
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::*;
+    use std::time::Duration;
+    use tokio::time::sleep;
+
+    #[tokio::test]
+    pub async fn tooltip_delay() {
+        fn tooltip_app() -> Element {
+            rsx!(
+                Tooltip {
+                    url: "Hello",
+                    delay_ms: 300,
+                    hide_delay_ms: 300,
+                    rect {
+                        width: "100",
+                        height: "50",
+                    }
+                }
+            )
+        }
+
+        fn is_tooltip_visible(utils: &mut TestingHandler) -> bool {
+            utils.root().get(0).get(1).child(0).is_some()
+        }
+
+        let mut utils = launch_test(tooltip_app);
+        utils.wait_for_update().await;
+
+        assert!(!is_tooltip_visible(&mut utils));
+
+        // Hover the children
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (50.0, 25.0).into(),
+            button: None,
+        });
+        utils.wait_for_update().await;
+
+        // The Tooltip waits before showing up
+        assert!(!is_tooltip_visible(&mut utils));
+
+        sleep(Duration::from_millis(350)).await;
+        utils.wait_for_update().await;
+
+        assert!(is_tooltip_visible(&mut utils));
+        assert_eq!(
+            utils
+                .root()
+                .get(0)
+                .get(1)
+                .get(0)
+                .get(0)
+                .get(0)
+                .get(0)
+                .text(),
+            Some("Hello")
+        );
+
+        // Leave the children
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (300.0, 300.0).into(),
+            button: None,
+        });
+        utils.wait_for_update().await;
+
+        // The Tooltip waits before hiding
+        assert!(is_tooltip_visible(&mut utils));
+
+        sleep(Duration::from_millis(350)).await;
+        utils.wait_for_update().await;
+
+        assert!(!is_tooltip_visible(&mut utils));
+    }
+}
@@ -53,7 +53,10 @@ impl AccessibilityManager {
         accessibility_id: AccessibilityId,
         node_accessibility: &AccessibilityNodeState,
     ) {
-        let mut builder = NodeBuilder::new(Role::Unknown);
+        let role = node_accessibility
+            .role
+            .unwrap_or_else(|| dioxus_node.get_default_role());
+        let mut builder = NodeBuilder::new(role);
 
         // Set children
         let children = dioxus_node.get_accessibility_children();
@@ -68,14 +71,11 @@ impl AccessibilityManager {
             builder.set_value(value);
         }
 
-        // Set name
+        // Set name, fallback to the texts inside, e.g the label of a button
         if let Some(name) = &node_accessibility.name {
             builder.set_name(name.to_owned());
-        }
-
-        // Set role
-        if let Some(role) = node_accessibility.role {
-            builder.set_role(role);
+        } else if let Some(texts) = dioxus_node.get_descendant_texts() {
+            builder.set_name(texts);
         }
 
         // Set the area
@@ -1,9 +1,9 @@
 pub mod accessibility_manager;
 pub use accessibility_manager::*;
 
-use accesskit::NodeId as AccessibilityId;
+use accesskit::{NodeId as AccessibilityId, Role};
 use dioxus_native_core::{
-    node::{NodeType, TextNode},
+    node::{ElementNode, NodeType, TextNode},
     real_dom::NodeImmutable,
     NodeId,
 };
@@ -25,8 +25,14 @@ trait NodeAccessibility {
     /// Return the first TextNode from this Node
     fn get_inner_texts(&self) -> Option<String>;
 
+    /// Collect the texts from this Node and its descendants
+    fn get_descendant_texts(&self) -> Option<String>;
+
     /// Collect all the AccessibilityIDs from a Node's children
     fn get_accessibility_children(&self) -> Vec<AccessibilityId>;
+
+    /// Role used when the Node doesn't specify one, based on its element
+    fn get_default_role(&self) -> Role;
 }
 
 impl NodeAccessibility for DioxusNode<'_> {
@@ -42,6 +48,30 @@ impl NodeAccessibility for DioxusNode<'_> {
         }
     }
 
+    /// Collect the texts from this Node and its descendants
+    fn get_descendant_texts(&self) -> Option<String> {
+        fn collect_texts(node: &DioxusNode, texts: &mut Vec<String>) {
+            if let NodeType::Text(TextNode { text, .. }) = &*node.node_type() {
+                let text = text.trim();
+                if !text.is_empty() {
+                    texts.push(text.to_owned());
+                }
+            }
+            for child in node.children() {
+                collect_texts(&child, texts);
+            }
+        }
+
+        let mut texts = Vec::new();
+        collect_texts(self, &mut texts);
+
+        if texts.is_empty() {
+            None
+        } else {
+            Some(texts.join(" "))
+        }
+    }
+
     /// Collect all the AccessibilityIDs from a Node's children
     fn get_accessibility_children(&self) -> Vec<AccessibilityId> {
         self.children()
@@ -52,6 +82,19 @@ impl NodeAccessibility for DioxusNode<'_> {
             })
             .collect::<Vec<AccessibilityId>>()
     }
+
+    /// Role used when the Node doesn't specify one, based on its element
+    fn get_default_role(&self) -> Role {
+        match &*self.node_type() {
+            NodeType::Element(ElementNode { tag, .. }) => match tag.as_str() {
+                "label" | "paragraph" | "text" => Role::StaticText,
+                "image" | "svg" => Role::Image,
+                "rect" => Role::GenericContainer,
+                _ => Role::Unknown,
+            },
+            _ => Role::Unknown,
+        }
+    }
 }
 
 pub fn process_accessibility(
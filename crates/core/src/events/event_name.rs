@@ -57,6 +57,36 @@ impl From<EventName> for &str {
     }
 }
 
+impl TryFrom<&str> for EventName {
+    type Error = ();
+
+    fn try_from(event: &str) -> Result<Self, Self::Error> {
+        match event {
+            "click" => Ok(EventName::Click),
+            "mousedown" => Ok(EventName::MouseDown),
+            "mouseover" => Ok(EventName::MouseOver),
+            "mouseenter" => Ok(EventName::MouseEnter),
+            "mouseleave" => Ok(EventName::MouseLeave),
+            "wheel" => Ok(EventName::Wheel),
+            "pointerover" => Ok(EventName::PointerOver),
+            "pointerdown" => Ok(EventName::PointerDown),
+            "pointerenter" => Ok(EventName::PointerEnter),
+            "pointerleave" => Ok(EventName::PointerLeave),
+            "pointerup" => Ok(EventName::PointerUp),
+            "keydown" => Ok(EventName::KeyDown),
+            "keyup" => Ok(EventName::KeyUp),
+            "touchcancel" => Ok(EventName::TouchCancel),
+            "touchstart" => Ok(EventName::TouchStart),
+            "touchmove" => Ok(EventName::TouchMove),
+            "touchend" => Ok(EventName::TouchEnd),
+            "globalclick" => Ok(EventName::GlobalClick),
+            "globalmousedown" => Ok(EventName::GlobalMouseDown),
+            "globalmouseover" => Ok(EventName::GlobalMouseOver),
+            _ => Err(()),
+        }
+    }
+}
+
 impl Eq for EventName {}
 
 impl PartialOrd for EventName {
@@ -118,6 +148,11 @@ impl EventName {
         matches!(&self, Self::MouseEnter | Self::PointerEnter)
     }
 
+    /// Check if the event means that the pointer (e.g cursor) just left a Node
+    pub fn is_leave(&self) -> bool {
+        matches!(&self, Self::MouseLeave | Self::PointerLeave)
+    }
+
     /// Check if it's one of the Pointer variants
     pub fn is_pointer(&self) -> bool {
         matches!(
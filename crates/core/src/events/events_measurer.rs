@@ -5,14 +5,30 @@ use dioxus_native_core::{prelude::NodeImmutableDioxusExt, tree::TreeRef};
 use freya_dom::{dom::DioxusDOM, prelude::FreyaDOM};
 
 use freya_engine::prelude::*;
-use freya_node_state::{Fill, Style};
+use freya_node_state::{CursorSettings, Fill, Style};
+use torin::prelude::CursorPoint;
+use winit::event::MouseButton;
+use winit::window::CursorIcon;
 
 pub use crate::events::{DomEvent, NodesState, PlatformEvent};
 
 use crate::types::{EventEmitter, EventsQueue, PotentialEvents};
 
+use super::event_name::EventName;
 use super::potential_event::PotentialEvent;
 
+/// What the platform must do after processing the events.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProcessedEvents {
+    /// New cursor icon, if the one of the hovered node has changed.
+    pub cursor_icon: Option<CursorIcon>,
+    /// The window must start being dragged, the mouse was pressed in a `window_drag` region.
+    pub drag_window: bool,
+    /// A node started or stopped being hovered or pressed, so the nodes
+    /// with attributes like `background_hover` must be repainted.
+    pub interaction_changed: bool,
+}
+
 /// Process the events and emit them to the VirtualDOM
 pub fn process_events(
     dom: &FreyaDOM,
@@ -22,27 +38,47 @@ pub fn process_events(
     nodes_state: &mut NodesState,
     viewports: &Viewports,
     scale_factor: f64,
-) {
+) -> ProcessedEvents {
+    // Remember the hovered and pressed nodes to know if any of them changed
+    let previous_interacted_nodes = nodes_state.interacted_nodes();
+
     // 1. Get global events created from the incoming events
     let global_events = measure_global_events(events);
 
     // 2. Get potential events that could be emitted based on the elements layout and viewports
-    let potential_events = measure_potential_event_listeners(layers, events, viewports, dom);
+    let mut potential_events = measure_potential_event_listeners(layers, events, viewports, dom);
+
+    // 2.1 Send the pointer events to the node that captured the pointer, if any
+    measure_pointer_capture(&mut potential_events, events, nodes_state, dom);
+
+    // 2.5 Get the cursor icon of the top-most hovered node
+    let cursor_icon = measure_cursor_icon(&potential_events, events, dom)
+        .and_then(|icon| nodes_state.set_cursor_icon(icon));
+
+    // 2.6 Check if the window must be dragged
+    let drag_window = measure_window_drag(&potential_events, dom);
+
+    // 2.7 Get all the nodes under the cursor, whether they are listening or not
+    let hovered_path = measure_hovered_path(&potential_events, events);
+
+    // 2.8 Mark the pressed nodes
+    nodes_state.update_pressed_nodes(&potential_events, events);
 
     // 3. Get what events can be actually emitted based on what elements are listening
     let dom_events = measure_dom_events(potential_events, dom, scale_factor);
 
     // 4. Filter the dom events and get potential colateral events, e.g mouseover -> mouseenter
     let (potential_colateral_events, mut to_emit_dom_events) =
-        nodes_state.process_events(&dom_events, events);
+        nodes_state.process_events(&dom_events, events, hovered_path);
 
     // 5. Get what colateral events can actually be emitted
     let to_emit_dom_colateral_events =
         measure_dom_events(potential_colateral_events, dom, scale_factor);
 
-    // 6. Join both the dom and colateral dom events and sort them
+    // 6. Join both the dom and colateral dom events and emit the leave events first,
+    // keeping the order in which they were measured so it's always the same
     to_emit_dom_events.extend(to_emit_dom_colateral_events);
-    to_emit_dom_events.sort_unstable();
+    to_emit_dom_events.sort_by_key(|event| !event.name.is_leave());
 
     // 7. Emit the DOM events
     for event in to_emit_dom_events {
@@ -54,6 +90,158 @@ pub fn process_events(
 
     // 9. Clear the events queue
     events.clear();
+
+    let interaction_changed = nodes_state.interacted_nodes() != previous_interacted_nodes;
+
+    ProcessedEvents {
+        cursor_icon,
+        drag_window,
+        interaction_changed,
+    }
+}
+
+/// Measure the cursor icon of the top-most node under the cursor.
+///
+/// Returns `None` if the cursor was not moved.
+pub fn measure_cursor_icon(
+    potential_events: &PotentialEvents,
+    events: &EventsQueue,
+    fdom: &FreyaDOM,
+) -> Option<Option<CursorIcon>> {
+    let cursor_moved = events
+        .iter()
+        .any(|event| event.get_name() == EventName::MouseOver);
+
+    if !cursor_moved {
+        return None;
+    }
+
+    let hovered_nodes = potential_events.get(&EventName::MouseOver);
+
+    let icon = hovered_nodes.and_then(|nodes| nodes.last()).and_then(
+        |PotentialEvent { node_id, .. }| {
+            let node = fdom.rdom().get(*node_id)?;
+            let cursor_settings = node.get::<CursorSettings>()?;
+            cursor_settings.icon
+        },
+    );
+
+    Some(icon)
+}
+
+/// Capture the pointer when the top-most pressed node has `pointer_capture` enabled,
+/// and send the mouse movements and releases only to the captured node until the mouse is released.
+pub fn measure_pointer_capture(
+    potential_events: &mut PotentialEvents,
+    events: &EventsQueue,
+    nodes_state: &mut NodesState,
+    fdom: &FreyaDOM,
+) {
+    if nodes_state.captured_node().is_none() {
+        let capturing_node = potential_events
+            .get(&EventName::MouseDown)
+            .and_then(|pressed_nodes| pressed_nodes.last())
+            .filter(|PotentialEvent { node_id, .. }| {
+                fdom.rdom()
+                    .get(*node_id)
+                    .and_then(|node| Some(node.get::<CursorSettings>()?.pointer_capture))
+                    .unwrap_or_default()
+            });
+
+        if let Some(PotentialEvent { node_id, layer, .. }) = capturing_node {
+            nodes_state.set_pointer_capture(*node_id, *layer);
+        }
+    }
+
+    let Some((captured_node, layer)) = nodes_state.captured_node() else {
+        return;
+    };
+
+    // The captured node was removed
+    if fdom.rdom().get(captured_node).is_none() {
+        nodes_state.release_pointer_capture();
+        return;
+    }
+
+    let is_captured_event =
+        |name: EventName| matches!(name, EventName::MouseOver | EventName::Click);
+
+    potential_events.retain(|name, _| !is_captured_event(*name));
+
+    for event in events {
+        let name = event.get_name();
+        if is_captured_event(name) {
+            potential_events
+                .entry(name)
+                .or_default()
+                .push(PotentialEvent {
+                    node_id: captured_node,
+                    layer,
+                    event: event.clone(),
+                });
+        }
+    }
+
+    // Releasing the mouse ends the capture
+    if events
+        .iter()
+        .any(|event| event.get_name() == EventName::Click)
+    {
+        nodes_state.release_pointer_capture();
+    }
+}
+
+/// Measure all the nodes under the cursor, used to know what nodes the cursor entered or left.
+///
+/// Returns `None` if the cursor was not moved.
+pub fn measure_hovered_path(
+    potential_events: &PotentialEvents,
+    events: &EventsQueue,
+) -> Option<Vec<(NodeId, Option<i16>)>> {
+    let cursor_moved = events
+        .iter()
+        .any(|event| event.get_name() == EventName::MouseOver);
+
+    if !cursor_moved {
+        return None;
+    }
+
+    let hovered_path = potential_events
+        .get(&EventName::MouseOver)
+        .map(|nodes| {
+            nodes
+                .iter()
+                .map(|PotentialEvent { node_id, layer, .. }| (*node_id, *layer))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(hovered_path)
+}
+
+/// Check if the left mouse button was pressed on a top-most node with `window_drag` enabled.
+pub fn measure_window_drag(potential_events: &PotentialEvents, fdom: &FreyaDOM) -> bool {
+    let Some(pressed_nodes) = potential_events.get(&EventName::MouseDown) else {
+        return false;
+    };
+
+    pressed_nodes
+        .last()
+        .filter(|PotentialEvent { event, .. }| {
+            matches!(
+                event,
+                PlatformEvent::Mouse {
+                    button: Some(MouseButton::Left),
+                    ..
+                }
+            )
+        })
+        .and_then(|PotentialEvent { node_id, .. }| {
+            let node = fdom.rdom().get(*node_id)?;
+            let cursor_settings = node.get::<CursorSettings>()?;
+            Some(cursor_settings.window_drag)
+        })
+        .unwrap_or_default()
 }
 
 /// Measure globale events
@@ -80,12 +268,19 @@ pub fn measure_potential_event_listeners(
     let mut potential_events = PotentialEvents::default();
 
     let layout = fdom.layout();
+    let rdom = fdom.rdom();
 
     // Propagate events from the top to the bottom
     for (layer, layer_nodes) in layers.layers() {
         for node_id in layer_nodes {
             let areas = layout.get(*node_id);
             if let Some(areas) = areas {
+                // Hidden elements are not hit by the pointer
+                let is_hidden = rdom
+                    .get(*node_id)
+                    .map(|node| node.get::<Style>().unwrap().visibility.is_hidden())
+                    .unwrap_or_default();
+
                 'events: for event in events.iter() {
                     if let PlatformEvent::Keyboard { name, .. } = event {
                         let event_data = PotentialEvent {
@@ -102,7 +297,8 @@ pub fn measure_potential_event_listeners(
                             _ => None,
                         };
                         if let Some((name, cursor)) = data {
-                            let cursor_is_inside = areas.area.contains(cursor.to_f32());
+                            let cursor_is_inside =
+                                !is_hidden && areas.area.contains(cursor.to_f32());
 
                             // Make sure the cursor is inside the node area
                             if cursor_is_inside {
@@ -141,6 +337,27 @@ pub fn measure_potential_event_listeners(
     potential_events
 }
 
+/// Get the top-most node under the given cursor position, whether it's listening for events or not.
+pub fn measure_hit_node(
+    layers: &Layers,
+    viewports: &Viewports,
+    fdom: &FreyaDOM,
+    cursor: CursorPoint,
+) -> Option<NodeId> {
+    let events = EventsQueue::from_iter([PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor,
+        button: None,
+    }]);
+
+    let potential_events = measure_potential_event_listeners(layers, &events, viewports, fdom);
+
+    potential_events
+        .get(&EventName::MouseOver)
+        .and_then(|nodes| nodes.last())
+        .map(|PotentialEvent { node_id, .. }| *node_id)
+}
+
 fn is_node_parent_of(rdom: &DioxusDOM, node: NodeId, parent_node: NodeId) -> bool {
     let mut stack = vec![parent_node];
     while let Some(id) = stack.pop() {
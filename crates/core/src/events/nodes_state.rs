@@ -1,7 +1,9 @@
 #![allow(clippy::type_complexity)]
 
 use dioxus_native_core::NodeId;
-use rustc_hash::FxHashMap;
+use freya_node_state::InteractionState;
+use rustc_hash::{FxHashMap, FxHashSet};
+use winit::window::CursorIcon;
 
 use crate::{
     events::{DomEvent, PlatformEvent},
@@ -17,82 +19,133 @@ struct NodeMetadata {
 #[derive(Default)]
 pub struct NodesState {
     hovered_nodes: FxHashMap<NodeId, NodeMetadata>,
+    cursor_icon: Option<CursorIcon>,
+    captured_node: Option<(NodeId, NodeMetadata)>,
+    pressed_nodes: FxHashSet<NodeId>,
 }
 
 impl NodesState {
-    /// Update the node states given the new events
+    /// Get the interaction state of a node, e.g if it's hovered or pressed.
+    pub fn interaction(&self, node_id: &NodeId) -> InteractionState {
+        InteractionState {
+            hovered: self.hovered_nodes.contains_key(node_id),
+            pressed: self.pressed_nodes.contains(node_id),
+        }
+    }
+
+    /// Get the hovered and pressed nodes.
+    pub(crate) fn interacted_nodes(&self) -> (FxHashSet<NodeId>, FxHashSet<NodeId>) {
+        (
+            self.hovered_nodes.keys().copied().collect(),
+            self.pressed_nodes.clone(),
+        )
+    }
+
+    /// Mark the nodes under the cursor as pressed when the mouse is pressed, until it's released.
+    pub(crate) fn update_pressed_nodes(
+        &mut self,
+        potential_events: &PotentialEvents,
+        events: &[PlatformEvent],
+    ) {
+        for event in events {
+            match event.get_name() {
+                EventName::MouseDown => {
+                    self.pressed_nodes = potential_events
+                        .get(&EventName::MouseDown)
+                        .map(|nodes| nodes.iter().map(|node| node.node_id).collect())
+                        .unwrap_or_default();
+                }
+                EventName::Click => {
+                    self.pressed_nodes.clear();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Get the node that captured the pointer and its layer, see the `pointer_capture` attribute.
+    pub fn captured_node(&self) -> Option<(NodeId, Option<i16>)> {
+        self.captured_node
+            .as_ref()
+            .map(|(node_id, metadata)| (*node_id, metadata.layer))
+    }
+
+    /// Route the following pointer events to the given node until the capture is released.
+    pub fn set_pointer_capture(&mut self, node_id: NodeId, layer: Option<i16>) {
+        self.captured_node = Some((node_id, NodeMetadata { layer }));
+    }
+
+    /// Release the pointer capture, if any node has it.
+    pub fn release_pointer_capture(&mut self) {
+        self.captured_node = None;
+    }
+
+    /// Update the node states given the new events.
+    ///
+    /// `hovered_path` are all the nodes under the cursor, if it was moved, whether they listen to events or not.
+    /// Nodes that were hovered and are not in the path anymore get a `leave` event, and `enter` events are only
+    /// emitted for nodes that were not hovered before, so moving between children of the same node doesn't re-emit them.
     pub fn process_events(
         &mut self,
         events_to_emit: &[DomEvent],
         events: &[PlatformEvent],
+        hovered_path: Option<Vec<(NodeId, Option<i16>)>>,
     ) -> (PotentialEvents, Vec<DomEvent>) {
         let mut new_events_to_emit = Vec::default();
         let mut potential_events = PotentialEvents::default();
 
+        // We clone this here so events emitted in the same batch that mark an node
+        // as hovered will not affect the other events
+        let previously_hovered_nodes = self.hovered_nodes.clone();
+
         let recent_mouse_movement_event = any_recent_mouse_movement(events);
 
-        self.hovered_nodes.retain(|node_id, metadata| {
-            let no_recent_mouse_movement_on_me =
-                has_node_been_hovered_recently(events_to_emit, node_id);
-
-            if no_recent_mouse_movement_on_me {
-                if let Some(PlatformEvent::Mouse { cursor, button, .. }) =
-                    recent_mouse_movement_event
-                {
-                    let events = potential_events.entry(EventName::MouseLeave).or_default();
-                    // Emit a MouseLeave event as the cursor was moved outside the Node bounds
-                    events.push(PotentialEvent {
-                        node_id: *node_id,
-                        layer: metadata.layer,
-                        event: PlatformEvent::Mouse {
-                            name: EventName::MouseLeave,
-                            cursor,
-                            button,
-                        },
-                    });
-
-                    // Remove the node from the list of hovered nodes as now, the cursor has left
-                    return false;
+        if let (Some(hovered_path), Some(PlatformEvent::Mouse { cursor, button, .. })) =
+            (hovered_path, recent_mouse_movement_event)
+        {
+            let hovered_path = hovered_path
+                .into_iter()
+                .map(|(node_id, layer)| (node_id, NodeMetadata { layer }))
+                .collect::<FxHashMap<NodeId, NodeMetadata>>();
+
+            for (node_id, metadata) in &previously_hovered_nodes {
+                if hovered_path.contains_key(node_id) {
+                    continue;
                 }
+
+                let events = potential_events.entry(EventName::MouseLeave).or_default();
+                // Emit a MouseLeave event as the cursor was moved outside the Node bounds
+                events.push(PotentialEvent {
+                    node_id: *node_id,
+                    layer: metadata.layer,
+                    event: PlatformEvent::Mouse {
+                        name: EventName::MouseLeave,
+                        cursor,
+                        button,
+                    },
+                });
             }
-            true
-        });
 
-        // We clone this here so events emitted in the same batch that mark an node
-        // as hovered will not affect the other events
-        let hovered_nodes = self.hovered_nodes.clone();
+            self.hovered_nodes = hovered_path;
+        }
 
         // Emit new colateral events
         for event in events_to_emit {
             if event.name.can_change_hover_state() {
-                let is_hovered = hovered_nodes.contains_key(&event.node_id);
-
-                // Mark the Node as hovered if it wasn't already
-                if !is_hovered {
-                    self.hovered_nodes
-                        .insert(event.node_id, NodeMetadata { layer: event.layer });
+                // If the Node was already hovered, we don't need to emit an `enter` event again.
+                if event.name.is_enter() && previously_hovered_nodes.contains_key(&event.node_id) {
+                    continue;
                 }
 
-                if event.name.is_enter() {
-                    // If the Node was already hovered, we don't need to emit an `enter` event again.
-                    if is_hovered {
-                        continue;
-                    }
-                }
+                // Mark the Node as hovered if it wasn't already, e.g by touch movements
+                self.hovered_nodes
+                    .entry(event.node_id)
+                    .or_insert(NodeMetadata { layer: event.layer });
             }
 
             new_events_to_emit.push(event.clone());
         }
 
-        // Update the internal states of nodes given the events
-        // e.g `mouseover` will mark the node as hovered.
-        for event in events_to_emit {
-            if event.name.was_cursor_moved() && !self.hovered_nodes.contains_key(&event.node_id) {
-                self.hovered_nodes
-                    .insert(event.node_id, NodeMetadata { layer: event.layer });
-            }
-        }
-
         // Order the events by their Nodes layer
         for events in potential_events.values_mut() {
             events.sort_by(|left, right| left.layer.cmp(&right.layer))
@@ -108,16 +161,3 @@ fn any_recent_mouse_movement(events: &[PlatformEvent]) -> Option<PlatformEvent>
         .find(|event| event.get_name().was_cursor_moved())
         .cloned()
 }
-
-fn has_node_been_hovered_recently(events_to_emit: &[DomEvent], node_id: &NodeId) -> bool {
-    events_to_emit
-        .iter()
-        .find_map(|event| {
-            if event.name.was_cursor_moved() && &event.node_id == node_id {
-                Some(false)
-            } else {
-                None
-            }
-        })
-        .unwrap_or(true)
-}
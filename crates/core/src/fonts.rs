@@ -0,0 +1,37 @@
+use freya_engine::prelude::*;
+
+/// Create a [`FontMgr`] with the given custom fonts, registered under their family names.
+///
+/// Fonts registered under the same family name are grouped as styles of that family,
+/// fonts that can't be loaded are skipped.
+pub fn create_custom_font_mgr<'a>(
+    fonts: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+    font_mgr: &FontMgr,
+) -> FontMgr {
+    let mut provider = TypefaceFontProvider::new();
+
+    for (font_name, font_data) in fonts {
+        if let Some(typeface) = font_mgr.new_from_data(font_data, None) {
+            provider.register_typeface(typeface, Some(font_name));
+        } else {
+            tracing::warn!("Could not load the font `{font_name}`, it will not be registered.");
+        }
+    }
+
+    provider.into()
+}
+
+/// Create a [`FontCollection`] that looks up the custom fonts first and falls back to the system fonts,
+/// e.g for families that are not registered or glyphs that the custom fonts don't have.
+pub fn create_font_collection(
+    custom_font_mgr: FontMgr,
+    system_font_mgr: FontMgr,
+    default_family_name: Option<&str>,
+) -> FontCollection {
+    let mut font_collection = FontCollection::new();
+    font_collection.set_default_font_manager(system_font_mgr, default_family_name);
+    font_collection.set_dynamic_font_manager(custom_font_mgr);
+    // Resolve glyphs missing in every family (e.g emojis) from any system font that has them
+    font_collection.enable_font_fallback();
+    font_collection
+}
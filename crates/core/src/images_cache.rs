@@ -0,0 +1,181 @@
+use std::hash::{Hash, Hasher};
+
+use freya_engine::prelude::*;
+use rustc_hash::{FxHashMap, FxHasher};
+
+/// Default memory budget of the [`ImagesCache`], 256MB.
+pub const DEFAULT_IMAGES_CACHE_SIZE: usize = 256 * 1024 * 1024;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum CacheKey {
+    /// Hash of the encoded image.
+    Image(u64),
+    /// Hash of the SVG source, the attributes that change how it looks and size it was rasterized at.
+    Svg(u64, i32, i32),
+}
+
+struct CacheEntry {
+    image: Image,
+    bytes: usize,
+    last_used: u64,
+}
+
+/// Usage of the [`ImagesCache`], useful for debugging.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImagesCacheStats {
+    /// Images currently cached.
+    pub entries: usize,
+    /// Memory used by the cached images, in bytes.
+    pub bytes: usize,
+    /// Memory budget, in bytes.
+    pub capacity: usize,
+    /// Times an image was found in the cache.
+    pub hits: u64,
+    /// Times an image had to be decoded or rasterized.
+    pub misses: u64,
+    /// Images removed to stay under the memory budget.
+    pub evictions: u64,
+}
+
+/// Least recently used cache of decoded images and rasterized SVGs, keyed by the hash of their content.
+///
+/// Images are evicted once the cache goes over its memory budget.
+pub struct ImagesCache {
+    entries: FxHashMap<CacheKey, CacheEntry>,
+    capacity: usize,
+    bytes: usize,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl Default for ImagesCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_IMAGES_CACHE_SIZE)
+    }
+}
+
+impl ImagesCache {
+    /// Create a cache that uses up to `capacity` bytes.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            capacity,
+            bytes: 0,
+            clock: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    /// Get the decoded image of the given encoded bytes, e.g a PNG.
+    pub fn image(&mut self, bytes: &[u8]) -> Option<Image> {
+        let key = CacheKey::Image(hash_bytes(bytes));
+        self.get_or_insert_with(key, || Image::from_encoded(Data::new_copy(bytes)))
+    }
+
+    /// Get an SVG rasterized at the given size, the `render` callback paints it when it's not cached.
+    ///
+    /// The `source` identifies how the SVG looks, e.g its bytes and its colors, so the
+    /// callback can do the expensive work of preparing the SVG only when it's not cached.
+    pub fn svg(
+        &mut self,
+        source: impl Hash,
+        (width, height): (i32, i32),
+        render: impl FnOnce(&Canvas),
+    ) -> Option<Image> {
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+
+        let mut hasher = FxHasher::default();
+        source.hash(&mut hasher);
+        let key = CacheKey::Svg(hasher.finish(), width, height);
+        self.get_or_insert_with(key, || {
+            let mut surface = surfaces::raster_n32_premul((width, height))?;
+            render(surface.canvas());
+            Some(surface.image_snapshot())
+        })
+    }
+
+    /// Usage of the cache.
+    pub fn stats(&self) -> ImagesCacheStats {
+        ImagesCacheStats {
+            entries: self.entries.len(),
+            bytes: self.bytes,
+            capacity: self.capacity,
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+        }
+    }
+
+    /// Remove all the cached images.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.bytes = 0;
+    }
+
+    fn get_or_insert_with(
+        &mut self,
+        key: CacheKey,
+        create: impl FnOnce() -> Option<Image>,
+    ) -> Option<Image> {
+        self.clock += 1;
+
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.last_used = self.clock;
+            self.hits += 1;
+            return Some(entry.image.clone());
+        }
+
+        self.misses += 1;
+        let image = create()?;
+        let bytes = image.width() as usize * image.height() as usize * 4;
+
+        // Images bigger than the whole budget are not cached
+        if bytes > self.capacity {
+            return Some(image);
+        }
+
+        self.bytes += bytes;
+        self.entries.insert(
+            key,
+            CacheEntry {
+                image: image.clone(),
+                bytes,
+                last_used: self.clock,
+            },
+        );
+        self.evict();
+
+        Some(image)
+    }
+
+    /// Remove the least recently used images until the cache is under its memory budget.
+    fn evict(&mut self) {
+        while self.bytes > self.capacity {
+            let Some(key) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| *key)
+            else {
+                break;
+            };
+
+            if let Some(entry) = self.entries.remove(&key) {
+                self.bytes -= entry.bytes;
+                self.evictions += 1;
+            }
+        }
+    }
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = FxHasher::default();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
@@ -10,12 +10,13 @@ use freya_dom::prelude::{DioxusDOM, FreyaDOM};
 use itertools::sorted;
 
 use freya_engine::prelude::*;
-use freya_node_state::{CursorMode, CursorSettings, LayoutState, References, Style};
+use freya_node_state::{CursorMode, CursorSettings, LayoutState, OverflowMode, References, Style};
 use rustc_hash::FxHashMap;
 use torin::torin::Torin;
 use uuid::Uuid;
 
 use crate::layout::*;
+use crate::render_settings::RenderSettings;
 
 fn traverse_dom(rdom: &DioxusDOM, mut f: impl FnMut(DioxusNode) -> bool) {
     let mut stack = vec![rdom.root_id()];
@@ -43,9 +44,16 @@ impl Layers {
         layout: &Torin<NodeId>,
         font_collection: &FontCollection,
         scale_factor: f32,
+        render_settings: RenderSettings,
     ) -> Self {
         let mut layers = Layers::default();
         let mut inherit_layers = FxHashMap::default();
+        // Areas where the children of every Node can be seen, clipped by the viewport and their ancestors
+        let mut inherit_clips = FxHashMap::default();
+        let root_area = layout
+            .get(rdom.root_id())
+            .map(|areas| areas.visible_area())
+            .unwrap_or_default();
 
         traverse_dom(rdom, |node| {
             let areas = layout.get(node.id());
@@ -69,6 +77,19 @@ impl Layers {
                 inherit_layers.insert(node.id(), node_relative_layer);
                 layers.add_element(node.id(), node_layer);
 
+                // Calculate the area where the Node can be seen
+                let visible_area = areas.visible_area();
+                let inherited_clip = node
+                    .parent_id()
+                    .and_then(|p| inherit_clips.get(&p).copied())
+                    .unwrap_or(Some(root_area));
+                let node_clip = if node_style.overflow == OverflowMode::Clip {
+                    inherited_clip.and_then(|clip| clip.intersection(&visible_area))
+                } else {
+                    inherited_clip
+                };
+                inherit_clips.insert(node.id(), node_clip);
+
                 // Register paragraph elements
 
                 let traverse_inner_children =
@@ -102,9 +123,19 @@ impl Layers {
                 let size_state = &*node.get::<LayoutState>().unwrap();
 
                 if let Some(reference) = &size_state.node_ref {
+                    let visible_size = inherited_clip
+                        .and_then(|clip| clip.intersection(&visible_area))
+                        .map(|area| area.area())
+                        .unwrap_or_default();
+                    let visible_ratio = if visible_area.is_empty() {
+                        0.0
+                    } else {
+                        visible_size / visible_area.area()
+                    };
                     let mut node_layout = NodeReferenceLayout {
                         area: areas.area,
                         inner: areas.inner_sizes,
+                        visible_ratio,
                     };
                     node_layout.div(scale_factor);
                     reference.0.send(node_layout).ok();
@@ -116,7 +147,13 @@ impl Layers {
             }
         });
 
-        layers.measure_all_paragraph_elements(rdom, layout, font_collection, scale_factor);
+        layers.measure_all_paragraph_elements(
+            rdom,
+            layout,
+            font_collection,
+            scale_factor,
+            render_settings,
+        );
 
         layers
     }
@@ -140,13 +177,21 @@ impl Layers {
         layout: &Torin<NodeId>,
         font_collection: &FontCollection,
         scale_factor: f32,
+        render_settings: RenderSettings,
     ) {
         for group in self.paragraph_elements.values() {
             for node_id in group {
                 let node = rdom.get(*node_id);
                 let areas = layout.get(*node_id);
                 if let Some((node, areas)) = node.zip(areas) {
-                    measure_paragraph(&node, &areas.area, font_collection, true, scale_factor);
+                    measure_paragraph(
+                        &node,
+                        &areas.area,
+                        font_collection,
+                        true,
+                        scale_factor,
+                        render_settings,
+                    );
                 }
             }
         }
@@ -159,6 +204,7 @@ impl Layers {
         dom: &FreyaDOM,
         font_collection: &FontCollection,
         scale_factor: f32,
+        render_settings: RenderSettings,
     ) {
         let group = self.paragraph_elements.get(text_id);
         let layout = dom.layout();
@@ -168,7 +214,14 @@ impl Layers {
                 let areas = layout.get(*node_id);
 
                 if let Some((node, areas)) = node.zip(areas) {
-                    measure_paragraph(&node, &areas.area, font_collection, true, scale_factor);
+                    measure_paragraph(
+                        &node,
+                        &areas.area,
+                        font_collection,
+                        true,
+                        scale_factor,
+                        render_settings,
+                    );
                 }
             }
         }
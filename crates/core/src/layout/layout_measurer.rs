@@ -1,4 +1,5 @@
 use crate::layout::*;
+use crate::render_settings::RenderSettings;
 use freya_dom::prelude::{DioxusDOMAdapter, FreyaDOM};
 use freya_engine::prelude::*;
 use torin::geometry::Area;
@@ -9,10 +10,11 @@ pub fn process_layout(
     area: Area,
     font_collection: &mut FontCollection,
     scale_factor: f32,
+    render_settings: RenderSettings,
 ) -> (Layers, Viewports) {
     let rdom = fdom.rdom();
     let mut dom_adapter = DioxusDOMAdapter::new_with_cache(rdom);
-    let skia_measurer = SkiaMeasurer::new(rdom, font_collection);
+    let skia_measurer = SkiaMeasurer::new(rdom, font_collection, scale_factor, render_settings);
 
     // Finds the best Node from where to start measuring
     fdom.layout().find_best_root(&mut dom_adapter);
@@ -24,7 +26,13 @@ pub fn process_layout(
         .measure(root_id, area, &mut Some(skia_measurer), &mut dom_adapter);
 
     // Create the layers
-    let layers = Layers::new(rdom, &fdom.layout(), font_collection, scale_factor);
+    let layers = Layers::new(
+        rdom,
+        &fdom.layout(),
+        font_collection,
+        scale_factor,
+        render_settings,
+    );
 
     // Calculate the viewports
     let viewports = Viewports::new(&layers, fdom);
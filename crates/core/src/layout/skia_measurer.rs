@@ -7,7 +7,12 @@ use dioxus_native_core::{
 };
 use freya_common::CursorLayoutResponse;
 use freya_dom::prelude::{DioxusDOM, DioxusNode};
-use freya_node_state::{CursorReference, CursorSettings, FontStyleState, References, TextOverflow};
+use freya_node_state::{
+    svg_intrinsic_size, CursorReference, CursorSettings, FontStyleState, InteractionState,
+    LayoutState, References, Style,
+};
+
+use crate::render_settings::RenderSettings;
 
 use freya_engine::prelude::*;
 use torin::{
@@ -15,17 +20,26 @@ use torin::{
     prelude::{LayoutMeasurer, Node, Size2D},
 };
 
-/// Provides Text measurements using Skia APIs like SkParagraph
+/// Provides Text measurements using Skia APIs like SkParagraph, and the intrinsic size of SVGs
 pub struct SkiaMeasurer<'a> {
     pub font_collection: &'a FontCollection,
     pub rdom: &'a DioxusDOM,
+    pub scale_factor: f32,
+    pub render_settings: RenderSettings,
 }
 
 impl<'a> SkiaMeasurer<'a> {
-    pub fn new(rdom: &'a DioxusDOM, font_collection: &'a FontCollection) -> Self {
+    pub fn new(
+        rdom: &'a DioxusDOM,
+        font_collection: &'a FontCollection,
+        scale_factor: f32,
+        render_settings: RenderSettings,
+    ) -> Self {
         Self {
             font_collection,
             rdom,
+            scale_factor,
+            render_settings,
         }
     }
 }
@@ -43,29 +57,76 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
 
         match &*node_type {
             NodeType::Element(ElementNode { tag, .. }) if tag == "label" => {
-                let label = create_label(&node, available_parent_area, self.font_collection);
+                let label = create_label(
+                    &node,
+                    available_parent_area,
+                    self.font_collection,
+                    InteractionState::default(),
+                    self.render_settings,
+                );
 
                 Some(Size2D::new(label.longest_line(), label.height()))
             }
             NodeType::Element(ElementNode { tag, .. }) if tag == "paragraph" => {
-                let paragraph =
-                    create_paragraph(&node, available_parent_area, self.font_collection, false);
+                let paragraph = create_paragraph(
+                    &node,
+                    available_parent_area,
+                    self.font_collection,
+                    false,
+                    InteractionState::default(),
+                    self.render_settings,
+                );
 
                 Some(Size2D::new(paragraph.longest_line(), paragraph.height()))
             }
+            NodeType::Element(ElementNode { tag, .. }) if tag == "svg" => {
+                let node_style = node.get::<Style>().unwrap();
+                let svg_data = node_style.svg_data.as_ref()?;
+                let (width, height) = svg_intrinsic_size(svg_data.as_slice())?;
+
+                Some(Size2D::new(width, height) * self.scale_factor)
+            }
             _ => None,
         }
     }
+
+    fn keeps_aspect_ratio(&mut self, node_id: NodeId) -> bool {
+        let node = self.rdom.get(node_id).unwrap();
+        let node_type = node.node_type();
+        matches!(&*node_type, NodeType::Element(ElementNode { tag, .. }) if tag == "svg")
+    }
+}
+
+/// Base direction of the text, the default `start` alignment follows it
+fn text_direction(node: &DioxusNode) -> TextDirection {
+    if node.get::<LayoutState>().unwrap().layout_direction.is_rtl() {
+        TextDirection::RTL
+    } else {
+        TextDirection::LTR
+    }
 }
 
-pub fn create_label(node: &DioxusNode, area: &Area, font_collection: &FontCollection) -> Paragraph {
+pub fn create_label(
+    node: &DioxusNode,
+    area: &Area,
+    font_collection: &FontCollection,
+    interaction: InteractionState,
+    render_settings: RenderSettings,
+) -> Paragraph {
     let font_style = &*node.get::<FontStyleState>().unwrap();
 
+    let mut text_style = TextStyle::from(font_style);
+    text_style.set_color(font_style.current_color(interaction));
+
     let mut paragraph_style = ParagraphStyle::default();
     paragraph_style.set_text_align(font_style.text_align);
+    paragraph_style.set_text_direction(text_direction(node));
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
-    paragraph_style.set_text_style(&font_style.into());
+    if !render_settings.text_hinting {
+        paragraph_style.turn_hinting_off();
+    }
+    paragraph_style.set_text_style(&text_style);
 
     if let Some(ellipsis) = font_style.text_overflow.get_ellipsis() {
         paragraph_style.set_ellipsis(ellipsis);
@@ -90,22 +151,36 @@ pub fn create_paragraph(
     node_area: &Area,
     font_collection: &FontCollection,
     is_rendering: bool,
+    interaction: InteractionState,
+    render_settings: RenderSettings,
 ) -> Paragraph {
     let font_style = &*node.get::<FontStyleState>().unwrap();
     let node_cursor_settings = &*node.get::<CursorSettings>().unwrap();
 
+    // Color of the hovered or pressed paragraph, it overrides the color of its text spans
+    let state_color =
+        Some(font_style.current_color(interaction)).filter(|color| *color != font_style.color);
+
     let mut paragraph_style = ParagraphStyle::default();
     paragraph_style.set_text_align(font_style.text_align);
+    paragraph_style.set_text_direction(text_direction(node));
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
+    if !render_settings.text_hinting {
+        paragraph_style.turn_hinting_off();
+    }
 
-    if font_style.text_overflow == TextOverflow::Ellipsis {
-        paragraph_style.set_ellipsis("…");
+    if let Some(ellipsis) = font_style.text_overflow.get_ellipsis() {
+        paragraph_style.set_ellipsis(ellipsis);
     }
 
     let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
 
-    paragraph_builder.push_style(&font_style.into());
+    let mut text_style = TextStyle::from(font_style);
+    if let Some(color) = state_color {
+        text_style.set_color(color);
+    }
+    paragraph_builder.push_style(&text_style);
 
     for text_span in node.children() {
         match &*text_span.node_type() {
@@ -116,7 +191,11 @@ pub fn create_paragraph(
 
                 if let NodeType::Text(TextNode { text, .. }) = text_node_type {
                     let font_style = text_node.get::<FontStyleState>().unwrap();
-                    paragraph_builder.push_style(&TextStyle::from(&*font_style));
+                    let mut text_style = TextStyle::from(&*font_style);
+                    if let Some(color) = state_color {
+                        text_style.set_color(color);
+                    }
+                    paragraph_builder.push_style(&text_style);
                     paragraph_builder.add_text(text);
                 }
             }
@@ -140,8 +219,16 @@ pub fn measure_paragraph(
     font_collection: &FontCollection,
     is_editable: bool,
     scale_factor: f32,
+    render_settings: RenderSettings,
 ) -> Paragraph {
-    let paragraph = create_paragraph(node, node_area, font_collection, false);
+    let paragraph = create_paragraph(
+        node,
+        node_area,
+        font_collection,
+        false,
+        InteractionState::default(),
+        render_settings,
+    );
     let scale_factors = scale_factor as f64;
 
     if is_editable {
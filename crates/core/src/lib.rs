@@ -1,21 +1,31 @@
 pub mod accessibility;
 pub mod events;
+pub mod fonts;
+pub mod images_cache;
 pub mod layout;
 pub mod navigation_mode;
 pub mod node;
+pub mod paragraph_cache;
 pub mod plugins;
 pub mod render;
+pub mod render_settings;
+pub mod text_measurer;
 pub mod types;
 pub mod utils;
 
 pub mod prelude {
     pub use crate::accessibility::*;
     pub use crate::events::*;
+    pub use crate::fonts::*;
+    pub use crate::images_cache::*;
     pub use crate::layout::*;
     pub use crate::navigation_mode::*;
     pub use crate::node::*;
+    pub use crate::paragraph_cache::*;
     pub use crate::plugins::*;
     pub use crate::render::*;
+    pub use crate::render_settings::*;
+    pub use crate::text_measurer::*;
     pub use crate::utils::*;
 
     pub use crate::types::EventEmitter;
@@ -23,5 +33,6 @@ pub mod prelude {
     pub use crate::types::EventsQueue;
     pub use crate::types::FocusReceiver;
     pub use crate::types::FocusSender;
+    pub use crate::types::PluginEventsReceiver;
     pub use crate::types::PotentialEvents;
 }
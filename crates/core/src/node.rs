@@ -1,14 +1,16 @@
+use accesskit::Role;
 use dioxus_native_core::real_dom::NodeImmutable;
 use freya_dom::prelude::DioxusNode;
 use freya_engine::prelude::*;
 use freya_node_state::{
-    Border, CornerRadius, CursorSettings, Fill, FontStyleState, LayoutState, References, Shadow,
-    Style, TextOverflow, Transform,
+    AccessibilityNodeState, Border, CornerRadius, CursorSettings, Fill, FontStyleState,
+    LayoutState, References, Shadow, Style, TextOverflow, Transform,
 };
 use torin::{alignment::Alignment, direction::DirectionMode, gaps::Gaps, size::Size};
 
 #[derive(Clone, PartialEq)]
 pub struct NodeState {
+    pub accessibility: AccessibilityNodeState,
     pub cursor: CursorSettings,
     pub font_style: FontStyleState,
     pub references: References,
@@ -18,6 +20,7 @@ pub struct NodeState {
 }
 
 pub fn get_node_state(node: &DioxusNode) -> NodeState {
+    let accessibility = node.get::<AccessibilityNodeState>().unwrap().clone();
     let cursor = node.get::<CursorSettings>().unwrap().clone();
     let font_style = node.get::<FontStyleState>().unwrap().clone();
     let references = node.get::<References>().unwrap().clone();
@@ -26,6 +29,7 @@ pub fn get_node_state(node: &DioxusNode) -> NodeState {
     let transform = node.get::<Transform>().unwrap().clone();
 
     NodeState {
+        accessibility,
         cursor,
         font_style,
         references,
@@ -90,6 +94,7 @@ impl<'a> Iterator for NodeStateIterator<'a> {
                 let fill = match *background {
                     Fill::Color(_) => AttributeType::Color(background.clone()),
                     Fill::LinearGradient(_) => AttributeType::LinearGradient(background.clone()),
+                    Fill::Image(_) => AttributeType::Text(background.to_string()),
                 };
                 Some(("background", fill))
             }
@@ -112,7 +117,7 @@ impl<'a> Iterator for NodeStateIterator<'a> {
             )),
             16 => Some((
                 "line_height",
-                AttributeType::Measure(self.state.font_style.line_height),
+                AttributeType::Text(self.state.font_style.line_height.pretty()),
             )),
             17 => Some((
                 "text_align",
@@ -130,10 +135,24 @@ impl<'a> Iterator for NodeStateIterator<'a> {
                 "offset_y",
                 AttributeType::Measure(self.state.size.offset_y.get()),
             )),
+            21 => Some((
+                "role",
+                AttributeType::Text(
+                    self.state
+                        .accessibility
+                        .role
+                        .map(|role| role.pretty())
+                        .unwrap_or_default(),
+                ),
+            )),
+            22 => Some((
+                "aria_label",
+                AttributeType::Text(self.state.accessibility.name.clone().unwrap_or_default()),
+            )),
             n => {
                 let shadows = &self.state.style.shadows;
                 let shadow = shadows
-                    .get(n - 21)
+                    .get(n - 23)
                     .map(|shadow| ("shadow", AttributeType::Shadow(shadow)));
 
                 if shadow.is_some() {
@@ -141,7 +160,7 @@ impl<'a> Iterator for NodeStateIterator<'a> {
                 } else {
                     let text_shadows = &self.state.font_style.text_shadows;
                     text_shadows
-                        .get(n - 21 + shadows.len())
+                        .get(n - 23 + shadows.len())
                         .map(|text_shadow| ("text_shadow", AttributeType::TextShadow(text_shadow)))
                 }
             }
@@ -189,3 +208,14 @@ impl ExternalPretty for TextAlign {
         }
     }
 }
+
+impl ExternalPretty for Role {
+    fn pretty(&self) -> String {
+        let role = format!("{self:?}");
+        let mut chars = role.chars();
+        match chars.next() {
+            Some(first) => first.to_lowercase().chain(chars).collect(),
+            None => role,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use dioxus_native_core::{
+    prelude::{ElementNode, NodeType, TextNode},
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use freya_dom::prelude::DioxusNode;
+use freya_engine::prelude::*;
+use freya_node_state::{CursorSettings, FontStyleState, InteractionState, LayoutState};
+use rustc_hash::FxHashMap;
+use torin::{direction::LayoutDirection, geometry::Area};
+
+use crate::layout::{create_label, create_paragraph};
+use crate::render_settings::RenderSettings;
+
+/// Everything that affects how a paragraph is laid out and painted.
+#[derive(PartialEq)]
+struct ParagraphKey {
+    font_style: FontStyleState,
+    spans: Vec<(Option<FontStyleState>, String)>,
+    width: f32,
+    color: Color,
+    cursor_space: bool,
+    layout_direction: LayoutDirection,
+    text_hinting: bool,
+}
+
+impl ParagraphKey {
+    fn new(
+        node: &DioxusNode,
+        area: &Area,
+        interaction: InteractionState,
+        is_rendering: bool,
+        render_settings: RenderSettings,
+    ) -> Self {
+        let font_style = node.get::<FontStyleState>().unwrap().clone();
+        let color = font_style.current_color(interaction);
+        let cursor_space = is_rendering
+            && node
+                .get::<CursorSettings>()
+                .map(|cursor_settings| cursor_settings.position.is_some())
+                .unwrap_or_default();
+
+        let layout_direction = node.get::<LayoutState>().unwrap().layout_direction;
+
+        let mut spans = Vec::new();
+        for child in node.children() {
+            match &*child.node_type() {
+                // Text of a `label`
+                NodeType::Text(TextNode { text, .. }) => spans.push((None, text.clone())),
+                // Text spans of a `paragraph`
+                NodeType::Element(ElementNode { tag, .. }) if tag == "text" => {
+                    let Some(text_node) = child.children().first().copied() else {
+                        continue;
+                    };
+                    if let NodeType::Text(TextNode { text, .. }) = &*text_node.node_type() {
+                        let span_style = text_node.get::<FontStyleState>().unwrap().clone();
+                        spans.push((Some(span_style), text.clone()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            font_style,
+            spans,
+            width: area.width(),
+            color,
+            cursor_space,
+            layout_direction,
+            text_hinting: render_settings.text_hinting,
+        }
+    }
+}
+
+struct CacheEntry {
+    key: ParagraphKey,
+    paragraph: Paragraph,
+}
+
+/// Usage of the [`ParagraphCache`], useful for debugging.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParagraphCacheStats {
+    /// Paragraphs currently cached.
+    pub entries: usize,
+    /// Times a paragraph was reused.
+    pub hits: u64,
+    /// Times a paragraph had to be shaped and laid out.
+    pub misses: u64,
+}
+
+/// Cache of the laid out paragraphs of `label` and `paragraph` elements, so they are not shaped again on every frame.
+///
+/// A paragraph is created again when its text, font styles, width, color, direction or hinting change.
+#[derive(Default)]
+pub struct ParagraphCache {
+    entries: FxHashMap<NodeId, CacheEntry>,
+    hits: u64,
+    misses: u64,
+}
+
+impl ParagraphCache {
+    /// Get the paragraph of a `label` element.
+    pub fn label(
+        &mut self,
+        node: &DioxusNode,
+        area: &Area,
+        font_collection: &FontCollection,
+        interaction: InteractionState,
+        render_settings: RenderSettings,
+    ) -> &Paragraph {
+        let key = ParagraphKey::new(node, area, interaction, false, render_settings);
+        self.get_or_create(node.id(), key, || {
+            create_label(node, area, font_collection, interaction, render_settings)
+        })
+    }
+
+    /// Get the paragraph of a `paragraph` element, ready to be painted.
+    pub fn paragraph(
+        &mut self,
+        node: &DioxusNode,
+        area: &Area,
+        font_collection: &FontCollection,
+        interaction: InteractionState,
+        render_settings: RenderSettings,
+    ) -> &Paragraph {
+        let key = ParagraphKey::new(node, area, interaction, true, render_settings);
+        self.get_or_create(node.id(), key, || {
+            create_paragraph(
+                node,
+                area,
+                font_collection,
+                true,
+                interaction,
+                render_settings,
+            )
+        })
+    }
+
+    /// Only keep the paragraphs of the nodes that match the predicate, e.g to drop those of removed nodes.
+    pub fn retain(&mut self, mut keep: impl FnMut(NodeId) -> bool) {
+        self.entries.retain(|node_id, _| keep(*node_id));
+    }
+
+    /// Usage of the cache.
+    pub fn stats(&self) -> ParagraphCacheStats {
+        ParagraphCacheStats {
+            entries: self.entries.len(),
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+
+    /// Remove all the cached paragraphs, e.g after the fonts changed.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn get_or_create(
+        &mut self,
+        node_id: NodeId,
+        key: ParagraphKey,
+        create: impl FnOnce() -> Paragraph,
+    ) -> &Paragraph {
+        let is_cached = self
+            .entries
+            .get(&node_id)
+            .map(|entry| entry.key == key)
+            .unwrap_or_default();
+
+        if is_cached {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+            self.entries.insert(
+                node_id,
+                CacheEntry {
+                    key,
+                    paragraph: create(),
+                },
+            );
+        }
+
+        &self.entries[&node_id].paragraph
+    }
+}
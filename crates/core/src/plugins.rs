@@ -1,12 +1,20 @@
 use dioxus_native_core::NodeId;
+use freya_common::EventMessage;
 use freya_dom::dom::FreyaDOM;
 use freya_engine::prelude::{Canvas, FontCollection};
 use torin::torin::Torin;
-use winit::window::Window;
+use winit::{event_loop::EventLoopProxy, window::Window};
 
+use crate::events::PlatformEvent;
+use crate::images_cache::ImagesCacheStats;
 use crate::layout::Viewports;
+use crate::types::PluginEventsSender;
 
 /// Manages all loaded plugins.
+///
+/// Plugins are notified in the order they were added, except for the events emitted after something
+/// happened ([`PluginEvent::AfterRender`] and [`PluginEvent::FinishedLayout`]), which notify the plugins in reverse order.
+/// This way the first plugin wraps the others, e.g a profiler added first also measures the time spent by the other plugins.
 #[derive(Default)]
 pub struct PluginsManager {
     plugins: Vec<Box<dyn FreyaPlugin>>,
@@ -18,32 +26,62 @@ impl PluginsManager {
     }
 
     pub fn send(&mut self, event: PluginEvent) {
-        for plugin in &mut self.plugins {
-            plugin.on_event(&event)
+        if event.is_after() {
+            for plugin in self.plugins.iter_mut().rev() {
+                plugin.notify(&event)
+            }
+        } else {
+            for plugin in &mut self.plugins {
+                plugin.notify(&event)
+            }
         }
     }
 }
 
+/// Lets plugins send events to the app as if they came from the Window, e.g to replay recorded input.
+///
+/// It can be cloned and used from other threads.
+#[derive(Clone)]
+pub struct PluginHandle {
+    proxy: EventLoopProxy<EventMessage>,
+    sender: PluginEventsSender,
+}
+
+impl PluginHandle {
+    pub fn new(proxy: EventLoopProxy<EventMessage>, sender: PluginEventsSender) -> Self {
+        Self { proxy, sender }
+    }
+
+    /// Send a [`PlatformEvent`] to the app, it is processed like the events of the Window.
+    pub fn send_platform_event(&self, event: PlatformEvent) {
+        if self.sender.send(event).is_ok() {
+            _ = self.proxy.send_event(EventMessage::DispatchPluginEvent);
+        }
+    }
+}
+
+/// What plugins can access while the app is rendered.
+pub struct RenderContext<'a> {
+    pub canvas: &'a Canvas,
+    pub font_collection: &'a FontCollection,
+    pub freya_dom: &'a FreyaDOM,
+    pub viewports: &'a Viewports,
+    pub images_cache: ImagesCacheStats,
+}
+
 /// Event emitted to Plugins.
 pub enum PluginEvent<'a> {
     /// The Window just got created.
-    WindowCreated(&'a Window),
+    WindowCreated(&'a Window, &'a PluginHandle),
+
+    /// A [`PlatformEvent`] is about to be processed.
+    PlatformEvent(&'a PlatformEvent),
 
     /// Before starting to render the app to the Canvas.
-    BeforeRender {
-        canvas: &'a Canvas,
-        font_collection: &'a FontCollection,
-        freya_dom: &'a FreyaDOM,
-        viewports: &'a Viewports,
-    },
+    BeforeRender(RenderContext<'a>),
 
     /// After rendering the app to the Canvas.
-    AfterRender {
-        canvas: &'a Canvas,
-        font_collection: &'a FontCollection,
-        freya_dom: &'a FreyaDOM,
-        viewports: &'a Viewports,
-    },
+    AfterRender(RenderContext<'a>),
 
     /// Before starting to measure the layout.
     StartedLayout(&'a Torin<NodeId>),
@@ -52,8 +90,41 @@ pub enum PluginEvent<'a> {
     FinishedLayout(&'a Torin<NodeId>),
 }
 
-/// Skeleton for Freya plugins.
+impl PluginEvent<'_> {
+    /// Check if the event is emitted after something happened.
+    pub fn is_after(&self) -> bool {
+        matches!(self, Self::AfterRender(_) | Self::FinishedLayout(_))
+    }
+}
+
+/// Skeleton for Freya plugins, all the hooks do nothing by default.
+///
+/// Plugins are registered with `LaunchConfigBuilder::with_plugin`, see [`PluginsManager`] for the order they are notified in.
 pub trait FreyaPlugin {
-    /// React on events emitted by Freya.
-    fn on_event(&mut self, event: &PluginEvent);
+    /// The Window of the app was just created, the [`PluginHandle`] can be cloned to send events later.
+    fn on_setup(&mut self, _window: &Window, _handle: &PluginHandle) {}
+
+    /// A [`PlatformEvent`] is about to be processed, this includes the events sent with a [`PluginHandle`].
+    fn on_platform_event(&mut self, _event: &PlatformEvent) {}
+
+    /// Before starting to render the app to the Canvas.
+    fn on_before_render(&mut self, _context: &RenderContext) {}
+
+    /// After rendering the app to the Canvas, anything drawn here is shown on top of the app.
+    fn on_after_render(&mut self, _context: &RenderContext) {}
+
+    /// React on every event emitted by Freya, called after the specific hook of the event.
+    fn on_event(&mut self, _event: &PluginEvent) {}
+
+    /// Call the hook of the given event and then [`FreyaPlugin::on_event`].
+    fn notify(&mut self, event: &PluginEvent) {
+        match event {
+            PluginEvent::WindowCreated(window, handle) => self.on_setup(window, handle),
+            PluginEvent::PlatformEvent(event) => self.on_platform_event(event),
+            PluginEvent::BeforeRender(context) => self.on_before_render(context),
+            PluginEvent::AfterRender(context) => self.on_after_render(context),
+            PluginEvent::StartedLayout(_) | PluginEvent::FinishedLayout(_) => {}
+        }
+        self.on_event(event)
+    }
 }
@@ -1,7 +1,9 @@
 use crate::layout::*;
+use dioxus_native_core::real_dom::NodeImmutable;
 use dioxus_native_core::NodeId;
 use freya_dom::prelude::FreyaDOM;
 use freya_engine::prelude::*;
+use freya_node_state::Style;
 use torin::prelude::Area;
 
 /// Call the render function for the nodes that should be rendered.
@@ -21,6 +23,16 @@ pub fn process_render<RenderOptions>(
             let areas = layout.get(*node_id);
 
             if let Some(areas) = areas {
+                // Hidden elements keep their space but are not painted
+                let is_hidden = fdom
+                    .rdom()
+                    .get(*node_id)
+                    .map(|node| node.get::<Style>().unwrap().visibility.is_hidden())
+                    .unwrap_or_default();
+                if is_hidden {
+                    continue;
+                }
+
                 // Skip elements that are completely out of any their parent's viewport
                 if let Some((_, node_viewports)) = node_viewports {
                     for viewport_id in node_viewports {
@@ -0,0 +1,40 @@
+/// How the edges of shapes, images and clips are smoothed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AntiAliasing {
+    /// Hard edges, every pixel is either inside or outside of a shape.
+    Disabled,
+    /// Smooth edges computed by Skia for every shape.
+    #[default]
+    Enabled,
+    /// Smooth edges, and also a multisampled OpenGL surface when the GPU supports it,
+    /// for better quality in rotated or scaled content. The software renderer behaves as [`AntiAliasing::Enabled`].
+    Multisampled,
+}
+
+impl AntiAliasing {
+    /// Whether the paints and clips should be anti-aliased.
+    pub fn is_enabled(&self) -> bool {
+        *self != Self::Disabled
+    }
+}
+
+/// Settings of how the Nodes are rasterized, the defaults match the rendering of previous versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RenderSettings {
+    /// Anti-aliasing of shapes, images and clips.
+    pub anti_aliasing: AntiAliasing,
+    /// Fit the glyph outlines to the pixel grid, crisper in low DPI displays.
+    pub text_hinting: bool,
+    /// Round the position and size of the Nodes to whole pixels before painting them, so thin borders and lines are crisp.
+    pub pixel_snapping: bool,
+}
+
+impl Default for RenderSettings {
+    fn default() -> Self {
+        Self {
+            anti_aliasing: AntiAliasing::default(),
+            text_hinting: true,
+            pixel_snapping: false,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use freya_engine::prelude::*;
+use freya_node_state::FontStyleState;
+use smallvec::SmallVec;
+
+use crate::render_settings::RenderSettings;
+
+/// Font of the text measured by [`TextMeasurer::measure`], the defaults match the ones of a `label`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextMeasureOptions {
+    /// Families to try in order, like the `font_family` attribute.
+    pub font_family: String,
+    /// Size of the font.
+    pub font_size: f32,
+    /// Weight of the font, from `100` (thin) to `900` (black).
+    pub font_weight: i32,
+    /// Wrap the text in lines that fit in this width.
+    pub max_width: Option<f32>,
+}
+
+impl Default for TextMeasureOptions {
+    fn default() -> Self {
+        Self {
+            font_family: "Fira Sans".to_string(),
+            font_size: 16.0,
+            font_weight: 400,
+            max_width: None,
+        }
+    }
+}
+
+impl TextMeasureOptions {
+    /// Specify the font families, separated by commas.
+    pub fn with_font_family(mut self, font_family: impl Into<String>) -> Self {
+        self.font_family = font_family.into();
+        self
+    }
+
+    /// Specify the font size.
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Specify the font weight.
+    pub fn with_font_weight(mut self, font_weight: i32) -> Self {
+        self.font_weight = font_weight;
+        self
+    }
+
+    /// Specify the maximum width of the lines.
+    pub fn with_max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    fn font_style(&self) -> FontStyleState {
+        let font_family = self
+            .font_family
+            .split(',')
+            .map(|f| f.trim().trim_matches(|c| c == '"' || c == '\'').trim())
+            .filter(|f| !f.is_empty())
+            .map(|f| f.to_string())
+            .collect::<SmallVec<[String; 2]>>();
+
+        let default = FontStyleState::default();
+        FontStyleState {
+            font_family: if font_family.is_empty() {
+                default.font_family.clone()
+            } else {
+                font_family
+            },
+            font_size: self.font_size,
+            font_weight: self.font_weight.into(),
+            ..default
+        }
+    }
+}
+
+/// Size of a measured text.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextMeasurement {
+    /// Width of the longest line.
+    pub width: f32,
+    /// Height of all the lines.
+    pub height: f32,
+    /// Number of lines, after wrapping the text in the maximum width.
+    pub lines: usize,
+}
+
+/// Measure text with the fonts of the app, without waiting for the layout.
+#[derive(Clone)]
+pub struct TextMeasurer {
+    font_collection: FontCollection,
+    render_settings: RenderSettings,
+}
+
+impl TextMeasurer {
+    pub fn new(font_collection: FontCollection, render_settings: RenderSettings) -> Self {
+        Self {
+            font_collection,
+            render_settings,
+        }
+    }
+
+    /// Measure the text as a `label` with the given font would be laid out.
+    ///
+    /// Sizes are in the same units as the attributes, not scaled by the scale factor.
+    pub fn measure(&self, text: &str, options: &TextMeasureOptions) -> TextMeasurement {
+        let text_style = TextStyle::from(&options.font_style());
+
+        let mut paragraph_style = ParagraphStyle::default();
+        paragraph_style.set_replace_tab_characters(true);
+        paragraph_style.set_text_style(&text_style);
+        if !self.render_settings.text_hinting {
+            paragraph_style.turn_hinting_off();
+        }
+
+        let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, &self.font_collection);
+        paragraph_builder.add_text(text);
+
+        let mut paragraph = paragraph_builder.build();
+        paragraph.layout(options.max_width.unwrap_or(f32::MAX));
+
+        TextMeasurement {
+            width: paragraph.longest_line(),
+            height: paragraph.height(),
+            lines: paragraph.line_number(),
+        }
+    }
+}
@@ -20,6 +20,12 @@ pub type EventEmitter = UnboundedSender<DomEvent>;
 /// Receive events to be emitted to the VirtualDOM
 pub type EventReceiver = UnboundedReceiver<DomEvent>;
 
+/// Send events from plugins to be processed by Freya.
+pub type PluginEventsSender = UnboundedSender<PlatformEvent>;
+
+/// Receive the events sent by plugins.
+pub type PluginEventsReceiver = UnboundedReceiver<PlatformEvent>;
+
 /// Queued list of events to be processed by Freya.
 pub type EventsQueue = SmallVec<[PlatformEvent; 2]>;
 
@@ -0,0 +1,65 @@
+use accesskit::Role;
+use freya::prelude::*;
+use freya_testing::launch_test;
+
+#[tokio::test]
+pub async fn accessibility_roles_and_names() {
+    fn accessibility_app() -> Element {
+        let button_focus = use_focus();
+        let icon_focus = use_focus();
+        let text_focus = use_focus();
+
+        rsx!(
+            rect {
+                focus_id: button_focus.attribute(),
+                role: "button",
+                label {
+                    "Save"
+                }
+                label {
+                    "file"
+                }
+            }
+            rect {
+                focus_id: icon_focus.attribute(),
+                role: "button",
+                name: "Close",
+                label {
+                    "X"
+                }
+            }
+            label {
+                focus_id: text_focus.attribute(),
+                "Hello, World!"
+            }
+        )
+    }
+
+    let mut utils = launch_test(accessibility_app);
+    utils.wait_for_update().await;
+
+    let nodes = utils.accessibility_nodes();
+    assert_eq!(nodes.len(), 3);
+
+    let find = |name: &str| {
+        nodes
+            .iter()
+            .map(|(_, node)| node)
+            .find(|node| node.name() == Some(name))
+            .cloned()
+    };
+
+    // The name of a button is taken from its labels
+    let save_button = find("Save file").unwrap();
+    assert_eq!(save_button.role(), Role::Button);
+
+    // An explicit name takes precedence
+    let close_button = find("Close").unwrap();
+    assert_eq!(close_button.role(), Role::Button);
+    assert!(find("X").is_none());
+
+    // Labels are exposed as static text by default
+    let text = find("Hello, World!").unwrap();
+    assert_eq!(text.role(), Role::StaticText);
+    assert_eq!(text.value(), Some("Hello, World!"));
+}
@@ -0,0 +1,173 @@
+use dioxus::prelude::*;
+use freya_core::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_engine::prelude::Color;
+use freya_node_state::{Fill, StyleSheet};
+use freya_testing::{events::pointer::MouseButton, launch_test};
+use torin::prelude::CursorPoint;
+
+fn stylesheet() -> StyleSheet {
+    StyleSheet::default()
+        .with_class(
+            "card",
+            [("width", "100"), ("height", "50"), ("background", "red")],
+        )
+        .with_class("primary", [("height", "20"), ("background", "blue")])
+}
+
+#[tokio::test]
+pub async fn later_classes_override_earlier_ones() {
+    fn class_app() -> Element {
+        rsx!(
+            rect {
+                class: "card primary",
+            }
+            rect {
+                class: "primary card",
+            }
+        )
+    }
+
+    let mut utils = launch_test(class_app);
+    utils.set_stylesheet(stylesheet());
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let card_primary = root.get(0);
+    let primary_card = root.get(1);
+
+    // The attributes only defined in one class are merged
+    assert_eq!(card_primary.area().unwrap().width(), 100.0);
+    assert_eq!(primary_card.area().unwrap().width(), 100.0);
+
+    // The shared attributes come from the last class
+    assert_eq!(card_primary.area().unwrap().height(), 20.0);
+    assert_eq!(card_primary.style().background, Fill::Color(Color::BLUE));
+    assert_eq!(primary_card.area().unwrap().height(), 50.0);
+    assert_eq!(primary_card.style().background, Fill::Color(Color::RED));
+}
+
+#[tokio::test]
+pub async fn attributes_override_classes() {
+    fn class_app() -> Element {
+        rsx!(rect {
+            class: "card primary",
+            height: "10",
+            background: "green",
+        })
+    }
+
+    let mut utils = launch_test(class_app);
+    utils.set_stylesheet(stylesheet());
+    utils.wait_for_update().await;
+
+    let rect = utils.root().get(0);
+    assert_eq!(rect.area().unwrap().width(), 100.0);
+    assert_eq!(rect.area().unwrap().height(), 10.0);
+    assert_eq!(rect.style().background, Fill::Color(Color::GREEN));
+}
+
+#[tokio::test]
+pub async fn unknown_classes_are_ignored() {
+    fn class_app() -> Element {
+        rsx!(
+            rect {
+                class: "unknown card",
+            }
+            rect {
+                width: "30",
+                height: "30",
+                class: "unknown",
+            }
+        )
+    }
+
+    let mut utils = launch_test(class_app);
+    utils.set_stylesheet(stylesheet());
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(0).area().unwrap().height(), 50.0);
+    assert_eq!(root.get(1).area().unwrap().height(), 30.0);
+}
+
+#[tokio::test]
+pub async fn change_classes() {
+    fn class_app() -> Element {
+        let mut is_primary = use_signal(|| false);
+
+        rsx!(rect {
+            class: if is_primary() { "card primary" } else { "card" },
+            onclick: move |_| is_primary.toggle(),
+        })
+    }
+
+    let mut utils = launch_test(class_app);
+    utils.set_stylesheet(stylesheet());
+    utils.wait_for_update().await;
+
+    let rect = utils.root().get(0);
+    assert_eq!(rect.area().unwrap().height(), 50.0);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(5.0, 5.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(rect.area().unwrap().height(), 20.0);
+    assert_eq!(rect.style().background, Fill::Color(Color::BLUE));
+}
+
+#[tokio::test]
+pub async fn replace_stylesheet() {
+    fn class_app() -> Element {
+        rsx!(rect { class: "card" })
+    }
+
+    let mut utils = launch_test(class_app);
+    utils.wait_for_update().await;
+
+    let rect = utils.root().get(0);
+    assert_eq!(rect.area().unwrap().height(), 0.0);
+
+    utils.set_stylesheet(stylesheet());
+    utils.wait_for_update().await;
+    assert_eq!(rect.area().unwrap().height(), 50.0);
+
+    utils.set_stylesheet(StyleSheet::default().with_class("card", [("height", "70")]));
+    utils.wait_for_update().await;
+
+    // The classes of the previous stylesheet are gone
+    assert_eq!(rect.area().unwrap().height(), 70.0);
+    assert_eq!(rect.area().unwrap().width(), 0.0);
+    assert_eq!(rect.style().background, Fill::default());
+}
+
+#[tokio::test]
+pub async fn invalid_class_values_are_skipped() {
+    fn class_app() -> Element {
+        rsx!(paragraph {
+            class: "editor",
+            text { "Hello" }
+        })
+    }
+
+    let mut utils = launch_test(class_app);
+    utils.set_stylesheet(StyleSheet::default().with_class("editor", [("cursor_index", "3")]));
+    utils.wait_for_update().await;
+
+    let paragraph = utils.root().get(0);
+    assert_eq!(paragraph.state().cursor.position, Some(3));
+
+    // A reloaded stylesheet can contain any value
+    utils.set_stylesheet(StyleSheet::default().with_class(
+        "editor",
+        [("cursor_index", "abc"), ("width", "abc"), ("height", "40")],
+    ));
+    utils.wait_for_update().await;
+
+    assert_eq!(paragraph.state().cursor.position, None);
+    assert_eq!(paragraph.area().unwrap().height(), 40.0);
+}
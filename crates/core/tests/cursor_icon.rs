@@ -0,0 +1,76 @@
+use dioxus::prelude::*;
+use freya_core::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_testing::{events::pointer::MouseButton, launch_test};
+use winit::window::CursorIcon;
+
+#[tokio::test]
+pub async fn cursor_attribute() {
+    fn cursor_attribute_app() -> Element {
+        rsx!(
+            rect {
+                height: "50%",
+                width: "100%",
+                cursor: "pointer",
+                label {
+                    "Pointer"
+                }
+            }
+            rect {
+                height: "50%",
+                width: "100%",
+                cursor: "text",
+                rect {
+                    height: "50%",
+                    width: "100%",
+                    cursor: "grab",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(cursor_attribute_app);
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.cursor_icon(), CursorIcon::default());
+
+    // Hover a child of the first rect, it inherits the cursor
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.cursor_icon(), CursorIcon::Pointer);
+
+    // Hover the inner rect of the second rect
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (100.0, 300.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.cursor_icon(), CursorIcon::Grab);
+
+    // Hover the second rect
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (100.0, 450.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.cursor_icon(), CursorIcon::Text);
+
+    // Leave the window
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (-1.0, -1.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.cursor_icon(), CursorIcon::default());
+}
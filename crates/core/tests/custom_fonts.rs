@@ -0,0 +1,47 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_testing::{launch_test_with_config, TestingConfig};
+
+static SANSITA_SWASHED: &[u8] = include_bytes!("../../../examples/SansitaSwashed-Regular.ttf");
+
+fn custom_font_app() -> Element {
+    rsx!(
+        label {
+            font_family: "Sansita Swashed",
+            font_size: "30",
+            "Hello, World!"
+        }
+    )
+}
+
+async fn measure_custom_font(fonts: &'static [(&'static str, &'static [u8])]) -> f32 {
+    let mut utils = launch_test_with_config(
+        custom_font_app,
+        TestingConfig {
+            fonts,
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    utils.root().get(0).area().unwrap().width()
+}
+
+#[tokio::test]
+pub async fn custom_font() {
+    let fallback = measure_custom_font(&[]).await;
+    let custom = measure_custom_font(&[("Sansita Swashed", SANSITA_SWASHED)]).await;
+
+    // The registered font is used instead of the fallback system font
+    assert!(custom > 0.0);
+    assert_ne!(custom, fallback);
+}
+
+#[tokio::test]
+pub async fn invalid_custom_font() {
+    let fallback = measure_custom_font(&[]).await;
+    let invalid = measure_custom_font(&[("Sansita Swashed", b"not a font")]).await;
+
+    // Invalid fonts are skipped and the text falls back to the system fonts
+    assert_eq!(invalid, fallback);
+}
@@ -0,0 +1,66 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_testing::{launch_test, launch_test_with_config, TestingConfig};
+
+static SANSITA_SWASHED: &[u8] = include_bytes!("../../../examples/SansitaSwashed-Regular.ttf");
+
+#[tokio::test]
+pub async fn font_family_fallback_chain() {
+    fn font_family_app() -> Element {
+        rsx!(
+            label {
+                font_family: "Sansita Swashed",
+                "Hello, World!"
+            }
+            label {
+                font_family: "Unknown Family, Sansita Swashed",
+                "Hello, World!"
+            }
+            label {
+                font_family: "'Unknown Family', \"Sansita Swashed\"",
+                "Hello, World!"
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        font_family_app,
+        TestingConfig {
+            fonts: &[("Sansita Swashed", SANSITA_SWASHED)],
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let primary = root.get(0).area().unwrap().width();
+
+    // Families that aren't available are skipped
+    assert_eq!(root.get(1).area().unwrap().width(), primary);
+    assert_eq!(root.get(2).area().unwrap().width(), primary);
+}
+
+#[tokio::test]
+pub async fn emoji_with_text() {
+    fn emoji_app() -> Element {
+        rsx!(
+            label {
+                "Hello"
+            }
+            label {
+                "Hello 😀"
+            }
+        )
+    }
+
+    let mut utils = launch_test(emoji_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let text = root.get(0).area().unwrap();
+    let text_with_emoji = root.get(1).area().unwrap();
+
+    // The emoji is laid out in the same line as the text
+    assert!(text_with_emoji.width() > text.width());
+    assert_eq!(root.get(1).get(0).text(), Some("Hello 😀"));
+}
@@ -0,0 +1,90 @@
+use dioxus::prelude::*;
+use freya_core::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_engine::prelude::Color;
+use freya_node_state::Fill;
+use freya_testing::{events::pointer::MouseButton, launch_test};
+
+#[tokio::test]
+pub async fn interaction_styles() {
+    fn interaction_styles_app() -> Element {
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                background: "red",
+                background_hover: "green",
+                background_active: "blue",
+                label {
+                    color: "black",
+                    color_hover: "white",
+                    color_active: "yellow",
+                    "Hello, World!"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(interaction_styles_app);
+    let rect = utils.root().get(0);
+    let label = rect.get(0);
+    utils.wait_for_update().await;
+
+    let background = |utils: &freya_testing::TestingHandler| {
+        rect.style()
+            .current_background(utils.interaction(&rect))
+            .clone()
+    };
+    let color = |utils: &freya_testing::TestingHandler| {
+        label
+            .state()
+            .font_style
+            .current_color(utils.interaction(&label))
+    };
+
+    assert_eq!(background(&utils), Fill::Color(Color::RED));
+    assert_eq!(color(&utils), Color::BLACK);
+
+    // Hover
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (5.0, 5.0).into(),
+        button: None,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(background(&utils), Fill::Color(Color::GREEN));
+    assert_eq!(color(&utils), Color::WHITE);
+
+    // Press
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(background(&utils), Fill::Color(Color::BLUE));
+    assert_eq!(color(&utils), Color::YELLOW);
+
+    // Release
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(background(&utils), Fill::Color(Color::GREEN));
+
+    // Leave
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (300.0, 300.0).into(),
+        button: None,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(background(&utils), Fill::Color(Color::RED));
+    assert_eq!(color(&utils), Color::BLACK);
+}
@@ -0,0 +1,42 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_testing::launch_test;
+
+#[tokio::test]
+pub async fn paragraph_line_height() {
+    fn line_height_app() -> Element {
+        rsx!(
+            paragraph {
+                font_size: "10",
+                line_height: "2",
+                text {
+                    "Line 1\nLine 2\nLine 3"
+                }
+            }
+            paragraph {
+                font_size: "10",
+                line_height: "30px",
+                text {
+                    "Line 1\nLine 2\n"
+                }
+                text {
+                    font_size: "20",
+                    "Line 3"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(line_height_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+
+    // Every line is twice the font size
+    let multiplier = root.get(0).area().unwrap().height();
+    assert!((multiplier - 60.0).abs() < 1.0);
+
+    // Every line has the same height, even with a bigger font size
+    let pixels = root.get(1).area().unwrap().height();
+    assert!((pixels - 90.0).abs() < 1.0);
+}
@@ -0,0 +1,55 @@
+use std::sync::{Arc, Mutex};
+
+use dioxus_native_core::NodeId;
+use freya_core::plugins::{FreyaPlugin, PluginEvent, PluginsManager};
+use torin::torin::Torin;
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+struct LogPlugin {
+    name: &'static str,
+    log: Log,
+}
+
+impl FreyaPlugin for LogPlugin {
+    fn on_event(&mut self, event: &PluginEvent) {
+        let event = match event {
+            PluginEvent::StartedLayout(_) => "started",
+            PluginEvent::FinishedLayout(_) => "finished",
+            _ => return,
+        };
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} {event}", self.name));
+    }
+}
+
+#[test]
+pub fn plugins_order() {
+    let log = Log::default();
+    let mut plugins = PluginsManager::default();
+    for name in ["first", "second", "third"] {
+        plugins.add_plugin(LogPlugin {
+            name,
+            log: log.clone(),
+        });
+    }
+
+    let layout = Torin::<NodeId>::new();
+    plugins.send(PluginEvent::StartedLayout(&layout));
+    plugins.send(PluginEvent::FinishedLayout(&layout));
+
+    // The first plugin wraps the others
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            "first started",
+            "second started",
+            "third started",
+            "third finished",
+            "second finished",
+            "first finished",
+        ]
+    );
+}
@@ -0,0 +1,81 @@
+use dioxus::prelude::*;
+use freya_core::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_elements::events::MouseEvent;
+use freya_testing::{events::pointer::MouseButton, launch_test};
+
+#[tokio::test]
+pub async fn pointer_capture_attribute() {
+    fn pointer_capture_app() -> Element {
+        let mut position = use_signal(|| 0.0);
+        let mut released = use_signal(|| false);
+        let mut outside_moves = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "50",
+                width: "100",
+                pointer_capture: "true",
+                onmouseover: move |e: MouseEvent| {
+                    position.set(e.get_element_coordinates().x);
+                },
+                onclick: move |_| {
+                    released.set(true);
+                },
+            }
+            rect {
+                height: "50",
+                width: "100",
+                onmouseover: move |_| {
+                    outside_moves.with_mut(|moves| *moves += 1);
+                },
+            }
+            label {
+                "{position} {released} {outside_moves}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(pointer_capture_app);
+    let label = utils.root().get(2);
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("0 false 0"));
+
+    // Press the capturing rect
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (10.0, 10.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // Moving outside of it is still sent to it, not to the rect under the cursor
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (250.0, 75.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("250 false 0"));
+
+    // Releasing the mouse outside of it is sent to it too, and ends the capture
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (250.0, 75.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("250 true 0"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (50.0, 75.0).into(),
+        button: None,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("250 true 1"));
+}
@@ -171,3 +171,89 @@ pub async fn pointer_events_from_touch() {
         Some(format!("{:?}", vec!["enter", "over", "down", "up"]).as_str())
     );
 }
+
+#[tokio::test]
+pub async fn pointer_enter_leave_nested() {
+    fn pointer_enter_leave_app() -> Element {
+        let mut state = use_signal(|| vec![]);
+
+        let onpointerenter = move |_| state.push("enter".to_string());
+
+        let onpointerleave = move |_| state.push("leave".to_string());
+
+        let onmouseleave = move |_| state.push("only leave".to_string());
+
+        rsx!(
+            rect {
+                height: "200",
+                width: "200",
+                direction: "horizontal",
+                onpointerenter: onpointerenter,
+                onpointerleave: onpointerleave,
+                rect {
+                    height: "100%",
+                    width: "50%",
+                    background: "red",
+                    onmouseleave: onmouseleave,
+                }
+                rect {
+                    height: "100%",
+                    width: "50%",
+                    background: "blue",
+                }
+            }
+            label { "{state:?}" }
+        )
+    }
+
+    let mut utils = launch_test(pointer_enter_leave_app);
+
+    let label = utils.root().get(1);
+
+    // Enter the container through its first child
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: None,
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["enter"]).as_str())
+    );
+
+    // Moving between children of the container doesn't emit `enter` or `leave` on it again
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: CursorPoint::new(150.0, 50.0),
+        button: None,
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["enter", "only leave"]).as_str())
+    );
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: None,
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["enter", "only leave"]).as_str())
+    );
+
+    // Leaving the container also leaves the child under the cursor
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: CursorPoint::new(300.0, 300.0),
+        button: None,
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["enter", "only leave", "only leave", "leave"]).as_str())
+    );
+}
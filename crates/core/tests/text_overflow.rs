@@ -0,0 +1,54 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_testing::launch_test;
+
+const LONG_TEXT: &str = "Freya is a native GUI library for Rust powered by Skia and Dioxus";
+
+#[tokio::test]
+pub async fn narrow_label_overflow() {
+    fn text_overflow_app() -> Element {
+        rsx!(
+            rect {
+                width: "100",
+                label {
+                    "{LONG_TEXT}"
+                }
+                label {
+                    max_lines: "1",
+                    "{LONG_TEXT}"
+                }
+                label {
+                    max_lines: "1",
+                    text_overflow: "ellipsis",
+                    "{LONG_TEXT}"
+                }
+                label {
+                    max_lines: "1",
+                    text_overflow: "ellipsis",
+                    text_align: "right",
+                    "{LONG_TEXT}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(text_overflow_app);
+    utils.wait_for_update().await;
+
+    let container = utils.root().get(0);
+    let wrapped = container.get(0).area().unwrap();
+    let clipped = container.get(1).area().unwrap();
+    let ellipsis = container.get(2).area().unwrap();
+    let aligned = container.get(3).area().unwrap();
+
+    // Without a limit the text wraps in many lines
+    assert!(wrapped.height() > clipped.height() * 2.0);
+
+    // Truncated labels are measured with a single line that fits the width
+    assert_eq!(clipped.height(), ellipsis.height());
+    assert!(clipped.width() <= 100.0);
+    assert!(ellipsis.width() <= 100.0);
+
+    // Aligning doesn't change the truncated size
+    assert_eq!(ellipsis.size, aligned.size);
+}
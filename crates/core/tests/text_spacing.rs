@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_testing::launch_test;
+
+#[tokio::test]
+pub async fn letter_spacing() {
+    fn letter_spacing_app() -> Element {
+        rsx!(
+            label {
+                "Hello, World!"
+            }
+            label {
+                letter_spacing: "5",
+                "Hello, World!"
+            }
+            label {
+                letter_spacing: "-1",
+                "Hello, World!"
+            }
+        )
+    }
+
+    let mut utils = launch_test(letter_spacing_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let normal = root.get(0).area().unwrap().width();
+    let wide = root.get(1).area().unwrap().width();
+    let tight = root.get(2).area().unwrap().width();
+
+    assert!(wide > normal);
+    assert!(tight < normal);
+}
+
+#[tokio::test]
+pub async fn word_spacing() {
+    fn word_spacing_app() -> Element {
+        rsx!(
+            label {
+                "Hello, World!"
+            }
+            label {
+                word_spacing: "20",
+                "Hello, World!"
+            }
+        )
+    }
+
+    let mut utils = launch_test(word_spacing_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let normal = root.get(0).area().unwrap().width();
+    let wide = root.get(1).area().unwrap().width();
+
+    assert!(wide > normal);
+}
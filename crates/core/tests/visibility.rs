@@ -0,0 +1,104 @@
+use dioxus::prelude::*;
+use freya_core::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_node_state::Visibility;
+use freya_testing::{events::pointer::MouseButton, launch_test};
+use torin::prelude::CursorPoint;
+
+#[tokio::test]
+pub async fn hidden_elements_keep_their_space() {
+    fn hidden_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50",
+                background: "red",
+                visibility: "hidden",
+                onclick: move |_| clicks += 1,
+                rect {
+                    width: "100%",
+                    height: "20",
+                    background: "blue",
+                }
+                rect {
+                    width: "100%",
+                    height: "20",
+                    background: "green",
+                    visibility: "visible",
+                }
+            }
+            label { "{clicks}" }
+        )
+    }
+
+    let mut utils = launch_test(hidden_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let hidden = root.get(0);
+    let label = root.get(1);
+
+    // The space of the hidden element is preserved
+    assert_eq!(hidden.area().unwrap().height(), 50.0);
+    assert_eq!(label.area().unwrap().min_y(), 50.0);
+
+    // Its children inherit the visibility unless they specify their own
+    assert!(!hidden.is_visible());
+    assert_eq!(hidden.get(0).style().visibility, Visibility::Hidden);
+    assert!(!hidden.get(0).is_visible());
+    assert!(hidden.get(1).is_visible());
+
+    // It is not hit by the pointer
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(5.0, 5.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("0"));
+}
+
+#[tokio::test]
+pub async fn hidden_elements_are_not_painted() {
+    fn hidden_app() -> Element {
+        rsx!(
+            rect {
+                width: "50",
+                height: "50",
+                background: "red",
+                visibility: "hidden",
+            }
+            rect {
+                width: "50",
+                height: "50",
+                background: "blue",
+            }
+        )
+    }
+
+    fn transparent_app() -> Element {
+        rsx!(
+            rect {
+                width: "50",
+                height: "50",
+            }
+            rect {
+                width: "50",
+                height: "50",
+                background: "blue",
+            }
+        )
+    }
+
+    let mut utils = launch_test(hidden_app);
+    utils.wait_for_update().await;
+    let hidden = utils.render_to_image();
+
+    let mut utils = launch_test(transparent_app);
+    utils.wait_for_update().await;
+    let transparent = utils.render_to_image();
+
+    assert_eq!(hidden, transparent);
+}
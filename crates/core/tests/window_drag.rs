@@ -0,0 +1,72 @@
+use dioxus::prelude::*;
+use freya_core::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_testing::{events::pointer::MouseButton, launch_test};
+
+#[tokio::test]
+pub async fn window_drag_attribute() {
+    fn window_drag_app() -> Element {
+        rsx!(
+            rect {
+                height: "50",
+                width: "100%",
+                direction: "horizontal",
+                window_drag: "true",
+                label {
+                    width: "100",
+                    "Titlebar"
+                }
+                rect {
+                    height: "100%",
+                    width: "50",
+                    window_drag: "false",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(window_drag_app);
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.window_drags(), 0);
+
+    // Press a child of the titlebar, it inherits the drag region
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.window_drags(), 1);
+
+    // The right button doesn't drag the window
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Right),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.window_drags(), 1);
+
+    // Press the rect that opted out
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (125.0, 25.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.window_drags(), 1);
+
+    // Press outside the titlebar
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: (5.0, 300.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.window_drags(), 1);
+}
@@ -5,15 +5,19 @@ use dioxus_native_core::real_dom::NodeImmutable;
 use dioxus_native_core::tree::TreeRef;
 use dioxus_native_core::NodeId;
 use dioxus_router::prelude::*;
+use freya_common::NodeReferenceLayout;
 use freya_components::*;
 use freya_core::node::{get_node_state, NodeState};
+use freya_core::prelude::DomEvent;
 use freya_dom::prelude::SafeDOM;
 use freya_elements::elements as dioxus_elements;
-use freya_hooks::{use_init_accessibility, use_init_theme, use_theme, DARK_THEME};
+use freya_elements::events::{keyboard::Key, KeyboardEvent, MouseEvent};
+use freya_hooks::{use_init_accessibility, use_init_theme, use_scale, use_theme, DARK_THEME};
+use freya_node_state::{CustomAttributeValues, NodeReference};
 
 use freya_renderer::HoveredNode;
 use std::sync::Arc;
-use tokio::sync::Notify;
+use tokio::sync::{broadcast, watch, Notify};
 use torin::prelude::NodeAreas;
 
 mod hooks;
@@ -23,7 +27,7 @@ mod tab;
 mod tabs;
 
 use tab::*;
-use tabs::{layout::*, style::*, tree::*};
+use tabs::{events::*, layout::*, style::*, tree::*};
 
 /// Run the [`VirtualDom`] with a sidepanel where the devtools are located.
 pub fn with_devtools(
@@ -31,6 +35,7 @@ pub fn with_devtools(
     root: fn() -> Element,
     mutations_notifier: Arc<Notify>,
     hovered_node: HoveredNode,
+    events_log: broadcast::Sender<DomEvent>,
 ) -> VirtualDom {
     VirtualDom::new_with_props(
         AppWithDevtools,
@@ -39,6 +44,7 @@ pub fn with_devtools(
             rdom,
             mutations_notifier,
             hovered_node,
+            events_log,
         },
     )
 }
@@ -49,6 +55,7 @@ struct AppWithDevtoolsProps {
     rdom: SafeDOM,
     mutations_notifier: Arc<Notify>,
     hovered_node: HoveredNode,
+    events_log: broadcast::Sender<DomEvent>,
 }
 
 impl PartialEq for AppWithDevtoolsProps {
@@ -65,43 +72,132 @@ fn AppWithDevtools(props: AppWithDevtoolsProps) -> Element {
     let Root = props.root;
     let mutations_notifier = props.mutations_notifier.clone();
     let hovered_node = props.hovered_node.clone();
+    let children = use_context_provider(|| Signal::new(Vec::<TreeNode>::new()));
+    let mut picking = use_context_provider(|| Signal::new(false));
+    let mut picked_node = use_context_provider(|| Signal::new(None::<NodeId>));
+    let mut candidate = use_signal(|| None::<TreeNode>);
+    let app_container = use_context_provider(|| {
+        AppContainer(NodeReference(Arc::new(
+            watch::channel(NodeReferenceLayout::default()).0,
+        )))
+    });
+    let scale = use_scale();
+
+    // Find the top-most node of the app under the cursor
+    let node_at = move |e: &MouseEvent| {
+        let cursor = e.get_screen_coordinates() * scale.read().factor;
+        children
+            .read()
+            .iter()
+            .rev()
+            .filter(|node| node.is_app_node)
+            .find(|node| node.areas.visible_area().contains(cursor.to_f32()))
+            .cloned()
+    };
+
+    let onpickermouseover = {
+        let hovered_node = hovered_node.clone();
+        move |e: MouseEvent| {
+            let node = node_at(&e);
+            if let Some(hovered_node) = &hovered_node {
+                *hovered_node.lock().unwrap() = node.as_ref().map(|node| node.id);
+            }
+            candidate.set(node);
+        }
+    };
+
+    let onpickerclick = move |e: MouseEvent| {
+        if let Some(node) = node_at(&e) {
+            picked_node.set(Some(node.id));
+        }
+        candidate.set(None);
+        picking.set(false);
+    };
+
+    let onpickerkeydown = move |e: KeyboardEvent| {
+        if e.key == Key::Escape {
+            candidate.set(None);
+            picking.set(false);
+        }
+    };
 
     rsx!(
-        rect {
-            width: "100%",
-            height: "100%",
-            direction: "horizontal",
-            rect {
-                overflow: "clip",
-                height: "100%",
-                width: "calc(100% - 350)",
-                Root { },
-            }
-            rect {
-                background: "rgb(40, 40, 40)",
-                height: "100%",
-                width: "350",
-                ThemeProvider {
-                    DevTools {
-                        rdom: props.rdom.clone(),
-                        mutations_notifier: mutations_notifier,
-                        hovered_node: hovered_node
+        SplitPane {
+            ratio: 0.7,
+            first_min_size: 200.0,
+            second_min_size: 350.0,
+            first: rsx!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    rect {
+                        reference: AttributeValue::any_value(CustomAttributeValues::Reference(app_container.0.clone())),
+                        width: "100%",
+                        height: "100%",
+                        Root { }
+                    }
+                    // Capture the pointer over the app while picking an element
+                    if *picking.read() {
+                        rect {
+                            position: "absolute",
+                            position_top: "0",
+                            position_left: "0",
+                            layer: "-9999",
+                            width: "100%",
+                            height: "100%",
+                            onmouseover: onpickermouseover,
+                            onclick: onpickerclick,
+                            onkeydown: onpickerkeydown,
+                        }
                     }
                 }
-            }
+            ),
+            second: rsx!(
+                rect {
+                    background: "rgb(40, 40, 40)",
+                    height: "100%",
+                    width: "100%",
+                    ThemeProvider {
+                        DevTools {
+                            rdom: props.rdom.clone(),
+                            mutations_notifier: mutations_notifier,
+                            hovered_node: hovered_node,
+                            events_log: props.events_log.clone()
+                        }
+                    }
+                    if let Some(node) = &*candidate.read() {
+                        label {
+                            position: "absolute",
+                            position_bottom: "10",
+                            position_left: "10",
+                            layer: "-1",
+                            font_size: "13",
+                            color: "white",
+                            "Inspecting {node.tag} #{node.id:?}"
+                        }
+                    }
+                }
+            )
         }
     )
 }
 
+/// Reference of the Node that contains the app, to tell its nodes apart from the devtools ones.
+#[derive(Clone)]
+struct AppContainer(NodeReference);
+
 #[derive(Clone, PartialEq)]
 pub struct TreeNode {
     tag: String,
     id: NodeId,
     height: u16,
+    /// Whether it's a descendant of the app container, always true if the devtools are mounted without one.
+    is_app_node: bool,
     #[allow(dead_code)]
     text: Option<String>,
     state: NodeState,
     areas: NodeAreas,
+    listeners: Vec<String>,
 }
 
 #[derive(Props, Clone)]
@@ -109,6 +205,7 @@ pub struct DevToolsProps {
     rdom: SafeDOM,
     mutations_notifier: Arc<Notify>,
     hovered_node: HoveredNode,
+    events_log: broadcast::Sender<DomEvent>,
 }
 
 impl PartialEq for DevToolsProps {
@@ -117,17 +214,31 @@ impl PartialEq for DevToolsProps {
     }
 }
 
+/// Get the signal shared with [`AppWithDevtools`], or a new one if the devtools are mounted somewhere else.
+fn use_shared_signal<T: 'static>(init: impl FnOnce() -> T) -> Signal<T> {
+    use_hook(|| {
+        try_consume_context::<Signal<T>>().unwrap_or_else(|| provide_context(Signal::new(init())))
+    })
+}
+
 #[allow(non_snake_case)]
 pub fn DevTools(props: DevToolsProps) -> Element {
-    let mut children = use_context_provider(|| Signal::new(Vec::<TreeNode>::new()));
+    let mut children = use_shared_signal(Vec::<TreeNode>::new);
+    use_shared_signal(|| false);
+    use_shared_signal(|| None::<NodeId>);
+    let mut events_log = use_context_provider(|| Signal::new(EventsLog::default()));
     use_context_provider::<Signal<HoveredNode>>(|| Signal::new(props.hovered_node.clone()));
+    use_context_provider(|| props.rdom.clone());
     use_init_theme(DARK_THEME);
     let theme = use_theme();
 
     let theme = theme.read();
     let color = &theme.body.color;
 
+    let app_container = use_hook(try_consume_context::<AppContainer>);
+
     use_effect(move || {
+        let app_container = app_container.clone();
         let rdom = props.rdom.clone();
         let mutations_notifier = props.mutations_notifier.clone();
         spawn(async move {
@@ -142,6 +253,8 @@ pub fn DevTools(props: DevToolsProps) -> Element {
 
                 let mut root_found = false;
                 let mut devtools_found = false;
+                // Height of the app container while traversing its descendants
+                let mut app_container_height = None;
 
                 rdom.traverse_depth_first(|node| {
                     let height = rdom.tree_ref().height(node.id()).unwrap();
@@ -153,28 +266,46 @@ pub fn DevTools(props: DevToolsProps) -> Element {
                         }
                     }
 
+                    if app_container_height.is_some_and(|app_height| height <= app_height) {
+                        app_container_height = None;
+                    }
+
                     if !devtools_found && root_found {
                         let areas = layout.get(node.id());
                         if let Some(areas) = areas {
-                            let (text, tag) = match &*node.node_type() {
+                            let (text, tag, listeners) = match &*node.node_type() {
                                 NodeType::Text(TextNode { text, .. }) => {
-                                    (Some(text.to_string()), "text".to_string())
+                                    (Some(text.to_string()), "text".to_string(), Vec::new())
+                                }
+                                NodeType::Element(ElementNode { tag, listeners, .. }) => {
+                                    let mut listeners = listeners.iter().cloned().collect::<Vec<_>>();
+                                    listeners.sort();
+                                    (None, tag.to_string(), listeners)
                                 }
-                                NodeType::Element(ElementNode { tag, .. }) => {
-                                    (None, tag.to_string())
+                                NodeType::Placeholder => {
+                                    (None, "placeholder".to_string(), Vec::new())
                                 }
-                                NodeType::Placeholder => (None, "placeholder".to_string()),
                             };
 
                             let state = get_node_state(&node);
 
+                            let is_app_node =
+                                app_container.is_none() || app_container_height.is_some();
+                            if let Some(app_container) = &app_container {
+                                if state.size.node_ref.as_ref() == Some(&app_container.0) {
+                                    app_container_height = Some(height);
+                                }
+                            }
+
                             new_children.push(TreeNode {
                                 height,
+                                is_app_node,
                                 id: node.id(),
                                 tag,
                                 text,
                                 state,
                                 areas: areas.clone(),
+                                listeners,
                             });
                         }
                     }
@@ -184,6 +315,28 @@ pub fn DevTools(props: DevToolsProps) -> Element {
         });
     });
 
+    use_hook(move || {
+        let mut receiver = props.events_log.subscribe();
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => {
+                        // Only log the events dispatched to the app
+                        let is_app_node = children
+                            .peek()
+                            .iter()
+                            .any(|node| node.id == event.node_id && node.is_app_node);
+                        if is_app_node {
+                            events_log.write().push(LoggedEvent::from(event));
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+
     rsx!(
         rect {
             width: "100%",
@@ -197,12 +350,30 @@ pub fn DevTools(props: DevToolsProps) -> Element {
 #[component]
 #[allow(non_snake_case)]
 pub fn DevtoolsBar() -> Element {
+    let router = use_navigator();
+    let mut picked_node = use_context::<Signal<Option<NodeId>>>();
+
+    // Select the node picked in the app
+    use_effect(move || {
+        if let Some(node_id) = *picked_node.read() {
+            router.replace(Route::TreeStyleTab {
+                node_id: node_id.serialize(),
+            });
+            picked_node.set(None);
+        }
+    });
+
     rsx!(
         TabsBar {
+            PickerButton { }
             TabButton {
                 to: Route::TreeElementsTab { },
                 label: "Elements"
             }
+            TabButton {
+                to: Route::EventsTab { },
+                label: "Events"
+            }
         }
         Outlet::<Route> {}
     )
@@ -237,6 +408,9 @@ pub enum Route {
 
         #[route("/elements/:node_id/layout")]
         TreeLayoutTab { node_id: String },
+
+        #[route("/events")]
+        EventsTab {},
     #[end_layout]
     #[route("/..route")]
     PageNotFound { },
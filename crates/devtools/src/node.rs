@@ -9,6 +9,7 @@ use crate::TreeNode;
 pub fn NodeElement(
     node: TreeNode,
     is_selected: bool,
+    is_match: bool,
     onselected: EventHandler<TreeNode>,
 ) -> Element {
     let mut status = use_signal(ButtonStatus::default);
@@ -35,6 +36,8 @@ pub fn NodeElement(
     };
     let color = if is_selected {
         "white"
+    } else if is_match {
+        "rgb(71, 180, 240)"
     } else {
         match *status.read() {
             ButtonStatus::Idle => "white",
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use freya_components::Input;
 use freya_elements::elements as dioxus_elements;
 use freya_engine::prelude::*;
 use freya_node_state::{Border, Fill, Shadow};
@@ -35,44 +36,24 @@ pub fn Property(name: String, value: String) -> Element {
     )
 }
 
+/// A property whose value can be edited, the changes are applied live to the node.
 #[allow(non_snake_case)]
 #[component]
-pub fn LinearGradientProperty(name: String, fill: Fill) -> Element {
-    rsx!(
-        rect {
-            padding: "5 10",
-            paragraph {
-                line_height: "1.9",
-                text {
-                    font_size: "15",
-                    color: "rgb(71, 180, 240)",
-                    "{name}"
-                }
-                text {
-                    font_size: "15",
-                    color: "rgb(215, 215, 215)",
-                    ": "
-                }
-                text {
-                    font_size: "15",
-                    color: "rgb(252,181,172)",
-                    "{fill}",
-                }
-            }
-        }
-    )
-}
+pub fn EditableProperty(
+    name: String,
+    value: String,
+    fill: Option<Fill>,
+    onchange: EventHandler<String>,
+) -> Element {
+    let mut edited_value = use_signal(|| value.clone());
 
-#[allow(non_snake_case)]
-#[component]
-pub fn ColorProperty(name: String, fill: Fill) -> Element {
     rsx!(
         rect {
             overflow: "clip",
-            height: "30",
             width: "100%",
             direction: "horizontal",
-            padding: "10",
+            cross_align: "center",
+            padding: "2 10",
             label {
                 font_size: "15",
                 color: "rgb(71, 180, 240)",
@@ -86,26 +67,58 @@ pub fn ColorProperty(name: String, fill: Fill) -> Element {
             rect {
                 width: "5"
             }
-            rect {
-                width: "17",
-                height: "17",
-                corner_radius: "5",
-                background: "white",
-                padding: "2.5",
+            if let Some(fill) = fill {
                 rect {
-                    corner_radius: "3",
-                    width: "100%",
-                    height: "100%",
-                    background: "{fill}",
+                    width: "17",
+                    height: "17",
+                    corner_radius: "5",
+                    background: "white",
+                    padding: "2.5",
+                    rect {
+                        corner_radius: "3",
+                        width: "100%",
+                        height: "100%",
+                        background: "{fill}",
+                    }
+                }
+                rect {
+                    width: "5"
                 }
             }
-            rect {
-                width: "5"
+            Input {
+                value: edited_value.read().clone(),
+                onchange: move |new_value: String| {
+                    edited_value.set(new_value.clone());
+                    onchange.call(new_value);
+                }
             }
-            label {
-                font_size: "15",
-                color: "rgb(252,181,172)",
-                "{fill}",
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+pub fn LinearGradientProperty(name: String, fill: Fill) -> Element {
+    rsx!(
+        rect {
+            padding: "5 10",
+            paragraph {
+                line_height: "1.9",
+                text {
+                    font_size: "15",
+                    color: "rgb(71, 180, 240)",
+                    "{name}"
+                }
+                text {
+                    font_size: "15",
+                    color: "rgb(215, 215, 215)",
+                    ": "
+                }
+                text {
+                    font_size: "15",
+                    color: "rgb(252,181,172)",
+                    "{fill}",
+                }
             }
         }
     )
@@ -78,3 +78,58 @@ pub fn TabButton(props: TabButtonProps) -> Element {
         }
     )
 }
+
+/// Toggle the element picker, to select a node by clicking it in the app.
+#[allow(non_snake_case)]
+#[component]
+pub fn PickerButton() -> Element {
+    let theme = use_get_theme();
+    let mut picking = use_context::<Signal<bool>>();
+    let mut status = use_signal(ButtonStatus::default);
+
+    let onclick = move |_| {
+        picking.toggle();
+    };
+
+    let onmouseover = move |_| {
+        if *status.read() != ButtonStatus::Hovering {
+            status.set(ButtonStatus::Hovering);
+        }
+    };
+
+    let onmouseleave = move |_| {
+        status.set(ButtonStatus::default());
+    };
+
+    let background = if *picking.read() {
+        "rgb(71, 180, 240)".into()
+    } else {
+        match *status.read() {
+            ButtonStatus::Hovering => theme.button.hover_background,
+            ButtonStatus::Idle => theme.button.background,
+        }
+    };
+    let color = theme.button.font_theme.color;
+    let border_fill = theme.button.border_fill;
+
+    rsx!(
+        rect {
+            margin: "2",
+            overflow: "clip",
+            background: "{background}",
+            onclick,
+            onmouseover,
+            onmouseleave,
+            corner_radius: "7",
+            height: "100%",
+            color: "{color}",
+            padding: "6 14",
+            shadow: "0 4 5 0 rgb(0, 0, 0, 0.3)",
+            border: "1 solid {border_fill}",
+            main_align: "center",
+            label {
+                "Inspect"
+            }
+        }
+    )
+}
@@ -0,0 +1,171 @@
+use dioxus::prelude::*;
+use dioxus_native_core::NodeId;
+use freya_components::*;
+use freya_core::prelude::{DomEvent, DomEventData, EventName};
+use freya_elements::elements as dioxus_elements;
+use freya_hooks::{theme_with, InputThemeWith, ScrollViewThemeWith};
+use std::collections::VecDeque;
+use torin::geometry::CursorPoint;
+
+/// Max amount of events kept in the log, the oldest ones are discarded first.
+pub const MAX_LOGGED_EVENTS: usize = 500;
+
+#[derive(Clone, PartialEq)]
+pub struct LoggedEvent {
+    pub name: EventName,
+    pub node_id: NodeId,
+    pub coordinates: Option<CursorPoint>,
+}
+
+impl From<DomEvent> for LoggedEvent {
+    fn from(event: DomEvent) -> Self {
+        let coordinates = match &event.data {
+            DomEventData::Mouse(data) => Some(data.get_screen_coordinates()),
+            DomEventData::Pointer(data) => Some(data.get_screen_coordinates()),
+            DomEventData::Touch(data) => Some(data.get_screen_coordinates()),
+            DomEventData::Keyboard(_) | DomEventData::Wheel(_) => None,
+        };
+
+        Self {
+            name: event.name,
+            node_id: event.node_id,
+            coordinates,
+        }
+    }
+}
+
+/// Events dispatched to the app, most recent last.
+#[derive(Clone, Default, PartialEq)]
+pub struct EventsLog {
+    pub events: VecDeque<LoggedEvent>,
+    pub paused: bool,
+}
+
+impl EventsLog {
+    /// Log a new event, unless paused.
+    pub fn push(&mut self, event: LoggedEvent) {
+        if self.paused {
+            return;
+        }
+        if self.events.len() >= MAX_LOGGED_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+}
+
+#[allow(non_snake_case)]
+#[component]
+pub fn EventsTab() -> Element {
+    let mut log = use_context::<Signal<EventsLog>>();
+    let mut filter = use_signal(String::new);
+
+    let filtered_events = use_memo(move || {
+        let filter = filter.read().trim().to_lowercase();
+        log.read()
+            .events
+            .iter()
+            .rev()
+            .filter(|event| {
+                let name: &str = event.name.into();
+                name.contains(&filter)
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    });
+
+    let paused = log.read().paused;
+    let count = log.read().events.len();
+
+    rsx!(
+        rect {
+            height: "calc(100% - 35)",
+            width: "100%",
+            rect {
+                width: "100%",
+                direction: "horizontal",
+                cross_align: "center",
+                padding: "5 15 0 15",
+                Button {
+                    onclick: move |_| {
+                        let mut log = log.write();
+                        log.paused = !log.paused;
+                    },
+                    label {
+                        if paused { "Resume" } else { "Pause" }
+                    }
+                }
+                Button {
+                    onclick: move |_| log.write().events.clear(),
+                    label {
+                        "Clear"
+                    }
+                }
+                Input {
+                    theme: theme_with!(InputTheme {
+                        width: "150".into(),
+                    }),
+                    value: filter.read().clone(),
+                    onchange: move |value| filter.set(value)
+                }
+                label {
+                    margin: "0 0 0 10",
+                    font_size: "12",
+                    color: "rgb(150, 150, 150)",
+                    "{count}/{MAX_LOGGED_EVENTS}"
+                }
+            }
+            VirtualScrollView {
+                show_scrollbar: true,
+                length: filtered_events.read().len(),
+                item_size: 27.0,
+                theme: theme_with!(ScrollViewTheme {
+                    height: "calc(100% - 45)".into(),
+                    padding: "15".into(),
+                }),
+                builder: move |i, _: &Option<()>| {
+                    let events = filtered_events.read();
+                    let event = events.get(i).cloned().unwrap();
+                    rsx! {
+                        EventElement {
+                            key: "{i}",
+                            event
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn EventElement(event: LoggedEvent) -> Element {
+    let name: &str = event.name.into();
+    let coordinates = event
+        .coordinates
+        .map(|coordinates| format!("{:.0}, {:.0}", coordinates.x, coordinates.y))
+        .unwrap_or_default();
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "27",
+            padding: "5",
+            direction: "horizontal",
+            label {
+                width: "130",
+                color: "rgb(71, 180, 240)",
+                "{name}"
+            }
+            label {
+                width: "80",
+                "#{event.node_id:?}"
+            }
+            label {
+                color: "rgb(150, 150, 150)",
+                "{coordinates}"
+            }
+        }
+    )
+}
@@ -3,6 +3,7 @@ use dioxus_native_core::NodeId;
 use freya_components::*;
 use freya_elements::elements as dioxus_elements;
 use freya_hooks::{theme_with, ScrollViewThemeWith};
+use torin::gaps::Gaps;
 
 use crate::{hooks::use_selected_node, NodeInspectorBar};
 
@@ -12,13 +13,25 @@ pub fn NodeInspectorLayout(node_id: NodeId) -> Element {
     let node = use_selected_node(&node_id);
 
     if let Some(node) = node {
+        let visible_area = node.areas.visible_area();
+        let position = format!("{}, {}", visible_area.min_x(), visible_area.min_y());
+        let area = format!("{}x{}", visible_area.width(), visible_area.height());
         let inner_area = format!(
             "{}x{}",
             node.areas.inner_area.width(),
             node.areas.inner_area.height()
         );
-        let area = format!("{}x{}", node.areas.area.width(), node.areas.area.height());
+        let content_position = format!(
+            "{}, {}",
+            node.areas.inner_area.min_x(),
+            node.areas.inner_area.min_y()
+        );
+        let inner_sizes = format!(
+            "{}x{}",
+            node.areas.inner_sizes.width, node.areas.inner_sizes.height
+        );
         let paddings = node.state.size.padding;
+        let margins = node.areas.margin;
 
         rsx!(
             rect {
@@ -35,81 +48,45 @@ pub fn NodeInspectorLayout(node_id: NodeId) -> Element {
                     }),
                     rect {
                         width: "100%",
-                        height: "200",
-                        padding: "20",
+                        padding: "20 20 10 20",
+                        label {
+                            height: "25",
+                            "Position: {position}"
+                        }
                         label {
                             height: "25",
                             "Area: {area}"
                         }
-                        rect {
-                            width: "100%",
-                            height: "calc(100% - 25)",
-                            main_align: "center",
-                            cross_align: "center",
-                            background: "rgb(40, 40, 40)",
-                            rect {
-                                width: "100%",
-                                height: "100%",
+                        label {
+                            height: "25",
+                            "Content: {inner_area} at {content_position}"
+                        }
+                        label {
+                            height: "25",
+                            "Children size: {inner_sizes}"
+                        }
+                    }
+                    rect {
+                        width: "100%",
+                        height: "250",
+                        padding: "0 20 20 20",
+                        BoxModelGaps {
+                            name: "margin",
+                            gaps: margins,
+                            background: "rgb(240, 170, 80)",
+                            BoxModelGaps {
+                                name: "padding",
+                                gaps: paddings,
                                 background: "rgb(71, 180, 240)",
-                                corner_radius: "5",
                                 rect {
-                                    main_align: "center",
-                                    cross_align: "center",
                                     width: "100%",
-                                    height: "25",
-                                    label {
-                                        width: "100%",
-                                        text_align: "center",
-                                        "{paddings.top()}"
-                                    }
-                                }
-                                rect {
-                                    width: "100%",
-                                    height: "calc(100% - 50)",
-                                    direction: "horizontal",
-                                    rect {
-                                        main_align: "center",
-                                        cross_align: "center",
-                                        width: "25",
-                                        height: "100%",
-                                        label {
-                                            width: "100%",
-                                            text_align: "center",
-                                            "{paddings.left()}"
-                                        }
-                                    }
-                                    rect {
-                                        width: "calc(100% - 50)",
-                                        height: "100%",
-                                        main_align: "center",
-                                        cross_align: "center",
-                                        background: "rgb(40, 40, 40)",
-                                        corner_radius: "5",
-                                        label {
-                                            "{inner_area}"
-                                        }
-                                    }
-                                    rect {
-                                        main_align: "center",
-                                        cross_align: "center",
-                                        width: "25",
-                                        height: "100%",
-                                        label {
-                                            width: "100%",
-                                            text_align: "center",
-                                            "{paddings.right()}"
-                                        }
-                                    }
-                                }
-                                rect {
+                                    height: "100%",
                                     main_align: "center",
                                     cross_align: "center",
-                                    width: "100%",
-                                    height: "25",
+                                    background: "rgb(40, 40, 40)",
+                                    corner_radius: "5",
                                     label {
-                                        width: "100%",
-                                        text_align: "center",
-                                        "{paddings.bottom()}"
+                                        "{inner_area}"
                                     }
                                 }
                             }
@@ -122,3 +99,77 @@ pub fn NodeInspectorLayout(node_id: NodeId) -> Element {
         None
     }
 }
+
+/// A ring of the box model diagram, showing the size of every side of the given gaps.
+#[allow(non_snake_case)]
+#[component]
+fn BoxModelGaps(name: String, gaps: Gaps, background: String, children: Element) -> Element {
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            background: "{background}",
+            corner_radius: "5",
+            rect {
+                direction: "horizontal",
+                width: "100%",
+                height: "25",
+                cross_align: "center",
+                padding: "0 0 0 8",
+                label {
+                    width: "60",
+                    font_size: "12",
+                    "{name}"
+                }
+                label {
+                    width: "calc(100% - 128)",
+                    text_align: "center",
+                    "{gaps.top()}"
+                }
+            }
+            rect {
+                width: "100%",
+                height: "calc(100% - 50)",
+                direction: "horizontal",
+                rect {
+                    main_align: "center",
+                    cross_align: "center",
+                    width: "25",
+                    height: "100%",
+                    label {
+                        width: "100%",
+                        text_align: "center",
+                        "{gaps.left()}"
+                    }
+                }
+                rect {
+                    width: "calc(100% - 50)",
+                    height: "100%",
+                    {children}
+                }
+                rect {
+                    main_align: "center",
+                    cross_align: "center",
+                    width: "25",
+                    height: "100%",
+                    label {
+                        width: "100%",
+                        text_align: "center",
+                        "{gaps.right()}"
+                    }
+                }
+            }
+            rect {
+                main_align: "center",
+                cross_align: "center",
+                width: "100%",
+                height: "25",
+                label {
+                    width: "100%",
+                    text_align: "center",
+                    "{gaps.bottom()}"
+                }
+            }
+        }
+    )
+}
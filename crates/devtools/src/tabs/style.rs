@@ -2,13 +2,14 @@ use dioxus::prelude::*;
 use dioxus_native_core::NodeId;
 use freya_components::*;
 use freya_core::prelude::*;
+use freya_dom::prelude::SafeDOM;
 use freya_elements::elements as dioxus_elements;
 use freya_hooks::{theme_with, ScrollViewThemeWith};
 
 use crate::{
     hooks::use_selected_node,
     property::{
-        BorderProperty, ColorProperty, LinearGradientProperty, Property, ShadowProperty,
+        BorderProperty, EditableProperty, LinearGradientProperty, Property, ShadowProperty,
         TextShadowProperty,
     },
     NodeInspectorBar,
@@ -18,6 +19,16 @@ use crate::{
 #[component]
 pub fn NodeInspectorStyle(node_id: NodeId) -> Element {
     let node = use_selected_node(&node_id);
+    let rdom = use_context::<SafeDOM>();
+
+    // Apply the edited value to the inspected node
+    let onedit = move |name: &str| {
+        let rdom = rdom.clone();
+        let name = name.to_string();
+        move |value: String| {
+            rdom.get_mut().set_attribute(node_id, &name, value);
+        }
+    };
 
     if let Some(node) = node {
         rsx!(
@@ -40,19 +51,21 @@ pub fn NodeInspectorStyle(node_id: NodeId) -> Element {
                         match attr {
                             AttributeType::Measure(measure) => {
                                 rsx!{
-                                    Property {
-                                        key: "{i}",
+                                    EditableProperty {
+                                        key: "{node_id:?}-{i}",
                                         name: "{name}",
-                                        value: measure.to_string()
+                                        value: measure.to_string(),
+                                        onchange: onedit(name)
                                     }
                                 }
                             }
                             AttributeType::Measures(measures) => {
                                 rsx!{
-                                    Property {
-                                        key: "{i}",
+                                    EditableProperty {
+                                        key: "{node_id:?}-{i}",
                                         name: "{name}",
-                                        value: measures.pretty()
+                                        value: measures.pretty(),
+                                        onchange: onedit(name)
                                     }
                                 }
                             }
@@ -67,19 +80,22 @@ pub fn NodeInspectorStyle(node_id: NodeId) -> Element {
                             }
                             AttributeType::Size(size) => {
                                 rsx!{
-                                    Property {
-                                        key: "{i}",
+                                    EditableProperty {
+                                        key: "{node_id:?}-{i}",
                                         name: "{name}",
-                                        value: size.pretty()
+                                        value: size.pretty(),
+                                        onchange: onedit(name)
                                     }
                                 }
                             }
                             AttributeType::Color(fill) => {
                                 rsx!{
-                                    ColorProperty {
-                                        key: "{i}",
+                                    EditableProperty {
+                                        key: "{node_id:?}-{i}",
                                         name: "{name}",
-                                        fill: fill.clone()
+                                        value: fill.to_string(),
+                                        fill: fill.clone(),
+                                        onchange: onedit(name)
                                     }
                                 }
                             }
@@ -103,10 +119,11 @@ pub fn NodeInspectorStyle(node_id: NodeId) -> Element {
                             }
                             AttributeType::Text(text) => {
                                 rsx!{
-                                    Property {
-                                        key: "{i}",
+                                    EditableProperty {
+                                        key: "{node_id:?}-{i}",
                                         name: "{name}",
-                                        value: text.to_string()
+                                        value: text.to_string(),
+                                        onchange: onedit(name)
                                     }
                                 }
                             }
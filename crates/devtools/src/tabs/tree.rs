@@ -3,7 +3,21 @@ use dioxus::prelude::*;
 use dioxus_native_core::NodeId;
 use dioxus_router::prelude::use_navigator;
 use freya_components::*;
-use freya_hooks::{theme_with, ScrollViewThemeWith};
+use freya_elements::elements as dioxus_elements;
+use freya_hooks::{theme_with, InputThemeWith, ScrollViewThemeWith};
+
+/// Check if the node matches the search by its tag, id or the events it listens to, e.g `rect`, `#12` or `click`.
+fn matches_search(node: &TreeNode, search: &str) -> bool {
+    let search = search.trim().to_lowercase();
+    let id = format!("#{:?}", node.id);
+
+    node.tag.to_lowercase().contains(&search)
+        || id.to_lowercase().contains(&search)
+        || node
+            .listeners
+            .iter()
+            .any(|listener| format!("on{listener}").contains(&search))
+}
 
 #[allow(non_snake_case)]
 #[component]
@@ -14,31 +28,65 @@ pub fn NodesTree(
 ) -> Element {
     let router = use_navigator();
     let nodes = use_context::<Signal<Vec<TreeNode>>>();
+    let mut search = use_signal(String::new);
 
-    rsx!(VirtualScrollView {
-        show_scrollbar: true,
-        length: nodes.read().len(),
-        item_size: 27.0,
-        theme: theme_with!(ScrollViewTheme {
-            height: height.to_string().into(),
-            padding: "15".into(),
-        }),
-        builder_args: selected_node_id,
-        builder: move |i, selected_node_id: &Option<Option<NodeId>>| {
-            let nodes = nodes.read();
-            let node = nodes.get(i).cloned().unwrap();
-            to_owned![onselected];
-            rsx! {
-                NodeElement {
-                    key: "{node.id:?}",
-                    is_selected: Some(node.id) == selected_node_id.flatten(),
-                    onselected: move |node: TreeNode| {
-                        onselected.call(node.clone());
-                        router.replace(Route::TreeStyleTab { node_id: node.id.serialize() });
-                    },
-                    node: node
+    let filtered_nodes = use_memo(move || {
+        let search = search.read();
+        let nodes = nodes.read();
+        if search.trim().is_empty() {
+            nodes.clone()
+        } else {
+            nodes
+                .iter()
+                .filter(|node| matches_search(node, &search))
+                .cloned()
+                .collect::<Vec<_>>()
+        }
+    });
+
+    rsx!(
+        rect {
+            height: "{height}",
+            width: "100%",
+            rect {
+                width: "100%",
+                padding: "5 15 0 15",
+                Input {
+                    theme: theme_with!(InputTheme {
+                        width: "100%".into(),
+                    }),
+                    value: search.read().clone(),
+                    onchange: move |value| search.set(value)
+                }
+            }
+            VirtualScrollView {
+                show_scrollbar: true,
+                length: filtered_nodes.read().len(),
+                item_size: 27.0,
+                theme: theme_with!(ScrollViewTheme {
+                    height: "calc(100% - 45)".into(),
+                    padding: "15".into(),
+                }),
+                builder_args: (selected_node_id, search.read().clone()),
+                builder: move |i, builder_args: &Option<(Option<NodeId>, String)>| {
+                    let (selected_node_id, search) = builder_args.clone().unwrap_or_default();
+                    let nodes = filtered_nodes.read();
+                    let node = nodes.get(i).cloned().unwrap();
+                    to_owned![onselected];
+                    rsx! {
+                        NodeElement {
+                            key: "{node.id:?}",
+                            is_selected: Some(node.id) == selected_node_id,
+                            is_match: !search.trim().is_empty() && matches_search(&node, &search),
+                            onselected: move |node: TreeNode| {
+                                onselected.call(node.clone());
+                                router.replace(Route::TreeStyleTab { node_id: node.id.serialize() });
+                            },
+                            node: node
+                        }
+                    }
                 }
             }
         }
-    })
+    )
 }
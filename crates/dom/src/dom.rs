@@ -2,14 +2,15 @@ use std::sync::{Arc, Mutex};
 
 use dioxus_core::VirtualDom;
 use dioxus_native_core::{
+    node::{NodeType, OwnedAttributeDiscription, OwnedAttributeValue},
     prelude::{DioxusState, State},
-    real_dom::{NodeRef, RealDom},
+    real_dom::{NodeImmutable, NodeRef, NodeTypeMut, RealDom},
     NodeId, SendAnyMap,
 };
 
 use freya_node_state::{
     AccessibilityNodeState, CursorSettings, CustomAttributeValues, FontStyleState, LayoutState,
-    References, Style, Transform,
+    References, Style, StyleSheet, Transform,
 };
 use std::sync::MutexGuard;
 use torin::prelude::*;
@@ -82,6 +83,8 @@ pub struct FreyaDOM {
     rdom: DioxusDOM,
     dioxus_integration_state: DioxusState,
     torin: Arc<Mutex<Torin<NodeId>>>,
+    dirty_nodes: Vec<NodeId>,
+    stylesheet: Arc<StyleSheet>,
 }
 
 impl Default for FreyaDOM {
@@ -100,6 +103,8 @@ impl Default for FreyaDOM {
             rdom,
             dioxus_integration_state,
             torin: Arc::new(Mutex::new(Torin::new())),
+            dirty_nodes: Vec::new(),
+            stylesheet: Arc::default(),
         }
     }
 }
@@ -122,6 +127,7 @@ impl FreyaDOM {
         let mut ctx = SendAnyMap::new();
         ctx.insert(scale_factor);
         ctx.insert(self.torin.clone());
+        ctx.insert(self.stylesheet.clone());
 
         self.rdom.update_state(ctx);
     }
@@ -140,12 +146,29 @@ impl FreyaDOM {
         let mut ctx = SendAnyMap::new();
         ctx.insert(scale_factor);
         ctx.insert(self.torin.clone());
+        ctx.insert(self.stylesheet.clone());
 
         // Update the Node's states
         let (_, diff) = self.rdom.update_state(ctx);
 
         let must_repaint = !diff.is_empty();
-        let must_relayout = !self.layout().get_dirty_nodes().is_empty();
+        self.dirty_nodes = diff.keys().copied().collect();
+
+        // New elements with the default layout state are not invalidated by their state,
+        // but they must be measured too as only the subtrees with dirty nodes are visited
+        let mut layout = self.layout();
+        for node_id in &self.dirty_nodes {
+            let is_element = self
+                .rdom
+                .get(*node_id)
+                .map(|node| matches!(*node.node_type(), NodeType::Element(_)))
+                .unwrap_or_default();
+            if is_element && layout.get(*node_id).is_none() {
+                layout.invalidate(*node_id);
+            }
+        }
+        let must_relayout = !layout.get_dirty_nodes().is_empty();
+        drop(layout);
 
         if !diff.is_empty() {
             info!(
@@ -157,6 +180,63 @@ impl FreyaDOM {
         (must_repaint, must_relayout)
     }
 
+    /// Get the nodes that changed in the last [`FreyaDOM::render_mutations`], used to only repaint what changed.
+    pub fn take_dirty_nodes(&mut self) -> Vec<NodeId> {
+        std::mem::take(&mut self.dirty_nodes)
+    }
+
+    /// Set an attribute of an element outside of the [`VirtualDOM`](dioxus_core::VirtualDom), e.g from the devtools.
+    ///
+    /// The new state is computed with the next mutations, and the component will override
+    /// the value the next time it sets that attribute.
+    pub fn set_attribute(&mut self, node_id: NodeId, name: &str, value: String) {
+        let Some(mut node) = self.rdom.get_mut(node_id) else {
+            return;
+        };
+
+        if let NodeTypeMut::Element(mut element) = node.node_type_mut() {
+            element.set_attribute(
+                OwnedAttributeDiscription {
+                    name: name.to_string(),
+                    namespace: None,
+                },
+                OwnedAttributeValue::Text(value),
+            );
+            self.torin.lock().unwrap().invalidate(node_id);
+        }
+    }
+
+    /// Replace the [`StyleSheet`] used by the `class` attribute.
+    ///
+    /// The elements with classes are updated with the next mutations.
+    pub fn set_stylesheet(&mut self, stylesheet: StyleSheet) {
+        self.stylesheet = Arc::new(stylesheet);
+
+        let mut classes = Vec::new();
+        self.rdom.traverse_depth_first(|node| {
+            if let NodeType::Element(element) = &*node.node_type() {
+                let class = element
+                    .attributes
+                    .iter()
+                    .find(|(attribute, _)| attribute.name == "class")
+                    .and_then(|(_, value)| value.as_text());
+                if let Some(class) = class {
+                    classes.push((node.id(), class.to_string()));
+                }
+            }
+        });
+
+        // Setting the class again marks the elements as dirty
+        for (node_id, class) in classes {
+            self.set_attribute(node_id, "class", class);
+        }
+    }
+
+    /// Get the [`StyleSheet`] used by the `class` attribute.
+    pub fn stylesheet(&self) -> &StyleSheet {
+        &self.stylesheet
+    }
+
     /// Get a reference to the [`DioxusDOM`].
     pub fn rdom(&self) -> &DioxusDOM {
         &self.rdom
@@ -40,6 +40,7 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             maximum_width: layout.maximum_width,
             maximum_height: layout.maximum_height,
             direction: layout.direction,
+            layout_direction: layout.layout_direction,
             padding: layout.padding,
             margin: layout.margin,
             main_alignment: layout.main_alignment,
@@ -48,6 +49,9 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             offset_y: layout.offset_y,
             has_layout_references: layout.node_ref.is_some(),
             position: layout.position,
+            content_visibility: layout.content_visibility,
+            contain_intrinsic_size: layout.contain_intrinsic_size,
+            display: layout.display,
         })
     }
 
@@ -71,6 +71,11 @@ impl<'a> WriteMutations for MutationsWriter<'a> {
         value: &dioxus_core::AttributeValue,
         id: dioxus_core::ElementId,
     ) {
+        // The intrinsic size of an SVG depends on its content
+        if matches!(name, "svg_data" | "svg_content") {
+            self.layout
+                .invalidate(self.native_writer.state.element_to_node_id(id));
+        }
         self.native_writer.set_attribute(name, ns, value, id);
     }
 
@@ -2,21 +2,33 @@
 //!
 //! The attributes that have colors as values can use the following syntax:
 //!
-//! #### Static colors
-//! - `rect`
-//! - `blue`
-//! - `green`
-//! - `yellow`
+//! #### Named colors
+//! All the [CSS color keywords](https://developer.mozilla.org/en-US/docs/Web/CSS/named-color) are supported, ignoring the case, e.g:
+//! - `red`
+//! - `rebeccapurple`
 //! - `black` (default for `color` attribute)
-//! - `gray`
 //! - `white` (default for `background` attribute)
-//! - `orange`
 //! - `transparent`
 //!
+//! `green` and `gray` keep the brighter values Freya always used instead of the CSS ones.
+//!
+//! #### Hex colors
+//!
+//! - With RGB: `#ff8800`
+//! - With RGB and alpha: `#ff880080`
+//! - Shorthand: `#f80`
+//! - Shorthand with alpha: `#f808`
+//!
 //! #### rgb() / hsl()
 //!
 //! - With RGB: `rgb(150, 60, 20)`
 //! - With RGB and alpha: `rgb(150, 60, 20, 0.7)`
 //!     - You can also use 0-255 for the alpha: `rgb(150, 60, 20, 70)`
+//! - With `rgba()`, where the alpha is 0-1 or a percentage: `rgba(150, 60, 20, 0.7)` or `rgba(150, 60, 20, 70%)`
 //! - With HSL: `hsl(28deg, 80%, 50%)`
-//! - With HSL and alpha: `hsl(28deg, 80%, 50%, 25%)`
+//!     - The `deg` unit of the hue is optional: `hsl(28, 80%, 50%)`
+//! - With HSL and alpha: `hsl(28deg, 80%, 50%, 25%)` or `hsla(28, 80%, 50%, 0.25)`
+//!
+//! #### Opacity
+//!
+//! Any color can be followed by `opacity()` to multiply its alpha, e.g `red opacity(0.5)` or `#000 opacity(50%)`.
@@ -186,11 +186,19 @@ builder_constructors! {
         max_width: String,
         #[doc = include_str!("_docs/attributes/background.md")]
         background: String,
+        #[doc = include_str!("_docs/attributes/background_hover_active.md")]
+        background_hover: String,
+        background_active: String,
+        #[doc = include_str!("_docs/attributes/background_image.md")]
+        background_repeat: String,
+        background_position: String,
         #[doc = include_str!("_docs/attributes/border.md")]
         border: String,
         border_align: String,
         #[doc = include_str!("_docs/attributes/direction.md")]
         direction: String,
+        #[doc = include_str!("_docs/attributes/direction_mode.md")]
+        direction_mode: String,
         #[doc = include_str!("_docs/attributes/shadow.md")]
         shadow: String,
         #[doc = include_str!("_docs/attributes/corner.md")]
@@ -225,11 +233,30 @@ builder_constructors! {
         position_right: String,
         position_bottom: String,
         position_left: String,
+        #[doc = include_str!("_docs/attributes/content_visibility.md")]
+        content_visibility: String,
+        contain_intrinsic_size: String,
+        #[doc = include_str!("_docs/attributes/display.md")]
+        display: String,
+        #[doc = include_str!("_docs/attributes/visibility.md")]
+        visibility: String,
+        #[doc = include_str!("_docs/attributes/class.md")]
+        class: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
-
+        #[doc = include_str!("_docs/attributes/cursor.md")]
+        cursor: String,
+        #[doc = include_str!("_docs/attributes/window_drag.md")]
+        window_drag: String,
+        #[doc = include_str!("_docs/attributes/pointer_capture.md")]
+        pointer_capture: String,
+
+        #[doc = include_str!("_docs/attributes/name.md")]
         name: String,
+        #[doc = include_str!("_docs/attributes/aria_label.md")]
+        aria_label: String,
         focusable: String,
+        #[doc = include_str!("_docs/attributes/role.md")]
         role: String,
         focus_id: AccessibilityId,
         alt: String,
@@ -257,11 +284,20 @@ builder_constructors! {
     label {
         #[doc = include_str!("_docs/attributes/color.md")]
         color: String,
+        #[doc = include_str!("_docs/attributes/color_hover_active.md")]
+        color_hover: String,
+        color_active: String,
         #[doc = include_str!("_docs/attributes/text_shadow.md")]
         text_shadow: String,
         #[doc = include_str!("_docs/attributes/width_height.md")]
         height: String,
         width: String,
+        #[doc = include_str!("_docs/attributes/display.md")]
+        display: String,
+        #[doc = include_str!("_docs/attributes/visibility.md")]
+        visibility: String,
+        #[doc = include_str!("_docs/attributes/class.md")]
+        class: String,
         #[doc = include_str!("_docs/attributes/font_size.md")]
         font_size: String,
         #[doc = include_str!("_docs/attributes/font_family.md")]
@@ -297,12 +333,18 @@ builder_constructors! {
         margin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/cursor.md")]
+        cursor: String,
 
         layer: String,
+        #[doc = include_str!("_docs/attributes/role.md")]
         role: String,
         alt: String,
         focus_id: AccessibilityId,
+        #[doc = include_str!("_docs/attributes/name.md")]
         name: String,
+        #[doc = include_str!("_docs/attributes/aria_label.md")]
+        aria_label: String,
     };
     /// `paragraph` element let's you build texts with different styles.
     ///
@@ -329,6 +371,12 @@ builder_constructors! {
         #[doc = include_str!("_docs/attributes/width_height.md")]
         height: String,
         width: String,
+        #[doc = include_str!("_docs/attributes/display.md")]
+        display: String,
+        #[doc = include_str!("_docs/attributes/visibility.md")]
+        visibility: String,
+        #[doc = include_str!("_docs/attributes/class.md")]
+        class: String,
         #[doc = include_str!("_docs/attributes/min_width_min_height.md")]
         min_height: String,
         min_width: String,
@@ -337,6 +385,9 @@ builder_constructors! {
         max_width: String,
         #[doc = include_str!("_docs/attributes/text_align.md")]
         text_align: String,
+        #[doc = include_str!("_docs/attributes/color_hover_active.md")]
+        color_hover: String,
+        color_active: String,
         direction: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
@@ -369,6 +420,8 @@ builder_constructors! {
         margin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/cursor.md")]
+        cursor: String,
 
         layer: String,
         cursor_index: String,
@@ -377,7 +430,11 @@ builder_constructors! {
         cursor_mode: String,
         cursor_id: String,
         alt: String,
+        #[doc = include_str!("_docs/attributes/name.md")]
         name: String,
+        #[doc = include_str!("_docs/attributes/aria_label.md")]
+        aria_label: String,
+        #[doc = include_str!("_docs/attributes/role.md")]
         role: String,
         focus_id: AccessibilityId,
         highlights: String,
@@ -414,6 +471,8 @@ builder_constructors! {
         decoration_style: String,
         #[doc = include_str!("_docs/attributes/decoration_color.md")]
         decoration_color: String,
+        #[doc = include_str!("_docs/attributes/class.md")]
+        class: String,
     };
     /// `image` element let's you show an image.
     ///
@@ -438,17 +497,29 @@ builder_constructors! {
        #[doc = include_str!("_docs/attributes/width_height.md")]
         height: String,
         width: String,
+        #[doc = include_str!("_docs/attributes/display.md")]
+        display: String,
+        #[doc = include_str!("_docs/attributes/visibility.md")]
+        visibility: String,
+        #[doc = include_str!("_docs/attributes/class.md")]
+        class: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/cursor.md")]
+        cursor: String,
 
         image_data: String,
         image_reference: String,
+        #[doc = include_str!("_docs/attributes/role.md")]
         role: String,
         focus_id: AccessibilityId,
         alt: String,
+        #[doc = include_str!("_docs/attributes/name.md")]
         name: String,
+        #[doc = include_str!("_docs/attributes/aria_label.md")]
+        aria_label: String,
         focusable: String,
     };
     /// `svg` element let's you display SVG code.
@@ -477,17 +548,35 @@ builder_constructors! {
        #[doc = include_str!("_docs/attributes/width_height.md")]
         height: String,
         width: String,
+        #[doc = include_str!("_docs/attributes/display.md")]
+        display: String,
+        #[doc = include_str!("_docs/attributes/visibility.md")]
+        visibility: String,
+        #[doc = include_str!("_docs/attributes/class.md")]
+        class: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/cursor.md")]
+        cursor: String,
 
         svg_data: String,
         svg_content: String,
+        #[doc = include_str!("_docs/attributes/svg_colors.md")]
+        color: String,
+        fill: String,
+        stroke: String,
+        #[doc = include_str!("_docs/attributes/fit.md")]
+        fit: String,
+        #[doc = include_str!("_docs/attributes/role.md")]
         role: String,
         focus_id: AccessibilityId,
         alt: String,
+        #[doc = include_str!("_docs/attributes/name.md")]
         name: String,
+        #[doc = include_str!("_docs/attributes/aria_label.md")]
+        aria_label: String,
         focusable: String,
     };
 }
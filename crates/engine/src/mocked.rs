@@ -9,7 +9,7 @@ use std::ops::*;
 use bitflags::bitflags;
 use glutin::context::PossiblyCurrentContext;
 
-#[derive(Clone, Debug, PartialEq, Copy, Eq)]
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub struct Color(u32);
 
 impl From<u32> for Color {
@@ -171,6 +171,22 @@ impl Matrix {
     pub fn set_rotate(&mut self, _degrees: f32, _pivot: impl Into<Option<Point>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn translate(_d: impl Into<Point>) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_scale(&mut self, _scale: (f32, f32), _pivot: impl Into<Option<Point>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn invert(&self) -> Option<Matrix> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn map_point(&self, _point: impl Into<Point>) -> Point {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -285,6 +301,12 @@ impl Weight {
     pub const EXTRA_BLACK: Self = Self(1000);
 }
 
+impl From<i32> for Weight {
+    fn from(weight: i32) -> Self {
+        Self(weight)
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum Slant {
     Upright = 0,
@@ -597,6 +619,10 @@ impl Paint {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_stroke_cap(&mut self, _cap: PaintCap) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn set_stroke_width(&mut self, _width: f32) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -612,6 +638,12 @@ pub enum PaintStyle {
     StrokeAndFill = 2,
 }
 
+pub enum PaintCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
 pub struct FontStyle;
 
 impl FontStyle {
@@ -676,6 +708,10 @@ impl FontCollection {
     pub fn set_dynamic_font_manager(&mut self, _font_manager: impl Into<Option<FontMgr>>) {
         unimplemented!("This is mocked")
     }
+
+    pub fn enable_font_fallback(&mut self) {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct Paragraph;
@@ -966,6 +1002,10 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn local_to_device_as_3x3(&self) -> Matrix {
+        unimplemented!("This is mocked")
+    }
+
     pub fn clip_rect(&self, _rect: Rect, _clip: ClipOp, _: bool) {
         unimplemented!("This is mocked")
     }
@@ -981,6 +1021,20 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn draw_image(&self, _image: &Image, _left_top: Point, _paint: Option<&Paint>) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn draw_image_rect(
+        &self,
+        _image: impl AsRef<Image>,
+        _src: Option<(&Rect, SrcRectConstraint)>,
+        _dst: impl AsRef<Rect>,
+        _paint: &Paint,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn draw_rect(&self, _rect: Rect, _paint: &Paint) -> &Self {
         unimplemented!("This is mocked")
     }
@@ -1002,6 +1056,10 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn reset_matrix(&self) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn scale(&self, _: impl Into<Point>) {
         unimplemented!("This is mocked")
     }
@@ -1130,12 +1188,75 @@ impl Rect {
     }
 }
 
+impl AsRef<Rect> for Rect {
+    fn as_ref(&self) -> &Rect {
+        self
+    }
+}
+
+#[derive(Clone)]
 pub struct Image;
 
 impl Image {
     pub fn from_encoded(_data: Data) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn width(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn height(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn to_shader<'a>(
+        &self,
+        _tile_modes: impl Into<Option<(TileMode, TileMode)>>,
+        _sampling: impl Into<SamplingOptions>,
+        _local_matrix: impl Into<Option<&'a Matrix>>,
+    ) -> Option<Shader> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn encode(
+        &self,
+        _context: Option<&mut DirectContext>,
+        _format: EncodedImageFormat,
+        _quality: impl Into<Option<u32>>,
+    ) -> Option<Data> {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum EncodedImageFormat {
+    BMP = 0,
+    GIF = 1,
+    ICO = 2,
+    JPEG = 3,
+    PNG = 4,
+    WBMP = 5,
+    WEBP = 6,
+    PKM = 7,
+    KTX = 8,
+    ASTC = 9,
+    DNG = 10,
+    HEIF = 11,
+    AVIF = 12,
+    JPEGXL = 13,
+}
+
+impl AsRef<Image> for Image {
+    fn as_ref(&self) -> &Image {
+        self
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SrcRectConstraint {
+    Strict = 0,
+    Fast = 1,
 }
 
 pub struct Data;
@@ -1144,6 +1265,14 @@ impl Data {
     pub unsafe fn new_bytes(_bytes: &[u8]) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn new_copy(_bytes: &[u8]) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -1176,6 +1305,15 @@ impl FilterMode {
     pub const Last: FilterMode = FilterMode::Linear;
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct SamplingOptions;
+
+impl From<FilterMode> for SamplingOptions {
+    fn from(_filter_mode: FilterMode) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct Path;
 
 impl Path {
@@ -1235,6 +1373,15 @@ impl Path {
     pub fn offset(&mut self, _d: impl Into<Point>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn add_arc(
+        &mut self,
+        _oval: impl AsRef<Rect>,
+        _start_angle: f32,
+        _sweep_angle: f32,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(i32)]
@@ -1324,7 +1471,9 @@ pub enum BlurStyle {
 }
 
 pub mod svg {
-    use super::{Canvas, FontMgr, Size};
+    use std::ops::Deref;
+
+    use super::{Data, FontMgr, Rect, Size};
 
     pub struct Dom;
 
@@ -1337,7 +1486,57 @@ pub mod svg {
             unimplemented!("This is mocked")
         }
 
-        pub fn render(&self, _canvas: &Canvas) {
+        pub fn render(&self, _canvas: &super::Canvas) {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    pub struct Canvas;
+
+    impl Canvas {
+        pub fn new(_bounds: impl AsRef<Rect>, _flags: Option<()>) -> Self {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn end(self) -> Data {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    impl Deref for Canvas {
+        type Target = super::Canvas;
+
+        fn deref(&self) -> &Self::Target {
+            unimplemented!("This is mocked")
+        }
+    }
+}
+
+pub mod pdf {
+    use super::{Canvas, Data, Rect, Size};
+
+    pub struct Metadata;
+
+    pub struct Document;
+
+    pub fn new_document(_metadata: Option<&Metadata>) -> Document {
+        unimplemented!("This is mocked")
+    }
+
+    impl Document {
+        pub fn begin_page(self, _size: impl Into<Size>, _content: Option<&Rect>) -> Self {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn canvas(&mut self) -> &Canvas {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn end_page(self) -> Self {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn close(self) -> Data {
             unimplemented!("This is mocked")
         }
     }
@@ -1370,6 +1569,36 @@ impl Surface {
         unimplemented!("This is mocked")
     }
 
+    pub fn image_snapshot(&mut self) -> Image {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn new_surface_with_dimensions(&mut self, _dim: (i32, i32)) -> Option<Surface> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn direct_context(&self) -> Option<DirectContext> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn width(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn height(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn read_pixels(
+        &mut self,
+        _dst_info: &ImageInfo,
+        _dst_pixels: &mut [u8],
+        _dst_row_bytes: usize,
+        _src_point: (i32, i32),
+    ) -> bool {
+        unimplemented!("This is mocked")
+    }
+
     pub fn from_backend_render_target(
         _context: &mut RecordingContext,
         _backend_render_target: &BackendRenderTarget,
@@ -1388,6 +1617,29 @@ pub struct ColorSpace;
 #[repr(i32)]
 pub enum ColorType {
     RGBA8888 = 4,
+    BGRA8888 = 6,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum AlphaType {
+    Unknown = 0,
+    Opaque = 1,
+    Premul = 2,
+    Unpremul = 3,
+}
+
+pub struct ImageInfo;
+
+impl ImageInfo {
+    pub fn new(
+        _dimensions: (i32, i32),
+        _ct: ColorType,
+        _at: AlphaType,
+        _cs: impl Into<Option<ColorSpace>>,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct SurfaceProps;
@@ -1537,6 +1789,14 @@ impl BackendRenderTarget {
     }
 }
 
+pub mod surfaces {
+    use crate::prelude::*;
+
+    pub fn raster_n32_premul(_size: (i32, i32)) -> Option<Surface> {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub mod backend_render_targets {
     use crate::prelude::*;
     pub fn make_gl(
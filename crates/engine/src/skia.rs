@@ -1,4 +1,5 @@
 pub use skia_safe::{
+    canvas::SrcRectConstraint,
     font_style::{Slant, Weight, Width},
     gpu::{
         backend_render_targets,
@@ -8,9 +9,10 @@ pub use skia_safe::{
     },
     gradient_shader::GradientShaderColors,
     path::ArcSize,
+    pdf,
     rrect::Corner,
     runtime_effect::Uniform,
-    svg,
+    surfaces, svg,
     textlayout::{
         paragraph::GlyphClusterInfo, Decoration, FontCollection, FontFeature, LineMetrics,
         Paragraph, ParagraphBuilder, ParagraphStyle, PlaceholderStyle, PositionWithAffinity,
@@ -18,7 +20,8 @@ pub use skia_safe::{
         TextDecoration, TextDecorationStyle, TextDirection, TextHeightBehavior, TextIndex,
         TextRange, TextShadow, TextStyle, TypefaceFontProvider,
     },
-    BlurStyle, Canvas, ClipOp, Color, ColorSpace, ColorType, Data, FilterMode, FontArguments,
-    FontMgr, FontStyle, IRect, Image, MaskFilter, Matrix, Paint, PaintStyle, Path, PathDirection,
-    Point, RRect, Rect, RuntimeEffect, Shader, Surface, TileMode, Typeface, HSV, RGB,
+    AlphaType, BlurStyle, Canvas, ClipOp, Color, ColorSpace, ColorType, Data, EncodedImageFormat,
+    FilterMode, FontArguments, FontMgr, FontStyle, IRect, Image, ImageInfo, MaskFilter, Matrix,
+    Paint, PaintCap, PaintStyle, Path, PathDirection, Point, RRect, Rect, RuntimeEffect,
+    SamplingOptions, Shader, Surface, TileMode, Typeface, HSV, RGB,
 };
@@ -190,40 +190,54 @@ pub fn launch_cfg<T: 'static + Clone + Send>(app: AppComponent, config: LaunchCo
         use tracing::Level;
         use tracing_subscriber::FmtSubscriber;
 
-        let subscriber = FmtSubscriber::builder()
-            .with_max_level(Level::TRACE)
-            .finish();
+        let subscriber = FmtSubscriber::builder().with_max_level(Level::TRACE);
+
+        // Log how long the spans around layout, events and rendering took
+        #[cfg(feature = "profiling")]
+        let subscriber =
+            subscriber.with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE);
+
+        let subscriber = subscriber.finish();
 
         tracing::subscriber::set_global_default(subscriber)
             .expect("Setting default subscriber failed");
     }
 
-    let (vdom, mutations_notifier, hovered_node) = {
+    let (vdom, mutations_notifier, hovered_node, events_log) = {
         #[cfg(feature = "devtools")]
         #[cfg(debug_assertions)]
         {
             use freya_devtools::with_devtools;
             use std::sync::{Arc, Mutex};
-            use tokio::sync::Notify;
+            use tokio::sync::{broadcast, Notify};
 
             let hovered_node = Some(Arc::new(Mutex::new(None)));
             let mutations_notifier = Arc::new(Notify::new());
+            let (events_log, _) = broadcast::channel(256);
             let vdom = with_devtools(
                 sdom.clone(),
                 app,
                 mutations_notifier.clone(),
                 hovered_node.clone(),
+                events_log.clone(),
             );
-            (vdom, Some(mutations_notifier), hovered_node)
+            (vdom, Some(mutations_notifier), hovered_node, Some(events_log))
         }
 
         #[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
         {
             let vdom = with_accessibility(app);
-            (vdom, None, None)
+            (vdom, None, None, None)
         }
     };
-    DesktopRenderer::launch(vdom, sdom, config, mutations_notifier, hovered_node);
+    DesktopRenderer::launch(
+        vdom,
+        sdom,
+        config,
+        mutations_notifier,
+        hovered_node,
+        events_log,
+    );
 }
 
 #[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
@@ -46,8 +46,13 @@
 //! ## Features flags
 //!
 //! - `devtools`: enables a side panel to inspect your App tree, styles and computed layout.
-//! - `use_camera`: enables the `use_camera` hook.
+//! - `use_camera`: enables the `use_camera_stream` hook and the `CameraView` component.
+//! - `use_persistent`: enables the `use_persistent` hook.
+//! - `i18n_json`: enables loading the translations of `use_init_i18n` from JSON.
+//! - `use_format`: enables the `use_format` hook and the locale-aware formatting functions.
+//! - `network_image`: enables the `NetworkImage` component, which fetches images over HTTP.
 //! - `log`: enables internal logs.
+//! - `profiling`: enables `tracing` spans around layout, events and rendering, and logs the time spent on every frame.
 //!
 
 /// Freya docs.
@@ -106,11 +111,14 @@ pub mod prelude {
 
     pub use crate::launch::*;
     pub use crate::plugins::*;
+    pub use freya_common::{CustomCursor, ExportFormat, NewWindow, PageOptions, PageSize};
     pub use freya_components::*;
     pub use freya_elements::elements as dioxus_elements;
     pub use freya_elements::events::*;
     pub use freya_hooks::*;
-    pub use freya_node_state::{bytes_to_data, static_bytes_to_data, CustomAttributeValues};
+    pub use freya_node_state::{
+        bytes_to_data, static_bytes_to_data, CustomAttributeValues, StyleSheet,
+    };
     pub use freya_renderer::*;
     pub use torin::prelude::*;
 }
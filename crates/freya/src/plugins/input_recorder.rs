@@ -0,0 +1,446 @@
+use std::{
+    fmt, fs, io,
+    path::Path,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use freya_core::{
+    events::PlatformEvent,
+    plugins::{FreyaPlugin, PluginEvent, PluginHandle},
+    prelude::EventName,
+};
+use freya_elements::events::{
+    keyboard::{Code, Key, Modifiers},
+    Force, MouseButton, TouchPhase,
+};
+use torin::geometry::CursorPoint;
+
+/// First line of a recording file, the number is the version of the format.
+const RECORDING_HEADER: &str = "freya-recording 1";
+
+/// Records the [`PlatformEvent`]s of the app with the time they happened, and replays them later.
+///
+/// Useful to reproduce bugs and for demos. The recorder is cheap to clone, so a clone can be
+/// registered as plugin and another one shared with the app to control it:
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let recorder = use_context::<InputRecorderPlugin>();
+///
+///     let onclick = move |_| {
+///         if recorder.is_recording() {
+///             if let Some(recording) = recorder.stop_recording() {
+///                 recording.save("session.recording").unwrap();
+///             }
+///         } else {
+///             recorder.start_recording();
+///         }
+///     };
+///
+///     rsx!(
+///         Button {
+///             onclick,
+///             label { "Record" }
+///         }
+///     )
+/// }
+///
+/// let recorder = InputRecorderPlugin::default();
+///
+/// launch_cfg(
+///     app,
+///     LaunchConfig::builder()
+///         .with_plugin(recorder.clone())
+///         .with_state(recorder)
+///         .build(),
+/// )
+/// ```
+#[derive(Clone, Default)]
+pub struct InputRecorderPlugin {
+    state: Arc<Mutex<RecorderState>>,
+}
+
+#[derive(Default)]
+struct RecorderState {
+    handle: Option<PluginHandle>,
+    recording: Option<(Instant, Recording)>,
+    replaying: Option<Arc<AtomicBool>>,
+}
+
+/// How fast a [`Recording`] is replayed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReplaySpeed {
+    /// Wait between the events as much as when they were recorded.
+    #[default]
+    Original,
+    /// Send the events one after another without waiting.
+    AsFastAsPossible,
+}
+
+impl InputRecorderPlugin {
+    /// Start recording the events, discarding the recording in progress if any.
+    pub fn start_recording(&self) {
+        self.state.lock().unwrap().recording = Some((Instant::now(), Recording::default()));
+    }
+
+    /// Stop recording the events and get the [`Recording`], if it was recording.
+    pub fn stop_recording(&self) -> Option<Recording> {
+        self.state
+            .lock()
+            .unwrap()
+            .recording
+            .take()
+            .map(|(_, recording)| recording)
+    }
+
+    /// Check if the events are being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.state.lock().unwrap().recording.is_some()
+    }
+
+    /// Replay the events of a [`Recording`] in the background, stopping the replay in progress if any.
+    ///
+    /// Nothing is replayed if the app has not been launched yet.
+    pub fn replay(&self, recording: Recording, speed: ReplaySpeed) {
+        self.stop_replay();
+
+        let mut state = self.state.lock().unwrap();
+        let Some(handle) = state.handle.clone() else {
+            return;
+        };
+
+        let replaying = Arc::new(AtomicBool::new(true));
+        state.replaying = Some(replaying.clone());
+
+        thread::spawn(move || {
+            let started = Instant::now();
+            for RecordedEvent { time, event } in recording.events {
+                if speed == ReplaySpeed::Original {
+                    thread::sleep((started + time).saturating_duration_since(Instant::now()));
+                }
+                if !replaying.load(Ordering::Relaxed) {
+                    return;
+                }
+                handle.send_platform_event(event);
+            }
+            replaying.store(false, Ordering::Relaxed);
+        });
+    }
+
+    /// Stop the replay in progress, the events already sent are still processed.
+    pub fn stop_replay(&self) {
+        if let Some(replaying) = self.state.lock().unwrap().replaying.take() {
+            replaying.store(false, Ordering::Relaxed);
+        }
+    }
+
+    /// Check if a [`Recording`] is being replayed.
+    pub fn is_replaying(&self) -> bool {
+        self.state
+            .lock()
+            .unwrap()
+            .replaying
+            .as_ref()
+            .map(|replaying| replaying.load(Ordering::Relaxed))
+            .unwrap_or_default()
+    }
+}
+
+impl FreyaPlugin for InputRecorderPlugin {
+    fn on_platform_event(&mut self, event: &PlatformEvent) {
+        if let Some((started, recording)) = &mut self.state.lock().unwrap().recording {
+            recording.events.push(RecordedEvent {
+                time: started.elapsed(),
+                event: event.clone(),
+            });
+        }
+    }
+
+    fn on_event(&mut self, event: &PluginEvent) {
+        if let PluginEvent::WindowCreated(_, handle) = event {
+            self.state.lock().unwrap().handle = Some((*handle).clone());
+        }
+    }
+}
+
+/// A [`PlatformEvent`] and the time it happened since the recording started.
+#[derive(Clone, Debug)]
+pub struct RecordedEvent {
+    pub time: Duration,
+    pub event: PlatformEvent,
+}
+
+/// Events recorded by the [`InputRecorderPlugin`].
+///
+/// It is saved as text, a header line followed by one line per event. Every line starts with
+/// the milliseconds since the recording started, the kind of event and its name, e.g:
+///
+/// ```text
+/// freya-recording 1
+/// 0 mouse mouseover 120.5 48 none
+/// 130 mouse mousedown 120.5 48 left
+/// 210 mouse click 120.5 48 left
+/// 900 wheel wheel 0 -53 120.5 48
+/// 1500 keyboard keydown KeyA 0 a
+/// 2100 touch touchstart 30 40 0 started 0.5
+/// ```
+///
+/// - `mouse`: cursor x and y, and the button (`left`, `right`, `middle`, `back`, `forward`, its number or `none`).
+/// - `wheel`: scroll x and y, and cursor x and y.
+/// - `keyboard`: code, modifiers bits and key, the key is the rest of the line.
+/// - `touch`: location x and y, finger id, phase (`started`, `moved`, `ended` or `cancelled`) and normalized force or `none`.
+#[derive(Clone, Debug, Default)]
+pub struct Recording {
+    pub events: Vec<RecordedEvent>,
+}
+
+impl Recording {
+    /// Save the recording to the given file.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    /// Load a recording from the given file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        fs::read_to_string(path)?
+            .parse()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+impl fmt::Display for Recording {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{RECORDING_HEADER}")?;
+        for RecordedEvent { time, event } in &self.events {
+            write!(f, "{} ", time.as_millis())?;
+            match event {
+                PlatformEvent::Mouse {
+                    name,
+                    cursor,
+                    button,
+                } => {
+                    let name: &str = (*name).into();
+                    let button = match button {
+                        None => "none".to_string(),
+                        Some(MouseButton::Left) => "left".to_string(),
+                        Some(MouseButton::Right) => "right".to_string(),
+                        Some(MouseButton::Middle) => "middle".to_string(),
+                        Some(MouseButton::Back) => "back".to_string(),
+                        Some(MouseButton::Forward) => "forward".to_string(),
+                        Some(MouseButton::Other(button)) => button.to_string(),
+                    };
+                    writeln!(f, "mouse {name} {} {} {button}", cursor.x, cursor.y)?;
+                }
+                PlatformEvent::Wheel {
+                    name,
+                    scroll,
+                    cursor,
+                } => {
+                    let name: &str = (*name).into();
+                    writeln!(
+                        f,
+                        "wheel {name} {} {} {} {}",
+                        scroll.x, scroll.y, cursor.x, cursor.y
+                    )?;
+                }
+                PlatformEvent::Keyboard {
+                    name,
+                    key,
+                    code,
+                    modifiers,
+                } => {
+                    let name: &str = (*name).into();
+                    writeln!(f, "keyboard {name} {code} {} {key}", modifiers.bits())?;
+                }
+                PlatformEvent::Touch {
+                    name,
+                    location,
+                    finger_id,
+                    phase,
+                    force,
+                } => {
+                    let name: &str = (*name).into();
+                    let phase = match phase {
+                        TouchPhase::Started => "started",
+                        TouchPhase::Moved => "moved",
+                        TouchPhase::Ended => "ended",
+                        TouchPhase::Cancelled => "cancelled",
+                    };
+                    let force = force
+                        .map(|force| force.normalized().to_string())
+                        .unwrap_or_else(|| "none".to_string());
+                    writeln!(
+                        f,
+                        "touch {name} {} {} {finger_id} {phase} {force}",
+                        location.x, location.y
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseRecordingError {
+    /// The file doesn't start with the recording header.
+    MissingHeader,
+    /// The event of the given line number could not be parsed.
+    InvalidEvent(usize),
+}
+
+impl fmt::Display for ParseRecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHeader => write!(f, "missing `{RECORDING_HEADER}` header"),
+            Self::InvalidEvent(line) => write!(f, "invalid event in line {line}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseRecordingError {}
+
+impl FromStr for Recording {
+    type Err = ParseRecordingError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut lines = value.lines();
+        if lines.next().map(str::trim_end) != Some(RECORDING_HEADER) {
+            return Err(ParseRecordingError::MissingHeader);
+        }
+
+        let mut events = Vec::new();
+        for (i, line) in lines.enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let event = parse_event(line).ok_or(ParseRecordingError::InvalidEvent(i + 2))?;
+            events.push(event);
+        }
+
+        Ok(Self { events })
+    }
+}
+
+fn parse_event(line: &str) -> Option<RecordedEvent> {
+    let mut fields = line.splitn(3, ' ');
+    let time = Duration::from_millis(fields.next()?.parse().ok()?);
+    let kind = fields.next()?;
+    let rest = fields.next()?;
+
+    let event = if kind == "keyboard" {
+        // The key is the rest of the line as it can contain spaces
+        let mut fields = rest.splitn(4, ' ');
+        PlatformEvent::Keyboard {
+            name: EventName::try_from(fields.next()?).ok()?,
+            code: Code::from_str(fields.next()?).ok()?,
+            modifiers: Modifiers::from_bits_truncate(fields.next()?.parse().ok()?),
+            key: Key::from_str(fields.next()?).ok()?,
+        }
+    } else {
+        let fields = rest.split_whitespace().collect::<Vec<_>>();
+        let name = EventName::try_from(*fields.first()?).ok()?;
+        let number = |index: usize| fields.get(index)?.parse::<f64>().ok();
+
+        match (kind, fields.len()) {
+            ("mouse", 4) => PlatformEvent::Mouse {
+                name,
+                cursor: CursorPoint::new(number(1)?, number(2)?),
+                button: match fields[3] {
+                    "none" => None,
+                    "left" => Some(MouseButton::Left),
+                    "right" => Some(MouseButton::Right),
+                    "middle" => Some(MouseButton::Middle),
+                    "back" => Some(MouseButton::Back),
+                    "forward" => Some(MouseButton::Forward),
+                    button => Some(MouseButton::Other(button.parse().ok()?)),
+                },
+            },
+            ("wheel", 5) => PlatformEvent::Wheel {
+                name,
+                scroll: CursorPoint::new(number(1)?, number(2)?),
+                cursor: CursorPoint::new(number(3)?, number(4)?),
+            },
+            ("touch", 6) => PlatformEvent::Touch {
+                name,
+                location: CursorPoint::new(number(1)?, number(2)?),
+                finger_id: fields[3].parse().ok()?,
+                phase: match fields[4] {
+                    "started" => TouchPhase::Started,
+                    "moved" => TouchPhase::Moved,
+                    "ended" => TouchPhase::Ended,
+                    "cancelled" => TouchPhase::Cancelled,
+                    _ => return None,
+                },
+                force: match fields[5] {
+                    "none" => None,
+                    force => Some(Force::Normalized(force.parse().ok()?)),
+                },
+            },
+            _ => return None,
+        }
+    };
+
+    Some(RecordedEvent { time, event })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn recording_round_trip() {
+        let source = concat!(
+            "freya-recording 1\n",
+            "0 mouse mouseover 120.5 48 none\n",
+            "130 mouse mousedown 120.5 48 left\n",
+            "210 mouse click 120.5 48 4\n",
+            "900 wheel wheel 0 -53 120.5 48\n",
+            "1500 keyboard keydown KeyA 2 a\n",
+            "1510 keyboard keydown Space 0  \n",
+            "1600 keyboard keyup Enter 0 Enter\n",
+            "2100 touch touchstart 30 40 1 started 0.5\n",
+            "2200 touch touchend 30 40 1 ended none\n",
+        );
+        let recording = source.parse::<Recording>().unwrap();
+        assert_eq!(recording.events.len(), 9);
+        assert_eq!(recording.events[3].time, Duration::from_millis(900));
+
+        match &recording.events[5].event {
+            PlatformEvent::Keyboard { key, code, .. } => {
+                assert_eq!(*key, Key::Character(" ".to_string()));
+                assert_eq!(*code, Code::Space);
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+
+        assert_eq!(recording.to_string(), source);
+    }
+
+    #[test]
+    fn invalid_recordings() {
+        assert_eq!(
+            "0 mouse click 1 1 left".parse::<Recording>().err(),
+            Some(ParseRecordingError::MissingHeader)
+        );
+        assert_eq!(
+            "freya-recording 1\n0 mouse click 1 1 left\n10 mouse click 1 left"
+                .parse::<Recording>()
+                .err(),
+            Some(ParseRecordingError::InvalidEvent(3))
+        );
+        assert_eq!(
+            "freya-recording 1\n0 keyboard keydown NotACode 0 a"
+                .parse::<Recording>()
+                .err(),
+            Some(ParseRecordingError::InvalidEvent(2))
+        );
+    }
+}
@@ -1,3 +1,5 @@
+mod input_recorder;
 mod performance_overlay;
 
+pub use input_recorder::*;
 pub use performance_overlay::*;
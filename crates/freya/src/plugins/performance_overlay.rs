@@ -1,105 +1,240 @@
 use std::time::{Duration, Instant};
 
-use freya_core::plugins::{FreyaPlugin, PluginEvent};
+use freya_core::plugins::{FreyaPlugin, PluginEvent, RenderContext};
 use freya_engine::prelude::{
-    Color, FontStyle, ParagraphBuilder, ParagraphStyle, Slant, TextShadow, TextStyle, Weight, Width,
+    Color, FontStyle, Paint, PaintStyle, ParagraphBuilder, ParagraphStyle, Rect, Slant,
+    TextShadow, TextStyle, Weight, Width,
 };
 
-#[derive(Default)]
+/// Space between the overlay and the window edges.
+const OVERLAY_MARGIN: f32 = 5.0;
+
+/// Corner of the window where the [`PerformanceOverlayPlugin`] is drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverlayCorner {
+    #[default]
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Draws the FPS, the time spent rendering and measuring the layout, and the size of the DOM on top of the app.
+///
+/// The times are measured before drawing the overlay, so it doesn't affect them.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # fn app() -> Element { None }
+/// launch_cfg(
+///     app,
+///     LaunchConfig::<()>::builder()
+///         .with_plugin(
+///             PerformanceOverlayPlugin::default()
+///                 .with_corner(OverlayCorner::BottomRight)
+///                 .with_visible(cfg!(debug_assertions)),
+///         )
+///         .build(),
+/// )
+/// ```
 pub struct PerformanceOverlayPlugin {
     frames: Vec<Instant>,
     started_render: Option<Instant>,
     started_layout: Option<Instant>,
     finished_layout: Option<Duration>,
+    corner: OverlayCorner,
+    visible: bool,
+}
+
+impl Default for PerformanceOverlayPlugin {
+    fn default() -> Self {
+        Self {
+            frames: Vec::new(),
+            started_render: None,
+            started_layout: None,
+            finished_layout: None,
+            corner: OverlayCorner::default(),
+            visible: true,
+        }
+    }
+}
+
+impl PerformanceOverlayPlugin {
+    /// Specify the corner of the window where the overlay is drawn.
+    pub fn with_corner(mut self, corner: OverlayCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Specify whether the overlay is drawn, e.g to only show it in debug builds.
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
 }
 
 impl FreyaPlugin for PerformanceOverlayPlugin {
     fn on_event(&mut self, event: &PluginEvent) {
+        if !self.visible {
+            return;
+        }
+
         match event {
             PluginEvent::StartedLayout(_) => self.started_layout = Some(Instant::now()),
             PluginEvent::FinishedLayout(_) => {
-                self.finished_layout = Some(self.started_layout.unwrap().elapsed())
-            }
-            PluginEvent::BeforeRender { .. } => self.started_render = Some(Instant::now()),
-            PluginEvent::AfterRender {
-                canvas,
-                font_collection,
-                freya_dom,
-                viewports,
-            } => {
-                let started_render = self.started_render.take().unwrap();
-                let finished_layout = self.finished_layout.unwrap();
-                let rdom = freya_dom.rdom();
-                let layout = freya_dom.layout();
-
-                let now = Instant::now();
-
-                self.frames
-                    .retain(|frame| now.duration_since(*frame).as_millis() < 1000);
-
-                self.frames.push(now);
-
-                // Render the texts
-                let mut paragraph_builder =
-                    ParagraphBuilder::new(&ParagraphStyle::default(), *font_collection);
-                let mut text_style = TextStyle::default();
-                text_style.set_color(Color::from_rgb(63, 255, 0));
-                text_style.add_shadow(TextShadow::new(
-                    Color::from_rgb(60, 60, 60),
-                    (0.0, 1.0),
-                    1.0,
-                ));
-                paragraph_builder.push_style(&text_style);
-
-                // FPS
-                add_text(
-                    &mut paragraph_builder,
-                    format!("{} \n", self.frames.len()),
-                    30.0,
-                );
-
-                // Rendering time
-                add_text(
-                    &mut paragraph_builder,
-                    format!("Rendering: {}ms \n", started_render.elapsed().as_millis()),
-                    18.0,
-                );
-
-                // Layout time
-                add_text(
-                    &mut paragraph_builder,
-                    format!("Layout: {}ms \n", finished_layout.as_millis()),
-                    18.0,
-                );
-
-                // DOM size
-                add_text(
-                    &mut paragraph_builder,
-                    format!("{} DOM Nodes \n", rdom.tree_ref().len()),
-                    14.0,
-                );
-
-                // Layout size
-                add_text(
-                    &mut paragraph_builder,
-                    format!("{} Layout Nodes \n", layout.size()),
-                    14.0,
-                );
-
-                // Viewports
-                add_text(
-                    &mut paragraph_builder,
-                    format!("{} Nodes viewports \n", viewports.size()),
-                    14.0,
-                );
-
-                let mut paragraph = paragraph_builder.build();
-                paragraph.layout(f32::MAX);
-                paragraph.paint(canvas, (5.0, 0.0));
+                self.finished_layout = self.started_layout.take().map(|start| start.elapsed())
             }
             _ => {}
         }
     }
+
+    fn on_before_render(&mut self, _context: &RenderContext) {
+        if self.visible {
+            self.started_render = Some(Instant::now());
+        }
+    }
+
+    fn on_after_render(&mut self, context: &RenderContext) {
+        if !self.visible {
+            return;
+        }
+
+        let RenderContext {
+            canvas,
+            font_collection,
+            freya_dom,
+            viewports,
+            images_cache,
+        } = context;
+
+        // Measure before drawing the overlay
+        let rendering_time = self
+            .started_render
+            .take()
+            .map(|start| start.elapsed())
+            .unwrap_or_default();
+        let layout_time = self.finished_layout.unwrap_or_default();
+
+        let rdom = freya_dom.rdom();
+        let layout = freya_dom.layout();
+
+        let now = Instant::now();
+
+        self.frames
+            .retain(|frame| now.duration_since(*frame).as_millis() < 1000);
+
+        self.frames.push(now);
+
+        // Render the texts
+        let mut paragraph_builder =
+            ParagraphBuilder::new(&ParagraphStyle::default(), *font_collection);
+        let mut text_style = TextStyle::default();
+        text_style.set_color(Color::from_rgb(63, 255, 0));
+        text_style.add_shadow(TextShadow::new(
+            Color::from_rgb(60, 60, 60),
+            (0.0, 1.0),
+            1.0,
+        ));
+        paragraph_builder.push_style(&text_style);
+
+        // FPS
+        add_text(
+            &mut paragraph_builder,
+            format!("{} \n", self.frames.len()),
+            30.0,
+        );
+
+        // Frame time
+        add_text(
+            &mut paragraph_builder,
+            format!("Frame: {} \n", format_ms(rendering_time + layout_time)),
+            18.0,
+        );
+
+        // Rendering time
+        add_text(
+            &mut paragraph_builder,
+            format!("Rendering: {} \n", format_ms(rendering_time)),
+            18.0,
+        );
+
+        // Layout time
+        add_text(
+            &mut paragraph_builder,
+            format!("Layout: {} \n", format_ms(layout_time)),
+            18.0,
+        );
+
+        // DOM size
+        add_text(
+            &mut paragraph_builder,
+            format!("{} DOM Nodes \n", rdom.tree_ref().len()),
+            14.0,
+        );
+
+        // Layout size
+        add_text(
+            &mut paragraph_builder,
+            format!("{} Layout Nodes \n", layout.size()),
+            14.0,
+        );
+
+        // Viewports
+        add_text(
+            &mut paragraph_builder,
+            format!("{} Nodes viewports \n", viewports.size()),
+            14.0,
+        );
+
+        // Images cache
+        add_text(
+            &mut paragraph_builder,
+            format!(
+                "{} Cached images ({:.1}MB) \n",
+                images_cache.entries,
+                images_cache.bytes as f32 / (1024.0 * 1024.0)
+            ),
+            14.0,
+        );
+
+        let mut paragraph = paragraph_builder.build();
+        paragraph.layout(f32::MAX);
+
+        let width = paragraph.max_intrinsic_width() + OVERLAY_MARGIN * 2.0;
+        let height = paragraph.height() + OVERLAY_MARGIN * 2.0;
+
+        // The root node covers the whole window
+        let window_area = layout
+            .get(rdom.root_id())
+            .map(|areas| areas.area)
+            .unwrap_or_default();
+
+        let x = match self.corner {
+            OverlayCorner::TopLeft | OverlayCorner::BottomLeft => OVERLAY_MARGIN,
+            OverlayCorner::TopRight | OverlayCorner::BottomRight => {
+                window_area.max_x() - width - OVERLAY_MARGIN
+            }
+        };
+        let y = match self.corner {
+            OverlayCorner::TopLeft | OverlayCorner::TopRight => OVERLAY_MARGIN,
+            OverlayCorner::BottomLeft | OverlayCorner::BottomRight => {
+                window_area.max_y() - height - OVERLAY_MARGIN
+            }
+        };
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+        paint.set_color(Color::from_argb(150, 20, 20, 20));
+        canvas.draw_rect(Rect::new(x, y, x + width, y + height), &paint);
+
+        paragraph.paint(canvas, (x + OVERLAY_MARGIN, y + OVERLAY_MARGIN));
+    }
+}
+
+/// Format a duration in milliseconds with two decimals.
+fn format_ms(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
 }
 
 fn add_text(paragraph_builder: &mut ParagraphBuilder, text: String, font_size: f32) {
@@ -10,14 +10,41 @@ mod theming;
 mod use_accessibility;
 mod use_animation;
 mod use_canvas;
+mod use_caret_blink;
+mod use_debounce;
 mod use_editable;
 mod use_focus;
+mod use_form;
+mod use_frame_timing;
+mod use_hotkeys;
+mod use_i18n;
+mod use_interval;
+mod use_navigation;
 mod use_node;
+mod use_node_export;
+mod use_on_visible;
 mod use_platform;
+mod use_reducer;
+mod use_route_params;
+mod use_scale;
+mod use_store;
+mod use_text_measurer;
 mod use_theme;
+mod use_throttle;
+mod use_timeout;
+mod use_tray_menu;
+mod use_undo;
+mod use_window;
+mod use_window_size;
 
+#[cfg(feature = "use_audio")]
+mod use_audio;
 #[cfg(feature = "use_camera")]
 mod use_camera;
+#[cfg(feature = "use_format")]
+mod use_format;
+#[cfg(feature = "use_persistent")]
+mod use_persistent;
 
 pub use editor_history::*;
 pub use rope_editor::*;
@@ -28,11 +55,38 @@ pub use theming::*;
 pub use use_accessibility::*;
 pub use use_animation::*;
 pub use use_canvas::*;
+pub use use_caret_blink::*;
+pub use use_debounce::*;
 pub use use_editable::*;
 pub use use_focus::*;
+pub use use_form::*;
+pub use use_frame_timing::*;
+pub use use_hotkeys::*;
+pub use use_i18n::*;
+pub use use_interval::*;
+pub use use_navigation::*;
 pub use use_node::*;
+pub use use_node_export::*;
+pub use use_on_visible::*;
 pub use use_platform::*;
+pub use use_reducer::*;
+pub use use_route_params::*;
+pub use use_scale::*;
+pub use use_store::*;
+pub use use_text_measurer::*;
 pub use use_theme::*;
+pub use use_throttle::*;
+pub use use_timeout::*;
+pub use use_tray_menu::*;
+pub use use_undo::*;
+pub use use_window::*;
+pub use use_window_size::*;
 
+#[cfg(feature = "use_audio")]
+pub use use_audio::*;
 #[cfg(feature = "use_camera")]
 pub use use_camera::*;
+#[cfg(feature = "use_format")]
+pub use use_format::*;
+#[cfg(feature = "use_persistent")]
+pub use use_persistent::*;
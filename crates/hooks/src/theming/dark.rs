@@ -100,6 +100,12 @@ pub const DARK_THEME: Theme = Theme {
         width: LIGHT_THEME.progress_bar.width,
         height: LIGHT_THEME.progress_bar.height,
     },
+    circular_progress: CircularProgressTheme {
+        color: cow_borrowed!("white"),
+        track_color: cow_borrowed!("rgb(60, 60, 60)"),
+        progress_color: cow_borrowed!("rgb(255, 95, 0)"),
+        size: LIGHT_THEME.circular_progress.size,
+    },
     table: TableTheme {
         font_theme: FontTheme {
             color: cow_borrowed!("white"),
@@ -108,6 +114,8 @@ pub const DARK_THEME: Theme = Theme {
         arrow_fill: cow_borrowed!("rgb(150, 150, 150)"),
         row_background: cow_borrowed!("transparent"),
         alternate_row_background: cow_borrowed!("rgb(50, 50, 50)"),
+        selected_row_background: cow_borrowed!("rgb(80, 50, 30)"),
+        checkbox_fill: cow_borrowed!("rgb(255, 95, 0)"),
         divider_fill: cow_borrowed!("rgb(100, 100, 100)"),
         height: LIGHT_THEME.table.height,
         corner_radius: LIGHT_THEME.table.corner_radius,
@@ -145,4 +153,33 @@ pub const DARK_THEME: Theme = Theme {
             color: cow_borrowed!("white"),
         },
     },
+    breadcrumb: BreadcrumbTheme {
+        separator_color: cow_borrowed!("rgb(110, 110, 110)"),
+        hover_color: cow_borrowed!("rgb(255, 95, 0)"),
+        hover_background: cow_borrowed!("rgb(45, 45, 45)"),
+        menu_background: cow_borrowed!("rgb(35, 35, 35)"),
+        border_fill: cow_borrowed!("rgb(80, 80, 80)"),
+        font_size: cow_borrowed!("16"),
+        font_family: cow_borrowed!("Fira Sans"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("white"),
+        },
+    },
+    pagination: PaginationTheme {
+        background: cow_borrowed!("rgb(35, 35, 35)"),
+        hover_background: cow_borrowed!("rgb(45, 45, 45)"),
+        active_background: cow_borrowed!("rgb(255, 95, 0)"),
+        active_color: cow_borrowed!("white"),
+        disabled_color: cow_borrowed!("rgb(100, 100, 100)"),
+        border_fill: cow_borrowed!("rgb(80, 80, 80)"),
+        focus_border_fill: cow_borrowed!("rgb(110, 110, 110)"),
+        corner_radius: LIGHT_THEME.pagination.corner_radius,
+        font_theme: FontTheme {
+            color: cow_borrowed!("white"),
+        },
+    },
+    split_pane: SplitPaneTheme {
+        divider_fill: cow_borrowed!("rgb(60, 60, 60)"),
+        hover_divider_fill: cow_borrowed!("rgb(255, 95, 0)"),
+    },
 };
@@ -100,6 +100,12 @@ pub const LIGHT_THEME: Theme = Theme {
         width: cow_borrowed!("100%"),
         height: cow_borrowed!("20"),
     },
+    circular_progress: CircularProgressTheme {
+        color: cow_borrowed!("black"),
+        track_color: cow_borrowed!("rgb(210, 210, 210)"),
+        progress_color: cow_borrowed!("rgb(103, 80, 164)"),
+        size: cow_borrowed!("48"),
+    },
     table: TableTheme {
         font_theme: FontTheme {
             color: cow_borrowed!("black"),
@@ -108,6 +114,8 @@ pub const LIGHT_THEME: Theme = Theme {
         arrow_fill: cow_borrowed!("rgb(40, 40, 40)"),
         row_background: cow_borrowed!("transparent"),
         alternate_row_background: cow_borrowed!("rgb(240, 240, 240)"),
+        selected_row_background: cow_borrowed!("rgb(234, 221, 255)"),
+        checkbox_fill: cow_borrowed!("rgb(103, 80, 164)"),
         divider_fill: cow_borrowed!("rgb(200, 200, 200)"),
         height: cow_borrowed!("auto"),
         corner_radius: cow_borrowed!("6"),
@@ -145,4 +153,33 @@ pub const LIGHT_THEME: Theme = Theme {
         },
         border_fill: cow_borrowed!("rgb(210, 210, 210)"),
     },
+    breadcrumb: BreadcrumbTheme {
+        separator_color: cow_borrowed!("rgb(150, 150, 150)"),
+        hover_color: cow_borrowed!("rgb(43,106,208)"),
+        hover_background: cow_borrowed!("rgb(235, 235, 235)"),
+        menu_background: cow_borrowed!("white"),
+        border_fill: cow_borrowed!("rgb(210, 210, 210)"),
+        font_size: cow_borrowed!("16"),
+        font_family: cow_borrowed!("Fira Sans"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("rgb(10, 10, 10)"),
+        },
+    },
+    pagination: PaginationTheme {
+        background: cow_borrowed!("rgb(245, 245, 245)"),
+        hover_background: cow_borrowed!("rgb(235, 235, 235)"),
+        active_background: cow_borrowed!("rgb(103, 80, 164)"),
+        active_color: cow_borrowed!("white"),
+        disabled_color: cow_borrowed!("rgb(170, 170, 170)"),
+        border_fill: cow_borrowed!("rgb(210, 210, 210)"),
+        focus_border_fill: cow_borrowed!("rgb(180, 180, 180)"),
+        corner_radius: cow_borrowed!("8"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("rgb(10, 10, 10)"),
+        },
+    },
+    split_pane: SplitPaneTheme {
+        divider_fill: cow_borrowed!("rgb(210, 210, 210)"),
+        hover_divider_fill: cow_borrowed!("rgb(103, 80, 164)"),
+    },
 };
@@ -393,6 +393,17 @@ define_theme! {
     }
 }
 
+define_theme! {
+    %[component]
+    pub CircularProgress {
+        %[cows]
+        color: str,
+        track_color: str,
+        progress_color: str,
+        size: str,
+    }
+}
+
 define_theme! {
     %[component]
     pub Table {
@@ -401,6 +412,8 @@ define_theme! {
         arrow_fill: str,
         alternate_row_background: str,
         row_background: str,
+        selected_row_background: str,
+        checkbox_fill: str,
         divider_fill: str,
         height: str,
         corner_radius: str,
@@ -470,6 +483,48 @@ define_theme! {
     }
 }
 
+define_theme! {
+    %[component]
+    pub Breadcrumb {
+        %[cows]
+        separator_color: str,
+        hover_color: str,
+        hover_background: str,
+        menu_background: str,
+        border_fill: str,
+        font_size: str,
+        font_family: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub Pagination {
+        %[cows]
+        background: str,
+        hover_background: str,
+        active_background: str,
+        active_color: str,
+        disabled_color: str,
+        border_fill: str,
+        focus_border_fill: str,
+        corner_radius: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub SplitPane {
+        %[cows]
+        divider_fill: str,
+        hover_divider_fill: str,
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Theme {
     pub name: &'static str,
@@ -486,6 +541,7 @@ pub struct Theme {
     pub loader: LoaderTheme,
     pub link: LinkTheme,
     pub progress_bar: ProgressBarTheme,
+    pub circular_progress: CircularProgressTheme,
     pub table: TableTheme,
     pub input: InputTheme,
     pub canvas: CanvasTheme,
@@ -494,6 +550,9 @@ pub struct Theme {
     pub arrow_icon: ArrowIconTheme,
     pub sidebar: SidebarTheme,
     pub sidebar_item: SidebarItemTheme,
+    pub breadcrumb: BreadcrumbTheme,
+    pub pagination: PaginationTheme,
+    pub split_pane: SplitPaneTheme,
 }
 
 impl Default for Theme {
@@ -0,0 +1,213 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Cursor};
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use dioxus_core::prelude::{provide_root_context, try_consume_context};
+use dioxus_core::use_hook;
+use dioxus_signals::{Readable, Signal, Writable};
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+
+/// Audio to play with [`UseAudio::play`], from memory or a file.
+#[derive(Clone, Debug)]
+pub enum AudioSource {
+    Bytes(Arc<[u8]>),
+    Path(PathBuf),
+}
+
+impl From<&'static [u8]> for AudioSource {
+    fn from(bytes: &'static [u8]) -> Self {
+        Self::Bytes(bytes.into())
+    }
+}
+
+impl From<Vec<u8>> for AudioSource {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(bytes.into())
+    }
+}
+
+impl From<PathBuf> for AudioSource {
+    fn from(path: PathBuf) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl From<&str> for AudioSource {
+    fn from(path: &str) -> Self {
+        Self::Path(path.into())
+    }
+}
+
+/// Error returned when audio can't be played.
+#[derive(Debug)]
+pub enum AudioError {
+    /// There is no audio output device available.
+    NoOutput(rodio::StreamError),
+    /// The audio file couldn't be read.
+    Io(std::io::Error),
+    /// The audio format is not supported or the data is corrupted.
+    Decode(rodio::decoder::DecoderError),
+    /// The audio couldn't be sent to the output device.
+    Play(rodio::PlayError),
+}
+
+impl fmt::Display for AudioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoOutput(err) => write!(f, "No audio output: {err}"),
+            Self::Io(err) => write!(f, "Could not read the audio: {err}"),
+            Self::Decode(err) => write!(f, "Could not decode the audio: {err}"),
+            Self::Play(err) => write!(f, "Could not play the audio: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for AudioError {}
+
+/// The output stream shared by all the [`UseAudio`] of the app, it's closed when dropped.
+#[derive(Clone)]
+struct AudioOutput(Rc<(OutputStream, OutputStreamHandle)>);
+
+/// Get the audio output of the app, opening it the first time.
+fn audio_output() -> Result<OutputStreamHandle, AudioError> {
+    if let Some(AudioOutput(output)) = try_consume_context::<AudioOutput>() {
+        return Ok(output.1.clone());
+    }
+
+    let (stream, handle) = OutputStream::try_default().map_err(AudioError::NoOutput)?;
+    provide_root_context(AudioOutput(Rc::new((stream, handle.clone()))));
+
+    Ok(handle)
+}
+
+/// Audio player created with [`use_audio`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseAudio {
+    sink: Signal<Option<Arc<Sink>>>,
+    volume: Signal<f32>,
+    looping: Signal<bool>,
+}
+
+impl UseAudio {
+    /// Play the given audio, replacing the one that was playing.
+    pub fn play(&self, source: impl Into<AudioSource>) -> Result<(), AudioError> {
+        let handle = audio_output()?;
+        let sink = Sink::try_new(&handle).map_err(AudioError::Play)?;
+
+        match source.into() {
+            AudioSource::Bytes(bytes) => {
+                let decoder = Decoder::new(Cursor::new(bytes)).map_err(AudioError::Decode)?;
+                self.append(&sink, decoder);
+            }
+            AudioSource::Path(path) => {
+                let file = File::open(path).map_err(AudioError::Io)?;
+                let decoder = Decoder::new(BufReader::new(file)).map_err(AudioError::Decode)?;
+                self.append(&sink, decoder);
+            }
+        }
+
+        sink.set_volume(*self.volume.peek());
+
+        let mut current_sink = self.sink;
+        if let Some(previous) = current_sink.write().replace(Arc::new(sink)) {
+            previous.stop();
+        }
+
+        Ok(())
+    }
+
+    fn append(&self, sink: &Sink, source: impl Source<Item = i16> + Send + 'static) {
+        if *self.looping.peek() {
+            sink.append(source.repeat_infinite());
+        } else {
+            sink.append(source);
+        }
+    }
+
+    /// Pause the audio.
+    pub fn pause(&self) {
+        if let Some(sink) = &*self.sink.peek() {
+            sink.pause();
+        }
+    }
+
+    /// Resume the audio after being paused.
+    pub fn resume(&self) {
+        if let Some(sink) = &*self.sink.peek() {
+            sink.play();
+        }
+    }
+
+    /// Stop the audio, it can't be resumed.
+    pub fn stop(&self) {
+        let mut sink = self.sink;
+        if let Some(sink) = sink.write().take() {
+            sink.stop();
+        }
+    }
+
+    /// Check if the audio is paused.
+    pub fn is_paused(&self) -> bool {
+        self.sink
+            .peek()
+            .as_ref()
+            .map(|sink| sink.is_paused())
+            .unwrap_or(true)
+    }
+
+    /// Set the volume, `1.0` is the original volume of the audio.
+    pub fn set_volume(&self, volume: f32) {
+        let mut current_volume = self.volume;
+        current_volume.set(volume);
+        if let Some(sink) = &*self.sink.peek() {
+            sink.set_volume(volume);
+        }
+    }
+
+    /// Get the volume.
+    pub fn volume(&self) -> f32 {
+        *self.volume.read()
+    }
+
+    /// Repeat the audio indefinitely, it applies to the next played audio.
+    pub fn set_looping(&self, looping: bool) {
+        let mut current_looping = self.looping;
+        current_looping.set(looping);
+    }
+}
+
+/// Play sound effects and music.
+///
+/// All the players share a single output stream, which is opened the first time any of them plays audio.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// static CLICK: &[u8] = &[];
+///
+/// fn app() -> Element {
+///     let audio = use_audio();
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| {
+///                 if let Err(err) = audio.play(CLICK) {
+///                     println!("{err}");
+///                 }
+///             },
+///             label { "Click me" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_audio() -> UseAudio {
+    use_hook(|| UseAudio {
+        sink: Signal::new(None),
+        volume: Signal::new(1.0),
+        looping: Signal::new(false),
+    })
+}
@@ -1,22 +1,31 @@
 use std::sync::{Arc, Mutex};
 
 use crate::use_platform;
+use bytes::Bytes;
 use dioxus_core::{prelude::spawn, use_hook, AttributeValue};
 use dioxus_hooks::use_signal;
-use dioxus_signals::{Signal, Writable};
+use dioxus_signals::{Readable, Signal, Writable};
 use freya_node_state::{CustomAttributeValues, ImageReference};
-pub use nokhwa::utils::{CameraIndex, RequestedFormatType, Resolution};
-use nokhwa::{pixel_format::RgbFormat, utils::RequestedFormat, Camera, NokhwaError};
+pub use nokhwa::utils::{
+    CameraFormat, CameraIndex, CameraInfo, FrameFormat, RequestedFormatType, Resolution,
+};
+use nokhwa::{
+    pixel_format::RgbFormat,
+    query,
+    utils::{ApiBackend, RequestedFormat},
+    Camera, NokhwaError,
+};
 
 /// Configuration for a camera
 pub struct CameraSettings {
     camera_index: CameraIndex,
     resolution: Option<Resolution>,
+    frame_rate: Option<u32>,
     camera_format: RequestedFormatType,
 }
 
 impl CameraSettings {
-    /// Specify a camera index   
+    /// Specify a camera index
     pub fn with_camera_index(mut self, camera_index: CameraIndex) -> Self {
         self.camera_index = camera_index;
         self
@@ -28,6 +37,12 @@ impl CameraSettings {
         self
     }
 
+    /// Specify a frame rate
+    pub fn with_frame_rate(mut self, frame_rate: u32) -> Self {
+        self.frame_rate = Some(frame_rate);
+        self
+    }
+
     /// Specify a camera format
     pub fn with_camera_format(mut self, camera_format: RequestedFormatType) -> Self {
         self.camera_format = camera_format;
@@ -40,66 +55,177 @@ impl Default for CameraSettings {
         Self {
             camera_index: CameraIndex::Index(0),
             resolution: None,
+            frame_rate: None,
             camera_format: RequestedFormatType::AbsoluteHighestFrameRate,
         }
     }
 }
 
+/// Get the cameras available in the system.
+///
+/// Use the index of a [`CameraInfo`] with [`CameraSettings::with_camera_index`] to pick it.
+pub fn list_cameras() -> Result<Vec<CameraInfo>, NokhwaError> {
+    query(ApiBackend::Auto)
+}
+
+/// Connection to a camera, created with [`use_camera_stream`].
+#[derive(Clone)]
+pub struct UseCamera {
+    image_reference: Arc<Mutex<Option<Bytes>>>,
+    error: Signal<Option<NokhwaError>>,
+    format: Signal<Option<CameraFormat>>,
+}
+
+impl PartialEq for UseCamera {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.image_reference, &other.image_reference)
+    }
+}
+
+impl UseCamera {
+    /// Attribute to render the camera frames in an `image` element with `image_reference`.
+    pub fn attribute(&self) -> AttributeValue {
+        AttributeValue::any_value(CustomAttributeValues::ImageReference(ImageReference(
+            self.image_reference.clone(),
+        )))
+    }
+
+    /// Error that stopped the camera, e.g the permission was denied or the device was disconnected.
+    pub fn error(&self) -> Signal<Option<NokhwaError>> {
+        self.error
+    }
+
+    /// Format actually used by the camera once it's open,
+    /// it might differ from the requested resolution or frame rate if not supported.
+    pub fn format(&self) -> Option<CameraFormat> {
+        *self.format.read()
+    }
+
+    /// Shared reference to the last captured frame, it's updated by the camera.
+    pub fn frame_reference(&self) -> ImageReference {
+        ImageReference(self.image_reference.clone())
+    }
+
+    /// Get the last captured frame.
+    pub fn frame(&self) -> Option<Bytes> {
+        self.image_reference.lock().unwrap().clone()
+    }
+}
+
 /// Connect to a given camera and render its frames into an image element
+///
+/// Returns the `image_reference` attribute and the error that stopped the camera, if any.
+#[deprecated(
+    note = "Use `use_camera_stream`, which also reports the format of the camera and gives access to its frames"
+)]
 pub fn use_camera(
     camera_settings: CameraSettings,
 ) -> (AttributeValue, Signal<Option<NokhwaError>>) {
+    let camera = use_camera_stream(camera_settings);
+    (camera.attribute(), camera.error())
+}
+
+/// Connect to a given camera and stream its frames, to render them into an image element or a [`CameraView`](https://docs.freyaui.dev/freya/prelude/fn.CameraView.html).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let camera = use_camera_stream(
+///         CameraSettings::default()
+///             .with_resolution(Resolution::new(1280, 720))
+///             .with_frame_rate(30),
+///     );
+///
+///     rsx!(
+///         if let Some(err) = &*camera.error().read() {
+///             label { "{err}" }
+///         } else {
+///             image {
+///                 width: "100%",
+///                 height: "100%",
+///                 image_reference: camera.attribute()
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_camera_stream(camera_settings: CameraSettings) -> UseCamera {
     let platform = use_platform();
     let mut camera_error = use_signal(|| None);
+    let mut camera_format = use_signal(|| None);
     let image_reference = use_hook(|| Arc::new(Mutex::new(None)));
 
-    let image_reference_attr = AttributeValue::any_value(CustomAttributeValues::ImageReference(
-        ImageReference(image_reference.clone()),
-    ));
-
-    use_hook(move || {
-        spawn(async move {
-            let mut handle_error = |e: NokhwaError| {
-                camera_error.set(Some(e));
-            };
-
-            let requested = RequestedFormat::new::<RgbFormat>(camera_settings.camera_format);
-            let camera = Camera::new(camera_settings.camera_index, requested);
+    use_hook({
+        let image_reference = image_reference.clone();
+        move || {
+            spawn(async move {
+                let mut handle_error = |e: NokhwaError| {
+                    camera_error.set(Some(e));
+                };
+
+                let requested = RequestedFormat::new::<RgbFormat>(camera_settings.camera_format);
+                let mut camera = match Camera::new(camera_settings.camera_index, requested) {
+                    Ok(camera) => camera,
+                    Err(err) => {
+                        handle_error(err);
+                        return;
+                    }
+                };
 
-            if let Ok(mut camera) = camera {
-                // Set the custom resolution if specified
+                // Set the custom resolution and frame rate if specified
                 if let Some(resolution) = camera_settings.resolution {
                     camera
                         .set_resolution(resolution)
                         .unwrap_or_else(&mut handle_error);
                 }
 
+                if let Some(frame_rate) = camera_settings.frame_rate {
+                    camera
+                        .set_frame_rate(frame_rate)
+                        .unwrap_or_else(&mut handle_error);
+                }
+
+                // Fails if the permission to use the camera is denied
+                if let Err(err) = camera.open_stream() {
+                    handle_error(err);
+                    return;
+                }
+
+                camera_format.set(Some(camera.camera_format()));
+
                 let mut ticker = platform.new_ticker();
 
                 loop {
                     // Wait for the event loop to tick
                     ticker.tick().await;
 
-                    // Capture the next frame
-                    let frame = camera.frame();
-
-                    if let Ok(frame) = frame {
-                        let bts = frame.buffer_bytes();
-                        // Send the frame to the renderer via the image reference
-                        image_reference.lock().unwrap().replace(bts);
-
-                        // Request the renderer to rerender
-                        platform.request_animation_frame();
-                    } else if let Err(err) = frame {
-                        handle_error(err);
-                        break;
+                    // Capture the next frame, it fails if the camera was disconnected
+                    match camera.frame() {
+                        Ok(frame) => {
+                            let bts = frame.buffer_bytes();
+                            // Send the frame to the renderer via the image reference
+                            image_reference.lock().unwrap().replace(bts);
+
+                            // Request the renderer to rerender
+                            platform.request_animation_frame();
+                        }
+                        Err(err) => {
+                            handle_error(err);
+                            break;
+                        }
                     }
                 }
-            } else if let Err(err) = camera {
-                handle_error(err);
-            }
-        });
+
+                camera.stop_stream().ok();
+            });
+        }
     });
 
-    (image_reference_attr, camera_error)
+    UseCamera {
+        image_reference,
+        error: camera_error,
+        format: camera_format,
+    }
 }
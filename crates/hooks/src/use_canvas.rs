@@ -5,10 +5,14 @@ use dioxus_hooks::{use_memo_with_dependencies, Dependency};
 use dioxus_signals::{ReadOnlySignal, Readable};
 use freya_node_state::{CanvasReference, CanvasRunner, CustomAttributeValues};
 
+use crate::{use_platform, UsePlatform};
+
 /// Holds a rendering hook callback that allows to render to the Canvas.
 #[derive(PartialEq, Clone)]
 pub struct UseCanvas {
     runner: ReadOnlySignal<UseCanvasRunner>,
+    local: bool,
+    platform: UsePlatform,
 }
 
 #[derive(Clone)]
@@ -24,8 +28,14 @@ impl UseCanvas {
     pub fn attribute(&self) -> AttributeValue {
         AttributeValue::any_value(CustomAttributeValues::Canvas(CanvasReference {
             runner: self.runner.read().0.clone(),
+            local: self.local,
         }))
     }
+
+    /// Draw again in the next frame, e.g after changing data read by the callback without changing the dependencies.
+    pub fn request_repaint(&self) {
+        self.platform.request_animation_frame();
+    }
 }
 
 /// Register a rendering hook to gain access to the Canvas.
@@ -57,6 +67,50 @@ where
     let runner = use_memo_with_dependencies(dependencies, move |dependencies| {
         UseCanvasRunner(Arc::new(renderer_cb(dependencies)))
     });
+    let platform = use_platform();
+
+    UseCanvas {
+        runner,
+        local: false,
+        platform,
+    }
+}
 
-    UseCanvas { runner }
+/// Same as [`use_canvas`] but the callback draws relative to the element:
+/// `(0, 0)` is its top-left corner, the given area starts at the origin and the drawing is clipped to the element bounds.
+///
+/// The callback runs during the paint of every frame, use [`UseCanvas::request_repaint`]
+/// to draw again when the data it reads changes.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use freya_engine::prelude::*;
+/// fn app() -> Element {
+///     let canvas = use_local_canvas((), |_| {
+///         Box::new(|canvas, _, area| {
+///             let mut paint = Paint::default();
+///             paint.set_color(Color::RED);
+///             canvas.draw_circle((area.width() / 2.0, area.height() / 2.0), 20.0, &paint);
+///         })
+///     });
+///
+///     rsx!(
+///         Canvas {
+///             canvas
+///         }
+///     )
+/// }
+/// ```
+pub fn use_local_canvas<D: Dependency>(
+    dependencies: D,
+    renderer_cb: impl Fn(D::Out) -> Box<CanvasRunner> + 'static,
+) -> UseCanvas
+where
+    D::Out: 'static,
+{
+    UseCanvas {
+        local: true,
+        ..use_canvas(dependencies, renderer_cb)
+    }
 }
@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{spawn, use_drop};
+use dioxus_core::{use_hook, Task};
+use dioxus_hooks::{use_effect, use_memo_with_dependencies};
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use tokio::time::{interval_at, Instant};
+
+/// Time the caret stays shown or hidden while blinking.
+pub const CARET_BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Blinking state of a text caret, created with [`use_caret_blink`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseCaretBlink {
+    visible: Signal<bool>,
+    task: CopyValue<Option<Task>>,
+}
+
+impl UseCaretBlink {
+    /// Check if the caret is in the shown phase of the blink. Reading it subscribes the component to its changes.
+    pub fn is_visible(&self) -> bool {
+        *self.visible.read()
+    }
+
+    /// Show the caret and start blinking again, e.g so it doesn't blink while the user is typing.
+    pub fn reset(&self) {
+        if self.task.peek().is_some() {
+            self.stop();
+            self.start();
+        }
+    }
+
+    fn start(&self) {
+        let mut visible = self.visible;
+        let mut task = self.task;
+
+        let new_task = spawn(async move {
+            if !*visible.peek() {
+                visible.set(true);
+            }
+
+            let mut interval =
+                interval_at(Instant::now() + CARET_BLINK_INTERVAL, CARET_BLINK_INTERVAL);
+            loop {
+                interval.tick().await;
+                let is_visible = *visible.peek();
+                visible.set(!is_visible);
+            }
+        });
+
+        task.set(Some(new_task));
+    }
+
+    fn stop(&self) {
+        let mut task = self.task;
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+    }
+}
+
+/// Blink a text caret while the editor is focused, see [`CARET_BLINK_INTERVAL`].
+///
+/// The timer only runs while focused, so unfocused editors don't cause any rerender.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut focus = use_focus();
+///     let caret = use_caret_blink(focus.is_focused());
+///     let cursor_color = if caret.is_visible() { "black" } else { "transparent" };
+///
+///     rsx!(
+///         paragraph {
+///             onclick: move |_| focus.focus(),
+///             cursor_index: "0",
+///             cursor_color,
+///             text {
+///                 "Hello, World!"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_caret_blink(is_focused: bool) -> UseCaretBlink {
+    let caret = use_hook(|| UseCaretBlink {
+        visible: Signal::new(true),
+        task: CopyValue::new(None),
+    });
+
+    let mut focused = use_hook(|| Signal::new(is_focused));
+
+    // Let the effect know when the focus changes
+    let _ = use_memo_with_dependencies(&is_focused, move |is_focused| {
+        if *focused.peek() != is_focused {
+            focused.set(is_focused);
+        }
+    });
+
+    use_effect(move || {
+        let is_blinking = caret.task.peek().is_some();
+        if *focused.read() {
+            if !is_blinking {
+                caret.start();
+            }
+        } else if is_blinking {
+            caret.stop();
+        }
+    });
+
+    use_drop(move || caret.stop());
+
+    caret
+}
@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{spawn, use_drop};
+use dioxus_core::{use_hook, Task};
+use dioxus_signals::{CopyValue, Readable, Writable};
+
+/// Debounced callback, created with [`use_debounce`].
+pub struct UseDebounce<T: 'static> {
+    callback: CopyValue<Box<dyn FnMut(T)>>,
+    task: CopyValue<Option<Task>>,
+    duration: Duration,
+}
+
+impl<T> Clone for UseDebounce<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseDebounce<T> {}
+
+impl<T> PartialEq for UseDebounce<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.callback == other.callback && self.duration == other.duration
+    }
+}
+
+impl<T: 'static> UseDebounce<T> {
+    /// Call the callback with the given value once no other call happens for the debounce duration.
+    ///
+    /// A pending call is replaced by this one.
+    pub fn call(&self, value: T) {
+        let mut callback = self.callback;
+        let mut task = self.task;
+        let duration = self.duration;
+
+        self.cancel();
+
+        let new_task = spawn(async move {
+            tokio::time::sleep(duration).await;
+            task.set(None);
+            (callback.write())(value);
+        });
+
+        task.set(Some(new_task));
+    }
+
+    /// Cancel the pending call, if any.
+    pub fn cancel(&self) {
+        let mut task = self.task;
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+    }
+
+    /// Check if there is a call waiting to happen.
+    pub fn is_pending(&self) -> bool {
+        self.task.read().is_some()
+    }
+}
+
+/// Wrap a callback so it's only called once the calls stop for the given duration,
+/// with the value of the last call, e.g to search as the user types.
+///
+/// The pending call is cancelled when the component is dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut text = use_signal(String::new);
+///     let mut query = use_signal(String::new);
+///     let search = use_debounce(Duration::from_millis(300), move |new_query: String| {
+///         query.set(new_query);
+///     });
+///
+///     rsx!(
+///         Input {
+///             value: text.read().clone(),
+///             onchange: move |new_text: String| {
+///                 text.set(new_text.clone());
+///                 search.call(new_text);
+///             }
+///         }
+///         label {
+///             "Searching: {query}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_debounce<T: 'static>(
+    duration: Duration,
+    callback: impl FnMut(T) + 'static,
+) -> UseDebounce<T> {
+    let mut callback_value = use_hook(|| CopyValue::new(Box::new(|_: T| {}) as Box<dyn FnMut(T)>));
+    let task = use_hook(|| CopyValue::new(None));
+
+    // Always call the latest callback
+    callback_value.set(Box::new(callback));
+
+    let debounce = UseDebounce {
+        callback: callback_value,
+        task,
+        duration,
+    };
+
+    use_drop(move || debounce.cancel());
+
+    debounce
+}
@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus_core::prelude::spawn;
+use dioxus_core::{use_hook, Task};
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+
+type SyncValidator = Rc<dyn Fn(&str) -> Result<(), String>>;
+type AsyncValidator = Rc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>>>>>;
+
+/// Values of the fields of a form, by name.
+pub type FormValues = HashMap<String, String>;
+
+#[derive(Clone, Default)]
+struct FieldValidators {
+    sync: Vec<SyncValidator>,
+    r#async: Vec<AsyncValidator>,
+}
+
+impl FieldValidators {
+    fn validate_sync(&self, value: &str) -> Result<(), String> {
+        self.sync.iter().try_for_each(|validator| validator(value))
+    }
+
+    async fn validate(self, value: String) -> Result<(), String> {
+        self.validate_sync(&value)?;
+        for validator in self.r#async {
+            validator(value.clone()).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Configuration of [`use_form`].
+#[derive(Clone, Default)]
+pub struct FormConfig {
+    fields: Vec<(String, String)>,
+    validators: HashMap<String, FieldValidators>,
+}
+
+impl FormConfig {
+    /// Create a form without fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a field with its initial value.
+    pub fn with_field(mut self, name: impl Into<String>, initial_value: impl Into<String>) -> Self {
+        self.fields.push((name.into(), initial_value.into()));
+        self
+    }
+
+    /// Add a boolean field, e.g for a `Switch`.
+    pub fn with_bool_field(self, name: impl Into<String>, initial_value: bool) -> Self {
+        self.with_field(name, initial_value.to_string())
+    }
+
+    /// Add a validator to a field, returning the error message for invalid values.
+    ///
+    /// The validators of a field run in the order they were added, stopping at the first error.
+    pub fn with_validator(
+        mut self,
+        name: impl Into<String>,
+        validator: impl Fn(&str) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.validators
+            .entry(name.into())
+            .or_default()
+            .sync
+            .push(Rc::new(validator));
+        self
+    }
+
+    /// Add an asynchronous validator to a field, e.g to check a value with a server.
+    ///
+    /// It only runs once the synchronous validators of the field succeed.
+    pub fn with_async_validator<F: Future<Output = Result<(), String>> + 'static>(
+        mut self,
+        name: impl Into<String>,
+        validator: impl Fn(String) -> F + 'static,
+    ) -> Self {
+        self.validators
+            .entry(name.into())
+            .or_default()
+            .r#async
+            .push(Rc::new(
+                move |value| -> Pin<Box<dyn Future<Output = Result<(), String>>>> {
+                    Box::pin(validator(value))
+                },
+            ));
+        self
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct FieldState {
+    initial_value: String,
+    value: String,
+    touched: bool,
+    error: Option<String>,
+    validating: bool,
+}
+
+/// Manage the fields of a form, created with [`use_form`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseForm {
+    fields: Signal<HashMap<String, FieldState>>,
+    validators: CopyValue<HashMap<String, FieldValidators>>,
+    tasks: CopyValue<HashMap<String, Task>>,
+}
+
+impl UseForm {
+    fn field<R>(&self, name: &str, f: impl FnOnce(&FieldState) -> R) -> Option<R> {
+        self.fields.read().get(name).map(f)
+    }
+
+    fn field_validators(&self, name: &str) -> FieldValidators {
+        self.validators
+            .read()
+            .get(name)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn cancel_validation(&self, name: &str) {
+        let mut tasks = self.tasks;
+        if let Some(task) = tasks.write().remove(name) {
+            task.cancel();
+        }
+    }
+
+    /// Get the value of a field, or an empty text if the field doesn't exist.
+    pub fn value(&self, name: &str) -> String {
+        self.field(name, |field| field.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// Get the value of a boolean field.
+    pub fn is_checked(&self, name: &str) -> bool {
+        self.value(name) == "true"
+    }
+
+    /// Get the error of a field. Only the errors of touched fields are returned,
+    /// so the form isn't full of errors before the user interacts with it.
+    pub fn error(&self, name: &str) -> Option<String> {
+        self.field(name, |field| {
+            if field.touched {
+                field.error.clone()
+            } else {
+                None
+            }
+        })
+        .flatten()
+    }
+
+    /// Check if the value of a field was changed by the user.
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.field(name, |field| field.touched).unwrap_or_default()
+    }
+
+    /// Check if the value of a field is different from its initial value.
+    pub fn is_dirty(&self, name: &str) -> bool {
+        self.field(name, |field| field.value != field.initial_value)
+            .unwrap_or_default()
+    }
+
+    /// Check if any field is different from its initial value.
+    pub fn is_any_dirty(&self) -> bool {
+        self.fields
+            .read()
+            .values()
+            .any(|field| field.value != field.initial_value)
+    }
+
+    /// Check if any asynchronous validator is still running.
+    pub fn is_validating(&self) -> bool {
+        self.fields.read().values().any(|field| field.validating)
+    }
+
+    /// Check if all the fields are valid, including the untouched ones,
+    /// and no asynchronous validator is still running.
+    pub fn is_valid(&self) -> bool {
+        self.fields
+            .read()
+            .values()
+            .all(|field| field.error.is_none() && !field.validating)
+    }
+
+    /// Get the values of all the fields.
+    pub fn values(&self) -> FormValues {
+        self.fields
+            .read()
+            .iter()
+            .map(|(name, field)| (name.clone(), field.value.clone()))
+            .collect()
+    }
+
+    /// Change the value of a field, marking it as touched and validating it.
+    pub fn set_value(&self, name: &str, value: impl Into<String>) {
+        let mut fields = self.fields;
+        if let Some(field) = fields.write().get_mut(name) {
+            field.value = value.into();
+            field.touched = true;
+        }
+        self.validate(name);
+    }
+
+    /// Toggle the value of a boolean field.
+    pub fn toggle(&self, name: &str) {
+        let checked = self.is_checked(name);
+        self.set_value(name, (!checked).to_string());
+    }
+
+    /// Create a handler that changes the value of a field, e.g for the `onchange` of an `Input`.
+    pub fn onchange(&self, name: &str) -> impl FnMut(String) + 'static {
+        let form = *self;
+        let name = name.to_string();
+        move |value| form.set_value(&name, value)
+    }
+
+    /// Validate a field. The synchronous validators run immediately, the asynchronous ones in the background.
+    pub fn validate(&self, name: &str) {
+        let mut fields = self.fields;
+        let mut tasks = self.tasks;
+        let validators = self.field_validators(name);
+        let value = self
+            .fields
+            .peek()
+            .get(name)
+            .map(|field| field.value.clone());
+        let Some(value) = value else {
+            return;
+        };
+
+        self.cancel_validation(name);
+
+        let error = validators.validate_sync(&value).err();
+        let validating = error.is_none() && !validators.r#async.is_empty();
+        if let Some(field) = fields.write().get_mut(name) {
+            field.error = error;
+            field.validating = validating;
+        }
+
+        if validating {
+            let task_name = name.to_string();
+            let task = spawn(async move {
+                let error = validators.validate(value).await.err();
+                tasks.write().remove(&task_name);
+                if let Some(field) = fields.write().get_mut(&task_name) {
+                    field.error = error;
+                    field.validating = false;
+                }
+            });
+            tasks.write().insert(name.to_string(), task);
+        }
+    }
+
+    /// Touch and validate all the fields, and call `onsubmit` with their values if they are all valid.
+    ///
+    /// The asynchronous validators are awaited before deciding whether the form is valid.
+    pub fn submit(&self, onsubmit: impl FnOnce(FormValues) + 'static) {
+        let form = *self;
+        let mut fields = self.fields;
+        let names = fields.peek().keys().cloned().collect::<Vec<_>>();
+
+        for name in &names {
+            form.cancel_validation(name);
+        }
+        for field in fields.write().values_mut() {
+            field.touched = true;
+            field.validating = true;
+        }
+
+        spawn(async move {
+            let mut is_valid = true;
+            for name in names {
+                let validators = form.field_validators(&name);
+                let value = form.fields.peek()[&name].value.clone();
+                let error = validators.validate(value).await.err();
+                is_valid &= error.is_none();
+                if let Some(field) = fields.write().get_mut(&name) {
+                    field.error = error;
+                    field.validating = false;
+                }
+            }
+
+            if is_valid {
+                onsubmit(form.values());
+            }
+        });
+    }
+
+    /// Restore the initial values of the fields and clear their touched state.
+    pub fn reset(&self) {
+        let mut fields = self.fields;
+        let names = fields.peek().keys().cloned().collect::<Vec<_>>();
+        for name in &names {
+            self.cancel_validation(name);
+        }
+
+        let validators = self.validators.read();
+        for (name, field) in fields.write().iter_mut() {
+            field.value = field.initial_value.clone();
+            field.touched = false;
+            field.validating = false;
+            field.error = validators
+                .get(name)
+                .and_then(|validators| validators.validate_sync(&field.value).err());
+        }
+    }
+}
+
+/// Manage the values, the touched and dirty states, and the validation of the fields of a form.
+///
+/// Fields are validated when they change and when the form is submitted.
+/// The synchronous validators of all the fields also run when the form is created,
+/// so [`UseForm::is_valid`] is accurate before the user interacts with the form.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let form = use_form(|| {
+///         FormConfig::new()
+///             .with_field("email", "")
+///             .with_validator("email", |email| {
+///                 if email.contains('@') {
+///                     Ok(())
+///                 } else {
+///                     Err("Invalid email".to_string())
+///                 }
+///             })
+///             .with_bool_field("newsletter", false)
+///     });
+///
+///     rsx!(
+///         Input {
+///             value: form.value("email"),
+///             onchange: form.onchange("email"),
+///         }
+///         if let Some(error) = form.error("email") {
+///             label { "{error}" }
+///         }
+///         Switch {
+///             enabled: form.is_checked("newsletter"),
+///             ontoggled: move |_| form.toggle("newsletter"),
+///         }
+///         Button {
+///             onclick: move |_| form.submit(|values| println!("Subscribed {}", values["email"])),
+///             label { "Subscribe" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_form(config: impl FnOnce() -> FormConfig) -> UseForm {
+    use_hook(|| {
+        let FormConfig { fields, validators } = config();
+        let fields = fields
+            .into_iter()
+            .map(|(name, value)| {
+                let error = validators
+                    .get(&name)
+                    .and_then(|validators| validators.validate_sync(&value).err());
+                let field = FieldState {
+                    initial_value: value.clone(),
+                    value,
+                    touched: false,
+                    error,
+                    validating: false,
+                };
+                (name, field)
+            })
+            .collect();
+
+        UseForm {
+            fields: Signal::new(fields),
+            validators: CopyValue::new(validators),
+            tasks: CopyValue::new(HashMap::new()),
+        }
+    })
+}
@@ -0,0 +1,138 @@
+use dioxus_hooks::try_use_context;
+use freya_common::{
+    format_currency, format_currency_with_symbol, format_date, format_datetime, format_number,
+    format_time,
+};
+pub use freya_common::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::UseI18n;
+
+/// Locale used when there is no [`use_init_i18n`](crate::use_init_i18n) in the ancestors.
+const DEFAULT_LOCALE: &str = "en_US";
+
+/// Format numbers, money and dates with the active locale, created with [`use_format`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseFormat {
+    i18n: Option<UseI18n>,
+}
+
+impl UseFormat {
+    /// Get the locale used for formatting. Reading it subscribes the component to its changes.
+    pub fn locale(&self) -> String {
+        self.i18n
+            .map(|i18n| i18n.locale())
+            .unwrap_or_else(|| DEFAULT_LOCALE.to_string())
+    }
+
+    /// Format a number with the given decimals. See [`format_number`].
+    pub fn number(&self, value: f64, decimals: usize) -> String {
+        format_number(value, decimals, &self.locale())
+    }
+
+    /// Format an amount of money in the currency of the locale. See [`format_currency`].
+    pub fn currency(&self, value: f64) -> String {
+        format_currency(value, &self.locale())
+    }
+
+    /// Format an amount of money with the given currency symbol. See [`format_currency_with_symbol`].
+    pub fn currency_with_symbol(&self, value: f64, symbol: &str) -> String {
+        format_currency_with_symbol(value, symbol, &self.locale())
+    }
+
+    /// Format a date. See [`format_date`].
+    pub fn date(&self, date: &NaiveDate) -> String {
+        format_date(date, &self.locale())
+    }
+
+    /// Format a time. See [`format_time`].
+    pub fn time(&self, time: &NaiveTime) -> String {
+        format_time(time, &self.locale())
+    }
+
+    /// Format a date and a time. See [`format_datetime`].
+    pub fn datetime(&self, datetime: &NaiveDateTime) -> String {
+        format_datetime(datetime, &self.locale())
+    }
+}
+
+/// Format numbers, money and dates with the locale of [`use_init_i18n`](crate::use_init_i18n).
+///
+/// The component is rerendered when the locale changes. Without a [`use_init_i18n`](crate::use_init_i18n)
+/// in the ancestors, `en_US` is used. Unknown locales also fall back to `en_US`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn invoice() -> Element {
+///     let format = use_format();
+///     let date = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+///
+///     rsx!(
+///         label { "Total: {format.currency(1234.5)}" }
+///         label { "Items: {format.number(1500.0, 0)}" }
+///         label { "Date: {format.date(&date)}" }
+///     )
+/// }
+/// ```
+pub fn use_format() -> UseFormat {
+    UseFormat {
+        i18n: try_use_context::<UseI18n>(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{use_format, use_init_i18n, I18nConfig};
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[tokio::test]
+    pub async fn format_with_locale() {
+        #[allow(non_snake_case)]
+        fn Prices() -> Element {
+            let format = use_format();
+            let date = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+            rsx!(
+                label { {format.number(1234.5, 1)} }
+                label { {format.currency(1234.5)} }
+                label { {format.date(&date)} }
+            )
+        }
+
+        fn format_app() -> Element {
+            let i18n = use_init_i18n(|| I18nConfig::new("en-US"));
+
+            rsx!(
+                rect {
+                    width: "100%",
+                    height: "50%",
+                    onclick: move |_| i18n.set_locale("de"),
+                }
+                Prices {}
+            )
+        }
+
+        let mut utils = launch_test(format_app);
+        utils.wait_for_update().await;
+
+        let texts = |utils: &TestingHandler| {
+            (1..=3)
+                .map(|i| utils.root().get(i).get(0).text().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(texts(&utils), ["1,234.5", "$1,234.50", "03/09/2024"]);
+
+        // Change the locale
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(texts(&utils), ["1.234,5", "1.234,50 €", "09.03.2024"]);
+    }
+}
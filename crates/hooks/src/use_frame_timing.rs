@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{spawn, use_hook};
+use dioxus_signals::{ReadOnlySignal, Readable, Signal, Writable};
+use tokio::time::Instant;
+
+use crate::use_platform;
+
+/// How often the [`FrameStats`] of [`use_frame_timing`] are updated.
+const FRAME_STATS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Time spent processing a frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrameTiming {
+    /// Time spent measuring the layout, zero if it didn't need to be measured.
+    pub layout: Duration,
+    /// Time spent painting the DOM.
+    pub render: Duration,
+}
+
+impl FrameTiming {
+    /// Total time spent in the frame.
+    pub fn total(&self) -> Duration {
+        self.layout + self.render
+    }
+}
+
+/// Frames rendered during the last second, see [`use_frame_timing`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    /// Rendered frames per second.
+    pub fps: u32,
+    /// Average time spent on every frame.
+    pub average: FrameTiming,
+    /// The frame that took the longest.
+    pub slowest: FrameTiming,
+}
+
+/// Subscribe to the [`FrameStats`] of the app, useful for an in-app FPS counter.
+///
+/// The stats are updated once per second at most, and only while the app is rendering frames.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let stats = use_frame_timing();
+///     let FrameStats { fps, slowest, .. } = *stats.read();
+///
+///     rsx!(
+///         label {
+///             "{fps} FPS, slowest frame took {slowest:?}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_frame_timing() -> ReadOnlySignal<FrameStats> {
+    let platform = use_platform();
+    let mut stats = use_hook(|| Signal::new(FrameStats::default()));
+
+    use_hook(move || {
+        spawn(async move {
+            let mut ticker = platform.new_ticker();
+            let mut since = Instant::now();
+            let mut frames = 0u32;
+            let mut total = FrameTiming::default();
+            let mut slowest = FrameTiming::default();
+
+            loop {
+                ticker.tick().await;
+
+                let frame_timing = platform.info().frame_timing;
+                frames += 1;
+                total.layout += frame_timing.layout;
+                total.render += frame_timing.render;
+                if frame_timing.total() > slowest.total() {
+                    slowest = frame_timing;
+                }
+
+                let elapsed = since.elapsed();
+                if elapsed >= FRAME_STATS_INTERVAL {
+                    stats.set(FrameStats {
+                        fps: (frames as f32 / elapsed.as_secs_f32()).round() as u32,
+                        average: FrameTiming {
+                            layout: total.layout / frames,
+                            render: total.render / frames,
+                        },
+                        slowest,
+                    });
+
+                    since = Instant::now();
+                    frames = 0;
+                    total = FrameTiming::default();
+                    slowest = FrameTiming::default();
+                }
+            }
+        });
+    });
+
+    ReadOnlySignal::new(stats)
+}
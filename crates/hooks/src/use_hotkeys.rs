@@ -0,0 +1,337 @@
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use dioxus_core::prelude::{consume_context, spawn};
+use dioxus_core::use_hook;
+use freya_elements::events::keyboard::{Key, Modifiers};
+use freya_elements::events::KeyboardData;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// A keyboard shortcut, made of a key and the modifiers that must be pressed with it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hotkey {
+    pub key: Key,
+    pub modifiers: Modifiers,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseHotkeyError(pub String);
+
+impl Hotkey {
+    /// Parse a shortcut like `ctrl+shift+s`.
+    ///
+    /// Supported modifiers are `ctrl`, `shift`, `alt`, `meta` (also `cmd` or `super`)
+    /// and `mod`, which is `meta` on macOS (the Cmd key) and `ctrl` everywhere else.
+    pub fn parse(hotkey: &str) -> Result<Self, ParseHotkeyError> {
+        let mut modifiers = Modifiers::empty();
+        let mut key = None;
+
+        for part in hotkey.split('+').map(str::trim) {
+            if part.is_empty() {
+                return Err(ParseHotkeyError(hotkey.to_string()));
+            }
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.insert(Modifiers::CONTROL),
+                "shift" => modifiers.insert(Modifiers::SHIFT),
+                "alt" | "option" => modifiers.insert(Modifiers::ALT),
+                "meta" | "cmd" | "command" | "super" => modifiers.insert(Modifiers::META),
+                "mod" if cfg!(target_os = "macos") => modifiers.insert(Modifiers::META),
+                "mod" => modifiers.insert(Modifiers::CONTROL),
+                _ if key.is_none() => key = Some(parse_key(part)?),
+                _ => return Err(ParseHotkeyError(hotkey.to_string())),
+            }
+        }
+
+        key.map(|key| Hotkey { key, modifiers })
+            .ok_or_else(|| ParseHotkeyError(hotkey.to_string()))
+    }
+
+    /// Check if the given key press triggers this shortcut.
+    pub fn matches(&self, data: &KeyboardData) -> bool {
+        if data.modifiers != self.modifiers {
+            return false;
+        }
+
+        match (&self.key, &data.key) {
+            (Key::Character(a), Key::Character(b)) => a.to_lowercase() == b.to_lowercase(),
+            (a, b) => a == b,
+        }
+    }
+}
+
+fn parse_key(key: &str) -> Result<Key, ParseHotkeyError> {
+    Ok(match key.to_lowercase().as_str() {
+        "space" => Key::Character(" ".to_string()),
+        "enter" | "return" => Key::Enter,
+        "esc" | "escape" => Key::Escape,
+        "tab" => Key::Tab,
+        "backspace" => Key::Backspace,
+        "del" | "delete" => Key::Delete,
+        "up" | "arrowup" => Key::ArrowUp,
+        "down" | "arrowdown" => Key::ArrowDown,
+        "left" | "arrowleft" => Key::ArrowLeft,
+        "right" | "arrowright" => Key::ArrowRight,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        lowercase if lowercase.chars().count() == 1 => Key::Character(lowercase.to_string()),
+        _ => Key::from_str(key).map_err(|_| ParseHotkeyError(key.to_string()))?,
+    })
+}
+
+/// Shares the key presses of the app with the hotkeys registered by [`use_hotkeys`].
+///
+/// The renderer provides it and calls [`HotkeysManager::process`] before emitting a `keydown` event.
+#[derive(Clone)]
+pub struct HotkeysManager {
+    sender: broadcast::Sender<KeyboardData>,
+    prevented: Arc<Mutex<Vec<(usize, Vec<Hotkey>)>>>,
+    counter: Arc<Mutex<usize>>,
+}
+
+impl Default for HotkeysManager {
+    fn default() -> Self {
+        Self {
+            sender: broadcast::channel(16).0,
+            prevented: Arc::default(),
+            counter: Arc::default(),
+        }
+    }
+}
+
+impl HotkeysManager {
+    /// Notify the registered hotkeys about a key press.
+    ///
+    /// Returns `true` if it matched a hotkey that prevents the default behavior,
+    /// in which case the `keydown` event must not be emitted to the elements.
+    pub fn process(&self, data: &KeyboardData) -> bool {
+        self.sender.send(data.clone()).ok();
+
+        self.prevented
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(_, hotkeys)| hotkeys.iter().any(|hotkey| hotkey.matches(data)))
+    }
+
+    fn register(&self, hotkeys: Vec<Hotkey>, prevent_default: bool) -> usize {
+        let mut counter = self.counter.lock().unwrap();
+        *counter += 1;
+        if prevent_default {
+            self.prevented.lock().unwrap().push((*counter, hotkeys));
+        }
+        *counter
+    }
+
+    fn unregister(&self, id: usize) {
+        self.prevented.lock().unwrap().retain(|(i, _)| *i != id);
+    }
+}
+
+/// Configuration for [`use_hotkeys_with_config`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HotkeysConfig {
+    /// Don't emit the `keydown` event to the elements when a hotkey is pressed,
+    /// e.g so a focused input does not receive it.
+    pub prevent_default: bool,
+}
+
+struct HotkeysRegistration {
+    manager: HotkeysManager,
+    id: usize,
+}
+
+impl Drop for HotkeysRegistration {
+    fn drop(&mut self) {
+        self.manager.unregister(self.id);
+    }
+}
+
+/// Parse a list of shortcuts separated by commas, skipping the invalid ones.
+fn parse_hotkeys(hotkeys: &str) -> Vec<Hotkey> {
+    hotkeys
+        .split(',')
+        .filter(|hotkey| !hotkey.trim().is_empty())
+        .filter_map(|hotkey| match Hotkey::parse(hotkey) {
+            Ok(hotkey) => Some(hotkey),
+            Err(_) => {
+                tracing::warn!("Invalid hotkey `{}`, it will be ignored.", hotkey.trim());
+                None
+            }
+        })
+        .collect()
+}
+
+/// Register app-wide keyboard shortcuts. The `handler` is called when any of them is pressed, regardless of what is focused.
+///
+/// Multiple shortcuts can be separated by commas, e.g `"mod+s, ctrl+shift+s"`. See [`Hotkey::parse`] for the syntax.
+/// Invalid shortcuts are skipped with a warning.
+/// Prefer `mod` over `ctrl` for common actions, macOS users expect to use the Cmd key instead of Ctrl.
+///
+/// The hotkeys are unregistered once the component is dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut saved = use_signal(|| false);
+///
+///     use_hotkeys("mod+s", move |_| saved.set(true));
+///
+///     rsx!(
+///         label {
+///             "Saved: {saved}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_hotkeys(hotkeys: &str, handler: impl FnMut(KeyboardData) + 'static) {
+    use_hotkeys_with_config(hotkeys, HotkeysConfig::default(), handler)
+}
+
+/// Same as [`use_hotkeys`] but with a custom [`HotkeysConfig`].
+pub fn use_hotkeys_with_config(
+    hotkeys: &str,
+    config: HotkeysConfig,
+    mut handler: impl FnMut(KeyboardData) + 'static,
+) {
+    use_hook(|| {
+        let manager = consume_context::<HotkeysManager>();
+        let hotkeys = parse_hotkeys(hotkeys);
+
+        let mut receiver = manager.sender.subscribe();
+        let id = manager.register(hotkeys.clone(), config.prevent_default);
+
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(data) => {
+                        if hotkeys.iter().any(|hotkey| hotkey.matches(&data)) {
+                            handler(data);
+                        }
+                    }
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+
+        Arc::new(HotkeysRegistration { manager, id })
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{use_hotkeys, use_hotkeys_with_config, Hotkey, HotkeysConfig};
+
+    use super::parse_hotkeys;
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[test]
+    pub fn parse_hotkey() {
+        assert_eq!(
+            Hotkey::parse("ctrl+shift+s"),
+            Ok(Hotkey {
+                key: Key::Character("s".to_string()),
+                modifiers: Modifiers::CONTROL | Modifiers::SHIFT
+            })
+        );
+        assert_eq!(
+            Hotkey::parse("alt+Enter"),
+            Ok(Hotkey {
+                key: Key::Enter,
+                modifiers: Modifiers::ALT
+            })
+        );
+        assert!(Hotkey::parse("ctrl+a+b").is_err());
+        assert!(Hotkey::parse("ctrl").is_err());
+        assert!(Hotkey::parse("ctrl+").is_err());
+        assert!(Hotkey::parse("").is_err());
+        assert!(Hotkey::parse("ctrl++s").is_err());
+    }
+
+    #[test]
+    pub fn parse_hotkeys_skips_invalid() {
+        let ctrl_s = Hotkey {
+            key: Key::Character("s".to_string()),
+            modifiers: Modifiers::CONTROL,
+        };
+
+        assert_eq!(parse_hotkeys("ctrl+s,"), vec![ctrl_s.clone()]);
+        assert_eq!(parse_hotkeys("ctrl+, ctrl+s"), vec![ctrl_s.clone()]);
+        assert_eq!(parse_hotkeys(", ctrl+s, ,"), vec![ctrl_s]);
+        assert_eq!(parse_hotkeys(""), vec![]);
+        assert_eq!(parse_hotkeys("ctrl+"), vec![]);
+    }
+
+    #[tokio::test]
+    pub async fn hotkeys() {
+        fn hotkeys_app() -> Element {
+            let mut saved = use_signal(|| 0);
+            let mut typed = use_signal(|| 0);
+
+            use_hotkeys("ctrl+s", move |_| saved += 1);
+            use_hotkeys_with_config(
+                "ctrl+p",
+                HotkeysConfig {
+                    prevent_default: true,
+                },
+                move |_| saved += 1,
+            );
+
+            rsx!(
+                rect {
+                    onkeydown: move |_| typed += 1,
+                    label {
+                        "{saved} {typed}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(hotkeys_app);
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("0 0"));
+
+        // The hotkey is triggered and the element still gets the event
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("s".to_string()),
+            code: Code::KeyS,
+            modifiers: Modifiers::CONTROL,
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("1 1"));
+
+        // Without the modifier it's not triggered
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("s".to_string()),
+            code: Code::KeyS,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("1 2"));
+
+        // The element doesn't get the event when preventing the default
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("p".to_string()),
+            code: Code::KeyP,
+            modifiers: Modifiers::CONTROL,
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("2 2"));
+    }
+}
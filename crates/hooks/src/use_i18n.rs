@@ -0,0 +1,428 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use dioxus_hooks::{use_context, use_context_provider};
+use dioxus_signals::{Readable, Signal, Writable};
+
+/// A translated text, with its plural forms if it has any.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Translation {
+    /// The same text for any count.
+    Text(String),
+    /// A text depending on the `count` argument.
+    Plural {
+        /// Used when the count is `0`, `other` is used if missing.
+        zero: Option<String>,
+        /// Used when the count is `1`.
+        one: String,
+        /// Used for any other count.
+        other: String,
+    },
+}
+
+impl Translation {
+    fn text(&self, count: Option<f64>) -> &str {
+        match self {
+            Self::Text(text) => text,
+            Self::Plural { zero, one, other } => match count {
+                Some(count) if count == 0.0 => zero.as_deref().unwrap_or(other),
+                Some(count) if count == 1.0 => one,
+                _ => other,
+            },
+        }
+    }
+}
+
+/// Translations of a locale, from keys to texts.
+///
+/// Texts can have arguments between braces, e.g `"Hello, {name}!"`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Translations {
+    entries: HashMap<String, Translation>,
+}
+
+impl Translations {
+    /// Create empty translations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a text for the given key.
+    pub fn with(mut self, key: impl Into<String>, text: impl Into<String>) -> Self {
+        self.entries
+            .insert(key.into(), Translation::Text(text.into()));
+        self
+    }
+
+    /// Add a text with plural forms for the given key, chosen with the `count` argument.
+    pub fn with_plural(
+        mut self,
+        key: impl Into<String>,
+        one: impl Into<String>,
+        other: impl Into<String>,
+    ) -> Self {
+        self.entries.insert(
+            key.into(),
+            Translation::Plural {
+                zero: None,
+                one: one.into(),
+                other: other.into(),
+            },
+        );
+        self
+    }
+
+    /// Add a [`Translation`] for the given key.
+    pub fn with_translation(mut self, key: impl Into<String>, translation: Translation) -> Self {
+        self.entries.insert(key.into(), translation);
+        self
+    }
+
+    /// Get the [`Translation`] of the given key.
+    pub fn get(&self, key: &str) -> Option<&Translation> {
+        self.entries.get(key)
+    }
+
+    /// Parse the translations from a JSON object.
+    ///
+    /// Nested objects are flattened with dots in their keys, except the ones with
+    /// `one` and `other` texts (and optionally `zero`), which are plural forms.
+    ///
+    /// ```json
+    /// {
+    ///     "greeting": "Hello, {name}!",
+    ///     "settings": {
+    ///         "title": "Settings"
+    ///     },
+    ///     "apples": {
+    ///         "zero": "No apples",
+    ///         "one": "One apple",
+    ///         "other": "{count} apples"
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "i18n_json")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        use serde_json::{Map, Value};
+
+        fn text(value: Option<&Value>) -> Option<String> {
+            value.and_then(Value::as_str).map(str::to_string)
+        }
+
+        fn flatten(translations: &mut Translations, prefix: &str, object: &Map<String, Value>) {
+            for (key, value) in object {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                match value {
+                    Value::String(text) => {
+                        translations
+                            .entries
+                            .insert(key, Translation::Text(text.clone()));
+                    }
+                    Value::Object(object) => {
+                        if let (Some(one), Some(other)) =
+                            (text(object.get("one")), text(object.get("other")))
+                        {
+                            translations.entries.insert(
+                                key,
+                                Translation::Plural {
+                                    zero: text(object.get("zero")),
+                                    one,
+                                    other,
+                                },
+                            );
+                        } else {
+                            flatten(translations, &key, object);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let object = serde_json::from_str::<Map<String, Value>>(json)?;
+        let mut translations = Translations::new();
+        flatten(&mut translations, "", &object);
+        Ok(translations)
+    }
+}
+
+/// Configuration of [`use_init_i18n`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct I18nConfig {
+    locale: String,
+    fallback_locale: Option<String>,
+    locales: HashMap<String, Translations>,
+}
+
+impl I18nConfig {
+    /// Start with the given active locale.
+    pub fn new(locale: impl Into<String>) -> Self {
+        Self {
+            locale: locale.into(),
+            fallback_locale: None,
+            locales: HashMap::default(),
+        }
+    }
+
+    /// Add the [`Translations`] of a locale.
+    pub fn with_locale(mut self, locale: impl Into<String>, translations: Translations) -> Self {
+        self.locales.insert(locale.into(), translations);
+        self
+    }
+
+    /// Locale to use for the keys that are missing in the active locale.
+    pub fn with_fallback(mut self, locale: impl Into<String>) -> Self {
+        self.fallback_locale = Some(locale.into());
+        self
+    }
+}
+
+/// Translate texts with the locales provided by [`use_init_i18n`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseI18n {
+    config: Signal<I18nConfig>,
+}
+
+impl UseI18n {
+    /// Translate the given key, or return the key itself if it is missing.
+    ///
+    /// The arguments replace their names between braces in the text. The `count` argument also chooses the plural form.
+    /// Translating subscribes the component to the locale changes.
+    pub fn t(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let config = self.config.read();
+        let translation = config
+            .locales
+            .get(&config.locale)
+            .and_then(|translations| translations.get(key))
+            .or_else(|| {
+                let fallback = config.fallback_locale.as_ref()?;
+                config.locales.get(fallback)?.get(key)
+            });
+
+        let Some(translation) = translation else {
+            return key.to_string();
+        };
+
+        let count = args
+            .iter()
+            .find(|(name, _)| *name == "count")
+            .and_then(|(_, value)| value.to_string().parse::<f64>().ok());
+
+        interpolate(translation.text(count), args)
+    }
+
+    /// Get the active locale. Reading it subscribes the component to its changes.
+    pub fn locale(&self) -> String {
+        self.config.read().locale.clone()
+    }
+
+    /// Change the active locale, rerendering the components that translate texts.
+    pub fn set_locale(&self, locale: impl Into<String>) {
+        let mut config = self.config;
+        config.write().locale = locale.into();
+    }
+
+    /// Add or replace the [`Translations`] of a locale, e.g after loading them.
+    pub fn add_locale(&self, locale: impl Into<String>, translations: Translations) {
+        let mut config = self.config;
+        config.write().locales.insert(locale.into(), translations);
+    }
+}
+
+/// Replace the `{name}` of the given arguments in a text. Unknown names are left as they are.
+fn interpolate(text: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let name = &after[..end];
+        match args.iter().find(|(arg, _)| *arg == name) {
+            Some((_, value)) => result.push_str(&value.to_string()),
+            None => result.push_str(&rest[start..start + end + 2]),
+        }
+        rest = &after[end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Provide the locales for [`use_i18n`] to the component and its descendants.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let i18n = use_init_i18n(|| {
+///         I18nConfig::new("en")
+///             .with_locale(
+///                 "en",
+///                 Translations::new()
+///                     .with("greeting", "Hello, {name}!")
+///                     .with_plural("unread", "One unread message", "{count} unread messages"),
+///             )
+///             .with_locale(
+///                 "es",
+///                 Translations::new()
+///                     .with("greeting", "¡Hola, {name}!")
+///                     .with_plural("unread", "Un mensaje sin leer", "{count} mensajes sin leer"),
+///             )
+///             .with_fallback("en")
+///     });
+///
+///     rsx!(
+///         label { {i18n.t("greeting", &[("name", &"Freya")])} }
+///         label { {i18n.t("unread", &[("count", &3)])} }
+///         Button {
+///             onclick: move |_| i18n.set_locale("es"),
+///             label { "Español" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_init_i18n(config: impl FnOnce() -> I18nConfig) -> UseI18n {
+    use_context_provider(|| UseI18n {
+        config: Signal::new(config()),
+    })
+}
+
+/// Translate texts with the locales provided by an ancestor with [`use_init_i18n`].
+pub fn use_i18n() -> UseI18n {
+    use_context::<UseI18n>()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{use_i18n, use_init_i18n, I18nConfig, Translations};
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[tokio::test]
+    pub async fn translate() {
+        #[allow(non_snake_case)]
+        fn Greeting() -> Element {
+            let i18n = use_i18n();
+            rsx!(
+                label { {i18n.t("greeting", &[("name", &"Freya")])} }
+                label { {i18n.t("unread", &[("count", &1)])} }
+                label { {i18n.t("unread", &[("count", &5)])} }
+                label { {i18n.t("missing", &[])} }
+                label { {i18n.t("only_english", &[])} }
+            )
+        }
+
+        fn i18n_app() -> Element {
+            let i18n = use_init_i18n(|| {
+                I18nConfig::new("en")
+                    .with_locale(
+                        "en",
+                        Translations::new()
+                            .with("greeting", "Hello, {name}!")
+                            .with("only_english", "English")
+                            .with_plural("unread", "One message", "{count} messages"),
+                    )
+                    .with_locale(
+                        "es",
+                        Translations::new()
+                            .with("greeting", "¡Hola, {name}!")
+                            .with_plural("unread", "Un mensaje", "{count} mensajes"),
+                    )
+                    .with_fallback("en")
+            });
+
+            rsx!(
+                rect {
+                    width: "100%",
+                    height: "50%",
+                    onclick: move |_| i18n.set_locale("es"),
+                }
+                Greeting {}
+            )
+        }
+
+        let mut utils = launch_test(i18n_app);
+        utils.wait_for_update().await;
+
+        let texts = |utils: &TestingHandler| {
+            (1..=5)
+                .map(|i| utils.root().get(i).get(0).text().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            texts(&utils),
+            [
+                "Hello, Freya!",
+                "One message",
+                "5 messages",
+                "missing",
+                "English"
+            ]
+        );
+
+        // Change the locale
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            texts(&utils),
+            [
+                "¡Hola, Freya!",
+                "Un mensaje",
+                "5 mensajes",
+                "missing",
+                "English"
+            ]
+        );
+    }
+
+    #[cfg(feature = "i18n_json")]
+    #[test]
+    pub fn translations_from_json() {
+        use crate::Translation;
+
+        let translations = Translations::from_json(
+            r#"{
+                "settings": { "title": "Settings" },
+                "apples": { "zero": "No apples", "one": "One apple", "other": "{count} apples" }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            translations.get("settings.title"),
+            Some(&Translation::Text("Settings".to_string()))
+        );
+        assert_eq!(
+            translations.get("apples"),
+            Some(&Translation::Plural {
+                zero: Some("No apples".to_string()),
+                one: "One apple".to_string(),
+                other: "{count} apples".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    pub fn interpolate() {
+        let args: &[(&str, &dyn std::fmt::Display)] = &[("name", &"Freya"), ("count", &2)];
+        assert_eq!(
+            super::interpolate("{name} has {count} {unknown} {", args),
+            "Freya has 2 {unknown} {"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{spawn, use_drop};
+use dioxus_core::{use_hook, Task};
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use tokio::time::{interval_at, Instant, MissedTickBehavior};
+
+/// Repeating timer, created with [`use_interval`].
+pub struct UseInterval {
+    callback: CopyValue<Box<dyn FnMut()>>,
+    task: CopyValue<Option<Task>>,
+    paused: Signal<bool>,
+    duration: CopyValue<Duration>,
+}
+
+impl Clone for UseInterval {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for UseInterval {}
+
+impl PartialEq for UseInterval {
+    fn eq(&self, other: &Self) -> bool {
+        self.callback == other.callback && self.duration == other.duration
+    }
+}
+
+impl UseInterval {
+    /// Stop calling the callback until [`UseInterval::resume`] is called.
+    pub fn pause(&self) {
+        let mut paused = self.paused;
+        self.stop();
+        paused.set(true);
+    }
+
+    /// Start calling the callback again, the next call happens after the full duration.
+    pub fn resume(&self) {
+        if self.task.read().is_some() {
+            return;
+        }
+
+        let mut callback = self.callback;
+        let mut task = self.task;
+        let mut paused = self.paused;
+        let duration = *self.duration.peek();
+
+        let new_task = spawn(async move {
+            let mut interval = interval_at(Instant::now() + duration, duration);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                interval.tick().await;
+                (callback.write())();
+            }
+        });
+
+        task.set(Some(new_task));
+
+        if *paused.peek() {
+            paused.set(false);
+        }
+    }
+
+    /// Check if the interval is paused. Reading it subscribes the component to its changes.
+    pub fn is_paused(&self) -> bool {
+        *self.paused.read()
+    }
+
+    fn stop(&self) {
+        let mut task = self.task;
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+    }
+}
+
+/// Call the callback every given duration, starting after the first one passes.
+///
+/// The interval is restarted when the duration changes, unless it's paused, and stopped when the component is dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut seconds = use_signal(|| 0);
+///     let interval = use_interval(Duration::from_secs(1), move || {
+///         seconds += 1;
+///     });
+///
+///     rsx!(
+///         label {
+///             "{seconds}s"
+///         }
+///         Button {
+///             onclick: move |_| {
+///                 if interval.is_paused() {
+///                     interval.resume();
+///                 } else {
+///                     interval.pause();
+///                 }
+///             },
+///             label {
+///                 "Toggle"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_interval(duration: Duration, callback: impl FnMut() + 'static) -> UseInterval {
+    let mut callback_value = use_hook(|| CopyValue::new(Box::new(|| {}) as Box<dyn FnMut()>));
+    let task = use_hook(|| CopyValue::new(None));
+    let paused = use_hook(|| Signal::new(false));
+    let mut current_duration = use_hook(|| CopyValue::new(duration));
+
+    // Always call the latest callback
+    callback_value.set(Box::new(callback));
+
+    let interval = UseInterval {
+        callback: callback_value,
+        task,
+        paused,
+        duration: current_duration,
+    };
+
+    use_hook(move || interval.resume());
+
+    // Restart the timer with the new duration
+    if *current_duration.peek() != duration {
+        current_duration.set(duration);
+        if !*paused.peek() {
+            interval.stop();
+            interval.resume();
+        }
+    }
+
+    use_drop(move || interval.stop());
+
+    interval
+}
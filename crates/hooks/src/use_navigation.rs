@@ -0,0 +1,448 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus_core::prelude::{
+    provide_root_context, spawn_forever, try_consume_context, use_drop, ScopeId,
+};
+use dioxus_core::use_hook;
+use dioxus_router::prelude::{navigator, router, IntoRoutable, Navigator, Routable};
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+
+/// Kind of a [`NavigationRequest`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NavigationKind {
+    /// Add a new entry to the history.
+    Push,
+    /// Replace the current entry of the history.
+    Replace,
+    /// Go to the previous entry of the history.
+    Back,
+    /// Go to the next entry of the history.
+    Forward,
+}
+
+/// A navigation that is about to happen, passed to the navigation guards.
+#[derive(Clone)]
+pub struct NavigationRequest {
+    /// Kind of navigation.
+    pub kind: NavigationKind,
+    /// Target of [`NavigationKind::Push`] and [`NavigationKind::Replace`] navigations.
+    pub to: Option<IntoRoutable>,
+}
+
+/// What a navigation guard decides about a [`NavigationRequest`].
+#[derive(Clone)]
+pub enum NavigationDecision {
+    /// Let the navigation happen, or let the next guard decide.
+    Allow,
+    /// Stay in the current route.
+    Cancel,
+    /// Push this target instead. Guards are not run again for it.
+    Redirect(IntoRoutable),
+}
+
+type NavigationGuard =
+    Box<dyn FnMut(NavigationRequest) -> Pin<Box<dyn Future<Output = NavigationDecision>>>>;
+
+/// Shared so the guards can be run while others are registered, updated or removed.
+type NavigationGuardHandle = Rc<RefCell<NavigationGuard>>;
+
+/// Shared by every [`UseNavigation`] of the app, it lives in the root scope.
+#[derive(Clone, Copy)]
+struct NavigationState {
+    guards: CopyValue<Vec<(usize, NavigationGuardHandle)>>,
+    next_guard_id: CopyValue<usize>,
+    back_len: Signal<usize>,
+    forward_len: Signal<usize>,
+}
+
+impl NavigationState {
+    fn get() -> Self {
+        try_consume_context::<NavigationState>().unwrap_or_else(|| {
+            provide_root_context(NavigationState {
+                guards: CopyValue::new_in_scope(Vec::new(), ScopeId::ROOT),
+                next_guard_id: CopyValue::new_in_scope(0, ScopeId::ROOT),
+                back_len: Signal::new_in_scope(0, ScopeId::ROOT),
+                forward_len: Signal::new_in_scope(0, ScopeId::ROOT),
+            })
+        })
+    }
+
+    /// Run the guards in the order they were registered, until one of them doesn't allow the navigation.
+    ///
+    /// Only the guards registered when the navigation started are run.
+    async fn run_guards(self, request: NavigationRequest) -> NavigationDecision {
+        let guards = self
+            .guards
+            .read()
+            .iter()
+            .map(|(_, guard)| guard.clone())
+            .collect::<Vec<_>>();
+
+        for guard in guards {
+            let decision = (guard.borrow_mut())(request.clone());
+            match decision.await {
+                NavigationDecision::Allow => {}
+                decision => return decision,
+            }
+        }
+
+        NavigationDecision::Allow
+    }
+
+    fn apply(self, navigator: Navigator, kind: NavigationKind, to: Option<IntoRoutable>) {
+        let mut back_len = self.back_len;
+        let mut forward_len = self.forward_len;
+        match (kind, to) {
+            (NavigationKind::Push, Some(to)) => {
+                navigator.push(to);
+                *back_len.write() += 1;
+                forward_len.set(0);
+            }
+            (NavigationKind::Replace, Some(to)) => {
+                navigator.replace(to);
+            }
+            (NavigationKind::Back, _) if *back_len.peek() > 0 => {
+                navigator.go_back();
+                *back_len.write() -= 1;
+                *forward_len.write() += 1;
+            }
+            (NavigationKind::Forward, _) if *forward_len.peek() > 0 => {
+                navigator.go_forward();
+                *back_len.write() += 1;
+                *forward_len.write() -= 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Guarded navigation with history, created with [`use_navigation`].
+#[derive(Clone, Copy)]
+pub struct UseNavigation {
+    state: NavigationState,
+}
+
+impl PartialEq for UseNavigation {
+    fn eq(&self, other: &Self) -> bool {
+        self.state.guards == other.state.guards
+    }
+}
+
+impl UseNavigation {
+    /// Navigate to a new route, adding it to the history.
+    pub fn push(&self, to: impl Into<IntoRoutable>) {
+        self.navigate(NavigationKind::Push, Some(to.into()));
+    }
+
+    /// Navigate to a new route, replacing the current entry of the history.
+    pub fn replace(&self, to: impl Into<IntoRoutable>) {
+        self.navigate(NavigationKind::Replace, Some(to.into()));
+    }
+
+    /// Go back to the previous route of the history, if there is any.
+    pub fn go_back(&self) {
+        self.navigate(NavigationKind::Back, None);
+    }
+
+    /// Go forward to the next route of the history, if there is any.
+    pub fn go_forward(&self) {
+        self.navigate(NavigationKind::Forward, None);
+    }
+
+    /// Check if there is a previous route to go back to. Reading it subscribes the component to its changes.
+    pub fn can_go_back(&self) -> bool {
+        *self.state.back_len.read() > 0
+    }
+
+    /// Check if there is a next route to go forward to. Reading it subscribes the component to its changes.
+    pub fn can_go_forward(&self) -> bool {
+        *self.state.forward_len.read() > 0
+    }
+
+    /// Get how many entries the history has, including the current route.
+    /// Reading it subscribes the component to its changes.
+    pub fn history_len(&self) -> usize {
+        *self.state.back_len.read() + 1 + *self.state.forward_len.read()
+    }
+
+    /// Get the current route, which also holds its params.
+    /// Reading it subscribes the component to its changes.
+    pub fn current_route<R: Routable + Clone>(&self) -> R {
+        router().current::<R>()
+    }
+
+    fn navigate(&self, kind: NavigationKind, to: Option<IntoRoutable>) {
+        let state = self.state;
+        // The navigator is only reachable from components under the Router
+        let navigator = navigator();
+
+        if state.guards.read().is_empty() {
+            state.apply(navigator, kind, to);
+            return;
+        }
+
+        // Keep running the guards even if the component that navigated is dropped
+        spawn_forever(async move {
+            let request = NavigationRequest {
+                kind,
+                to: to.clone(),
+            };
+            match state.run_guards(request).await {
+                NavigationDecision::Allow => state.apply(navigator, kind, to),
+                NavigationDecision::Redirect(to) => {
+                    state.apply(navigator, NavigationKind::Push, Some(to))
+                }
+                NavigationDecision::Cancel => {}
+            }
+        });
+    }
+}
+
+/// Navigate between the routes of a [`Router`](dioxus_router::components::Router), going through the navigation guards
+/// registered with [`use_navigation_guard`].
+///
+/// [`Link`](https://docs.rs/freya-components/latest/freya_components/fn.Link.html) navigates with it as well,
+/// so the guards also apply to it. Navigations done directly with the dioxus-router `navigator()` skip the guards
+/// and are not counted in the history.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// # #[derive(Routable, Clone, PartialEq)]
+/// # #[rustfmt::skip]
+/// # enum Route {
+/// #     #[route("/")]
+/// #     Home,
+/// #     #[route("/user/:id")]
+/// #     User { id: usize },
+/// # }
+/// # #[component]
+/// # fn Home() -> Element { None }
+/// # #[component]
+/// # fn User(id: usize) -> Element { None }
+/// fn toolbar() -> Element {
+///     let navigation = use_navigation();
+///
+///     // Reactive, the component is rerendered when the route changes
+///     let title = match navigation.current_route::<Route>() {
+///         Route::Home => "Home".to_string(),
+///         Route::User { id } => format!("User {id}"),
+///     };
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| navigation.go_back(),
+///             label { "Back" }
+///         }
+///         label { "{title} ({navigation.history_len()} entries)" }
+///         Button {
+///             onclick: move |_| navigation.go_forward(),
+///             label { "Forward" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_navigation() -> UseNavigation {
+    use_hook(|| UseNavigation {
+        state: NavigationState::get(),
+    })
+}
+
+/// Register a guard that decides whether a navigation can happen, while the component is alive.
+///
+/// Guards are async, so they can wait for the user before deciding. They only apply to
+/// navigations done with [`use_navigation`] and to [`Link`](https://docs.rs/freya-components/latest/freya_components/fn.Link.html).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn editor() -> Element {
+///     let mut unsaved_changes = use_signal(|| false);
+///     let mut confirm = use_signal(|| None::<tokio::sync::oneshot::Sender<bool>>);
+///
+///     use_navigation_guard(move |_request| async move {
+///         if !*unsaved_changes.peek() {
+///             return NavigationDecision::Allow;
+///         }
+///         // Ask the user before leaving
+///         let (tx, rx) = tokio::sync::oneshot::channel();
+///         confirm.set(Some(tx));
+///         match rx.await {
+///             Ok(true) => NavigationDecision::Allow,
+///             _ => NavigationDecision::Cancel,
+///         }
+///     });
+///
+///     rsx!(
+///         Input {
+///             value: "",
+///             onchange: move |_| unsaved_changes.set(true),
+///         }
+///         if confirm.read().is_some() {
+///             Button {
+///                 onclick: move |_| {
+///                     if let Some(tx) = confirm.write().take() {
+///                         let _ = tx.send(true);
+///                     }
+///                 },
+///                 label { "Discard changes and leave" }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_navigation_guard<F: Future<Output = NavigationDecision> + 'static>(
+    mut guard: impl FnMut(NavigationRequest) -> F + 'static,
+) {
+    let state = use_hook(NavigationState::get);
+    let guard_id = use_hook(|| {
+        let mut next_guard_id = state.next_guard_id;
+        let guard_id = *next_guard_id.peek();
+        next_guard_id.set(guard_id + 1);
+        let mut guards = state.guards;
+        let guard: NavigationGuard = Box::new(|_| Box::pin(async { NavigationDecision::Allow }));
+        guards
+            .write()
+            .push((guard_id, Rc::new(RefCell::new(guard))));
+        guard_id
+    });
+
+    // Always call the latest guard
+    let guards = state.guards;
+    if let Some((_, current)) = guards.read().iter().find(|(id, _)| *id == guard_id) {
+        *current.borrow_mut() = Box::new(move |request| Box::pin(guard(request)));
+    }
+
+    use_drop(move || {
+        let mut guards = state.guards;
+        guards.write().retain(|(id, _)| *id != guard_id);
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{use_navigation, use_navigation_guard, NavigationDecision, NavigationKind};
+    use dioxus_router::prelude::{IntoRoutable, Outlet, Routable, Router};
+    use freya::prelude::*;
+    use freya_testing::*;
+
+    #[tokio::test]
+    pub async fn navigation_guards() {
+        #[derive(Routable, Clone, PartialEq)]
+        #[rustfmt::skip]
+        enum Route {
+            #[layout(Layout)]
+            #[route("/")]
+            Home,
+            #[route("/locked")]
+            Locked,
+            #[route("/somewhere")]
+            Somewhere,
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Home() -> Element {
+            rsx!(label { "Home" })
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Locked() -> Element {
+            rsx!(label { "Locked" })
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Somewhere() -> Element {
+            rsx!(label { "Somewhere" })
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn Layout() -> Element {
+            let navigation = use_navigation();
+            let mut step = use_signal(|| 0);
+
+            use_navigation_guard(|request| async move {
+                match (request.kind, request.to) {
+                    (NavigationKind::Push, Some(IntoRoutable::FromStr(to))) if to == "/locked" => {
+                        NavigationDecision::Redirect(Route::Somewhere.into())
+                    }
+                    (NavigationKind::Forward, _) => NavigationDecision::Cancel,
+                    _ => NavigationDecision::Allow,
+                }
+            });
+
+            rsx!(
+                rect {
+                    width: "100%",
+                    height: "50%",
+                    onclick: move |_| {
+                        match *step.peek() {
+                            0 => navigation.push("/locked"),
+                            1 => navigation.go_back(),
+                            _ => navigation.go_forward(),
+                        }
+                        *step.write() += 1;
+                    },
+                    label { "{navigation.history_len()}" }
+                }
+                Outlet::<Route> {}
+            )
+        }
+
+        fn navigation_app() -> Element {
+            rsx!(Router::<Route> {})
+        }
+
+        let mut utils = launch_test(navigation_app);
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Home"));
+        assert_eq!(utils.root().get(0).get(0).text(), Some("1"));
+
+        // Pushing the locked route is redirected
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Somewhere"));
+        assert_eq!(utils.root().get(0).get(0).text(), Some("2"));
+
+        // Going back is allowed
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Home"));
+        assert_eq!(utils.root().get(0).get(0).text(), Some("2"));
+
+        // Going forward is cancelled
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Home"));
+    }
+}
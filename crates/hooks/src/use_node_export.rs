@@ -0,0 +1,194 @@
+use std::{
+    io,
+    path::Path,
+    process::Command,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use dioxus_core::{prelude::use_hook, AttributeValue};
+use dioxus_signals::{Readable, Signal};
+use freya_common::{EventMessage, ExportFormat, ExportNode, NodeReferenceLayout, PageOptions};
+use freya_node_state::{CustomAttributeValues, NodeReference};
+use tokio::sync::{oneshot, watch};
+
+use crate::{use_platform, UsePlatform};
+
+/// Export a Node and its descendants to a vector document, see [`use_node_export`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseNodeExport {
+    reference: Signal<Arc<watch::Sender<NodeReferenceLayout>>>,
+    platform: UsePlatform,
+}
+
+impl UseNodeExport {
+    /// Value for the `reference` attribute of the Node to export.
+    pub fn reference(&self) -> AttributeValue {
+        AttributeValue::any_value(CustomAttributeValues::Reference(NodeReference(
+            self.reference.peek().clone(),
+        )))
+    }
+
+    /// Paint the Node and its descendants into a document of the given format, as they were last laid out.
+    ///
+    /// Returns `None` if the Node is not mounted or the document could not be created.
+    pub async fn export(&self, format: ExportFormat) -> Option<Vec<u8>> {
+        export(&self.platform, Some(self.reference.peek().clone()), format).await
+    }
+
+    /// Export the Node and save the document to the given file, see [`UseNodeExport::export`].
+    pub async fn save(&self, path: impl AsRef<Path>, format: ExportFormat) -> io::Result<()> {
+        let document = self
+            .export(format)
+            .await
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Could not export the node"))?;
+        std::fs::write(path, document)
+    }
+
+    /// Print the Node in a page with the given options, directly with the default printer, see [`print_pdf_directly`].
+    pub async fn print(&self, page: PageOptions) -> io::Result<()> {
+        let document = self
+            .export(ExportFormat::PdfPage(page))
+            .await
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Could not export the node"))?;
+        print_pdf_directly(document).await
+    }
+}
+
+/// Export the Node with the given reference, or the whole window if there is none.
+pub(crate) async fn export(
+    platform: &UsePlatform,
+    reference: Option<Arc<watch::Sender<NodeReferenceLayout>>>,
+    format: ExportFormat,
+) -> Option<Vec<u8>> {
+    let (sender, receiver) = oneshot::channel();
+    platform
+        .send(EventMessage::ExportNode(ExportNode {
+            reference,
+            format,
+            sender,
+        }))
+        .ok()?;
+    receiver.await.ok().flatten()
+}
+
+/// Send a PDF document directly to the default printer of the system, without showing a print dialog.
+///
+/// The document is printed with `lp` on Linux and other Unix systems, `lpr` on macOS, and the
+/// default PDF application on Windows. An error of kind [`io::ErrorKind::Unsupported`] is returned
+/// where printing is not available, e.g when the print command is not installed.
+///
+/// The document is written to a temporary file that is removed once the print command finishes.
+pub async fn print_pdf_directly(document: Vec<u8>) -> io::Result<()> {
+    tokio::task::spawn_blocking(move || {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("freya-print-{nanos}.pdf"));
+        std::fs::write(&path, document)?;
+
+        let status = print_command(&path).and_then(|mut command| {
+            command.status().map_err(|err| {
+                if err.kind() == io::ErrorKind::NotFound {
+                    io::Error::new(io::ErrorKind::Unsupported, "No print command available")
+                } else {
+                    err
+                }
+            })
+        });
+        // The print commands copy the document to the queue, so it's no longer needed
+        std::fs::remove_file(&path).ok();
+        let status = status?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("The print command failed with {status}"),
+            ))
+        }
+    })
+    .await?
+}
+
+#[cfg(target_os = "windows")]
+fn print_command(path: &Path) -> io::Result<Command> {
+    let mut command = Command::new("powershell");
+    command
+        .args([
+            "-NoProfile",
+            "-Command",
+            "Start-Process",
+            "-Wait",
+            "-Verb",
+            "Print",
+            "-FilePath",
+        ])
+        .arg(path);
+    Ok(command)
+}
+
+#[cfg(target_os = "macos")]
+fn print_command(path: &Path) -> io::Result<Command> {
+    let mut command = Command::new("lpr");
+    command.arg(path);
+    Ok(command)
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn print_command(path: &Path) -> io::Result<Command> {
+    let mut command = Command::new("lp");
+    command.arg(path);
+    Ok(command)
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn print_command(_path: &Path) -> io::Result<Command> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Printing is not supported in this platform",
+    ))
+}
+
+/// Export a Node, e.g a chart drawn with a `canvas`, to an SVG or PDF document.
+///
+/// Text and shapes are kept as vectors. Raster images are embedded with the resolution they were decoded with,
+/// as base64 encoded PNGs in SVG documents and compressed in PDF documents.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let chart = use_node_export();
+///
+///     let onclick = move |_| {
+///         spawn(async move {
+///             chart.save("chart.pdf", ExportFormat::Pdf).await.ok();
+///         });
+///     };
+///
+///     rsx!(
+///         rect {
+///             reference: chart.reference(),
+///             width: "300",
+///             height: "200",
+///             background: "rgb(0, 119, 182)",
+///         }
+///         Button {
+///             onclick,
+///             label { "Export chart" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_node_export() -> UseNodeExport {
+    let platform = use_platform();
+    use_hook(|| {
+        let (sender, _) = watch::channel(NodeReferenceLayout::default());
+        UseNodeExport {
+            reference: Signal::new(Arc::new(sender)),
+            platform,
+        }
+    })
+}
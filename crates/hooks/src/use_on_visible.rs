@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use dioxus_core::{prelude::spawn, use_hook, AttributeValue};
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use freya_common::NodeReferenceLayout;
+use freya_node_state::{CustomAttributeValues, NodeReference};
+use tokio::sync::watch::channel;
+
+/// Subscribe to the visibility of a Node in the viewport.
+///
+/// The callback is called with `true` when the Node enters the viewport, that is when the visible portion of it
+/// reaches the `threshold` (from `0.0` to `1.0`), and with `false` when it leaves it.
+/// A `threshold` of `0.0` means that a single pixel is enough.
+///
+/// It also returns a signal with the current visible portion of the Node, from `0.0` to `1.0`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut seen = use_signal(|| false);
+///     let (reference, visible_ratio) = use_on_visible(0.5, move |visible| {
+///         if visible {
+///             seen.set(true);
+///         }
+///     });
+///
+///     rsx!(
+///         ScrollView {
+///             rect {
+///                 height: "1000",
+///             }
+///             rect {
+///                 reference,
+///                 height: "100",
+///                 label {
+///                     "Seen: {seen}, visible: {visible_ratio.read() * 100.0}%"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_on_visible(
+    threshold: f32,
+    callback: impl FnMut(bool) + 'static,
+) -> (AttributeValue, Signal<f32>) {
+    let mut callback_value = use_hook(|| CopyValue::new(Box::new(|_| {}) as Box<dyn FnMut(bool)>));
+    let mut threshold_value = use_hook(|| CopyValue::new(threshold));
+
+    // Always use the latest callback and threshold
+    callback_value.set(Box::new(callback));
+    threshold_value.set(threshold.clamp(0.0, 1.0));
+
+    let (tx, visible_ratio) = use_hook(|| {
+        let (tx, mut rx) = channel::<NodeReferenceLayout>(NodeReferenceLayout::default());
+        let mut visible_ratio = Signal::new(0.0);
+
+        spawn(async move {
+            let mut is_visible = false;
+            while rx.changed().await.is_ok() {
+                let ratio = rx.borrow().visible_ratio;
+                if *visible_ratio.peek() != ratio {
+                    visible_ratio.set(ratio);
+                }
+
+                let threshold = *threshold_value.peek();
+                let now_visible = ratio > 0.0 && ratio >= threshold;
+                if now_visible != is_visible {
+                    is_visible = now_visible;
+                    (callback_value.write())(is_visible);
+                }
+            }
+        });
+
+        (Arc::new(tx), visible_ratio)
+    });
+
+    (
+        AttributeValue::any_value(CustomAttributeValues::Reference(NodeReference(tx))),
+        visible_ratio,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::use_on_visible;
+    use freya::prelude::*;
+    use freya_testing::{launch_test_with_config, TestingConfig};
+
+    #[tokio::test]
+    pub async fn track_visibility() {
+        fn use_on_visible_app() -> Element {
+            let mut half_visible = use_signal(|| false);
+            let mut fully_visible = use_signal(|| false);
+            let (reference, visible_ratio) = use_on_visible(0.5, move |visible| {
+                half_visible.set(visible);
+            });
+            let (fully_reference, _) = use_on_visible(1.0, move |visible| {
+                fully_visible.set(visible);
+            });
+
+            rsx!(
+                rect {
+                    height: "750",
+                }
+                rect {
+                    reference,
+                    height: "100",
+                    width: "100%",
+                    rect {
+                        reference: fully_reference,
+                        height: "100%",
+                        width: "100%",
+                        label {
+                            "{visible_ratio} {half_visible} {fully_visible}"
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test_with_config(
+            use_on_visible_app,
+            TestingConfig {
+                size: (500.0, 800.0).into(),
+                ..TestingConfig::default()
+            },
+        );
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        let label = utils.root().get(1).get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("0.5 true false"));
+
+        utils.config().size = (500.0, 1000.0).into();
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        let label = utils.root().get(1).get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("1 true true"));
+    }
+}
@@ -0,0 +1,231 @@
+use std::cell::RefCell;
+use std::fs;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus_core::prelude::{spawn, try_consume_context, use_drop};
+use dioxus_core::{use_hook, Task};
+use dioxus_hooks::use_effect;
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Time to wait after the last change before writing a persistent value to disk.
+const SAVE_DELAY: Duration = Duration::from_millis(300);
+
+/// Directory where [`use_persistent`] stores the values.
+///
+/// Defaults to a folder named after the app executable inside the platform config directory,
+/// provide it as a context to use a different one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PersistentStorage {
+    dir: PathBuf,
+}
+
+impl Default for PersistentStorage {
+    fn default() -> Self {
+        let app_name = std::env::current_exe()
+            .ok()
+            .and_then(|exe| Some(exe.file_stem()?.to_string_lossy().to_string()))
+            .unwrap_or_else(|| "freya".to_string());
+
+        let base_dir = dirs::config_dir().unwrap_or_else(std::env::temp_dir);
+
+        Self::new(base_dir.join(app_name))
+    }
+}
+
+impl PersistentStorage {
+    /// Store the values in the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Get the file of the value with the given key.
+    ///
+    /// The characters of the key that are not letters, digits, `-` or `_` are percent-encoded,
+    /// so keys like `../settings` or `a/b` stay inside the directory.
+    pub fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", encode_key(key)))
+    }
+
+    /// Read the value with the given key, if it exists and can be parsed.
+    pub fn load<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let content = fs::read_to_string(self.path(key)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Write the value with the given key.
+    pub fn save<T: Serialize>(&self, key: &str, value: &T) -> std::io::Result<()> {
+        let content = serde_json::to_string_pretty(value)?;
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(key), content)
+    }
+}
+
+/// Encode the key as a file name.
+fn encode_key(key: &str) -> String {
+    let mut file_name = String::with_capacity(key.len());
+    for byte in key.bytes() {
+        if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' {
+            file_name.push(byte as char);
+        } else {
+            file_name.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    file_name
+}
+
+/// Write the value, logging the errors since there is no one to report them to.
+fn save_value<T: Serialize>(storage: &PersistentStorage, key: &str, value: &T) {
+    if let Err(err) = storage.save(key, value) {
+        tracing::warn!(
+            "Could not save the persistent value `{key}` in {}: {err}",
+            storage.path(key).display()
+        );
+    }
+}
+
+/// Create a signal whose value is persisted to disk as JSON under the given key,
+/// e.g for settings or the last opened file.
+///
+/// The value is loaded on the first render, falling back to the given default if it doesn't exist
+/// or can't be parsed, and saved shortly after it changes.
+/// See [`PersistentStorage`] for where it's stored.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut dark_mode = use_persistent("dark_mode", || false);
+///
+///     rsx!(
+///         Switch {
+///             enabled: *dark_mode.read(),
+///             ontoggled: move |_| dark_mode.toggle()
+///         }
+///     )
+/// }
+/// ```
+pub fn use_persistent<T>(key: impl ToString, default: impl FnOnce() -> T) -> Signal<T>
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+{
+    let (storage, key) = use_hook(|| {
+        let storage = try_consume_context::<PersistentStorage>().unwrap_or_default();
+        (storage, key.to_string())
+    });
+    let value = use_hook(|| Signal::new(storage.load(&key).unwrap_or_else(default)));
+    let mut task = use_hook(|| CopyValue::new(None::<Task>));
+    let mut is_loaded = use_hook(|| CopyValue::new(false));
+    // Changes not written yet, saved on drop if the component is dropped before the delay
+    let unsaved = use_hook(|| Rc::new(RefCell::new(None::<T>)));
+
+    use_effect({
+        let storage = storage.clone();
+        let key = key.clone();
+        let unsaved = unsaved.clone();
+        move || {
+            let new_value = value.read().clone();
+
+            // The loaded value doesn't need to be saved
+            if !*is_loaded.peek() {
+                is_loaded.set(true);
+                return;
+            }
+
+            *unsaved.borrow_mut() = Some(new_value);
+
+            if let Some(task) = task.write().take() {
+                task.cancel();
+            }
+
+            let storage = storage.clone();
+            let key = key.clone();
+            let unsaved = unsaved.clone();
+            let new_task = spawn(async move {
+                tokio::time::sleep(SAVE_DELAY).await;
+                task.set(None);
+                if let Some(value) = unsaved.borrow_mut().take() {
+                    save_value(&storage, &key, &value);
+                }
+            });
+            task.set(Some(new_task));
+        }
+    });
+
+    use_drop(move || {
+        if let Some(value) = unsaved.borrow_mut().take() {
+            save_value(&storage, &key, &value);
+        }
+    });
+
+    value
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::PersistentStorage;
+
+    fn storage(name: &str) -> PersistentStorage {
+        let dir =
+            std::env::temp_dir().join(format!("freya-persistent-{name}-{}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        PersistentStorage::new(dir)
+    }
+
+    #[test]
+    pub fn round_trip() {
+        let storage = storage("round-trip");
+
+        storage.save("settings", &vec![1, 2, 3]).unwrap();
+        assert_eq!(storage.load::<Vec<i32>>("settings"), Some(vec![1, 2, 3]));
+
+        storage.save("settings", &vec![4]).unwrap();
+        assert_eq!(storage.load::<Vec<i32>>("settings"), Some(vec![4]));
+    }
+
+    #[test]
+    pub fn missing_file() {
+        let storage = storage("missing");
+
+        assert_eq!(storage.load::<bool>("dark_mode"), None);
+    }
+
+    #[test]
+    pub fn corrupt_file() {
+        let storage = storage("corrupt");
+
+        storage.save("dark_mode", &true).unwrap();
+        fs::write(storage.path("dark_mode"), "{ not json").unwrap();
+        assert_eq!(storage.load::<bool>("dark_mode"), None);
+
+        // A value of another type can't be loaded either
+        storage.save("dark_mode", &"yes").unwrap();
+        assert_eq!(storage.load::<bool>("dark_mode"), None);
+    }
+
+    #[test]
+    pub fn keys_stay_in_the_directory() {
+        let storage = storage("keys");
+        let dir = storage.path("key").parent().unwrap().to_path_buf();
+
+        for key in ["../escaped", "a/b", "..", "C:\\settings", "dark mode"] {
+            let path = storage.path(key);
+            assert_eq!(path.parent(), Some(dir.as_path()), "{key}");
+
+            storage.save(key, &key).unwrap();
+            assert_eq!(storage.load::<String>(key).as_deref(), Some(key));
+        }
+
+        assert_eq!(storage.path("dark_mode-2"), dir.join("dark_mode-2.json"));
+        assert_eq!(
+            storage.path("../escaped"),
+            dir.join("%2E%2E%2Fescaped.json")
+        );
+        assert!(!dir.parent().unwrap().join("escaped.json").exists());
+    }
+}
@@ -1,11 +1,21 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    io,
+    path::Path,
+    sync::{Arc, Mutex},
+};
 
-use dioxus_core::prelude::{consume_context, try_consume_context, use_hook};
-use dioxus_signals::{Readable, Signal};
-use freya_common::EventMessage;
-use tokio::sync::{broadcast, mpsc::UnboundedSender};
+use dioxus_core::prelude::{consume_context, spawn, try_consume_context, use_hook};
+use dioxus_signals::{ReadOnlySignal, Readable, Signal, Writable};
+use freya_common::{CustomCursor, EventMessage, ExportFormat, NewWindow, PageOptions};
+use tokio::sync::{broadcast, mpsc::UnboundedSender, oneshot};
 use torin::geometry::Size2D;
-use winit::{dpi::PhysicalSize, event_loop::EventLoopProxy, window::CursorIcon};
+use winit::{
+    dpi::PhysicalSize,
+    event_loop::EventLoopProxy,
+    window::{CursorIcon, WindowId},
+};
+
+use crate::{export, print_pdf_directly, FrameTiming};
 
 #[derive(Clone, Copy, PartialEq)]
 pub struct UsePlatform {
@@ -13,6 +23,7 @@ pub struct UsePlatform {
     event_loop_proxy: Signal<Option<EventLoopProxy<EventMessage>>>,
     platform_emitter: Signal<Option<UnboundedSender<EventMessage>>>,
     platform_information: Signal<Arc<Mutex<PlatformInformation>>>,
+    window_id: Signal<Option<WindowId>>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -29,11 +40,17 @@ impl UsePlatform {
             platform_emitter: Signal::new(try_consume_context::<UnboundedSender<EventMessage>>()),
             ticker: Signal::new(consume_context::<Arc<broadcast::Receiver<()>>>()),
             platform_information: Signal::new(consume_context::<Arc<Mutex<PlatformInformation>>>()),
+            window_id: Signal::new(try_consume_context::<WindowId>()),
         }
     }
 
     pub fn send(&self, event: EventMessage) -> Result<(), UsePlatformError> {
         if let Some(event_loop_proxy) = &*self.event_loop_proxy.peek() {
+            // Let the event loop know what window sent the message
+            let event = match *self.window_id.peek() {
+                Some(window_id) => EventMessage::WindowMessage(window_id, Box::new(event)),
+                None => event,
+            };
             event_loop_proxy
                 .send_event(event)
                 .map_err(|_| UsePlatformError::EventLoopProxyFailed)?;
@@ -49,6 +66,125 @@ impl UsePlatform {
         self.send(EventMessage::SetCursorIcon(cursor_icon)).ok();
     }
 
+    /// Use an image as cursor, with the given hotspot.
+    ///
+    /// The fallback icon of the [`CustomCursor`] is used if the image can't be decoded.
+    pub fn set_custom_cursor(&self, cursor: CustomCursor) {
+        self.send(EventMessage::SetCustomCursor(Some(cursor))).ok();
+    }
+
+    /// Go back to the system cursor after having used [`UsePlatform::set_custom_cursor`].
+    pub fn remove_custom_cursor(&self) {
+        self.send(EventMessage::SetCustomCursor(None)).ok();
+    }
+
+    /// Stop sending the pointer events to the element that captured the pointer with the `pointer_capture` attribute,
+    /// before the mouse is released.
+    pub fn release_pointer_capture(&self) {
+        self.send(EventMessage::ReleasePointerCapture).ok();
+    }
+
+    /// Open a new window with its own root component.
+    ///
+    /// ```rust,no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let platform = use_platform();
+    ///
+    ///     rsx!(
+    ///         Button {
+    ///             onclick: move |_| platform.new_window(NewWindow::new(settings).with_title("Settings")),
+    ///             label { "Open settings" }
+    ///         }
+    ///     )
+    /// }
+    ///
+    /// fn settings() -> Element {
+    ///     rsx!(label { "Settings" })
+    /// }
+    /// ```
+    pub fn new_window(&self, window: NewWindow) {
+        self.send(EventMessage::NewWindow(window)).ok();
+    }
+
+    /// Capture the last frame painted in the window, encoded as a PNG.
+    ///
+    /// Returns `None` if the frame could not be captured.
+    ///
+    /// ```rust,no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let platform = use_platform();
+    ///
+    ///     let onclick = move |_| {
+    ///         spawn(async move {
+    ///             if let Err(err) = platform.save_screenshot("chart.png").await {
+    ///                 println!("Could not export the chart: {err}");
+    ///             }
+    ///         });
+    ///     };
+    ///
+    ///     rsx!(
+    ///         Button {
+    ///             onclick,
+    ///             label { "Export chart" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    pub async fn screenshot(&self) -> Option<Vec<u8>> {
+        let (sender, receiver) = oneshot::channel();
+        self.send(EventMessage::Screenshot(sender)).ok()?;
+        receiver.await.ok().flatten()
+    }
+
+    /// Capture the last frame painted in the window and save it as a PNG file, see [`UsePlatform::screenshot`].
+    pub async fn save_screenshot(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let image = self
+            .screenshot()
+            .await
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Could not capture the window"))?;
+        std::fs::write(path, image)
+    }
+
+    /// Export the whole window to a vector document, see `use_node_export` to only export a Node.
+    pub async fn export(&self, format: ExportFormat) -> Option<Vec<u8>> {
+        export(self, None, format).await
+    }
+
+    /// Print the whole window in a page with the given options, directly with the default printer,
+    /// see [`print_pdf_directly`](crate::print_pdf_directly).
+    ///
+    /// ```rust,no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let platform = use_platform();
+    ///
+    ///     let onclick = move |_| {
+    ///         spawn(async move {
+    ///             let page = PageOptions::default().with_size(PageSize::Letter);
+    ///             if let Err(err) = platform.print(page).await {
+    ///                 println!("Could not print: {err}");
+    ///             }
+    ///         });
+    ///     };
+    ///
+    ///     rsx!(
+    ///         Button {
+    ///             onclick,
+    ///             label { "Print" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    pub async fn print(&self, page: PageOptions) -> io::Result<()> {
+        let document = self
+            .export(ExportFormat::PdfPage(page))
+            .await
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Could not export the window"))?;
+        print_pdf_directly(document).await
+    }
+
     pub fn request_animation_frame(&self) {
         self.send(EventMessage::RequestRerender).ok();
     }
@@ -72,6 +208,31 @@ pub fn use_platform() -> UsePlatform {
     use_hook(UsePlatform::new)
 }
 
+/// Subscribe to a value derived from the [`PlatformInformation`], it's updated after every render if it changed.
+pub(crate) fn use_platform_information<T: PartialEq + 'static>(
+    map: fn(PlatformInformation) -> T,
+) -> ReadOnlySignal<T> {
+    let platform = use_platform();
+    let mut value = use_hook(|| Signal::new(map(platform.info())));
+
+    use_hook(move || {
+        spawn(async move {
+            let mut ticker = platform.new_ticker();
+
+            loop {
+                ticker.tick().await;
+
+                let new_value = map(platform.info());
+                if *value.peek() != new_value {
+                    value.set(new_value);
+                }
+            }
+        });
+    });
+
+    ReadOnlySignal::new(value)
+}
+
 pub struct Ticker {
     inner: broadcast::Receiver<()>,
 }
@@ -85,17 +246,28 @@ impl Ticker {
 /// Information about the platform.
 #[derive(Clone)]
 pub struct PlatformInformation {
+    /// Physical size of the window.
     pub window_size: Size2D,
+    /// Scale factor of the monitor where the window is.
+    pub scale_factor: f64,
+    /// Time spent processing the last frame.
+    pub frame_timing: FrameTiming,
 }
 
 impl PlatformInformation {
-    pub fn from_winit(physical_size: PhysicalSize<u32>) -> Self {
+    pub fn from_winit(physical_size: PhysicalSize<u32>, scale_factor: f64) -> Self {
         Self {
             window_size: Size2D::new(physical_size.width as f32, physical_size.height as f32),
+            scale_factor,
+            frame_timing: FrameTiming::default(),
         }
     }
 
-    pub fn new(window_size: Size2D) -> Self {
-        Self { window_size }
+    pub fn new(window_size: Size2D, scale_factor: f64) -> Self {
+        Self {
+            window_size,
+            scale_factor,
+            frame_timing: FrameTiming::default(),
+        }
     }
 }
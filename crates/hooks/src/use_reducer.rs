@@ -0,0 +1,98 @@
+use dioxus_core::use_hook;
+use dioxus_signals::{ReadOnlySignal, Readable, Signal, Writable};
+
+/// State managed by a reducer, created with [`use_reducer`].
+pub struct UseReducer<S: 'static, A: 'static> {
+    state: Signal<S>,
+    reducer: fn(S, A) -> S,
+}
+
+impl<S, A> Clone for UseReducer<S, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, A> Copy for UseReducer<S, A> {}
+
+impl<S, A> PartialEq for UseReducer<S, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<S: Clone + 'static, A: 'static> UseReducer<S, A> {
+    /// Get the current state. Reading it subscribes the component to its changes.
+    pub fn state(&self) -> S {
+        self.state.read().clone()
+    }
+
+    /// Get the state as a read-only signal, e.g to pass it to other components.
+    pub fn signal(&self) -> ReadOnlySignal<S> {
+        self.state.into()
+    }
+
+    /// Run the reducer with the current state and the given action, replacing the state with its result.
+    pub fn dispatch(&self, action: A) {
+        let mut state = self.state;
+        let current = state.peek().clone();
+        state.set((self.reducer)(current, action));
+    }
+}
+
+/// Manage state with a reducer that returns the next state for every dispatched action,
+/// e.g for components with many actions that update the same state.
+///
+/// The components reading the state are rerendered after each dispatch.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// #[derive(Clone, Default)]
+/// struct Counter {
+///     count: i32,
+/// }
+///
+/// enum CounterAction {
+///     Increment,
+///     Decrement,
+///     Reset,
+/// }
+///
+/// fn reduce(state: Counter, action: CounterAction) -> Counter {
+///     match action {
+///         CounterAction::Increment => Counter { count: state.count + 1 },
+///         CounterAction::Decrement => Counter { count: state.count - 1 },
+///         CounterAction::Reset => Counter::default(),
+///     }
+/// }
+///
+/// fn app() -> Element {
+///     let counter = use_reducer(Counter::default, reduce);
+///
+///     rsx!(
+///         label { "{counter.state().count}" }
+///         Button {
+///             onclick: move |_| counter.dispatch(CounterAction::Increment),
+///             label { "+" }
+///         }
+///         Button {
+///             onclick: move |_| counter.dispatch(CounterAction::Decrement),
+///             label { "-" }
+///         }
+///         Button {
+///             onclick: move |_| counter.dispatch(CounterAction::Reset),
+///             label { "Reset" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_reducer<S: Clone + 'static, A: 'static>(
+    initial_state: impl FnOnce() -> S,
+    reducer: fn(S, A) -> S,
+) -> UseReducer<S, A> {
+    let state = use_hook(|| Signal::new(initial_state()));
+
+    UseReducer { state, reducer }
+}
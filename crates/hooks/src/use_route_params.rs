@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use dioxus_router::prelude::{router, Routable};
+
+/// Params of a route, created with [`use_route_params`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RouteParams {
+    matches: bool,
+    segments: HashMap<String, String>,
+    query: HashMap<String, String>,
+}
+
+impl RouteParams {
+    /// Parse the params of `route` (e.g `/user/7?tab=posts`) following the given `pattern` (e.g `/user/:id`).
+    ///
+    /// Dynamic segments start with `:`. Query params are always parsed, even if the path doesn't match the pattern.
+    pub fn parse(pattern: &str, route: &str) -> Self {
+        let (path, query) = route.split_once('?').unwrap_or((route, ""));
+
+        let query = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (decode(key), decode(value))
+            })
+            .collect();
+
+        let pattern_segments = pattern.split('/').filter(|s| !s.is_empty());
+        let mut path_segments = path.split('/').filter(|s| !s.is_empty());
+        let mut segments = HashMap::new();
+        let mut matches = true;
+
+        for pattern_segment in pattern_segments {
+            let Some(path_segment) = path_segments.next() else {
+                matches = false;
+                break;
+            };
+            if let Some(name) = pattern_segment.strip_prefix(':') {
+                segments.insert(name.to_string(), decode(path_segment));
+            } else if pattern_segment != path_segment {
+                matches = false;
+                break;
+            }
+        }
+
+        if !matches || path_segments.next().is_some() {
+            return Self {
+                matches: false,
+                segments: HashMap::new(),
+                query,
+            };
+        }
+
+        Self {
+            matches: true,
+            segments,
+            query,
+        }
+    }
+
+    /// Check if the route matched the pattern.
+    pub fn matches(&self) -> bool {
+        self.matches
+    }
+
+    /// Get a dynamic segment, or `None` if it's missing or can't be parsed into `T`.
+    pub fn segment<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.segments.get(name)?.parse().ok()
+    }
+
+    /// Get a query param, or `None` if it's missing or can't be parsed into `T`.
+    pub fn query<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.query.get(name)?.parse().ok()
+    }
+
+    /// Get all the dynamic segments, not parsed.
+    pub fn segments(&self) -> &HashMap<String, String> {
+        &self.segments
+    }
+
+    /// Get all the query params, not parsed.
+    pub fn query_params(&self) -> &HashMap<String, String> {
+        &self.query
+    }
+}
+
+/// Decode `+` and `%XX` escapes, leaving invalid escapes as they are.
+fn decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let hex = text
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                if let Some(byte) = hex {
+                    decoded.push(byte);
+                    i += 2;
+                } else {
+                    decoded.push(b'%');
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Read the params of the current route of a [`Router`](dioxus_router::components::Router), following the given `pattern`.
+///
+/// The pattern uses the same syntax as `#[route("/user/:id")]`. The component is rerendered when the route changes.
+/// Missing or invalid params are returned as `None`, so they can fall back to a default value.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// # #[derive(Routable, Clone, PartialEq)]
+/// # #[rustfmt::skip]
+/// # enum Route {
+/// #     #[route("/user/:id?:query")]
+/// #     User { id: String, query: String },
+/// # }
+/// # #[component]
+/// # fn User(id: String, query: String) -> Element { None }
+/// fn user_posts() -> Element {
+///     // For `/user/7?page=2`
+///     let params = use_route_params::<Route>("/user/:id");
+///     let id = params.segment::<usize>("id");
+///     let page = params.query::<usize>("page").unwrap_or(1);
+///
+///     rsx!(
+///         if let Some(id) = id {
+///             label { "Posts of user {id}, page {page}" }
+///         } else {
+///             label { "Unknown user" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_route_params<R: Routable + Clone>(pattern: &str) -> RouteParams {
+    let route = router().current::<R>();
+    RouteParams::parse(pattern, &route.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::RouteParams;
+
+    #[test]
+    pub fn route_params() {
+        let params = RouteParams::parse("/user/:id/posts", "/user/7/posts?page=2&sort=new+first");
+        assert!(params.matches());
+        assert_eq!(params.segment::<usize>("id"), Some(7));
+        assert_eq!(params.query::<usize>("page"), Some(2));
+        assert_eq!(params.query::<String>("sort").as_deref(), Some("new first"));
+
+        // Missing and invalid params
+        assert_eq!(params.segment::<usize>("name"), None);
+        assert_eq!(params.query::<bool>("page"), None);
+
+        // Escaped segments
+        let params = RouteParams::parse("/search/:text", "/search/hello%20world");
+        assert_eq!(
+            params.segment::<String>("text").as_deref(),
+            Some("hello world")
+        );
+
+        // Not matching routes
+        let params = RouteParams::parse("/user/:id", "/settings?page=3");
+        assert!(!params.matches());
+        assert_eq!(params.segment::<usize>("id"), None);
+        assert_eq!(params.query::<usize>("page"), Some(3));
+
+        let params = RouteParams::parse("/user/:id", "/user/7/posts");
+        assert!(!params.matches());
+    }
+}
@@ -0,0 +1,52 @@
+use dioxus_signals::ReadOnlySignal;
+use torin::geometry::Size2D;
+
+use crate::{use_platform_information, PlatformInformation};
+
+/// Scale factor and size of the window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scale {
+    /// Scale factor of the monitor where the window is.
+    pub factor: f64,
+    /// Size of the window in physical pixels.
+    pub physical_size: Size2D,
+    /// Size of the window in logical pixels, the physical size divided by the scale factor.
+    pub logical_size: Size2D,
+}
+
+impl From<PlatformInformation> for Scale {
+    fn from(info: PlatformInformation) -> Self {
+        let factor = info.scale_factor;
+        Self {
+            factor,
+            physical_size: info.window_size,
+            logical_size: Size2D::new(
+                info.window_size.width / factor as f32,
+                info.window_size.height / factor as f32,
+            ),
+        }
+    }
+}
+
+/// Get the [`Scale`] of the window, useful for pixel-perfect custom drawing.
+///
+/// It's updated when the window is resized or moved to a monitor with a different scale factor.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let scale = use_scale();
+///     let factor = scale.read().factor;
+///
+///     rsx!(
+///         label {
+///             "Scale factor: {factor}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_scale() -> ReadOnlySignal<Scale> {
+    use_platform_information(Scale::from)
+}
@@ -0,0 +1,118 @@
+use dioxus_hooks::{use_context, use_context_provider, use_memo};
+use dioxus_signals::{ReadOnlySignal, Readable, Signal, Writable};
+
+/// App-wide state, created with [`use_init_store`].
+pub struct Store<T: 'static> {
+    state: Signal<T>,
+}
+
+impl<T> Clone for Store<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Store<T> {}
+
+impl<T> PartialEq for Store<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T: 'static> Store<T> {
+    /// Read the whole state. This subscribes the component to any change of the state,
+    /// use [`use_selector`] to only subscribe to a part of it.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.state.read())
+    }
+
+    /// Read the whole state without subscribing to its changes.
+    pub fn peek_with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.state.peek())
+    }
+
+    /// Update the state, notifying the selectors and the components reading the whole state.
+    pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut state = self.state;
+        let mut state = state.write();
+        f(&mut state)
+    }
+
+    /// Replace the state.
+    pub fn set(&self, value: T) {
+        let mut state = self.state;
+        state.set(value);
+    }
+}
+
+/// Provide an app-wide state of type `T` to the component and its descendants,
+/// usually called in the root component.
+///
+/// The state can be read and updated with [`use_store`] anywhere below, without passing it down with props.
+/// Unlike sharing a [`Signal`] through the context, the components can subscribe to a part of the state
+/// with [`use_selector`], so updating a field only rerenders the components that read that field.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// #[derive(Default)]
+/// struct AppState {
+///     count: i32,
+///     username: String,
+/// }
+///
+/// fn app() -> Element {
+///     use_init_store(AppState::default);
+///
+///     rsx!(
+///         Counter {}
+///         Username {}
+///     )
+/// }
+///
+/// #[allow(non_snake_case)]
+/// fn Counter() -> Element {
+///     let store = use_store::<AppState>();
+///     // Only rerendered when the count changes
+///     let count = use_selector(|state: &AppState| state.count);
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| store.update(|state| state.count += 1),
+///             label { "Clicked {count} times" }
+///         }
+///     )
+/// }
+///
+/// #[allow(non_snake_case)]
+/// fn Username() -> Element {
+///     // Not rerendered when the count changes
+///     let username = use_selector(|state: &AppState| state.username.clone());
+///
+///     rsx!(
+///         label { "Hello, {username}" }
+///     )
+/// }
+/// ```
+pub fn use_init_store<T: 'static>(init: impl FnOnce() -> T) -> Store<T> {
+    use_context_provider(|| Store {
+        state: Signal::new(init()),
+    })
+}
+
+/// Get the [`Store`] of type `T` provided by an ancestor with [`use_init_store`].
+pub fn use_store<T: 'static>() -> Store<T> {
+    use_context::<Store<T>>()
+}
+
+/// Select a part of the [`Store`] of type `T` provided by an ancestor with [`use_init_store`].
+///
+/// The component is only rerendered when the selected value changes, not with every change of the state.
+pub fn use_selector<T: 'static, V: PartialEq + 'static>(
+    selector: impl Fn(&T) -> V + 'static,
+) -> ReadOnlySignal<V> {
+    let store = use_store::<T>();
+    use_memo(move || store.with(&selector))
+}
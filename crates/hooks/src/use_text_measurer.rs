@@ -0,0 +1,30 @@
+use dioxus_core::prelude::{consume_context, use_hook};
+pub use freya_core::text_measurer::{TextMeasureOptions, TextMeasurement, TextMeasurer};
+
+/// Measure text with the fonts of the app, e.g to size a tooltip or a custom layout before it's laid out.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let measurer = use_text_measurer();
+///     let text = "Hello, World!";
+///     let size = measurer.measure(text, &TextMeasureOptions::default().with_font_size(20.0));
+///
+///     rsx!(
+///         rect {
+///             width: "{size.width + 16.0}",
+///             height: "{size.height + 16.0}",
+///             padding: "8",
+///             background: "black",
+///             label {
+///                 font_size: "20",
+///                 color: "white",
+///                 "{text}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_text_measurer() -> TextMeasurer {
+    use_hook(consume_context::<TextMeasurer>)
+}
@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{spawn, use_drop};
+use dioxus_core::{use_hook, Task};
+use dioxus_signals::{CopyValue, Readable, Writable};
+use tokio::time::Instant;
+
+/// Throttled callback, created with [`use_throttle`].
+pub struct UseThrottle<T: 'static> {
+    callback: CopyValue<Box<dyn FnMut(T)>>,
+    last_call: CopyValue<Option<Instant>>,
+    pending: CopyValue<Option<T>>,
+    task: CopyValue<Option<Task>>,
+    duration: Duration,
+}
+
+impl<T> Clone for UseThrottle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseThrottle<T> {}
+
+impl<T> PartialEq for UseThrottle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.callback == other.callback && self.duration == other.duration
+    }
+}
+
+impl<T: 'static> UseThrottle<T> {
+    /// Call the callback right away if it wasn't called in the throttle duration,
+    /// otherwise call it with the latest value once the duration has passed.
+    pub fn call(&self, value: T) {
+        let mut callback = self.callback;
+        let mut last_call = self.last_call;
+        let mut pending = self.pending;
+        let mut task = self.task;
+        let duration = self.duration;
+
+        let now = Instant::now();
+        let next_call = last_call.read().map(|last_call| last_call + duration);
+
+        match next_call {
+            Some(next_call) if next_call > now => {
+                // Keep the latest value for the trailing call
+                pending.set(Some(value));
+
+                if task.read().is_none() {
+                    let new_task = spawn(async move {
+                        tokio::time::sleep_until(next_call).await;
+                        task.set(None);
+
+                        if let Some(value) = pending.write().take() {
+                            last_call.set(Some(Instant::now()));
+                            (callback.write())(value);
+                        }
+                    });
+                    task.set(Some(new_task));
+                }
+            }
+            _ => {
+                last_call.set(Some(now));
+                (callback.write())(value);
+            }
+        }
+    }
+
+    /// Cancel the pending trailing call, if any.
+    pub fn cancel(&self) {
+        let mut task = self.task;
+        let mut pending = self.pending;
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+        pending.set(None);
+    }
+}
+
+/// Wrap a callback so it's called at most once every given duration, e.g to handle resizes.
+///
+/// The first call happens right away, and calls made during the duration are
+/// merged into a single call with the latest value once it passes.
+/// The pending call is cancelled when the component is dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut position = use_signal(|| 0.0);
+///     let track = use_throttle(Duration::from_millis(100), move |x: f64| {
+///         position.set(x);
+///     });
+///
+///     rsx!(
+///         rect {
+///             width: "100%",
+///             height: "100%",
+///             onmouseover: move |e: MouseEvent| track.call(e.get_screen_coordinates().x),
+///             label {
+///                 "{position}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_throttle<T: 'static>(
+    duration: Duration,
+    callback: impl FnMut(T) + 'static,
+) -> UseThrottle<T> {
+    let mut callback_value = use_hook(|| CopyValue::new(Box::new(|_: T| {}) as Box<dyn FnMut(T)>));
+    let last_call = use_hook(|| CopyValue::new(None));
+    let pending = use_hook(|| CopyValue::new(None));
+    let task = use_hook(|| CopyValue::new(None));
+
+    // Always call the latest callback
+    callback_value.set(Box::new(callback));
+
+    let throttle = UseThrottle {
+        callback: callback_value,
+        last_call,
+        pending,
+        task,
+        duration,
+    };
+
+    use_drop(move || throttle.cancel());
+
+    throttle
+}
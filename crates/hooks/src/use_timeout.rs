@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{spawn, use_drop};
+use dioxus_core::{use_hook, Task};
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+use tokio::time::Instant;
+
+/// State of a [`UseTimeout`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeoutState {
+    /// Waiting to call the callback.
+    Running,
+    /// Paused, with the remaining time.
+    Paused(Duration),
+    /// The callback was called or the timeout was cancelled.
+    Finished,
+}
+
+/// One-shot timer, created with [`use_timeout`].
+pub struct UseTimeout {
+    callback: CopyValue<Box<dyn FnMut()>>,
+    task: CopyValue<Option<Task>>,
+    deadline: CopyValue<Instant>,
+    state: Signal<TimeoutState>,
+    duration: Duration,
+}
+
+impl Clone for UseTimeout {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for UseTimeout {}
+
+impl PartialEq for UseTimeout {
+    fn eq(&self, other: &Self) -> bool {
+        self.callback == other.callback && self.duration == other.duration
+    }
+}
+
+impl UseTimeout {
+    /// Wait the full duration again before calling the callback, even if it was already called.
+    pub fn reset(&self) {
+        self.start(self.duration);
+    }
+
+    /// Stop the timeout without calling the callback, it can be started again with [`UseTimeout::reset`].
+    pub fn cancel(&self) {
+        let mut state = self.state;
+        self.stop();
+        state.set(TimeoutState::Finished);
+    }
+
+    /// Stop waiting, keeping the remaining time for [`UseTimeout::resume`].
+    pub fn pause(&self) {
+        let mut state = self.state;
+        if *state.peek() != TimeoutState::Running {
+            return;
+        }
+
+        self.stop();
+        let remaining = self
+            .deadline
+            .read()
+            .saturating_duration_since(Instant::now());
+        state.set(TimeoutState::Paused(remaining));
+    }
+
+    /// Keep waiting the remaining time of a paused timeout.
+    pub fn resume(&self) {
+        if let TimeoutState::Paused(remaining) = *self.state.peek() {
+            self.start(remaining);
+        }
+    }
+
+    /// Get the state of the timeout. Reading it subscribes the component to its changes.
+    pub fn state(&self) -> TimeoutState {
+        *self.state.read()
+    }
+
+    fn start(&self, duration: Duration) {
+        let mut callback = self.callback;
+        let mut task = self.task;
+        let mut deadline = self.deadline;
+        let mut state = self.state;
+
+        self.stop();
+
+        let new_deadline = Instant::now() + duration;
+        let new_task = spawn(async move {
+            tokio::time::sleep_until(new_deadline).await;
+            task.set(None);
+            state.set(TimeoutState::Finished);
+            (callback.write())();
+        });
+
+        task.set(Some(new_task));
+        deadline.set(new_deadline);
+
+        if *state.peek() != TimeoutState::Running {
+            state.set(TimeoutState::Running);
+        }
+    }
+
+    fn stop(&self) {
+        let mut task = self.task;
+        if let Some(task) = task.write().take() {
+            task.cancel();
+        }
+    }
+}
+
+/// Call the callback once after the given duration.
+///
+/// The timeout is cancelled when the component is dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut show_tip = use_signal(|| false);
+///     let timeout = use_timeout(Duration::from_secs(3), move || {
+///         show_tip.set(true);
+///     });
+///
+///     rsx!(
+///         rect {
+///             width: "100%",
+///             height: "100%",
+///             // Wait again while the user is active
+///             onmouseover: move |_| timeout.reset(),
+///             if *show_tip.read() {
+///                 label {
+///                     "Tip: you can press Ctrl+K to search"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_timeout(duration: Duration, callback: impl FnMut() + 'static) -> UseTimeout {
+    let mut callback_value = use_hook(|| CopyValue::new(Box::new(|| {}) as Box<dyn FnMut()>));
+    let task = use_hook(|| CopyValue::new(None));
+    let deadline = use_hook(|| CopyValue::new(Instant::now()));
+    let state = use_hook(|| Signal::new(TimeoutState::Running));
+
+    // Always call the latest callback
+    callback_value.set(Box::new(callback));
+
+    let timeout = UseTimeout {
+        callback: callback_value,
+        task,
+        deadline,
+        state,
+        duration,
+    };
+
+    use_hook(move || timeout.reset());
+
+    use_drop(move || timeout.stop());
+
+    timeout
+}
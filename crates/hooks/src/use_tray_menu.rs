@@ -0,0 +1,68 @@
+use dioxus_core::prelude::{spawn, try_consume_context};
+use dioxus_core::use_hook;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// Shares the clicks on the system tray menu items with the handlers registered by [`use_tray_menu`].
+#[derive(Clone)]
+pub struct TrayMenuEvents {
+    sender: broadcast::Sender<String>,
+}
+
+impl Default for TrayMenuEvents {
+    fn default() -> Self {
+        Self {
+            sender: broadcast::channel(16).0,
+        }
+    }
+}
+
+impl TrayMenuEvents {
+    /// Notify that the menu item with the given ID was clicked.
+    pub fn emit(&self, id: String) {
+        self.sender.send(id).ok();
+    }
+}
+
+/// Listen for clicks on the items of the system tray menu, the `handler` receives the ID of the clicked item.
+///
+/// The tray is configured with `LaunchConfigBuilder::with_tray`.
+/// Nothing happens on platforms without tray support.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut syncs = use_signal(|| 0);
+///
+///     use_tray_menu(move |id| {
+///         if id == "sync" {
+///             syncs += 1;
+///         }
+///     });
+///
+///     rsx!(
+///         label {
+///             "Synced {syncs} times"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_tray_menu(mut handler: impl FnMut(String) + 'static) {
+    use_hook(|| {
+        let Some(events) = try_consume_context::<TrayMenuEvents>() else {
+            return;
+        };
+        let mut receiver = events.sender.subscribe();
+
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(id) => handler(id),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+}
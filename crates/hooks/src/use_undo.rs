@@ -0,0 +1,304 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use dioxus_core::use_hook;
+use dioxus_signals::{Readable, Signal, Writable};
+
+/// Configuration of [`use_undo_with_config`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UndoConfig {
+    /// Maximum number of entries that can be undone, the oldest ones are dropped.
+    pub max_entries: usize,
+    /// Changes happening within this duration of the previous one are merged into a single entry.
+    pub coalesce_window: Duration,
+}
+
+impl Default for UndoConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 100,
+            coalesce_window: Duration::from_millis(500),
+        }
+    }
+}
+
+/// History of a value, with the previous values that can be undone and the next ones that can be redone.
+#[derive(Clone, Debug)]
+pub struct UndoHistory<T> {
+    past: VecDeque<T>,
+    present: T,
+    future: Vec<T>,
+    config: UndoConfig,
+    last_change: Option<Instant>,
+}
+
+impl<T: Clone> UndoHistory<T> {
+    /// Create a history without entries.
+    pub fn new(value: T, config: UndoConfig) -> Self {
+        Self {
+            past: VecDeque::new(),
+            present: value,
+            future: Vec::new(),
+            config,
+            last_change: None,
+        }
+    }
+
+    /// Get the current value.
+    pub fn value(&self) -> &T {
+        &self.present
+    }
+
+    /// Change the value at the given instant, merging it with the previous change if it happened within the coalesce window.
+    ///
+    /// The values that could be redone are discarded.
+    pub fn set_at(&mut self, value: T, now: Instant) {
+        let coalesce = self.last_change.is_some_and(|last_change| {
+            now.saturating_duration_since(last_change) < self.config.coalesce_window
+        });
+
+        if !coalesce {
+            let previous = std::mem::replace(&mut self.present, value);
+            self.past.push_back(previous);
+            if self.past.len() > self.config.max_entries {
+                self.past.pop_front();
+            }
+        } else {
+            self.present = value;
+        }
+
+        self.future.clear();
+        self.last_change = Some(now);
+    }
+
+    /// Change the value now. See [`UndoHistory::set_at`].
+    pub fn set(&mut self, value: T) {
+        self.set_at(value, Instant::now());
+    }
+
+    /// Go back to the previous value. Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(previous) = self.past.pop_back() else {
+            return false;
+        };
+        let present = std::mem::replace(&mut self.present, previous);
+        self.future.push(present);
+        self.last_change = None;
+        true
+    }
+
+    /// Go forward to the value that was undone. Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.future.pop() else {
+            return false;
+        };
+        let present = std::mem::replace(&mut self.present, next);
+        self.past.push_back(present);
+        self.last_change = None;
+        true
+    }
+
+    /// Check if there is a previous value.
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    /// Check if there is a value that was undone.
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+
+    /// Forget the previous and next values, keeping the current one.
+    pub fn clear(&mut self) {
+        self.past.clear();
+        self.future.clear();
+        self.last_change = None;
+    }
+}
+
+/// Value with an undo and redo history, created with [`use_undo`].
+pub struct UseUndo<T: 'static> {
+    history: Signal<UndoHistory<T>>,
+}
+
+impl<T> Clone for UseUndo<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseUndo<T> {}
+
+impl<T> PartialEq for UseUndo<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.history == other.history
+    }
+}
+
+impl<T: Clone + 'static> UseUndo<T> {
+    /// Get the current value. Reading it subscribes the component to its changes.
+    pub fn value(&self) -> T {
+        self.history.read().value().clone()
+    }
+
+    /// Change the value, recording the previous one in the history.
+    pub fn set(&self, value: T) {
+        let mut history = self.history;
+        history.write().set(value);
+    }
+
+    /// Go back to the previous value. Returns `false` if there was nothing to undo.
+    pub fn undo(&self) -> bool {
+        let mut history = self.history;
+        history.write().undo()
+    }
+
+    /// Go forward to the value that was undone. Returns `false` if there was nothing to redo.
+    pub fn redo(&self) -> bool {
+        let mut history = self.history;
+        history.write().redo()
+    }
+
+    /// Check if there is a previous value.
+    pub fn can_undo(&self) -> bool {
+        self.history.read().can_undo()
+    }
+
+    /// Check if there is a value that was undone.
+    pub fn can_redo(&self) -> bool {
+        self.history.read().can_redo()
+    }
+
+    /// Forget the previous and next values, keeping the current one.
+    pub fn clear_history(&self) {
+        let mut history = self.history;
+        history.write().clear();
+    }
+}
+
+/// Wrap a value with an undo and redo history, using the default [`UndoConfig`].
+///
+/// Changes made within 500ms of the previous one are merged into a single entry,
+/// e.g so undoing after typing a word removes the whole word.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let text = use_undo(String::new);
+///
+///     rsx!(
+///         Input {
+///             value: text.value(),
+///             onchange: move |value| text.set(value),
+///         }
+///         Button {
+///             onclick: move |_| {
+///                 text.undo();
+///             },
+///             label { "Undo" }
+///         }
+///         Button {
+///             onclick: move |_| {
+///                 text.redo();
+///             },
+///             label { "Redo" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_undo<T: Clone + 'static>(initial_value: impl FnOnce() -> T) -> UseUndo<T> {
+    use_undo_with_config(initial_value, UndoConfig::default())
+}
+
+/// Same as [`use_undo`], but with a custom [`UndoConfig`].
+pub fn use_undo_with_config<T: Clone + 'static>(
+    initial_value: impl FnOnce() -> T,
+    config: UndoConfig,
+) -> UseUndo<T> {
+    use_hook(|| UseUndo {
+        history: Signal::new(UndoHistory::new(initial_value(), config)),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use crate::{UndoConfig, UndoHistory};
+
+    #[test]
+    pub fn undo_redo() {
+        let config = UndoConfig {
+            max_entries: 10,
+            coalesce_window: Duration::from_millis(500),
+        };
+        let mut history = UndoHistory::new(0, config);
+        let now = Instant::now();
+
+        assert!(!history.can_undo());
+        history.set_at(1, now);
+        history.set_at(2, now + Duration::from_secs(1));
+
+        assert!(history.undo());
+        assert_eq!(*history.value(), 1);
+        assert!(history.undo());
+        assert_eq!(*history.value(), 0);
+        assert!(!history.undo());
+
+        assert!(history.redo());
+        assert_eq!(*history.value(), 1);
+
+        // A new change discards the values that could be redone
+        history.set_at(3, now + Duration::from_secs(2));
+        assert!(!history.can_redo());
+        assert!(history.undo());
+        assert_eq!(*history.value(), 1);
+    }
+
+    #[test]
+    pub fn coalesce_changes() {
+        let config = UndoConfig {
+            max_entries: 10,
+            coalesce_window: Duration::from_millis(500),
+        };
+        let mut history = UndoHistory::new(String::new(), config);
+        let now = Instant::now();
+
+        // Typing fast is a single entry
+        history.set_at("h".to_string(), now);
+        history.set_at("he".to_string(), now + Duration::from_millis(200));
+        history.set_at("hey".to_string(), now + Duration::from_millis(400));
+
+        // The window starts again from the last change
+        history.set_at("hey!".to_string(), now + Duration::from_millis(800));
+
+        // A pause starts a new entry
+        history.set_at("hey! you".to_string(), now + Duration::from_secs(2));
+
+        assert!(history.undo());
+        assert_eq!(history.value(), "hey!");
+        assert!(history.undo());
+        assert_eq!(history.value(), "");
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    pub fn bounded_history() {
+        let config = UndoConfig {
+            max_entries: 3,
+            coalesce_window: Duration::ZERO,
+        };
+        let mut history = UndoHistory::new(0, config);
+        let now = Instant::now();
+
+        for value in 1..=5 {
+            history.set_at(value, now);
+        }
+
+        // Only the last 3 previous values are kept
+        while history.undo() {}
+        assert_eq!(*history.value(), 2);
+    }
+}
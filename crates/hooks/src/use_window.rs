@@ -0,0 +1,68 @@
+use dioxus_core::use_hook;
+use freya_common::EventMessage;
+
+use crate::{use_platform, UsePlatform};
+
+/// Control the window of the app.
+///
+/// These operations do nothing in the headless testing environment.
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseWindow {
+    platform: UsePlatform,
+}
+
+impl UseWindow {
+    /// Change the title of the window.
+    pub fn set_title(&self, title: impl Into<String>) {
+        self.platform
+            .send(EventMessage::SetWindowTitle(title.into()))
+            .ok();
+    }
+
+    /// Minimize the window.
+    pub fn minimize(&self) {
+        self.platform.send(EventMessage::MinimizeWindow).ok();
+    }
+
+    /// Maximize the window, or restore it if it's already maximized.
+    pub fn toggle_maximize(&self) {
+        self.platform.send(EventMessage::ToggleMaximizeWindow).ok();
+    }
+
+    /// Enter the fullscreen mode, or exit it if the window is already in fullscreen.
+    pub fn toggle_fullscreen(&self) {
+        self.platform
+            .send(EventMessage::ToggleFullscreenWindow)
+            .ok();
+    }
+
+    /// Close the window. Closing the main window exits the app.
+    pub fn close(&self) {
+        self.platform.send(EventMessage::CloseWindow).ok();
+    }
+}
+
+/// Get access to the operations of the window, like changing its title or closing it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let window = use_window();
+///     let document = use_signal(|| "notes.txt".to_string());
+///
+///     use_effect(move || window.set_title(format!("{document} - Editor")));
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| window.close(),
+///             label { "Close" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_window() -> UseWindow {
+    let platform = use_platform();
+    use_hook(|| UseWindow { platform })
+}
@@ -0,0 +1,81 @@
+use dioxus_signals::{ReadOnlySignal, Readable};
+use torin::geometry::Size2D;
+
+use crate::{use_platform_information, Scale};
+
+/// Common layouts of an app depending on the width of the window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Breakpoint {
+    /// Less than `600` wide.
+    Mobile,
+    /// Between `600` and `1024` wide.
+    Tablet,
+    /// `1024` or more wide.
+    Desktop,
+}
+
+impl Breakpoint {
+    /// Get the breakpoint of the given width in logical pixels.
+    pub fn from_width(width: f32) -> Self {
+        if width < 600.0 {
+            Self::Mobile
+        } else if width < 1024.0 {
+            Self::Tablet
+        } else {
+            Self::Desktop
+        }
+    }
+}
+
+/// Get the size of the window in logical pixels, the same unit used by the elements attributes.
+///
+/// Reading it subscribes the component to the window resizes.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let size = use_window_size();
+///     let width = size.read().width;
+///
+///     rsx!(
+///         label {
+///             "Width: {width}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_window_size() -> ReadOnlySignal<Size2D> {
+    use_platform_information(|info| Scale::from(info).logical_size)
+}
+
+/// Get the [`Breakpoint`] of the window width, to switch between layouts.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let breakpoint = use_breakpoint();
+///
+///     let direction = if breakpoint == Breakpoint::Mobile {
+///         "vertical"
+///     } else {
+///         "horizontal"
+///     };
+///
+///     rsx!(
+///         rect {
+///             direction: "{direction}",
+///             label { "Sidebar" }
+///             label { "Content" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_breakpoint() -> Breakpoint {
+    let size = use_window_size();
+    let width = size.read().width;
+    Breakpoint::from_width(width)
+}
@@ -166,3 +166,48 @@ pub async fn animate_color() {
         Fill::Color(Color::parse("rgb(50, 100, 200)").unwrap())
     );
 }
+
+#[tokio::test]
+pub async fn redraw_only_while_animating() {
+    fn use_animation_app() -> Element {
+        let animation = use_animation(|ctx| ctx.with(AnimNum::new(0., 100.).time(300)));
+
+        let progress = animation.read().get().read().as_f32();
+
+        rsx!(rect {
+            width: "{progress}",
+            height: "100%",
+            onclick: move |_| animation.read().start(),
+        })
+    }
+
+    let mut utils = launch_test(use_animation_app);
+    utils.config().event_loop_ticker = false;
+
+    utils.wait_for_update().await;
+    assert!(utils.needs_redraw());
+
+    // Nothing changes while idle
+    utils.wait_for_update().await;
+    assert!(!utils.needs_redraw());
+    utils.wait_for_update().await;
+    assert!(!utils.needs_redraw());
+
+    utils.click_at(5.0, 5.0).await;
+
+    // Every frame of the animation requests the next one
+    for _ in 0..3 {
+        sleep(Duration::from_millis(10)).await;
+        utils.wait_for_update().await;
+        assert!(utils.needs_redraw());
+    }
+
+    // Finish the animation
+    utils.config().event_loop_ticker = true;
+    sleep(Duration::from_millis(300)).await;
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 100.0);
+
+    utils.wait_for_update().await;
+    assert!(!utils.needs_redraw());
+}
@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use freya::events::pointer::MouseButton;
+use freya::prelude::*;
+use freya_testing::*;
+
+#[tokio::test(start_paused = true)]
+async fn caret_blink() {
+    fn use_caret_blink_app() -> Element {
+        let mut focus = use_focus();
+        let is_focused = focus.is_focused();
+        let caret = use_caret_blink(is_focused);
+        let is_visible = caret.is_visible();
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50%",
+                focus_id: focus.attribute(),
+                onclick: move |_| focus.focus(),
+                label {
+                    "{is_focused} {is_visible}"
+                }
+            }
+            rect {
+                width: "100%",
+                height: "50%",
+                onclick: move |_| focus.unfocus(),
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_caret_blink_app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("false true"));
+
+    // Focus it
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("true true"));
+
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("true false"));
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("true true"));
+
+    // Unfocus it, the caret stops blinking
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 400.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("false true"));
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("false true"));
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use freya::events::pointer::MouseButton;
+use freya::prelude::*;
+use freya_testing::*;
+
+#[tokio::test(start_paused = true)]
+async fn debounce() {
+    fn use_debounce_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        let mut value = use_signal(|| 0);
+        let debounce = use_debounce(Duration::from_secs(1), move |clicks: i32| {
+            value.set(clicks);
+        });
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick: move |_| {
+                    clicks += 1;
+                    debounce.call(*clicks.read());
+                },
+                label {
+                    "{value}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_debounce_app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    // Click a few times in a row
+    for _ in 0..3 {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+    }
+
+    // The calls are still pending
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    // Only the last call happens once the calls stopped for the debounce duration
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("3"));
+}
@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use freya::events::pointer::MouseButton;
+use freya::prelude::*;
+use freya_testing::*;
+
+#[tokio::test(start_paused = true)]
+async fn form_validation() {
+    fn use_form_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        let mut submitted = use_signal(|| None::<String>);
+        let form = use_form(|| {
+            FormConfig::new()
+                .with_field("name", "")
+                .with_validator("name", |name| {
+                    if name.is_empty() {
+                        Err("Required".to_string())
+                    } else {
+                        Ok(())
+                    }
+                })
+                .with_field("username", "guest")
+                .with_async_validator("username", |username| async move {
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    if username == "admin" {
+                        Err("Taken".to_string())
+                    } else {
+                        Ok(())
+                    }
+                })
+        });
+
+        let submit = move || {
+            form.submit(move |values| {
+                submitted.set(Some(format!("{} {}", values["name"], values["username"])))
+            })
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick: move |_| {
+                    clicks += 1;
+                    match *clicks.read() {
+                        1 => submit(),
+                        2 => form.set_value("name", "Freya"),
+                        3 => form.set_value("username", "admin"),
+                        4 => form.set_value("username", "marc"),
+                        _ => submit(),
+                    }
+                },
+                label { "{form.error(\"name\").unwrap_or_default()}" }
+                label { "{form.error(\"username\").unwrap_or_default()}" }
+                label { "{form.is_valid()}" }
+                label { "{form.is_dirty(\"name\")}" }
+                label { "{submitted.read().clone().unwrap_or_default()}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_form_app);
+    let root = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    let texts = |root: &TestNode| {
+        (0..5)
+            .map(|i| root.get(i).get(0).text().unwrap_or_default().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    // The errors of untouched fields are hidden, but the form is still invalid
+    assert_eq!(texts(&root), ["", "", "false", "false", ""]);
+
+    let click = |utils: &mut TestingHandler| {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+    };
+
+    // Submitting an invalid form shows the errors without submitting it
+    click(&mut utils);
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(texts(&root), ["Required", "", "false", "false", ""]);
+
+    // Fixing the value validates it on change
+    click(&mut utils);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(texts(&root), ["", "", "true", "true", ""]);
+
+    // The asynchronous validators run in the background
+    click(&mut utils);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(texts(&root), ["", "", "false", "true", ""]);
+
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(texts(&root), ["", "Taken", "false", "true", ""]);
+
+    // Submitting a valid form
+    click(&mut utils);
+    utils.wait_for_update().await;
+    click(&mut utils);
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(texts(&root), ["", "", "true", "true", "Freya marc"]);
+}
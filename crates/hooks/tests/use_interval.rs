@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use freya::events::pointer::MouseButton;
+use freya::prelude::*;
+use freya_testing::*;
+
+#[tokio::test(start_paused = true)]
+async fn interval() {
+    fn use_interval_app() -> Element {
+        let mut count = use_signal(|| 0);
+        let interval = use_interval(Duration::from_secs(1), move || {
+            count += 1;
+        });
+        let paused = interval.is_paused();
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick: move |_| {
+                    if interval.is_paused() {
+                        interval.resume();
+                    } else {
+                        interval.pause();
+                    }
+                },
+                label {
+                    "{count} {paused}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_interval_app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("0 false"));
+
+    tokio::time::sleep(Duration::from_millis(3500)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("3 false"));
+
+    // Pause it
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("3 true"));
+
+    // Resume it
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("4 false"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn interval_duration_change() {
+    fn use_interval_app() -> Element {
+        let mut count = use_signal(|| 0);
+        let mut fast = use_signal(|| false);
+        let duration = if fast() {
+            Duration::from_millis(200)
+        } else {
+            Duration::from_secs(1)
+        };
+        use_interval(duration, move || {
+            count += 1;
+        });
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick: move |_| fast.set(true),
+                label {
+                    "{count}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_interval_app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    utils.advance_time(Duration::from_millis(2500)).await;
+
+    assert_eq!(label.get(0).text(), Some("2"));
+
+    // Make it faster
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    utils.advance_time(Duration::from_millis(1100)).await;
+
+    assert_eq!(label.get(0).text(), Some("7"));
+}
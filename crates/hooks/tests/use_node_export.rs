@@ -0,0 +1,106 @@
+use dioxus::prelude::*;
+use freya_common::{ExportFormat, PageOptions, PageSize};
+use freya_elements::elements as dioxus_elements;
+use freya_hooks::{use_node_export, use_platform};
+use freya_testing::launch_test;
+
+#[tokio::test]
+async fn export_node() {
+    fn export_app() -> Element {
+        let chart = use_node_export();
+        let mut svg = use_signal(String::new);
+        let mut pdf = use_signal(Vec::new);
+
+        use_hook(move || {
+            spawn(async move {
+                svg.set(String::from_utf8(chart.export(ExportFormat::Svg).await.unwrap()).unwrap());
+                pdf.set(chart.export(ExportFormat::Pdf).await.unwrap());
+            })
+        });
+
+        let is_pdf = pdf.read().starts_with(b"%PDF");
+
+        rsx!(
+            rect {
+                height: "100",
+                rect {
+                    reference: chart.reference(),
+                    width: "120",
+                    height: "80",
+                    background: "red",
+                }
+            }
+            label { "{svg}" }
+            label { "{is_pdf}" }
+        )
+    }
+
+    let mut utils = launch_test(export_app);
+    for _ in 0..4 {
+        utils.wait_for_update().await;
+    }
+
+    let root = utils.root();
+    let svg = root.get(1).get(0);
+    let svg = svg.text().unwrap();
+    // The document has the size of the exported Node
+    assert!(svg.contains("<svg"));
+    assert!(svg.contains(r#"width="120""#));
+    assert!(svg.contains(r#"height="80""#));
+
+    assert_eq!(root.get(2).get(0).text(), Some("true"));
+}
+
+#[tokio::test]
+async fn export_page() {
+    fn export_app() -> Element {
+        let platform = use_platform();
+        let chart = use_node_export();
+        let mut pages = use_signal(Vec::new);
+
+        use_hook(move || {
+            spawn(async move {
+                let page = PageOptions::default()
+                    .with_size(PageSize::Letter)
+                    .with_landscape(true);
+                let chart_page = chart.export(ExportFormat::PdfPage(page)).await.unwrap();
+                let window_page = platform
+                    .export(ExportFormat::PdfPage(PageOptions::default()))
+                    .await
+                    .unwrap();
+                pages.set(vec![chart_page, window_page]);
+            })
+        });
+
+        let media_boxes = pages
+            .read()
+            .iter()
+            .map(|page| {
+                let page = String::from_utf8_lossy(page);
+                let start = page.find("/MediaBox").unwrap();
+                page[start..start + page[start..].find(']').unwrap() + 1].to_string()
+            })
+            .collect::<Vec<_>>();
+
+        rsx!(
+            rect {
+                reference: chart.reference(),
+                width: "120",
+                height: "80",
+                background: "red",
+            }
+            label { "{media_boxes:?}" }
+        )
+    }
+
+    let mut utils = launch_test(export_app);
+    for _ in 0..4 {
+        utils.wait_for_update().await;
+    }
+
+    // The pages have the given size in points, in their orientation
+    assert_eq!(
+        utils.root().get(1).get(0).text(),
+        Some(r#"["/MediaBox [0 0 792 612]", "/MediaBox [0 0 595 842]"]"#)
+    );
+}
@@ -1,6 +1,7 @@
 use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
 use freya_hooks::use_platform;
-use freya_testing::{launch_test_with_config, TestingConfig};
+use freya_testing::{launch_test, launch_test_with_config, TestingConfig};
 
 #[tokio::test]
 async fn window_size() {
@@ -24,3 +25,32 @@ async fn window_size() {
 
     assert_eq!(utils.root().get(0).text(), Some("333.0x190.0"));
 }
+
+#[tokio::test]
+async fn screenshot() {
+    fn screenshot_app() -> Element {
+        let platform = use_platform();
+        let mut screenshot = use_signal(|| None);
+
+        use_hook(move || {
+            spawn(async move {
+                screenshot.set(platform.screenshot().await);
+            })
+        });
+
+        // PNG signature
+        let is_png = screenshot
+            .read()
+            .as_ref()
+            .map(|image: &Vec<u8>| image.starts_with(&[137, 80, 78, 71, 13, 10, 26, 10]));
+
+        rsx!(label { "{is_png:?}" })
+    }
+
+    let mut utils = launch_test(screenshot_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("Some(true)"));
+}
@@ -0,0 +1,74 @@
+use freya::events::pointer::MouseButton;
+use freya::prelude::*;
+use freya_testing::*;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+enum Door {
+    #[default]
+    Closed,
+    Open,
+    Locked,
+}
+
+enum DoorAction {
+    Open,
+    Close,
+    Lock,
+}
+
+fn reduce(state: Door, action: DoorAction) -> Door {
+    match (state, action) {
+        (Door::Closed, DoorAction::Open) => Door::Open,
+        (Door::Open, DoorAction::Close) => Door::Closed,
+        (Door::Closed, DoorAction::Lock) => Door::Locked,
+        // Invalid transitions keep the state
+        (state, _) => state,
+    }
+}
+
+#[tokio::test]
+async fn reducer() {
+    fn use_reducer_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        let door = use_reducer(Door::default, reduce);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick: move |_| {
+                    clicks += 1;
+                    let action = match *clicks.read() {
+                        1 => DoorAction::Open,
+                        2 => DoorAction::Lock,
+                        3 => DoorAction::Close,
+                        _ => DoorAction::Lock,
+                    };
+                    door.dispatch(action);
+                },
+                label {
+                    "{door.state():?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_reducer_app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("Closed"));
+
+    // Invalid transitions keep the previous state
+    for expected in ["Open", "Open", "Closed", "Locked", "Locked"] {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some(expected));
+    }
+}
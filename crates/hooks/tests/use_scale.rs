@@ -0,0 +1,33 @@
+use dioxus::prelude::*;
+use freya_hooks::{use_scale, Scale};
+use freya_testing::{launch_test_with_config, TestingConfig};
+
+#[tokio::test]
+async fn scale() {
+    fn use_scale_app() -> Element {
+        let scale = use_scale();
+        let Scale {
+            factor,
+            physical_size,
+            logical_size,
+        } = *scale.read();
+
+        rsx!("{factor} {physical_size:?} {logical_size:?}")
+    }
+
+    let mut utils = launch_test_with_config(
+        use_scale_app,
+        TestingConfig {
+            size: (300.0, 200.0).into(),
+            scale_factor: 2.0,
+            ..TestingConfig::default()
+        },
+    );
+
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.root().get(0).text(),
+        Some("2 300.0x200.0 150.0x100.0")
+    );
+}
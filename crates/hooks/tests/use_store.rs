@@ -0,0 +1,79 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use freya::events::pointer::MouseButton;
+use freya::prelude::*;
+use freya_testing::*;
+
+#[derive(Default)]
+struct AppState {
+    count: i32,
+    name: String,
+}
+
+static COUNT_RENDERS: AtomicUsize = AtomicUsize::new(0);
+static NAME_RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+#[allow(non_snake_case)]
+fn CountLabel() -> Element {
+    COUNT_RENDERS.fetch_add(1, Ordering::SeqCst);
+    let count = use_selector(|state: &AppState| state.count);
+    rsx!(
+        label { "{count}" }
+    )
+}
+
+#[allow(non_snake_case)]
+fn NameLabel() -> Element {
+    NAME_RENDERS.fetch_add(1, Ordering::SeqCst);
+    let name = use_selector(|state: &AppState| state.name.clone());
+    rsx!(
+        label { "{name}" }
+    )
+}
+
+#[tokio::test]
+async fn store_selectors() {
+    fn use_store_app() -> Element {
+        let store = use_init_store(|| AppState {
+            count: 0,
+            name: "Freya".to_string(),
+        });
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50%",
+                onclick: move |_| store.update(|state| state.count += 1),
+            }
+            CountLabel {}
+            NameLabel {}
+        )
+    }
+
+    let mut utils = launch_test(use_store_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(1).get(0).text(), Some("0"));
+    assert_eq!(root.get(2).get(0).text(), Some("Freya"));
+
+    let count_renders = COUNT_RENDERS.load(Ordering::SeqCst);
+    let name_renders = NAME_RENDERS.load(Ordering::SeqCst);
+
+    // Update the count
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(1).get(0).text(), Some("1"));
+    assert_eq!(root.get(2).get(0).text(), Some("Freya"));
+
+    // Only the reader of the count was rerendered
+    assert!(COUNT_RENDERS.load(Ordering::SeqCst) > count_renders);
+    assert_eq!(NAME_RENDERS.load(Ordering::SeqCst), name_renders);
+}
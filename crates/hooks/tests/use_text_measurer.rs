@@ -0,0 +1,75 @@
+use dioxus::prelude::*;
+use freya_core::text_measurer::TextMeasureOptions;
+use freya_elements::elements as dioxus_elements;
+use freya_hooks::use_text_measurer;
+use freya_testing::launch_test;
+
+#[tokio::test]
+async fn measure_like_label() {
+    fn measure_app() -> Element {
+        let measurer = use_text_measurer();
+        let size = measurer.measure(
+            "Hello, World!",
+            &TextMeasureOptions::default()
+                .with_font_size(20.0)
+                .with_font_weight(700),
+        );
+
+        rsx!(
+            label {
+                font_size: "20",
+                font_weight: "bold",
+                "Hello, World!"
+            }
+            rect {
+                width: "{size.width}",
+                height: "{size.height}",
+            }
+            label { "{size.lines}" }
+        )
+    }
+
+    let mut utils = launch_test(measure_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let label = root.get(0).area().unwrap();
+    let measured = root.get(1).area().unwrap();
+
+    // The text has the same size as a label with the same font
+    assert!(label.width() > 0.0);
+    assert_eq!(label.size, measured.size);
+    assert_eq!(root.get(2).get(0).text(), Some("1"));
+}
+
+#[tokio::test]
+async fn measure_lines() {
+    fn measure_app() -> Element {
+        let measurer = use_text_measurer();
+        let options = TextMeasureOptions::default();
+        let single = measurer.measure("One", &options);
+        let lines = measurer.measure("One\nTwo\nThree", &options);
+        let wrapped = measurer.measure(
+            "Some words that don't fit in a single line",
+            &options.clone().with_max_width(100.0),
+        );
+
+        rsx!(
+            label { "{lines.lines}" }
+            label { "{lines.height > single.height * 2.0}" }
+            label { "{wrapped.lines > 1}" }
+            label { "{wrapped.width <= 100.0}" }
+        )
+    }
+
+    let mut utils = launch_test(measure_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    // Line breaks start new lines
+    assert_eq!(root.get(0).get(0).text(), Some("3"));
+    assert_eq!(root.get(1).get(0).text(), Some("true"));
+    // Long text is wrapped in the maximum width
+    assert_eq!(root.get(2).get(0).text(), Some("true"));
+    assert_eq!(root.get(3).get(0).text(), Some("true"));
+}
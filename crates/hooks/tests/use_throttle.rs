@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use freya::events::pointer::MouseButton;
+use freya::prelude::*;
+use freya_testing::*;
+
+#[tokio::test(start_paused = true)]
+async fn throttle() {
+    fn use_throttle_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        let mut calls = use_signal(Vec::new);
+        let throttle = use_throttle(Duration::from_secs(1), move |clicks: i32| {
+            calls.write().push(clicks);
+        });
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick: move |_| {
+                    clicks += 1;
+                    throttle.call(*clicks.read());
+                },
+                label {
+                    "{calls:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_throttle_app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    // Click a few times in a row
+    for _ in 0..3 {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+    }
+
+    // The first call happens right away
+    assert_eq!(label.get(0).text(), Some("[1]"));
+
+    // The rest are merged into a call with the latest value once the throttle duration passes
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("[1, 3]"));
+}
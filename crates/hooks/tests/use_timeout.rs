@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use freya::events::pointer::MouseButton;
+use freya::prelude::*;
+use freya_testing::*;
+
+#[tokio::test(start_paused = true)]
+async fn timeout() {
+    fn use_timeout_app() -> Element {
+        let mut calls = use_signal(|| 0);
+        let timeout = use_timeout(Duration::from_secs(1), move || {
+            calls += 1;
+        });
+        let state = timeout.state();
+        let is_running = state == TimeoutState::Running;
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick: move |_| {
+                    if timeout.state() == TimeoutState::Running {
+                        timeout.pause();
+                    } else {
+                        timeout.resume();
+                    }
+                },
+                label {
+                    "{calls} {is_running}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_timeout_app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("0 true"));
+
+    // Pause it before it finishes
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("0 false"));
+
+    // Resume it, it only waits the remaining time
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("1 false"));
+
+    // It only fires once
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("1 false"));
+}
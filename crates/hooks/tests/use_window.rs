@@ -0,0 +1,48 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_hooks::use_window;
+use freya_testing::{events::pointer::MouseButton, launch_test, EventName, PlatformEvent};
+
+#[tokio::test]
+async fn window_operations_are_noop() {
+    fn use_window_app() -> Element {
+        let window = use_window();
+        let mut clicks = use_signal(|| 0);
+
+        use_effect(move || window.set_title(format!("Clicks: {clicks}")));
+
+        let onclick = move |_| {
+            window.minimize();
+            window.toggle_maximize();
+            window.toggle_fullscreen();
+            window.close();
+            clicks += 1;
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick,
+                label {
+                    "{clicks}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_window_app);
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("0"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The app keeps running in the testing environment
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("1"));
+}
@@ -0,0 +1,38 @@
+use dioxus::prelude::*;
+use freya_hooks::{use_breakpoint, use_window_size};
+use freya_testing::{launch_test_with_config, TestingConfig};
+
+#[tokio::test]
+async fn window_size_and_breakpoint() {
+    fn use_window_size_app() -> Element {
+        let size = use_window_size();
+        let breakpoint = use_breakpoint();
+        let size = *size.read();
+
+        rsx!("{size:?} {breakpoint:?}")
+    }
+
+    let mut utils = launch_test_with_config(
+        use_window_size_app,
+        TestingConfig {
+            size: (400.0, 300.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).text(), Some("400.0x300.0 Mobile"));
+
+    utils.resize((800.0, 300.0).into());
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).text(), Some("800.0x300.0 Tablet"));
+
+    utils.resize((1200.0, 300.0).into());
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).text(), Some("1200.0x300.0 Desktop"));
+}
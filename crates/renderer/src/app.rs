@@ -1,30 +1,46 @@
-use dioxus_core::{Template, VirtualDom};
+use dioxus_core::{Element, Template, VirtualDom};
 use dioxus_native_core::NodeId;
-use freya_common::EventMessage;
+use freya_common::{CustomCursor, EventMessage, ExportNode, NewWindow};
 use freya_core::prelude::*;
-use freya_dom::prelude::SafeDOM;
+use freya_dom::prelude::{FreyaDOM, SafeDOM};
 use freya_engine::prelude::*;
-use freya_hooks::PlatformInformation;
+use freya_hooks::{
+    use_init_accessibility, FrameTiming, HotkeysManager, PlatformInformation, TrayMenuEvents,
+};
+use freya_node_state::{Parse, StyleSheet};
 use futures_task::Waker;
 use futures_util::FutureExt;
 use pin_utils::pin_mut;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::broadcast;
 use tokio::{
     select,
     sync::{mpsc, watch, Notify},
 };
-use torin::geometry::{Area, Size2D};
-use tracing::info;
+use torin::geometry::{Area, CursorPoint, Size2D};
+use tracing::{info, warn};
 use uuid::Uuid;
 use winit::dpi::PhysicalSize;
-use winit::event_loop::{EventLoop, EventLoopProxy};
+use winit::event_loop::{EventLoop, EventLoopProxy, EventLoopWindowTarget};
+use winit::keyboard::ModifiersState;
 
 use crate::{
-    accessibility::AccessKitManager, event_loop::run_event_loop, renderer::render_skia,
+    accessibility::AccessKitManager,
+    dirty_region::{paint_bounds, DirtyRegion},
+    event_loop::run_event_loop,
+    export::{export_node, find_export_node},
+    renderer::render_skia,
     winit_waker::winit_waker,
 };
-use crate::{FontsConfig, HoveredNode, WindowEnv};
+use crate::{EventsLog, FontsConfig, HoveredNode, TrayConfig, WindowConfig, WindowEnv};
+
+/// Max time between two presses in a `window_drag` region to maximize the window.
+const DOUBLE_CLICK_DURATION: Duration = Duration::from_millis(500);
+
+/// Time without animation frames after which the paused pixel snapping is applied again.
+const PIXEL_SNAPPING_SETTLE_DELAY: Duration = Duration::from_millis(100);
 
 /// Manages the Application lifecycle
 pub struct App<State: 'static + Clone> {
@@ -34,6 +50,7 @@ pub struct App<State: 'static + Clone> {
     pub(crate) vdom_waker: Waker,
     pub(crate) proxy: EventLoopProxy<EventMessage>,
     pub(crate) mutations_notifier: Option<Arc<Notify>>,
+    pub(crate) events_log: EventsLog,
     pub(crate) event_emitter: EventEmitter,
     pub(crate) event_receiver: EventReceiver,
     pub(crate) window_env: WindowEnv<State>,
@@ -47,9 +64,28 @@ pub struct App<State: 'static + Clone> {
     pub(crate) font_mgr: FontMgr,
     pub(crate) ticker_sender: broadcast::Sender<()>,
     pub(crate) plugins: PluginsManager,
+    pub(crate) plugin_events: PluginEventsReceiver,
     pub(crate) navigator_state: NavigatorState,
     pub(crate) measure_layout_on_next_render: bool,
     pub(crate) platform_information: Arc<Mutex<PlatformInformation>>,
+    pub(crate) custom_cursor: Option<(Image, (f32, f32))>,
+    pub(crate) hotkeys: HotkeysManager,
+    pub(crate) cursor_pos: CursorPoint,
+    pub(crate) modifiers_state: ModifiersState,
+    pub(crate) tray_menu_events: TrayMenuEvents,
+    pub(crate) last_window_drag: Option<Instant>,
+    pub(crate) dirty_region: DirtyRegion,
+    pub(crate) painted_bounds: HashMap<NodeId, Area>,
+    pub(crate) last_hovered_node: Option<NodeId>,
+    pub(crate) uses_layer_effects: bool,
+    pub(crate) images_cache: ImagesCache,
+    pub(crate) paragraph_cache: ParagraphCache,
+    pub(crate) last_frame_at: Option<Instant>,
+    pub(crate) deferred_frame_at: Option<Instant>,
+    pub(crate) needs_redraw: bool,
+    pub(crate) animation_frame_requested: bool,
+    pub(crate) paused_pixel_snapping: bool,
+    pub(crate) styles: StyleSheet,
 }
 
 impl<State: 'static + Clone> App<State> {
@@ -58,6 +94,7 @@ impl<State: 'static + Clone> App<State> {
         vdom: VirtualDom,
         proxy: &EventLoopProxy<EventMessage>,
         mutations_notifier: Option<Arc<Notify>>,
+        events_log: EventsLog,
         window_env: WindowEnv<State>,
         fonts_config: FontsConfig,
         mut plugins: PluginsManager,
@@ -66,36 +103,40 @@ impl<State: 'static + Clone> App<State> {
 
         window_env.window.set_visible(true);
 
-        let mut font_collection = FontCollection::new();
         let def_mgr = FontMgr::default();
-
-        let mut provider = TypefaceFontProvider::new();
-
-        for (font_name, font_data) in fonts_config {
-            let ft_type = def_mgr.new_from_data(font_data, None).unwrap();
-            provider.register_typeface(ft_type, Some(font_name));
-        }
-
-        let font_mgr: FontMgr = provider.into();
-        font_collection.set_default_font_manager(def_mgr, "Fira Sans");
-        font_collection.set_dynamic_font_manager(font_mgr.clone());
+        let font_mgr = create_custom_font_mgr(
+            fonts_config
+                .iter()
+                .map(|(font_name, font_data)| (*font_name, font_data.as_ref())),
+            &def_mgr,
+        );
+        let font_collection = create_font_collection(font_mgr.clone(), def_mgr, Some("Fira Sans"));
 
         let (event_emitter, event_receiver) = mpsc::unbounded_channel::<DomEvent>();
         let (focus_sender, focus_receiver) = watch::channel(ACCESSIBILITY_ROOT_ID);
 
-        plugins.send(PluginEvent::WindowCreated(&window_env.window));
+        let (plugin_events_sender, plugin_events) = mpsc::unbounded_channel::<PlatformEvent>();
+        let plugin_handle = PluginHandle::new(proxy.clone(), plugin_events_sender);
+        plugins.send(PluginEvent::WindowCreated(
+            &window_env.window,
+            &plugin_handle,
+        ));
+
+        let images_cache = ImagesCache::new(window_env.window_config.images_cache_size);
 
         let platform_information = Arc::new(Mutex::new(PlatformInformation::from_winit(
             window_env.window.inner_size(),
+            window_env.window.scale_factor(),
         )));
 
         Self {
             sdom,
             vdom,
             events: EventsQueue::new(),
-            vdom_waker: winit_waker(proxy),
+            vdom_waker: winit_waker(proxy, window_env.window.id()),
             proxy: proxy.clone(),
             mutations_notifier,
+            events_log,
             event_emitter,
             event_receiver,
             window_env,
@@ -109,10 +150,95 @@ impl<State: 'static + Clone> App<State> {
             font_mgr,
             ticker_sender: broadcast::channel(5).0,
             plugins,
+            plugin_events,
             navigator_state: NavigatorState::new(NavigationMode::NotKeyboard),
             measure_layout_on_next_render: false,
             platform_information,
+            custom_cursor: None,
+            hotkeys: HotkeysManager::default(),
+            cursor_pos: CursorPoint::default(),
+            modifiers_state: ModifiersState::empty(),
+            tray_menu_events: TrayMenuEvents::default(),
+            last_window_drag: None,
+            dirty_region: DirtyRegion::Full,
+            painted_bounds: HashMap::default(),
+            last_hovered_node: None,
+            uses_layer_effects: false,
+            images_cache,
+            paragraph_cache: ParagraphCache::default(),
+            last_frame_at: None,
+            deferred_frame_at: None,
+            needs_redraw: false,
+            animation_frame_requested: false,
+            paused_pixel_snapping: false,
+            styles: StyleSheet::default(),
+        }
+    }
+
+    /// Open a new window with its own VirtualDOM, it shares the fonts with this app.
+    pub fn new_window(
+        &self,
+        new_window: NewWindow,
+        event_loop: &EventLoopWindowTarget<EventMessage>,
+    ) -> Self {
+        #[derive(Clone)]
+        struct RootProps {
+            app: fn() -> Element,
+        }
+
+        #[allow(non_snake_case)]
+        fn Root(props: RootProps) -> Element {
+            use_init_accessibility();
+
+            (props.app)()
+        }
+
+        let mut vdom = VirtualDom::new_with_props(
+            Root,
+            RootProps {
+                app: new_window.app,
+            },
+        );
+
+        for context in new_window.contexts {
+            vdom.insert_any_root_context(context);
         }
+
+        let window_config = WindowConfig {
+            title: new_window.title,
+            width: new_window.width,
+            height: new_window.height,
+            // Don't try the GPU again if this window fell back to the software renderer
+            backend: self.window_env.backend(),
+            render_settings: self.window_env.window_config.render_settings,
+            ..WindowConfig::default()
+        };
+        let window_env = WindowEnv::new(window_config, event_loop);
+
+        // New windows share the stylesheet of this app
+        let mut fdom = FreyaDOM::default();
+        fdom.set_stylesheet(self.sdom.get().stylesheet().clone());
+
+        let mut app = Self::new(
+            SafeDOM::new(fdom),
+            vdom,
+            &self.proxy,
+            None,
+            None,
+            window_env,
+            Vec::new(),
+            PluginsManager::default(),
+        );
+
+        app.styles = self.styles.clone();
+        app.font_collection
+            .set_dynamic_font_manager(self.font_mgr.clone());
+        app.font_mgr = self.font_mgr.clone();
+
+        app.init_doms();
+        app.process_layout();
+
+        app
     }
 
     /// Provide the launch state and few other utilities like the EventLoopProxy
@@ -130,6 +256,17 @@ impl<State: 'static + Clone> App<State> {
             .insert_any_root_context(Box::new(self.navigator_state.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.platform_information.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.hotkeys.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.window_env.window.id()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.tray_menu_events.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(TextMeasurer::new(
+                self.font_collection.clone(),
+                self.window_env.window_config.render_settings,
+            )));
     }
 
     /// Make the first build of the VirtualDOM and sync it with the RealDOM.
@@ -143,10 +280,17 @@ impl<State: 'static + Clone> App<State> {
     /// Update the DOM with the mutations from the VirtualDOM.
     pub fn apply_vdom_changes(&mut self) -> (bool, bool) {
         let scale_factor = self.window_env.window.scale_factor() as f32;
-        let (repaint, relayout) = self
-            .sdom
-            .get_mut()
-            .render_mutations(&mut self.vdom, scale_factor);
+        let (repaint, relayout, dirty_nodes) = {
+            let mut fdom = self.sdom.get_mut();
+            let (repaint, relayout) = fdom.render_mutations(&mut self.vdom, scale_factor);
+            (repaint, relayout, fdom.take_dirty_nodes())
+        };
+
+        if relayout {
+            self.dirty_region.invalidate_all();
+        } else if repaint {
+            self.invalidate_nodes(&dirty_nodes);
+        }
 
         if repaint {
             if let Some(mutations_notifier) = &self.mutations_notifier {
@@ -157,7 +301,47 @@ impl<State: 'static + Clone> App<State> {
         (repaint, relayout)
     }
 
+    /// Mark the areas painted by the given nodes as changed, both before and after the change.
+    fn invalidate_nodes(&mut self, nodes: &[NodeId]) {
+        let fdom = self.sdom.get();
+        let layout = fdom.layout();
+        let rdom = fdom.rdom();
+
+        for node_id in nodes {
+            let (Some(areas), Some(node)) = (layout.get(*node_id), rdom.get(*node_id)) else {
+                // The node has not been measured yet
+                self.dirty_region.invalidate_all();
+                return;
+            };
+
+            if let Some(previous_bounds) = self.painted_bounds.get(node_id) {
+                self.dirty_region.invalidate(*previous_bounds);
+            }
+            self.dirty_region
+                .invalidate(paint_bounds(&areas.visible_area(), &node));
+        }
+    }
+
+    /// Set the classes defined in code, the classes of the stylesheet file are applied on top of them.
+    pub fn set_styles(&mut self, styles: StyleSheet) {
+        self.styles = styles.clone();
+        self.sdom.get_mut().set_stylesheet(styles);
+    }
+
+    /// Replace the stylesheet used by the `class` attribute, invalid stylesheets are ignored.
+    pub fn set_stylesheet(&mut self, source: &str) {
+        match StyleSheet::parse(source) {
+            Ok(stylesheet) => {
+                let mut styles = self.styles.clone();
+                styles.extend(stylesheet);
+                self.sdom.get_mut().set_stylesheet(styles);
+            }
+            Err(err) => warn!("Failed to parse the stylesheet: {err}"),
+        }
+    }
+
     /// Poll the VirtualDOM for any new change
+    #[cfg_attr(feature = "profiling", tracing::instrument(skip_all))]
     pub fn poll_vdom(&mut self) {
         let waker = &self.vdom_waker.clone();
         let mut cx = std::task::Context::from_waker(waker);
@@ -168,6 +352,9 @@ impl<State: 'static + Clone> App<State> {
                     select! {
                         ev = self.event_receiver.recv() => {
                             if let Some(ev) = ev {
+                                if let Some(events_log) = &self.events_log {
+                                    events_log.send(ev.clone()).ok();
+                                }
                                 let data = ev.data.any();
                                 self.vdom.handle_event(ev.name.into(), data, ev.element_id, ev.bubbles);
 
@@ -192,15 +379,16 @@ impl<State: 'static + Clone> App<State> {
             }
 
             if must_relayout || must_repaint {
-                self.window_env.window.request_redraw();
+                self.request_redraw();
             }
         }
     }
 
     /// Process the events queue
+    #[cfg_attr(feature = "profiling", tracing::instrument(skip_all))]
     pub fn process_events(&mut self) {
         let scale_factor = self.window_env.window.scale_factor();
-        process_events(
+        let processed_events = process_events(
             &self.sdom.get(),
             &self.layers,
             &mut self.events,
@@ -208,7 +396,39 @@ impl<State: 'static + Clone> App<State> {
             &mut self.nodes_state,
             &self.viewports,
             scale_factor,
-        )
+        );
+
+        // Update the cursor icon if the hovered node has a different one
+        if let Some(cursor_icon) = processed_events.cursor_icon {
+            self.window_env.window.set_cursor_icon(cursor_icon);
+        }
+
+        if processed_events.drag_window {
+            self.drag_window();
+        }
+
+        // Repaint the nodes that look different when hovered or pressed
+        if processed_events.interaction_changed {
+            self.dirty_region.invalidate_all();
+            self.request_redraw();
+        }
+    }
+
+    /// Start dragging the window, or toggle its maximized state on double click.
+    fn drag_window(&mut self) {
+        let now = Instant::now();
+        let is_double_click = self
+            .last_window_drag
+            .is_some_and(|last| now.duration_since(last) < DOUBLE_CLICK_DURATION);
+
+        if is_double_click {
+            let window = &self.window_env.window;
+            window.set_maximized(!window.is_maximized());
+            self.last_window_drag = None;
+        } else {
+            self.window_env.window.drag_window().ok();
+            self.last_window_drag = Some(now);
+        }
     }
 
     /// Create the Accessibility tree
@@ -230,45 +450,199 @@ impl<State: 'static + Clone> App<State> {
 
     /// Send an event
     pub fn send_event(&mut self, event: PlatformEvent) {
+        self.plugins.send(PluginEvent::PlatformEvent(&event));
         self.events.push(event);
         self.process_events();
     }
 
+    /// Send the next event sent by a plugin, one at a time so the VirtualDOM can react to each of them.
+    pub fn dispatch_plugin_event(&mut self) {
+        if let Ok(event) = self.plugin_events.try_recv() {
+            self.send_event(event);
+        }
+    }
+
+    /// Export a Node and its descendants, answering with the encoded document.
+    pub fn export_node(&mut self, request: ExportNode) {
+        let fdom = self.sdom.get();
+        let data = find_export_node(&fdom, request.reference.as_ref()).and_then(|node_id| {
+            export_node(
+                node_id,
+                request.format,
+                &fdom,
+                &self.layers,
+                &self.viewports,
+                &self.nodes_state,
+                &mut self.font_collection,
+                &self.font_mgr,
+                &mut self.images_cache,
+                &mut self.paragraph_cache,
+                self.window_env.window_config.render_settings,
+            )
+        });
+        _ = request.sender.send(data);
+    }
+
     /// Replace a VirtualDOM Template
     pub fn vdom_replace_template(&mut self, template: Template) {
         self.vdom.replace_template(template);
     }
 
+    /// Measure the layout if needed and render the App, keeping track of the time spent on each.
+    #[cfg_attr(feature = "profiling", tracing::instrument(skip_all))]
+    pub fn render_frame(&mut self, hovered_node: &HoveredNode) {
+        self.last_frame_at = Some(Instant::now());
+        self.deferred_frame_at = None;
+        self.needs_redraw = false;
+
+        // Moving elements would advance in whole pixels, so the snapping is paused while animating
+        // and the Window is painted again with snapping once the animations settle
+        let is_animating = std::mem::take(&mut self.animation_frame_requested);
+        if self.window_env.window_config.render_settings.pixel_snapping {
+            if is_animating {
+                self.paused_pixel_snapping = true;
+                self.deferred_frame_at = Some(Instant::now() + PIXEL_SNAPPING_SETTLE_DELAY);
+            } else if self.paused_pixel_snapping {
+                self.paused_pixel_snapping = false;
+                self.dirty_region.invalidate_all();
+            }
+        }
+
+        let layout_start = Instant::now();
+        if self.measure_layout_on_next_render {
+            self.process_layout();
+            self.measure_layout_on_next_render = false;
+        }
+        let layout = layout_start.elapsed();
+
+        let render_start = Instant::now();
+        self.render(hovered_node);
+        let render = render_start.elapsed();
+
+        #[cfg(feature = "profiling")]
+        tracing::trace!(?layout, ?render, "Rendered frame");
+
+        self.platform_information.lock().unwrap().frame_timing = FrameTiming { layout, render };
+    }
+
+    /// Mark the Window as changed, a frame is requested once the pending events are processed.
+    pub fn request_redraw(&mut self) {
+        self.needs_redraw = true;
+    }
+
+    /// Check if rendering a frame now would go over the frame rate cap,
+    /// in which case the frame is deferred until the cap allows it.
+    pub fn must_defer_frame(&mut self) -> bool {
+        let (Some(frame_interval), Some(last_frame_at)) =
+            (self.window_env.frame_interval(), self.last_frame_at)
+        else {
+            return false;
+        };
+
+        let next_frame_at = last_frame_at + frame_interval;
+        if Instant::now() < next_frame_at {
+            self.deferred_frame_at = Some(next_frame_at);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Render the App into the Window Canvas
+    #[cfg_attr(feature = "profiling", tracing::instrument(skip_all))]
     pub fn render(&mut self, hovered_node: &HoveredNode) {
-        self.plugins.send(PluginEvent::BeforeRender {
+        self.window_env.make_current();
+
+        self.plugins.send(PluginEvent::BeforeRender(RenderContext {
             canvas: self.window_env.canvas(),
             font_collection: &self.font_collection,
             freya_dom: &self.sdom.get(),
             viewports: &self.viewports,
-        });
+            images_cache: self.images_cache.stats(),
+        }));
 
         self.start_render(hovered_node);
+        self.window_env.present_app();
 
         self.accessibility
             .render_accessibility(self.window_env.window.title().as_str());
 
-        self.plugins.send(PluginEvent::AfterRender {
+        self.plugins.send(PluginEvent::AfterRender(RenderContext {
             canvas: self.window_env.canvas(),
             font_collection: &self.font_collection,
             freya_dom: &self.sdom.get(),
             viewports: &self.viewports,
-        });
+            images_cache: self.images_cache.stats(),
+        }));
+
+        self.render_custom_cursor();
 
         self.finish_render();
     }
 
+    /// Use an image as cursor, or go back to the system cursor.
+    pub fn set_custom_cursor(&mut self, cursor: Option<CustomCursor>) {
+        self.custom_cursor = cursor.and_then(|cursor| {
+            let image = Image::from_encoded(Data::new_copy(&cursor.image));
+
+            // Fallback to a system cursor if the image is not valid
+            if image.is_none() {
+                self.window_env.window.set_cursor_icon(cursor.fallback);
+            }
+
+            image.map(|image| (image, cursor.hotspot))
+        });
+
+        // Hide the system cursor while drawing the custom one
+        self.window_env
+            .window
+            .set_cursor_visible(self.custom_cursor.is_none());
+        self.request_redraw();
+    }
+
+    /// Draw the custom cursor on top of the app.
+    fn render_custom_cursor(&mut self) {
+        let cursor_pos = self.cursor_pos;
+        let Some((image, (hotspot_x, hotspot_y))) = &self.custom_cursor else {
+            return;
+        };
+
+        // The cursor is outside the window
+        if cursor_pos.x < 0.0 || cursor_pos.y < 0.0 {
+            return;
+        }
+
+        // The cursor position is in physical pixels, map it to the space of the canvas transform
+        // so the image and its hotspot are scaled like the rest of the app
+        let canvas = self.window_env.canvas();
+        let Some(device_to_local) = canvas.local_to_device_as_3x3().invert() else {
+            return;
+        };
+        let position = device_to_local.map_point((cursor_pos.x as f32, cursor_pos.y as f32));
+
+        canvas.draw_image(
+            image,
+            Point::new(position.x - hotspot_x, position.y - hotspot_y),
+            None,
+        );
+    }
+
     /// Resize the Window
     pub fn resize(&mut self, size: PhysicalSize<u32>) {
         self.measure_layout_on_next_render = true;
         self.sdom.get().layout().reset();
+        self.window_env.make_current();
         self.window_env.resize(size);
-        *self.platform_information.lock().unwrap() = PlatformInformation::from_winit(size);
+        *self.platform_information.lock().unwrap() =
+            PlatformInformation::from_winit(size, self.window_env.window.scale_factor());
+    }
+
+    /// The Window was moved to a monitor with a different scale factor
+    pub fn scale_factor_changed(&mut self, scale_factor: f64) {
+        self.measure_layout_on_next_render = true;
+        self.sdom.get().layout().reset();
+        self.platform_information.lock().unwrap().scale_factor = scale_factor;
+        self.request_redraw();
     }
 
     /// Measure the a text group given it's ID.
@@ -279,6 +653,7 @@ impl<State: 'static + Clone> App<State> {
             &self.sdom.get(),
             &self.font_collection,
             scale_factor,
+            self.window_env.window_config.render_settings,
         );
     }
 
@@ -298,8 +673,10 @@ impl<State: 'static + Clone> App<State> {
     }
 
     /// Measure the layout
+    #[cfg_attr(feature = "profiling", tracing::instrument(skip_all))]
     pub fn process_layout(&mut self) {
         self.accessibility.clear_accessibility();
+        self.dirty_region.invalidate_all();
 
         {
             let fdom = self.sdom.get();
@@ -317,10 +694,16 @@ impl<State: 'static + Clone> App<State> {
                 ))),
                 &mut self.font_collection,
                 scale_factor,
+                self.window_env.window_config.render_settings,
             );
             self.layers = layers;
             self.viewports = viewports;
 
+            // Drop the paragraphs of the removed nodes
+            let rdom = fdom.rdom();
+            self.paragraph_cache
+                .retain(|node_id| rdom.get(node_id).is_some());
+
             self.plugins
                 .send(PluginEvent::FinishedLayout(&fdom.layout()));
         }
@@ -339,11 +722,50 @@ impl<State: 'static + Clone> App<State> {
         info!("Processed {} viewports", self.viewports.size());
     }
 
-    /// Start rendering the RealDOM to Window
+    /// Get the region of the Window to repaint in this frame.
+    fn take_dirty_region(&mut self, hovered_node: &HoveredNode) -> DirtyRegion {
+        let dirty_region = self.dirty_region.take();
+
+        // The devtools highlight a different node
+        let hovered_node = hovered_node
+            .as_ref()
+            .and_then(|hovered_node| *hovered_node.lock().unwrap());
+        let hovered_node_changed = hovered_node != self.last_hovered_node;
+        self.last_hovered_node = hovered_node;
+
+        if dirty_region == DirtyRegion::Clean && !hovered_node_changed {
+            return DirtyRegion::Clean;
+        }
+
+        // Skipping nodes would also skip the transforms and opacities they apply to their children,
+        // and the highlight of the devtools is painted outside of the node
+        let force_full = self.window_env.window_config.full_repaints
+            || self.uses_layer_effects
+            || hovered_node.is_some();
+
+        if force_full || hovered_node_changed {
+            DirtyRegion::Full
+        } else {
+            dirty_region
+        }
+    }
+
+    /// Start rendering the RealDOM to Window, only repainting the region that changed since the last frame.
     pub fn start_render(&mut self, hovered_node: &HoveredNode) {
-        self.window_env.clear();
+        let dirty_area = match self.take_dirty_region(hovered_node) {
+            DirtyRegion::Clean => return,
+            DirtyRegion::Area(area) => Some(area),
+            DirtyRegion::Full => {
+                self.painted_bounds.clear();
+                None
+            }
+        };
 
-        let canvas = self.window_env.canvas();
+        self.window_env.start_app_render(dirty_area);
+
+        let mut render_settings = self.window_env.window_config.render_settings;
+        render_settings.pixel_snapping &= !self.paused_pixel_snapping;
+        let canvas = self.window_env.app_canvas();
         let fdom = self.sdom.get();
 
         let mut matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
@@ -354,8 +776,20 @@ impl<State: 'static + Clone> App<State> {
             &fdom,
             &mut self.font_collection,
             &self.layers,
-            &mut (canvas, &mut matrices, &mut opacities),
-            |dom, node_id, area, font_collection, viewports, (canvas, matrices, opacities)| {
+            &mut (
+                canvas,
+                &mut matrices,
+                &mut opacities,
+                &mut self.painted_bounds,
+                &mut self.images_cache,
+                &mut self.paragraph_cache,
+            ),
+            |dom,
+             node_id,
+             area,
+             font_collection,
+             viewports,
+             (canvas, matrices, opacities, painted_bounds, images_cache, paragraph_cache)| {
                 let render_wireframe = if let Some(hovered_node) = &hovered_node {
                     hovered_node
                         .lock()
@@ -366,20 +800,38 @@ impl<State: 'static + Clone> App<State> {
                     false
                 };
                 if let Some(dioxus_node) = dom.rdom().get(*node_id) {
+                    let bounds = paint_bounds(area, &dioxus_node);
+
+                    // Skip the nodes outside the region being repainted
+                    if let Some(dirty_area) = dirty_area {
+                        if !bounds.intersects(&dirty_area) {
+                            return;
+                        }
+                    }
+
+                    painted_bounds.insert(*node_id, bounds);
+
                     render_skia(
                         canvas,
                         area,
                         &dioxus_node,
+                        self.nodes_state.interaction(node_id),
                         font_collection,
                         &self.font_mgr,
                         viewports,
                         render_wireframe,
                         matrices,
                         opacities,
+                        images_cache,
+                        paragraph_cache,
+                        render_settings,
                     );
                 }
             },
         );
+
+        self.uses_layer_effects = !matrices.is_empty() || !opacities.is_empty();
+        self.window_env.finish_app_render();
     }
 
     /// Finish all rendering in the Window
@@ -393,7 +845,8 @@ impl<State: 'static + Clone> App<State> {
         event_loop: EventLoop<EventMessage>,
         proxy: EventLoopProxy<EventMessage>,
         hovered_node: HoveredNode,
+        tray: Option<TrayConfig>,
     ) {
-        run_event_loop(self, event_loop, proxy, hovered_node)
+        run_event_loop(self, event_loop, proxy, hovered_node, tray)
     }
 }
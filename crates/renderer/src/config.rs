@@ -1,13 +1,17 @@
-use std::{io::Cursor, sync::Arc};
+use std::{borrow::Cow, io::Cursor, path::PathBuf, sync::Arc};
 
+use freya_core::images_cache::DEFAULT_IMAGES_CACHE_SIZE;
 use freya_core::plugins::{FreyaPlugin, PluginsManager};
+use freya_core::render_settings::{AntiAliasing, RenderSettings};
 use freya_engine::prelude::Color;
-use freya_node_state::Parse;
+use freya_node_state::{Parse, StyleSheet};
 use image::io::Reader;
 use winit::window::{Icon, Window, WindowBuilder};
 
+use crate::TrayConfig;
+
 pub type WindowBuilderHook = Box<dyn Fn(WindowBuilder) -> WindowBuilder>;
-pub type FontsConfig<'a> = Vec<(&'a str, &'a [u8])>;
+pub type FontsConfig<'a> = Vec<(&'a str, Cow<'a, [u8]>)>;
 
 /// Configuration for a Window.
 pub struct WindowConfig<T: Clone> {
@@ -23,6 +27,8 @@ pub struct WindowConfig<T: Clone> {
     pub max_width: Option<f64>,
     /// Maximum height of the window.
     pub max_height: Option<f64>,
+    /// Allow the user to resize the Window.
+    pub resizable: bool,
     /// Enable Window decorations.
     pub decorations: bool,
     /// Title for the Window.
@@ -31,8 +37,12 @@ pub struct WindowConfig<T: Clone> {
     pub transparent: bool,
     /// A custom value to consume from your app.
     pub state: Option<T>,
-    /// Background color of the Window.
+    /// Background color of the Window, transparent by default if the Window is transparent.
     pub background: Color,
+    /// Background color used instead if the platform can't make the Window transparent.
+    pub fallback_background: Color,
+    /// Blur what is behind the Window, useful for translucent Windows.
+    pub blur: bool,
     /// The Icon of the Window.
     pub icon: Option<Icon>,
     /// Setup callback.
@@ -41,6 +51,20 @@ pub struct WindowConfig<T: Clone> {
     pub on_exit: Option<WindowCallback>,
     /// Hook function called with the Window Builder.
     pub window_builder_hook: Option<WindowBuilderHook>,
+    /// File where the position, size and maximized state of the Window are persisted.
+    pub geometry_storage: Option<PathBuf>,
+    /// Repaint the whole Window on every frame instead of only what changed.
+    pub full_repaints: bool,
+    /// Memory budget in bytes for the decoded images and rasterized SVGs.
+    pub images_cache_size: usize,
+    /// Backend used to render the Window.
+    pub backend: RendererBackend,
+    /// Wait for the display refresh before showing a new frame.
+    pub vsync: bool,
+    /// Maximum frames per second while the app is animating.
+    pub max_fps: Option<u32>,
+    /// Anti-aliasing, text hinting and pixel snapping used to render the Window.
+    pub render_settings: RenderSettings,
 }
 
 impl<T: Clone> Default for WindowConfig<T> {
@@ -55,6 +79,9 @@ pub struct LaunchConfig<'a, T: Clone> {
     pub window: WindowConfig<T>,
     pub fonts: FontsConfig<'a>,
     pub plugins: PluginsManager,
+    pub tray: Option<TrayConfig>,
+    pub stylesheet: Option<PathBuf>,
+    pub styles: StyleSheet,
 }
 
 impl<'a, T: Clone> LaunchConfig<'a, T> {
@@ -80,6 +107,18 @@ impl LaunchConfig<'_, ()> {
 
 pub type WindowCallback = Arc<Box<fn(&mut Window)>>;
 
+/// Backend used to render the Windows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RendererBackend {
+    /// Use the GPU, and fall back to the software renderer if the GPU can't be used.
+    #[default]
+    Auto,
+    /// Always use the GPU through OpenGL.
+    Gpu,
+    /// Always render in the CPU, e.g for virtual machines or remote desktops where GPU contexts don't work.
+    Software,
+}
+
 /// Configuration Builder.
 pub struct LaunchConfigBuilder<'a, T> {
     pub(crate) width: f64,
@@ -88,17 +127,30 @@ pub struct LaunchConfigBuilder<'a, T> {
     pub(crate) min_height: Option<f64>,
     pub(crate) max_width: Option<f64>,
     pub(crate) max_height: Option<f64>,
+    pub(crate) resizable: bool,
     pub(crate) decorations: bool,
     pub(crate) title: &'static str,
     pub(crate) transparent: bool,
     pub(crate) state: Option<T>,
-    pub(crate) background: Color,
-    pub(crate) fonts: Vec<(&'a str, &'a [u8])>,
+    pub(crate) background: Option<Color>,
+    pub(crate) fallback_background: Color,
+    pub(crate) blur: bool,
+    pub(crate) fonts: FontsConfig<'a>,
     pub(crate) icon: Option<Icon>,
     pub(crate) on_setup: Option<WindowCallback>,
     pub(crate) on_exit: Option<WindowCallback>,
     pub(crate) plugins: PluginsManager,
     pub(crate) window_builder_hook: Option<WindowBuilderHook>,
+    pub(crate) geometry_storage: Option<PathBuf>,
+    pub(crate) full_repaints: bool,
+    pub(crate) images_cache_size: usize,
+    pub(crate) backend: RendererBackend,
+    pub(crate) vsync: bool,
+    pub(crate) max_fps: Option<u32>,
+    pub(crate) render_settings: RenderSettings,
+    pub(crate) tray: Option<TrayConfig>,
+    pub(crate) stylesheet: Option<PathBuf>,
+    pub(crate) styles: StyleSheet,
 }
 
 impl<T> Default for LaunchConfigBuilder<'_, T> {
@@ -110,17 +162,30 @@ impl<T> Default for LaunchConfigBuilder<'_, T> {
             min_height: None,
             max_height: None,
             max_width: None,
+            resizable: true,
             decorations: true,
             title: "Freya app",
             transparent: false,
             state: None,
-            background: Color::WHITE,
+            background: None,
+            fallback_background: Color::WHITE,
+            blur: false,
             fonts: Vec::default(),
             icon: None,
             on_setup: None,
             on_exit: None,
             plugins: PluginsManager::default(),
             window_builder_hook: None,
+            geometry_storage: None,
+            full_repaints: false,
+            images_cache_size: DEFAULT_IMAGES_CACHE_SIZE,
+            backend: RendererBackend::default(),
+            vsync: true,
+            max_fps: None,
+            render_settings: RenderSettings::default(),
+            tray: None,
+            stylesheet: None,
+            styles: StyleSheet::default(),
         }
     }
 }
@@ -162,6 +227,12 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
         self
     }
 
+    /// Whether the Window can be resized by the user or not.
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
     /// Whether the Window will have decorations or not.
     pub fn with_decorations(mut self, decorations: bool) -> Self {
         self.decorations = decorations;
@@ -174,7 +245,10 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
         self
     }
 
-    /// Make the Window transparent or not.
+    /// Make the Window transparent or not, its background will be transparent unless specified otherwise.
+    ///
+    /// Supported on Windows, macOS, Wayland and X11 with a compositor,
+    /// the fallback background is used where the Window can't be transparent.
     pub fn with_transparency(mut self, transparency: bool) -> Self {
         self.transparent = transparency;
         self
@@ -188,13 +262,33 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
 
     /// Specify the Window background color.
     pub fn with_background(mut self, background: &str) -> Self {
-        self.background = Color::parse(background).unwrap_or(Color::WHITE);
+        self.background = Some(Color::parse(background).unwrap_or(Color::WHITE));
+        self
+    }
+
+    /// Specify the background color used when the platform doesn't support transparent Windows.
+    pub fn with_fallback_background(mut self, fallback_background: &str) -> Self {
+        self.fallback_background = Color::parse(fallback_background).unwrap_or(Color::WHITE);
+        self
+    }
+
+    /// Blur what is behind the transparent Window.
+    ///
+    /// Only supported on Wayland compositors with the KDE blur protocol,
+    /// the Window stays transparent without blur elsewhere.
+    pub fn with_blur(mut self, blur: bool) -> Self {
+        self.blur = blur;
         self
     }
 
-    /// Register a font.
-    pub fn with_font(mut self, font_name: &'a str, font: &'a [u8]) -> Self {
-        self.fonts.push((font_name, font));
+    /// Register a TTF or OTF font under the given family name, so it can be used with `font_family`.
+    ///
+    /// Fonts registered under the same family name become styles of that family,
+    /// e.g a regular and a bold variant. Glyphs missing in the font fall back to the system fonts.
+    ///
+    /// The font can be embedded with `include_bytes!` or read at runtime with [`std::fs::read`].
+    pub fn with_font(mut self, font_name: &'a str, font: impl Into<Cow<'a, [u8]>>) -> Self {
+        self.fonts.push((font_name, font.into()));
         self
     }
 
@@ -217,6 +311,9 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
     }
 
     /// Add a new plugin.
+    ///
+    /// Plugins are notified in the order they are added, and in reverse order after rendering and measuring the layout,
+    /// see [`PluginsManager`].
     pub fn with_plugin(mut self, plugin: impl FreyaPlugin + 'static) -> Self {
         self.plugins.add_plugin(plugin);
         self
@@ -231,6 +328,110 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
         self
     }
 
+    /// Remember the position, size and maximized state of the Window in the given file,
+    /// and restore them on the next launch.
+    ///
+    /// The stored geometry is ignored if it's not in any of the connected monitors.
+    pub fn with_geometry_storage(mut self, path: impl Into<PathBuf>) -> Self {
+        self.geometry_storage = Some(path.into());
+        self
+    }
+
+    /// Repaint the whole Window on every frame instead of only the area of the elements that changed.
+    ///
+    /// Useful to find out if a rendering issue is caused by the partial repaints.
+    pub fn with_full_repaints(mut self, full_repaints: bool) -> Self {
+        self.full_repaints = full_repaints;
+        self
+    }
+
+    /// Specify how much memory in bytes can be used to keep the decoded images and rasterized SVGs,
+    /// the least recently used ones are discarded first. 256MB by default.
+    pub fn with_images_cache_size(mut self, images_cache_size: usize) -> Self {
+        self.images_cache_size = images_cache_size;
+        self
+    }
+
+    /// Choose the backend used to render the Windows, by default the GPU is used if available,
+    /// falling back to the software renderer otherwise.
+    pub fn with_backend(mut self, backend: RendererBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Enable or disable vsync, enabled by default.
+    ///
+    /// Only the GPU renderer can control the swap interval. It's supported by EGL (Linux, Android), GLX (X11)
+    /// and WGL (Windows) when the driver exposes the swap control extension, and by CGL (macOS).
+    /// Some Wayland compositors always wait for the display refresh.
+    pub fn with_vsync(mut self, vsync: bool) -> Self {
+        self.vsync = vsync;
+        self
+    }
+
+    /// Render at most `max_fps` frames per second, e.g `30` to save battery.
+    ///
+    /// The cap only delays the frames of animations, the app keeps sleeping while nothing changes.
+    pub fn with_max_fps(mut self, max_fps: u32) -> Self {
+        self.max_fps = Some(max_fps).filter(|max_fps| *max_fps > 0);
+        self
+    }
+
+    /// Choose how the edges of shapes, images and clips are smoothed, [`AntiAliasing::Enabled`] by default.
+    ///
+    /// [`AntiAliasing::Multisampled`] also requests a multisampled OpenGL surface, it's ignored by the software renderer
+    /// and by GPU drivers without multisampled configs.
+    pub fn with_anti_aliasing(mut self, anti_aliasing: AntiAliasing) -> Self {
+        self.render_settings.anti_aliasing = anti_aliasing;
+        self
+    }
+
+    /// Enable or disable the font hinting, enabled by default.
+    ///
+    /// Hinted glyphs are fitted to the pixel grid, which gives crisper text on low DPI displays.
+    /// Without hinting the glyphs keep their exact shapes and subpixel positions, which looks smoother on high DPI displays.
+    ///
+    /// Hinting is applied by FreeType on Linux and by DirectWrite on Windows.
+    /// macOS never hints the text, so it has no effect there.
+    pub fn with_text_hinting(mut self, text_hinting: bool) -> Self {
+        self.render_settings.text_hinting = text_hinting;
+        self
+    }
+
+    /// Round the position and size of the elements to whole pixels before painting them, disabled by default.
+    ///
+    /// Borders and lines of 1 pixel are painted across two pixels when they don't start at a whole pixel,
+    /// which makes them look blurry, mostly on displays without scaling. Snapping paints them crisp instead.
+    ///
+    /// Moving elements would advance in whole pixels, so the snapping is paused while animating
+    /// and the Window is painted again with snapping once the animations finish.
+    pub fn with_pixel_snapping(mut self, pixel_snapping: bool) -> Self {
+        self.render_settings.pixel_snapping = pixel_snapping;
+        self
+    }
+
+    /// Show an icon with a menu in the system tray. Requires the `tray` feature.
+    pub fn with_tray(mut self, tray: TrayConfig) -> Self {
+        self.tray = Some(tray);
+        self
+    }
+
+    /// Load the classes of the `class` attribute from the given stylesheet file.
+    ///
+    /// The file is watched while the app runs, so the changes are applied without recompiling.
+    /// Its classes replace the ones with the same name from [`LaunchConfigBuilder::with_styles`].
+    /// See the [`StyleSheet`] format.
+    pub fn with_stylesheet(mut self, path: impl Into<PathBuf>) -> Self {
+        self.stylesheet = Some(path.into());
+        self
+    }
+
+    /// Define the classes of the `class` attribute in code.
+    pub fn with_styles(mut self, styles: StyleSheet) -> Self {
+        self.styles = styles;
+        self
+    }
+
     /// Build the configuration.
     pub fn build(self) -> LaunchConfig<'a, T> {
         LaunchConfig {
@@ -242,17 +443,34 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
                 max_width: self.max_width,
                 max_height: self.max_height,
                 title: self.title,
+                resizable: self.resizable,
                 decorations: self.decorations,
                 transparent: self.transparent,
                 state: self.state,
-                background: self.background,
+                background: self.background.unwrap_or(if self.transparent {
+                    Color::TRANSPARENT
+                } else {
+                    Color::WHITE
+                }),
+                fallback_background: self.fallback_background,
+                blur: self.blur,
                 icon: self.icon,
                 on_setup: self.on_setup,
                 on_exit: self.on_exit,
                 window_builder_hook: self.window_builder_hook,
+                geometry_storage: self.geometry_storage,
+                full_repaints: self.full_repaints,
+                images_cache_size: self.images_cache_size,
+                backend: self.backend,
+                vsync: self.vsync,
+                max_fps: self.max_fps,
+                render_settings: self.render_settings,
             },
             fonts: self.fonts,
             plugins: self.plugins,
+            tray: self.tray,
+            stylesheet: self.stylesheet,
+            styles: self.styles,
         }
     }
 }
@@ -0,0 +1,66 @@
+use dioxus_native_core::real_dom::NodeImmutable;
+use freya_dom::prelude::DioxusNode;
+use freya_node_state::{BorderAlignment, ShadowPosition, Style};
+use torin::geometry::Area;
+
+/// Part of the Window that must be repainted in the next frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DirtyRegion {
+    /// Nothing changed since the last frame, the previous one can be presented again.
+    Clean,
+    /// Only this area changed.
+    Area(Area),
+    /// Repaint the whole Window.
+    #[default]
+    Full,
+}
+
+impl DirtyRegion {
+    /// Mark the given area as changed.
+    pub fn invalidate(&mut self, area: Area) {
+        *self = match self {
+            Self::Clean => Self::Area(area),
+            Self::Area(dirty_area) => Self::Area(dirty_area.union(&area)),
+            Self::Full => Self::Full,
+        }
+    }
+
+    /// Mark the whole Window as changed.
+    pub fn invalidate_all(&mut self) {
+        *self = Self::Full;
+    }
+
+    /// Get the region to repaint, leaving it clean.
+    pub fn take(&mut self) -> Self {
+        std::mem::replace(self, Self::Clean)
+    }
+}
+
+/// Area where the given node paints, including its outer shadows and borders.
+pub fn paint_bounds(area: &Area, node: &DioxusNode) -> Area {
+    let Some(style) = node.get::<Style>() else {
+        return *area;
+    };
+
+    let mut bounds = *area;
+
+    for shadow in style.shadows.iter() {
+        if shadow.position == ShadowPosition::Inset {
+            continue;
+        }
+        let extent = shadow.blur + shadow.spread.max(0.0);
+        let shadow_area = area
+            .translate((shadow.x, shadow.y).into())
+            .inflate(extent, extent);
+        bounds = bounds.union(&shadow_area);
+    }
+
+    let border_extent = match style.border.alignment {
+        BorderAlignment::Inner => 0.0,
+        BorderAlignment::Center => style.border.width / 2.0,
+        BorderAlignment::Outer => style.border.width,
+    };
+
+    // Leave a pixel for antialiasing
+    bounds.inflate(border_extent + 1.0, border_extent + 1.0)
+}
@@ -0,0 +1,310 @@
+use freya_common::EventMessage;
+use freya_engine::prelude::*;
+use gl::{types::*, *};
+use glutin::context::GlProfile;
+use glutin::context::NotCurrentGlContext;
+use glutin::prelude::PossiblyCurrentGlContext;
+use glutin::{
+    config::{Config, ConfigTemplateBuilder, GlConfig},
+    context::{ContextApi, ContextAttributesBuilder, PossiblyCurrentContext},
+    display::{GetGlDisplay, GlDisplay},
+    prelude::GlSurface,
+    surface::{Surface as GlutinSurface, SurfaceAttributesBuilder, SwapInterval, WindowSurface},
+};
+use glutin_winit::DisplayBuilder;
+use raw_window_handle::HasRawWindowHandle;
+use std::ffi::CString;
+use std::num::NonZeroU32;
+use std::panic::{self, AssertUnwindSafe};
+use tracing::warn;
+use winit::dpi::PhysicalSize;
+use winit::{
+    event_loop::EventLoopWindowTarget,
+    window::{Window, WindowBuilder},
+};
+
+use super::DriverError;
+
+/// Renders with the GPU through OpenGL.
+pub struct GlDriver {
+    pub(crate) gr_context: DirectContext,
+    pub(crate) surface: Surface,
+    pub(crate) gl_surface: GlutinSurface<WindowSurface>,
+    pub(crate) gl_context: PossiblyCurrentContext,
+    pub(crate) fb_info: FramebufferInfo,
+    pub(crate) num_samples: usize,
+    pub(crate) stencil_size: usize,
+    pub(crate) supports_transparency: bool,
+}
+
+impl Drop for GlDriver {
+    fn drop(&mut self) {
+        if !self.gl_context.is_current() && self.gl_context.make_current(&self.gl_surface).is_err()
+        {
+            self.gr_context.abandon();
+        }
+    }
+}
+
+impl GlDriver {
+    /// Create the Window with an OpenGL context, with the most samples per pixel available if `multisampling`
+    /// is enabled or the fewest otherwise.
+    pub fn new(
+        event_loop: &EventLoopWindowTarget<EventMessage>,
+        window_builder: WindowBuilder,
+        transparent: bool,
+        multisampling: bool,
+    ) -> Result<(Window, Self), DriverError> {
+        let template = ConfigTemplateBuilder::new()
+            .with_alpha_size(8)
+            .with_transparency(transparent);
+
+        let display_builder = DisplayBuilder::new().with_window_builder(Some(window_builder));
+        // The picker must return a config, so an empty list of configs can only be reported by unwinding
+        let picked = panic::catch_unwind(AssertUnwindSafe(|| {
+            display_builder.build(event_loop, template, |configs| {
+                pick_config(configs, multisampling).expect("No OpenGL config available")
+            })
+        }));
+        let (window, gl_config) = match picked {
+            Ok(Ok(picked)) => picked,
+            Ok(Err(err)) => return Err(DriverError::new(None, format!("No OpenGL config: {err}"))),
+            Err(_) => {
+                return Err(DriverError::new(
+                    None,
+                    "No OpenGL config available".to_string(),
+                ))
+            }
+        };
+
+        let Some(window) = window else {
+            return Err(DriverError::new(
+                None,
+                "Could not create window with OpenGL context".to_string(),
+            ));
+        };
+        let raw_window_handle = window.raw_window_handle();
+
+        let context_attributes = ContextAttributesBuilder::new()
+            .with_profile(GlProfile::Core)
+            .build(Some(raw_window_handle));
+
+        let fallback_context_attributes = ContextAttributesBuilder::new()
+            .with_profile(GlProfile::Core)
+            .with_context_api(ContextApi::Gles(None))
+            .build(Some(raw_window_handle));
+
+        let not_current_gl_context = unsafe {
+            gl_config
+                .display()
+                .create_context(&gl_config, &context_attributes)
+                .or_else(|_| {
+                    gl_config
+                        .display()
+                        .create_context(&gl_config, &fallback_context_attributes)
+                })
+        };
+        let not_current_gl_context = match not_current_gl_context {
+            Ok(context) => context,
+            Err(err) => {
+                return Err(DriverError::new(
+                    Some(window),
+                    format!("Could not create the OpenGL context: {err}"),
+                ))
+            }
+        };
+
+        let (width, height): (u32, u32) = window.inner_size().into();
+
+        let attrs = SurfaceAttributesBuilder::<WindowSurface>::new().build(
+            raw_window_handle,
+            NonZeroU32::new(width.max(1)).unwrap(),
+            NonZeroU32::new(height.max(1)).unwrap(),
+        );
+
+        let gl_surface = match unsafe {
+            gl_config
+                .display()
+                .create_window_surface(&gl_config, &attrs)
+        } {
+            Ok(gl_surface) => gl_surface,
+            Err(err) => {
+                return Err(DriverError::new(
+                    Some(window),
+                    format!("Could not create the OpenGL window surface: {err}"),
+                ))
+            }
+        };
+
+        let gl_context = match not_current_gl_context.make_current(&gl_surface) {
+            Ok(gl_context) => gl_context,
+            Err(err) => {
+                return Err(DriverError::new(
+                    Some(window),
+                    format!("Could not make the OpenGL context current: {err}"),
+                ))
+            }
+        };
+
+        load_with(|s| {
+            gl_config
+                .display()
+                .get_proc_address(CString::new(s).unwrap().as_c_str())
+        });
+        let interface = Interface::new_load_with(|name| {
+            if name == "eglGetCurrentDisplay" {
+                return std::ptr::null();
+            }
+            gl_config
+                .display()
+                .get_proc_address(CString::new(name).unwrap().as_c_str())
+        });
+        let Some(interface) = interface else {
+            return Err(DriverError::new(
+                Some(window),
+                "Could not create the OpenGL interface".to_string(),
+            ));
+        };
+
+        let Some(mut gr_context) = DirectContext::new_gl(Some(interface), None) else {
+            return Err(DriverError::new(
+                Some(window),
+                "Could not create the Skia direct context".to_string(),
+            ));
+        };
+
+        let fb_info = {
+            let mut fboid: GLint = 0;
+            unsafe { GetIntegerv(FRAMEBUFFER_BINDING, &mut fboid) };
+
+            FramebufferInfo {
+                fboid: fboid.try_into().unwrap(),
+                format: Format::RGBA8.into(),
+                ..Default::default()
+            }
+        };
+
+        let num_samples = gl_config.num_samples() as usize;
+        let stencil_size = gl_config.stencil_size() as usize;
+
+        let Some(surface) =
+            create_surface(&window, fb_info, &mut gr_context, num_samples, stencil_size)
+        else {
+            return Err(DriverError::new(
+                Some(window),
+                "Could not create the Skia surface".to_string(),
+            ));
+        };
+
+        Ok((
+            window,
+            Self {
+                gr_context,
+                surface,
+                gl_surface,
+                gl_context,
+                fb_info,
+                num_samples,
+                stencil_size,
+                supports_transparency: gl_config.supports_transparency().unwrap_or(false),
+            },
+        ))
+    }
+
+    /// Make the GL context of this Window the current one, needed when there are multiple windows.
+    pub fn make_current(&self) {
+        if !self.gl_context.is_current() {
+            self.gl_context.make_current(&self.gl_surface).ok();
+        }
+    }
+
+    /// Wait or not for the display refresh before swapping the buffers.
+    pub fn set_vsync(&self, vsync: bool) {
+        let interval = if vsync {
+            SwapInterval::Wait(NonZeroU32::new(1).unwrap())
+        } else {
+            SwapInterval::DontWait
+        };
+
+        if let Err(err) = self
+            .gl_surface
+            .set_swap_interval(&self.gl_context, interval)
+        {
+            warn!("Could not change the swap interval: {err}");
+        }
+    }
+
+    /// Flush and submit the frame.
+    pub fn present(&mut self) {
+        self.gr_context.flush_and_submit();
+        self.gl_surface.swap_buffers(&self.gl_context).unwrap();
+    }
+
+    /// Resize the surface.
+    pub fn resize(&mut self, window: &Window, size: PhysicalSize<u32>) -> Result<(), DriverError> {
+        self.surface = create_surface(
+            window,
+            self.fb_info,
+            &mut self.gr_context,
+            self.num_samples,
+            self.stencil_size,
+        )
+        .ok_or_else(|| DriverError::new(None, "Could not create the Skia surface".to_string()))?;
+
+        let (width, height): (u32, u32) = size.into();
+
+        self.gl_surface.resize(
+            &self.gl_context,
+            NonZeroU32::new(width.max(1)).unwrap(),
+            NonZeroU32::new(height.max(1)).unwrap(),
+        );
+
+        Ok(())
+    }
+}
+
+/// Pick the config that supports transparency, with the most samples per pixel if `multisampling` is enabled
+/// or the fewest otherwise.
+fn pick_config(configs: impl Iterator<Item = Config>, multisampling: bool) -> Option<Config> {
+    configs.reduce(|accum, config| {
+        let transparency_check = config.supports_transparency().unwrap_or(false)
+            & !accum.supports_transparency().unwrap_or(false);
+
+        let samples_check = if multisampling {
+            config.num_samples() > accum.num_samples()
+        } else {
+            config.num_samples() < accum.num_samples()
+        };
+
+        if transparency_check || samples_check {
+            config
+        } else {
+            accum
+        }
+    })
+}
+
+/// Create the surface for Skia to render in
+fn create_surface(
+    window: &Window,
+    fb_info: FramebufferInfo,
+    gr_context: &mut DirectContext,
+    num_samples: usize,
+    stencil_size: usize,
+) -> Option<Surface> {
+    let size = window.inner_size();
+    let size = (
+        size.width.try_into().expect("Could not convert width"),
+        size.height.try_into().expect("Could not convert height"),
+    );
+    let backend_render_target =
+        backend_render_targets::make_gl(size, num_samples, stencil_size, fb_info);
+    wrap_backend_render_target(
+        gr_context,
+        &backend_render_target,
+        SurfaceOrigin::BottomLeft,
+        ColorType::RGBA8888,
+        None,
+        None,
+    )
+}
@@ -0,0 +1,250 @@
+mod gl;
+mod software;
+
+use freya_common::EventMessage;
+use freya_engine::prelude::*;
+use tracing::{info, warn};
+use winit::dpi::PhysicalSize;
+use winit::{
+    event_loop::EventLoopWindowTarget,
+    window::{Window, WindowBuilder},
+};
+
+use crate::config::RendererBackend;
+
+pub use gl::GlDriver;
+pub use software::SoftwareDriver;
+
+/// Why a graphics driver could not be created.
+pub struct DriverError {
+    /// The Window, if it was created before failing.
+    pub window: Option<Window>,
+    pub reason: String,
+}
+
+impl DriverError {
+    pub fn new(window: Option<Window>, reason: String) -> Self {
+        Self { window, reason }
+    }
+}
+
+/// Presents the frames of a Window with the selected [`RendererBackend`].
+pub enum GraphicsDriver {
+    Gl(Box<GlDriver>),
+    Software(Box<SoftwareDriver>),
+}
+
+impl GraphicsDriver {
+    /// Create the Window and the driver for the given backend, falling back to the software renderer
+    /// if the GPU can't be used and the backend is [`RendererBackend::Auto`].
+    pub fn new(
+        event_loop: &EventLoopWindowTarget<EventMessage>,
+        window_builder: WindowBuilder,
+        transparent: bool,
+        backend: RendererBackend,
+        multisampling: bool,
+    ) -> (Window, Self) {
+        if backend != RendererBackend::Software {
+            match GlDriver::new(
+                event_loop,
+                window_builder.clone(),
+                transparent,
+                multisampling,
+            ) {
+                Ok((window, driver)) => {
+                    info!("Using the OpenGL renderer");
+                    return (window, Self::Gl(Box::new(driver)));
+                }
+                Err(err) if backend == RendererBackend::Gpu => {
+                    panic!("Could not use the GPU renderer: {}", err.reason)
+                }
+                Err(DriverError { window, reason }) => {
+                    warn!("Falling back to the software renderer, the GPU failed: {reason}");
+                    return Self::new_software(event_loop, window, window_builder);
+                }
+            }
+        }
+
+        Self::new_software(event_loop, None, window_builder)
+    }
+
+    fn new_software(
+        event_loop: &EventLoopWindowTarget<EventMessage>,
+        window: Option<Window>,
+        window_builder: WindowBuilder,
+    ) -> (Window, Self) {
+        let window = window.unwrap_or_else(|| {
+            window_builder
+                .build(event_loop)
+                .expect("Could not create the window")
+        });
+
+        let driver = Self::Software(Box::new(Self::software_driver(&window)));
+        info!("Using the software renderer");
+        (window, driver)
+    }
+
+    fn software_driver(window: &Window) -> SoftwareDriver {
+        match SoftwareDriver::new(window) {
+            Ok(driver) => driver,
+            Err(err) => panic!("Could not use the software renderer: {}", err.reason),
+        }
+    }
+
+    /// Replace the GPU driver with the software renderer, e.g when the GPU surface can't be recreated.
+    pub fn fall_back_to_software(&mut self, window: &Window, reason: &str) {
+        if let Self::Gl(_) = self {
+            warn!("Falling back to the software renderer, the GPU failed: {reason}");
+            *self = Self::Software(Box::new(Self::software_driver(window)));
+        }
+    }
+
+    /// The backend that is actually being used.
+    pub fn backend(&self) -> RendererBackend {
+        match self {
+            Self::Gl(_) => RendererBackend::Gpu,
+            Self::Software(_) => RendererBackend::Software,
+        }
+    }
+
+    /// Whether the Window can be transparent.
+    pub fn supports_transparency(&self) -> bool {
+        match self {
+            Self::Gl(driver) => driver.supports_transparency,
+            // The software buffer has no alpha channel
+            Self::Software(_) => false,
+        }
+    }
+
+    /// Surface where the frames are painted.
+    pub fn surface(&mut self) -> &mut Surface {
+        match self {
+            Self::Gl(driver) => &mut driver.surface,
+            Self::Software(driver) => &mut driver.surface,
+        }
+    }
+
+    /// Make the context of this Window the current one, needed when there are multiple windows.
+    pub fn make_current(&self) {
+        if let Self::Gl(driver) = self {
+            driver.make_current();
+        }
+    }
+
+    /// Wait or not for the display refresh before showing a frame, the software renderer never waits.
+    pub fn set_vsync(&self, vsync: bool) {
+        if let Self::Gl(driver) = self {
+            driver.set_vsync(vsync);
+        }
+    }
+
+    /// Show the painted frame in the Window.
+    pub fn present(&mut self) {
+        match self {
+            Self::Gl(driver) => driver.present(),
+            Self::Software(driver) => driver.present(),
+        }
+    }
+
+    /// Resize the surface, falling back to the software renderer if the GPU surface can't be recreated.
+    pub fn resize(&mut self, window: &Window, size: PhysicalSize<u32>) {
+        match self {
+            Self::Gl(driver) => {
+                if let Err(err) = driver.resize(window, size) {
+                    self.fall_back_to_software(window, &err.reason);
+                }
+            }
+            Self::Software(driver) => driver.resize(size),
+        }
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod test {
+    use dioxus::prelude::*;
+    use freya_common::EventMessage;
+    use freya_elements::elements as dioxus_elements;
+    use freya_engine::prelude::*;
+    use freya_testing::{launch_test, TestingHandler};
+    use winit::dpi::PhysicalSize;
+    use winit::event_loop::EventLoopBuilder;
+    use winit::platform::x11::EventLoopBuilderExtX11;
+    use winit::window::WindowBuilder;
+
+    use super::{GlDriver, GraphicsDriver, SoftwareDriver};
+
+    const SIZE: (i32, i32) = (500, 500);
+
+    /// Paint the app in a surface created by the driver, like the app surface of a Window.
+    fn render(driver: &mut GraphicsDriver, utils: &mut TestingHandler) -> Vec<u8> {
+        let mut surface = driver
+            .surface()
+            .new_surface_with_dimensions(SIZE)
+            .expect("Could not create the surface");
+        surface.canvas().clear(Color::WHITE);
+        utils.render_to_canvas(surface.canvas());
+
+        let image_info = ImageInfo::new(SIZE, ColorType::RGBA8888, AlphaType::Premul, None);
+        let mut pixels = vec![0; SIZE.0 as usize * SIZE.1 as usize * 4];
+        assert!(surface.read_pixels(&image_info, &mut pixels, SIZE.0 as usize * 4, (0, 0)));
+        pixels
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a display and a GPU"]
+    async fn gpu_and_software_render_the_same() {
+        fn drivers_app() -> Element {
+            rsx!(
+                rect {
+                    width: "200",
+                    height: "150",
+                    background: "rgb(0, 119, 182)",
+                    corner_radius: "12",
+                    border: "4 solid red",
+                    rect {
+                        width: "50%",
+                        height: "50%",
+                        background: "linear-gradient(red, blue)",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(drivers_app);
+        utils.wait_for_update().await;
+
+        // Tests don't run in the main thread
+        let event_loop = EventLoopBuilder::<EventMessage>::with_user_event()
+            .with_any_thread(true)
+            .build()
+            .expect("There is no display to create the Window");
+        let window_builder = WindowBuilder::new()
+            .with_visible(false)
+            .with_inner_size(PhysicalSize::new(SIZE.0 as u32, SIZE.1 as u32));
+
+        let (window, gl_driver) = GlDriver::new(&event_loop, window_builder, false, false)
+            .unwrap_or_else(|err| panic!("{}", err.reason));
+        let mut gl_driver = GraphicsDriver::Gl(Box::new(gl_driver));
+        let gpu_pixels = render(&mut gl_driver, &mut utils);
+
+        let software_driver =
+            SoftwareDriver::new(&window).unwrap_or_else(|err| panic!("{}", err.reason));
+        let mut software_driver = GraphicsDriver::Software(Box::new(software_driver));
+        let software_pixels = render(&mut software_driver, &mut utils);
+
+        // The anti-aliased edges might differ a bit between the GPU and the CPU
+        let different_pixels = gpu_pixels
+            .chunks(4)
+            .zip(software_pixels.chunks(4))
+            .filter(|(gpu, software)| {
+                gpu.iter()
+                    .zip(software.iter())
+                    .any(|(gpu, software)| gpu.abs_diff(*software) > 8)
+            })
+            .count();
+        assert!(
+            different_pixels < (SIZE.0 * SIZE.1) as usize / 100,
+            "{different_pixels} pixels are different"
+        );
+    }
+}
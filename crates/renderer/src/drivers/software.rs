@@ -0,0 +1,92 @@
+use std::num::NonZeroU32;
+
+use freya_engine::prelude::*;
+use winit::dpi::PhysicalSize;
+use winit::window::Window;
+
+use super::DriverError;
+
+/// Renders in the CPU and copies the pixels into the Window.
+pub struct SoftwareDriver {
+    pub(crate) surface: Surface,
+    pub(crate) buffer_surface: softbuffer::Surface,
+    // The context must outlive the surface
+    _buffer_context: softbuffer::Context,
+}
+
+impl SoftwareDriver {
+    /// Use the given Window to present the frames rendered in the CPU.
+    pub fn new(window: &Window) -> Result<Self, DriverError> {
+        let buffer_context = unsafe { softbuffer::Context::new(window) }.map_err(|err| {
+            DriverError::new(
+                None,
+                format!("Could not create the software context: {err}"),
+            )
+        })?;
+        let buffer_surface =
+            unsafe { softbuffer::Surface::new(&buffer_context, window) }.map_err(|err| {
+                DriverError::new(
+                    None,
+                    format!("Could not create the software surface: {err}"),
+                )
+            })?;
+
+        let mut driver = Self {
+            surface: create_surface(window.inner_size())?,
+            buffer_surface,
+            _buffer_context: buffer_context,
+        };
+        driver.resize(window.inner_size());
+
+        Ok(driver)
+    }
+
+    /// Copy the frame into the Window.
+    pub fn present(&mut self) {
+        let Ok(mut buffer) = self.buffer_surface.buffer_mut() else {
+            return;
+        };
+
+        let (width, height) = (self.surface.width(), self.surface.height());
+        // Skia writes the same `0xAARRGGBB` words in little endian that the Window buffer expects
+        let image_info = ImageInfo::new(
+            (width, height),
+            ColorType::BGRA8888,
+            AlphaType::Premul,
+            None,
+        );
+        let pixels = unsafe {
+            std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, buffer.len() * 4)
+        };
+        self.surface
+            .read_pixels(&image_info, pixels, width as usize * 4, (0, 0));
+
+        buffer.present().ok();
+    }
+
+    /// Resize the surface.
+    pub fn resize(&mut self, size: PhysicalSize<u32>) {
+        if let Ok(surface) = create_surface(size) {
+            self.surface = surface;
+        }
+
+        self.buffer_surface
+            .resize(
+                NonZeroU32::new(size.width.max(1)).unwrap(),
+                NonZeroU32::new(size.height.max(1)).unwrap(),
+            )
+            .ok();
+    }
+}
+
+/// Create the surface in memory for Skia to render in.
+fn create_surface(size: PhysicalSize<u32>) -> Result<Surface, DriverError> {
+    surfaces::raster_n32_premul((size.width.max(1) as i32, size.height.max(1) as i32)).ok_or_else(
+        || {
+            DriverError::new(
+                None,
+                "Could not create the software skia surface".to_string(),
+            )
+        },
+    )
+}
@@ -1,19 +1,26 @@
 use dioxus_native_core::real_dom::NodeImmutable;
+use freya_core::prelude::{ImagesCache, RenderSettings};
 use freya_dom::prelude::DioxusNode;
 use freya_engine::prelude::*;
 use freya_node_state::{References, Style};
 use torin::geometry::Area;
 
 /// Render an `image` element
-pub fn render_image(area: &Area, node_ref: &DioxusNode, canvas: &Canvas) {
+pub fn render_image(
+    area: &Area,
+    node_ref: &DioxusNode,
+    canvas: &Canvas,
+    images_cache: &mut ImagesCache,
+    render_settings: RenderSettings,
+) {
     let node_style = node_ref.get::<Style>().unwrap();
     let node_references = node_ref.get::<References>().unwrap();
 
-    let draw_img = |bytes: &[u8]| {
-        let pic = Image::from_encoded(unsafe { Data::new_bytes(bytes) });
+    let mut draw_img = |bytes: &[u8]| {
+        let pic = images_cache.image(bytes);
         if let Some(pic) = pic {
             let mut paint = Paint::default();
-            paint.set_anti_alias(true);
+            paint.set_anti_alias(render_settings.anti_aliasing.is_enabled());
             canvas.draw_image_nine(
                 pic,
                 IRect::new(0, 0, 0, 0),
@@ -1,6 +1,8 @@
-use freya_core::layout::create_label;
+use freya_core::paragraph_cache::ParagraphCache;
+use freya_core::render_settings::RenderSettings;
 use freya_dom::prelude::DioxusNode;
 use freya_engine::prelude::*;
+use freya_node_state::InteractionState;
 use torin::geometry::Area;
 
 /// Render a `label` element
@@ -9,8 +11,17 @@ pub fn render_label(
     node_ref: &DioxusNode,
     canvas: &Canvas,
     font_collection: &mut FontCollection,
+    interaction: InteractionState,
+    paragraph_cache: &mut ParagraphCache,
+    render_settings: RenderSettings,
 ) {
-    let paragraph = create_label(node_ref, area, font_collection);
+    let paragraph = paragraph_cache.label(
+        node_ref,
+        area,
+        font_collection,
+        interaction,
+        render_settings,
+    );
 
     let x = area.min_x();
     let y = area.min_y();
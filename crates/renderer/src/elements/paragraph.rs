@@ -1,8 +1,9 @@
 use dioxus_native_core::real_dom::NodeImmutable;
-use freya_core::layout::create_paragraph;
+use freya_core::paragraph_cache::ParagraphCache;
+use freya_core::render_settings::RenderSettings;
 use freya_dom::prelude::DioxusNode;
 use freya_engine::prelude::*;
-use freya_node_state::CursorSettings;
+use freya_node_state::{CursorSettings, InteractionState};
 use torin::geometry::Area;
 
 /// Render a `paragraph` element
@@ -11,15 +12,24 @@ pub fn render_paragraph(
     dioxus_node: &DioxusNode,
     canvas: &Canvas,
     font_collection: &mut FontCollection,
+    interaction: InteractionState,
+    paragraph_cache: &mut ParagraphCache,
+    render_settings: RenderSettings,
 ) {
     let (x, y) = area.origin.to_tuple();
-    let paragraph = create_paragraph(dioxus_node, area, font_collection, true);
+    let paragraph = paragraph_cache.paragraph(
+        dioxus_node,
+        area,
+        font_collection,
+        interaction,
+        render_settings,
+    );
 
     // Draw the highlights if specified
-    draw_cursor_highlights(area, &paragraph, canvas, dioxus_node);
+    draw_cursor_highlights(area, paragraph, canvas, dioxus_node, render_settings);
 
     // Draw a cursor if specified
-    draw_cursor(area, &paragraph, canvas, dioxus_node);
+    draw_cursor(area, paragraph, canvas, dioxus_node, render_settings);
 
     paragraph.paint(canvas, (x, y));
 }
@@ -29,6 +39,7 @@ fn draw_cursor_highlights(
     paragraph: &Paragraph,
     canvas: &Canvas,
     dioxus_node: &DioxusNode,
+    render_settings: RenderSettings,
 ) -> Option<()> {
     let node_cursor_settings = &*dioxus_node.get::<CursorSettings>().unwrap();
 
@@ -56,7 +67,7 @@ fn draw_cursor_highlights(
             let y2 = y + (cursor_rect.rect.bottom - cursor_rect.rect.top);
 
             let mut paint = Paint::default();
-            paint.set_anti_alias(true);
+            paint.set_anti_alias(render_settings.anti_aliasing.is_enabled());
             paint.set_style(PaintStyle::Fill);
             paint.set_color(highlight_color);
 
@@ -72,6 +83,7 @@ fn draw_cursor(
     paragraph: &Paragraph,
     canvas: &Canvas,
     dioxus_node: &DioxusNode,
+    render_settings: RenderSettings,
 ) -> Option<()> {
     let node_cursor_settings = &*dioxus_node.get::<CursorSettings>().unwrap();
 
@@ -93,7 +105,7 @@ fn draw_cursor(
     let y2 = y + (cursor_rect.rect.bottom - cursor_rect.rect.top);
 
     let mut paint = Paint::default();
-    paint.set_anti_alias(true);
+    paint.set_anti_alias(render_settings.anti_aliasing.is_enabled());
     paint.set_style(PaintStyle::Fill);
     paint.set_color(cursor_color);
 
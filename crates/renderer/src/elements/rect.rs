@@ -1,45 +1,67 @@
 use dioxus_native_core::real_dom::NodeImmutable;
+use freya_core::prelude::{ImagesCache, RenderSettings};
 use freya_dom::prelude::DioxusNode;
 use freya_engine::prelude::*;
-use freya_node_state::{BorderAlignment, BorderStyle, Fill, References, ShadowPosition, Style};
+use freya_node_state::{
+    BorderAlignment, BorderStyle, Fill, InteractionState, References, ShadowPosition, Style,
+};
 use torin::prelude::Area;
 
+/// Paint the given fill in the given area.
+fn set_fill(paint: &mut Paint, fill: &Fill, area: Area, images_cache: &mut ImagesCache) {
+    match fill {
+        Fill::Color(color) => {
+            paint.set_color(*color);
+        }
+        Fill::LinearGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(area));
+        }
+        Fill::Image(image_fill) => {
+            // Images that can't be decoded are not painted
+            let shader = images_cache
+                .image(image_fill.data.as_slice())
+                .and_then(|image| image_fill.into_shader(&image, area));
+            if shader.is_none() {
+                paint.set_color(Color::TRANSPARENT);
+            }
+            paint.set_shader(shader);
+        }
+    }
+}
+
 /// Render a `rect` element
 pub fn render_rect(
     area: &Area,
     node_ref: &DioxusNode,
     canvas: &Canvas,
     font_collection: &mut FontCollection,
+    interaction: InteractionState,
+    images_cache: &mut ImagesCache,
+    render_settings: RenderSettings,
 ) {
+    let anti_alias = render_settings.anti_aliasing.is_enabled();
     let node_style = &*node_ref.get::<Style>().unwrap();
 
     let mut paint = Paint::default();
     let mut path = Path::new();
     let area = area.to_f32();
 
-    paint.set_anti_alias(true);
+    paint.set_anti_alias(anti_alias);
     paint.set_style(PaintStyle::Fill);
 
     let area = area.to_f32();
 
-    match &node_style.background {
-        Fill::Color(color) => {
-            paint.set_color(*color);
-        }
-        Fill::LinearGradient(gradient) => {
-            paint.set_shader(gradient.into_shader(area));
-        }
-    }
+    set_fill(
+        &mut paint,
+        node_style.current_background(interaction),
+        area,
+        images_cache,
+    );
 
-    let radius = node_style.corner_radius;
+    // Skia scales down the radii that don't fit in the rect
     let rounded_rect = RRect::new_rect_radii(
         Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
-        &[
-            (radius.top_left, radius.top_left).into(),
-            (radius.top_right, radius.top_right).into(),
-            (radius.bottom_right, radius.bottom_right).into(),
-            (radius.bottom_left, radius.bottom_left).into(),
-        ],
+        &node_style.corner_radius.radii(),
     );
 
     if node_style.corner_radius.smoothing > 0.0 {
@@ -60,14 +82,7 @@ pub fn render_rect(
             let mut shadow_paint = paint.clone();
             let mut shadow_path = Path::new();
 
-            match &shadow.fill {
-                Fill::Color(color) => {
-                    shadow_paint.set_color(*color);
-                }
-                Fill::LinearGradient(gradient) => {
-                    shadow_paint.set_shader(gradient.into_shader(area));
-                }
-            }
+            set_fill(&mut shadow_paint, &shadow.fill, area, images_cache);
 
             // Shadows can be either outset or inset
             // If they are outset, we fill a copy of the path outset by spread_radius, and blur it.
@@ -117,7 +132,7 @@ pub fn render_rect(
                     ShadowPosition::Normal => ClipOp::Difference,
                     ShadowPosition::Inset => ClipOp::Intersect,
                 },
-                true,
+                anti_alias,
             );
             canvas.draw_path(&shadow_path, &shadow_paint);
             canvas.restore();
@@ -131,16 +146,14 @@ pub fn render_rect(
         let mut border_path = Path::new();
 
         // Setup paint params
-        border_paint.set_anti_alias(true);
+        border_paint.set_anti_alias(anti_alias);
         border_paint.set_style(PaintStyle::Stroke);
-        match &node_style.border.fill {
-            Fill::Color(color) => {
-                border_paint.set_color(*color);
-            }
-            Fill::LinearGradient(gradient) => {
-                border_paint.set_shader(gradient.into_shader(area));
-            }
-        }
+        set_fill(
+            &mut border_paint,
+            &node_style.border.fill,
+            area,
+            images_cache,
+        );
         border_paint.set_stroke_width(node_style.border.width);
 
         // Skia draws strokes centered on the edge of the path. This means that half of the stroke is inside the path, and half outside.
@@ -171,6 +184,20 @@ pub fn render_rect(
     let references = node_ref.get::<References>().unwrap();
 
     if let Some(canvas_ref) = &references.canvas_ref {
-        (canvas_ref.runner)(canvas, font_collection, area);
+        if canvas_ref.local {
+            let local_area = Area::from_size(area.size);
+
+            canvas.save();
+            canvas.translate((area.min_x(), area.min_y()));
+            canvas.clip_rect(
+                Rect::new(0.0, 0.0, area.width(), area.height()),
+                ClipOp::Intersect,
+                anti_alias,
+            );
+            (canvas_ref.runner)(canvas, font_collection, local_area);
+            canvas.restore();
+        } else {
+            (canvas_ref.runner)(canvas, font_collection, area);
+        }
     }
 }
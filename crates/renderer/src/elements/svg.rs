@@ -1,23 +1,45 @@
+use std::borrow::Cow;
+
 use dioxus_native_core::real_dom::NodeImmutable;
+use freya_core::prelude::ImagesCache;
 use freya_dom::prelude::DioxusNode;
 use freya_engine::prelude::*;
 use freya_node_state::Style;
 use torin::geometry::Area;
 
 /// Render a `svg` element
-pub fn render_svg(area: &Area, node_ref: &DioxusNode, canvas: &Canvas, font_manager: &FontMgr) {
+pub fn render_svg(
+    area: &Area,
+    node_ref: &DioxusNode,
+    canvas: &Canvas,
+    font_manager: &FontMgr,
+    images_cache: &mut ImagesCache,
+) {
     let node_style = &*node_ref.get::<Style>().unwrap();
 
     let x = area.min_x();
     let y = area.min_y();
     if let Some(svg_data) = &node_style.svg_data {
-        let svg_dom = svg::Dom::from_bytes(svg_data.as_slice(), font_manager);
-        if let Ok(mut svg_dom) = svg_dom {
-            canvas.save();
-            canvas.translate((x, y));
-            svg_dom.set_container_size((area.width() as i32, area.height() as i32));
-            svg_dom.render(canvas);
-            canvas.restore();
+        let size = (area.width().ceil() as i32, area.height().ceil() as i32);
+        let source = (
+            svg_data.as_slice(),
+            node_style.svg_colors,
+            node_style.svg_fit,
+        );
+        // The SVG is only restyled and parsed again when it's not cached
+        let image = images_cache.svg(source, size, |svg_canvas| {
+            let mut svg_data = node_style.svg_colors.apply(svg_data.as_slice());
+            if let Some(svg_fit) = node_style.svg_fit {
+                svg_data = Cow::Owned(svg_fit.apply(&svg_data).into_owned());
+            }
+            let svg_dom = svg::Dom::from_bytes(&svg_data, font_manager);
+            if let Ok(mut svg_dom) = svg_dom {
+                svg_dom.set_container_size(size);
+                svg_dom.render(svg_canvas);
+            }
+        });
+        if let Some(image) = image {
+            canvas.draw_image(&image, Point::new(x, y), None);
         }
     }
 }
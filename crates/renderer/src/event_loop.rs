@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
 use accesskit::Action;
 use accesskit_winit::ActionRequestEvent;
 use freya_common::EventMessage;
@@ -5,16 +8,19 @@ use freya_core::prelude::*;
 use freya_elements::events::keyboard::{
     map_winit_key, map_winit_modifiers, map_winit_physical_key, Code, Key,
 };
+use freya_elements::events::KeyboardData;
 use torin::geometry::CursorPoint;
 use winit::event::{
     ElementState, Event, Ime, KeyEvent, MouseScrollDelta, StartCause, Touch, TouchPhase,
     WindowEvent,
 };
-use winit::event_loop::{EventLoop, EventLoopProxy};
-use winit::keyboard::{KeyCode, ModifiersState, PhysicalKey};
+use winit::event_loop::{ControlFlow, EventLoop, EventLoopProxy, EventLoopWindowTarget};
+use winit::keyboard::{KeyCode, PhysicalKey};
+use winit::window::{Fullscreen, Window, WindowId};
 
 use crate::app::App;
-use crate::HoveredNode;
+use crate::tray::create_tray;
+use crate::{HoveredNode, TrayAction, TrayConfig};
 
 // https://github.com/emilk/egui/issues/461
 // https://github.com/rust-windowing/winit/issues/22
@@ -26,197 +32,402 @@ pub fn run_event_loop<State: Clone>(
     event_loop: EventLoop<EventMessage>,
     proxy: EventLoopProxy<EventMessage>,
     hovered_node: HoveredNode,
+    tray_config: Option<TrayConfig>,
 ) {
-    let mut cursor_pos = CursorPoint::default();
-    let mut modifiers_state = ModifiersState::empty();
+    let main_window = app.window_env.window.id();
 
     app.window_env.run_on_setup();
 
+    let mut apps = HashMap::from([(main_window, app)]);
+    let mut tray = None;
+
     event_loop
         .run(move |event, event_loop| match event {
             Event::NewEvents(StartCause::Init) => {
+                // Sleep until a mutation, an event or an animation requests a new frame
+                event_loop.set_control_flow(ControlFlow::Wait);
+
                 _ = proxy.send_event(EventMessage::PollVDOM);
-            }
-            Event::UserEvent(EventMessage::FocusAccessibilityNode(id)) => {
-                app.accessibility
-                    .set_accessibility_focus(id, &app.window_env.window);
-            }
-            Event::UserEvent(EventMessage::RequestRerender) => {
-                app.window_env.window.request_redraw();
-            }
-            Event::UserEvent(EventMessage::RemeasureTextGroup(text_id)) => {
-                app.measure_text_group(&text_id);
-            }
-            Event::UserEvent(EventMessage::ActionRequestEvent(ActionRequestEvent {
-                request,
-                ..
-            })) => {
-                if Action::Focus == request.action {
-                    app.accessibility
-                        .set_accessibility_focus(request.target, &app.window_env.window);
-                }
-            }
-            Event::UserEvent(EventMessage::SetCursorIcon(icon)) => {
-                app.window_env.window.set_cursor_icon(icon)
-            }
-            Event::UserEvent(ev) => {
-                if let EventMessage::UpdateTemplate(template) = ev {
-                    app.vdom_replace_template(template);
-                }
 
-                if matches!(ev, EventMessage::PollVDOM)
-                    || matches!(ev, EventMessage::UpdateTemplate(_))
-                {
-                    app.poll_vdom();
+                // The tray must be created once the event loop is running
+                if let Some(tray_config) = &tray_config {
+                    tray = create_tray(tray_config, proxy.clone());
                 }
             }
-            Event::WindowEvent { event, .. } => {
-                app.accessibility
-                    .process_accessibility_event(&event, &app.window_env.window);
-                match event {
-                    WindowEvent::CloseRequested => event_loop.exit(),
-                    WindowEvent::Ime(Ime::Commit(text)) => {
-                        app.send_event(PlatformEvent::Keyboard {
-                            name: EventName::KeyDown,
-                            key: Key::Character(text),
-                            code: Code::Unidentified,
-                            modifiers: map_winit_modifiers(modifiers_state),
-                        });
-                    }
-                    WindowEvent::RedrawRequested => {
-                        if app.measure_layout_on_next_render {
-                            app.process_layout();
+            Event::UserEvent(message) => {
+                // Messages that don't come from a specific window are for the main window
+                let (window_id, message) = match message {
+                    EventMessage::WindowMessage(window_id, message) => (window_id, *message),
+                    message => (main_window, message),
+                };
 
-                            app.measure_layout_on_next_render = false;
+                match message {
+                    EventMessage::UpdateTemplate(template) => {
+                        for app in apps.values_mut() {
+                            app.vdom_replace_template(template);
+                            app.poll_vdom();
                         }
-                        app.render(&hovered_node);
-                        app.event_loop_tick();
                     }
-                    WindowEvent::MouseInput { state, button, .. } => {
-                        app.set_navigation_mode(NavigationMode::NotKeyboard);
-
-                        let name = match state {
-                            ElementState::Pressed => EventName::MouseDown,
-                            ElementState::Released => EventName::Click,
-                        };
-
-                        app.send_event(PlatformEvent::Mouse {
-                            name,
-                            cursor: cursor_pos,
-                            button: Some(button),
-                        });
+                    EventMessage::UpdateStyleSheet(source) => {
+                        for app in apps.values_mut() {
+                            app.set_stylesheet(&source);
+
+                            let (must_repaint, must_relayout) = app.apply_vdom_changes();
+                            if must_relayout {
+                                app.measure_layout_on_next_render = true;
+                            }
+                            if must_relayout || must_repaint {
+                                app.request_redraw();
+                            }
+                        }
                     }
-                    WindowEvent::MouseWheel { delta, phase, .. } => {
-                        if TouchPhase::Moved == phase {
-                            let scroll_data = {
-                                match delta {
-                                    MouseScrollDelta::LineDelta(x, y) => (
-                                        (x * WHEEL_SPEED_MODIFIER) as f64,
-                                        (y * WHEEL_SPEED_MODIFIER) as f64,
-                                    ),
-                                    MouseScrollDelta::PixelDelta(pos) => (pos.x, pos.y),
-                                }
-                            };
-
-                            app.send_event(PlatformEvent::Wheel {
-                                name: EventName::Wheel,
-                                scroll: CursorPoint::from(scroll_data),
-                                cursor: cursor_pos,
-                            });
+                    EventMessage::NewWindow(new_window) => {
+                        if let Some(main_app) = apps.get(&main_window) {
+                            let app = main_app.new_window(new_window, event_loop);
+                            let window_id = app.window_env.window.id();
+                            apps.insert(window_id, app);
+
+                            _ = proxy.send_event(EventMessage::WindowMessage(
+                                window_id,
+                                Box::new(EventMessage::PollVDOM),
+                            ));
                         }
                     }
-                    WindowEvent::ModifiersChanged(modifiers) => {
-                        modifiers_state = modifiers.state();
+                    EventMessage::ActionRequestEvent(ActionRequestEvent { request, window_id }) => {
+                        if let Some(app) = apps.get_mut(&window_id) {
+                            if Action::Focus == request.action {
+                                app.accessibility.set_accessibility_focus(
+                                    request.target,
+                                    &app.window_env.window,
+                                );
+                            }
+                        }
                     }
-                    WindowEvent::KeyboardInput {
-                        event:
-                            KeyEvent {
-                                physical_key,
-                                logical_key,
-                                state,
-                                ..
-                            },
-                        ..
-                    } => {
-                        if state == ElementState::Pressed
-                            && physical_key == PhysicalKey::Code(KeyCode::Tab)
-                        {
-                            app.set_navigation_mode(NavigationMode::Keyboard);
-
-                            let direction = if modifiers_state.shift_key() {
-                                AccessibilityFocusDirection::Backward
-                            } else {
-                                AccessibilityFocusDirection::Forward
-                            };
-
-                            app.focus_next_node(direction);
-
-                            return;
+                    EventMessage::TrayMenuClicked(id) => {
+                        let action = tray_config
+                            .as_ref()
+                            .and_then(|tray_config| tray_config.action(&id));
+
+                        if let Some(action) = action {
+                            if let Some(app) = apps.get(&main_window) {
+                                run_tray_action(action, &app.window_env.window, event_loop);
+                            }
                         }
 
-                        let name = match state {
-                            ElementState::Pressed => EventName::KeyDown,
-                            ElementState::Released => EventName::KeyUp,
-                        };
-                        app.send_event(PlatformEvent::Keyboard {
-                            name,
-                            key: map_winit_key(&logical_key),
-                            code: map_winit_physical_key(&physical_key),
-                            modifiers: map_winit_modifiers(modifiers_state),
-                        })
+                        for app in apps.values() {
+                            app.tray_menu_events.emit(id.clone());
+                        }
                     }
-                    WindowEvent::CursorLeft { .. } => {
-                        cursor_pos = CursorPoint::new(-1.0, -1.0);
-
-                        app.send_event(PlatformEvent::Mouse {
-                            name: EventName::MouseOver,
-                            cursor: cursor_pos,
-                            button: None,
-                        });
+                    EventMessage::CloseWindow => {
+                        close_window(&mut apps, window_id, main_window, event_loop)
                     }
-                    WindowEvent::CursorMoved { position, .. } => {
-                        cursor_pos = CursorPoint::from((position.x, position.y));
-
-                        app.send_event(PlatformEvent::Mouse {
-                            name: EventName::MouseOver,
-                            cursor: cursor_pos,
-                            button: None,
-                        });
+                    message => {
+                        if let Some(app) = apps.get_mut(&window_id) {
+                            handle_window_message(app, message);
+                        }
                     }
-                    WindowEvent::Touch(Touch {
-                        location,
-                        phase,
-                        id,
-                        force,
-                        ..
-                    }) => {
-                        cursor_pos = CursorPoint::from((location.x, location.y));
-
-                        let name = match phase {
-                            TouchPhase::Cancelled => EventName::TouchCancel,
-                            TouchPhase::Ended => EventName::TouchEnd,
-                            TouchPhase::Moved => EventName::TouchMove,
-                            TouchPhase::Started => EventName::TouchStart,
-                        };
-
-                        app.send_event(PlatformEvent::Touch {
-                            name,
-                            location: cursor_pos,
-                            finger_id: id,
-                            phase,
-                            force,
-                        });
+                }
+            }
+            Event::WindowEvent { window_id, event } => {
+                if matches!(event, WindowEvent::CloseRequested) {
+                    close_window(&mut apps, window_id, main_window, event_loop);
+                } else if let Some(app) = apps.get_mut(&window_id) {
+                    // Devtools only inspect the main window
+                    let hovered_node = if window_id == main_window {
+                        &hovered_node
+                    } else {
+                        &None
+                    };
+                    handle_window_event(app, event, hovered_node);
+                }
+            }
+            Event::AboutToWait => {
+                // Only the windows that changed since their last frame are rendered,
+                // the frames deferred by the frame rate cap or the paused pixel snapping wait for their time
+                let now = Instant::now();
+                let mut next_frame_at = None;
+                for app in apps.values_mut() {
+                    match app.deferred_frame_at {
+                        Some(frame_at) if frame_at <= now => {
+                            app.deferred_frame_at = None;
+                            app.needs_redraw = true;
+                        }
+                        Some(frame_at) => {
+                            next_frame_at = Some(
+                                next_frame_at.map_or(frame_at, |next: Instant| next.min(frame_at)),
+                            );
+                            continue;
+                        }
+                        None => {}
                     }
-                    WindowEvent::Resized(size) => {
-                        app.resize(size);
+
+                    if app.needs_redraw {
+                        app.window_env.window.request_redraw();
                     }
-                    _ => {}
                 }
+
+                event_loop.set_control_flow(match next_frame_at {
+                    Some(next_frame_at) => ControlFlow::WaitUntil(next_frame_at),
+                    None => ControlFlow::Wait,
+                });
             }
             Event::LoopExiting => {
-                app.window_env.run_on_exit();
+                tray.take();
+
+                for app in apps.values_mut() {
+                    app.window_env.save_geometry();
+                    app.window_env.run_on_exit();
+                }
             }
             _ => (),
         })
         .expect("Failed to run Eventloop.");
 }
+
+/// Close the given window, closing the main window exits the app.
+fn close_window<State: Clone>(
+    apps: &mut HashMap<WindowId, App<State>>,
+    window_id: WindowId,
+    main_window: WindowId,
+    event_loop: &EventLoopWindowTarget<EventMessage>,
+) {
+    if window_id == main_window {
+        event_loop.exit();
+    } else if let Some(mut app) = apps.remove(&window_id) {
+        app.window_env.run_on_exit();
+    }
+}
+
+/// Run the action of a clicked tray menu item on the main window.
+fn run_tray_action(
+    action: TrayAction,
+    window: &Window,
+    event_loop: &EventLoopWindowTarget<EventMessage>,
+) {
+    let show = match action {
+        TrayAction::ShowWindow => true,
+        TrayAction::HideWindow => false,
+        TrayAction::ToggleWindow => !window.is_visible().unwrap_or(true),
+        TrayAction::Quit => {
+            event_loop.exit();
+            return;
+        }
+        TrayAction::Custom => return,
+    };
+
+    window.set_visible(show);
+    if show {
+        window.focus_window();
+    }
+}
+
+/// Handle a message sent to a window.
+fn handle_window_message<State: Clone>(app: &mut App<State>, message: EventMessage) {
+    match message {
+        EventMessage::FocusAccessibilityNode(id) => {
+            app.accessibility
+                .set_accessibility_focus(id, &app.window_env.window);
+        }
+        EventMessage::RequestRerender => {
+            app.animation_frame_requested = true;
+            app.dirty_region.invalidate_all();
+            app.request_redraw();
+        }
+        EventMessage::RemeasureTextGroup(text_id) => {
+            app.measure_text_group(&text_id);
+        }
+        EventMessage::SetCursorIcon(icon) => app.window_env.window.set_cursor_icon(icon),
+        EventMessage::SetCustomCursor(cursor) => app.set_custom_cursor(cursor),
+        EventMessage::ReleasePointerCapture => app.nodes_state.release_pointer_capture(),
+        EventMessage::SetWindowTitle(title) => app.window_env.window.set_title(&title),
+        EventMessage::MinimizeWindow => app.window_env.window.set_minimized(true),
+        EventMessage::ToggleMaximizeWindow => {
+            let window = &app.window_env.window;
+            window.set_maximized(!window.is_maximized())
+        }
+        EventMessage::ToggleFullscreenWindow => {
+            let window = &app.window_env.window;
+            if window.fullscreen().is_some() {
+                window.set_fullscreen(None)
+            } else {
+                window.set_fullscreen(Some(Fullscreen::Borderless(None)))
+            }
+        }
+        EventMessage::PollVDOM => app.poll_vdom(),
+        EventMessage::DispatchPluginEvent => app.dispatch_plugin_event(),
+        EventMessage::Screenshot(sender) => {
+            _ = sender.send(app.window_env.screenshot());
+        }
+        EventMessage::ExportNode(request) => app.export_node(request),
+        _ => {}
+    }
+}
+
+/// Handle an event of a window.
+fn handle_window_event<State: Clone>(
+    app: &mut App<State>,
+    event: WindowEvent,
+    hovered_node: &HoveredNode,
+) {
+    app.accessibility
+        .process_accessibility_event(&event, &app.window_env.window);
+    match event {
+        WindowEvent::Ime(Ime::Commit(text)) => {
+            app.send_event(PlatformEvent::Keyboard {
+                name: EventName::KeyDown,
+                key: Key::Character(text),
+                code: Code::Unidentified,
+                modifiers: map_winit_modifiers(app.modifiers_state),
+            });
+        }
+        WindowEvent::RedrawRequested => {
+            // Rendered once the frame rate cap allows it
+            if app.must_defer_frame() {
+                return;
+            }
+            app.render_frame(hovered_node);
+            app.event_loop_tick();
+        }
+        WindowEvent::MouseInput { state, button, .. } => {
+            app.set_navigation_mode(NavigationMode::NotKeyboard);
+
+            let name = match state {
+                ElementState::Pressed => EventName::MouseDown,
+                ElementState::Released => EventName::Click,
+            };
+
+            app.send_event(PlatformEvent::Mouse {
+                name,
+                cursor: app.cursor_pos,
+                button: Some(button),
+            });
+        }
+        WindowEvent::MouseWheel { delta, phase, .. } => {
+            if TouchPhase::Moved == phase {
+                let scroll_data = {
+                    match delta {
+                        MouseScrollDelta::LineDelta(x, y) => (
+                            (x * WHEEL_SPEED_MODIFIER) as f64,
+                            (y * WHEEL_SPEED_MODIFIER) as f64,
+                        ),
+                        MouseScrollDelta::PixelDelta(pos) => (pos.x, pos.y),
+                    }
+                };
+
+                app.send_event(PlatformEvent::Wheel {
+                    name: EventName::Wheel,
+                    scroll: CursorPoint::from(scroll_data),
+                    cursor: app.cursor_pos,
+                });
+            }
+        }
+        WindowEvent::ModifiersChanged(modifiers) => {
+            app.modifiers_state = modifiers.state();
+        }
+        WindowEvent::KeyboardInput {
+            event:
+                KeyEvent {
+                    physical_key,
+                    logical_key,
+                    state,
+                    ..
+                },
+            ..
+        } => {
+            if state == ElementState::Pressed && physical_key == PhysicalKey::Code(KeyCode::Tab) {
+                app.set_navigation_mode(NavigationMode::Keyboard);
+
+                let direction = if app.modifiers_state.shift_key() {
+                    AccessibilityFocusDirection::Backward
+                } else {
+                    AccessibilityFocusDirection::Forward
+                };
+
+                app.focus_next_node(direction);
+
+                return;
+            }
+
+            let name = match state {
+                ElementState::Pressed => EventName::KeyDown,
+                ElementState::Released => EventName::KeyUp,
+            };
+            let key = map_winit_key(&logical_key);
+            let code = map_winit_physical_key(&physical_key);
+            let modifiers = map_winit_modifiers(app.modifiers_state);
+
+            // Let the hotkeys know about the key press, they might prevent the keydown event
+            if name == EventName::KeyDown {
+                let data = KeyboardData::new(key.clone(), code, modifiers);
+                if app.hotkeys.process(&data) {
+                    return;
+                }
+            }
+
+            app.send_event(PlatformEvent::Keyboard {
+                name,
+                key,
+                code,
+                modifiers,
+            })
+        }
+        WindowEvent::CursorLeft { .. } => {
+            app.cursor_pos = CursorPoint::new(-1.0, -1.0);
+
+            if app.custom_cursor.is_some() {
+                app.request_redraw();
+            }
+
+            app.send_event(PlatformEvent::Mouse {
+                name: EventName::MouseOver,
+                cursor: app.cursor_pos,
+                button: None,
+            });
+        }
+        WindowEvent::CursorMoved { position, .. } => {
+            app.cursor_pos = CursorPoint::from((position.x, position.y));
+
+            // Redraw the custom cursor in its new position
+            if app.custom_cursor.is_some() {
+                app.request_redraw();
+            }
+
+            app.send_event(PlatformEvent::Mouse {
+                name: EventName::MouseOver,
+                cursor: app.cursor_pos,
+                button: None,
+            });
+        }
+        WindowEvent::Touch(Touch {
+            location,
+            phase,
+            id,
+            force,
+            ..
+        }) => {
+            app.cursor_pos = CursorPoint::from((location.x, location.y));
+
+            let name = match phase {
+                TouchPhase::Cancelled => EventName::TouchCancel,
+                TouchPhase::Ended => EventName::TouchEnd,
+                TouchPhase::Moved => EventName::TouchMove,
+                TouchPhase::Started => EventName::TouchStart,
+            };
+
+            app.send_event(PlatformEvent::Touch {
+                name,
+                location: app.cursor_pos,
+                finger_id: id,
+                phase,
+                force,
+            });
+        }
+        WindowEvent::Resized(size) => {
+            app.resize(size);
+        }
+        WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+            app.scale_factor_changed(scale_factor);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use dioxus_native_core::real_dom::NodeImmutable;
+use dioxus_native_core::NodeId;
+use freya_common::{ExportFormat, NodeReferenceLayout};
+use freya_core::prelude::*;
+use freya_dom::prelude::FreyaDOM;
+use freya_engine::prelude::*;
+use freya_node_state::LayoutState;
+use tokio::sync::watch;
+
+use crate::render_skia;
+
+/// Points in a layout pixel when printing at 96 DPI.
+const POINTS_PER_PIXEL: f32 = 72.0 / 96.0;
+
+/// Find the Node to export, the one with the given `reference` or the root Node for the whole window.
+pub fn find_export_node(
+    fdom: &FreyaDOM,
+    reference: Option<&Arc<watch::Sender<NodeReferenceLayout>>>,
+) -> Option<NodeId> {
+    match reference {
+        Some(reference) => find_node_by_reference(fdom, reference),
+        None => Some(fdom.rdom().root_id()),
+    }
+}
+
+/// Find the Node that has the given `reference`.
+pub fn find_node_by_reference(
+    fdom: &FreyaDOM,
+    reference: &Arc<watch::Sender<NodeReferenceLayout>>,
+) -> Option<NodeId> {
+    let mut found = None;
+    fdom.rdom().traverse_depth_first(|node| {
+        if found.is_some() {
+            return;
+        }
+        let layout = node.get::<LayoutState>().unwrap();
+        if let Some(node_ref) = &layout.node_ref {
+            if Arc::ptr_eq(&node_ref.0, reference) {
+                found = Some(node.id());
+            }
+        }
+    });
+    found
+}
+
+/// Paint a Node and its descendants into a vector document of the given format, with the size of the Node.
+///
+/// The Nodes are painted the same way as in the window, but without the Nodes outside the subtree.
+/// Shapes and text are kept as vectors, raster images are embedded as they are decoded.
+#[allow(clippy::too_many_arguments)]
+pub fn export_node(
+    node_id: NodeId,
+    format: ExportFormat,
+    fdom: &FreyaDOM,
+    layers: &Layers,
+    viewports: &Viewports,
+    nodes_state: &NodesState,
+    font_collection: &mut FontCollection,
+    font_mgr: &FontMgr,
+    images_cache: &mut ImagesCache,
+    paragraph_cache: &mut ParagraphCache,
+    render_settings: RenderSettings,
+) -> Option<Vec<u8>> {
+    let area = fdom.layout().get(node_id)?.visible_area();
+    let bounds = Rect::new(0.0, 0.0, area.width(), area.height());
+
+    let mut subtree = HashSet::new();
+    let mut pending = vec![fdom.rdom().get(node_id)?];
+    while let Some(node) = pending.pop() {
+        subtree.insert(node.id());
+        pending.extend(node.children());
+    }
+
+    let paint = |canvas: &Canvas,
+                 font_collection: &mut FontCollection,
+                 images_cache: &mut ImagesCache,
+                 paragraph_cache: &mut ParagraphCache| {
+        canvas.translate((-area.min_x(), -area.min_y()));
+
+        let mut matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
+        let mut opacities: Vec<(f32, Vec<NodeId>)> = Vec::default();
+
+        process_render(
+            viewports,
+            fdom,
+            font_collection,
+            layers,
+            &mut (
+                canvas,
+                &mut matrices,
+                &mut opacities,
+                images_cache,
+                paragraph_cache,
+            ),
+            |dom,
+             node_id,
+             area,
+             font_collection,
+             viewports,
+             (canvas, matrices, opacities, images_cache, paragraph_cache)| {
+                if !subtree.contains(node_id) {
+                    return;
+                }
+                if let Some(dioxus_node) = dom.rdom().get(*node_id) {
+                    render_skia(
+                        canvas,
+                        area,
+                        &dioxus_node,
+                        nodes_state.interaction(node_id),
+                        font_collection,
+                        font_mgr,
+                        viewports,
+                        false,
+                        matrices,
+                        opacities,
+                        images_cache,
+                        paragraph_cache,
+                        render_settings,
+                    );
+                }
+            },
+        );
+    };
+
+    let data = match format {
+        ExportFormat::Svg => {
+            let canvas = svg::Canvas::new(bounds, None);
+            paint(&canvas, font_collection, images_cache, paragraph_cache);
+            canvas.end()
+        }
+        ExportFormat::Pdf => {
+            let mut document =
+                pdf::new_document(None).begin_page((area.width(), area.height()), None);
+            paint(
+                document.canvas(),
+                font_collection,
+                images_cache,
+                paragraph_cache,
+            );
+            document.end_page().close()
+        }
+        ExportFormat::PdfPage(page) => {
+            let (page_width, page_height) = page.page_size();
+            let available_width = (page_width - page.margin * 2.0).max(0.0);
+            let available_height = (page_height - page.margin * 2.0).max(0.0);
+            let scale = POINTS_PER_PIXEL
+                .min(available_width / area.width())
+                .min(available_height / area.height());
+
+            let mut document = pdf::new_document(None).begin_page((page_width, page_height), None);
+            let canvas = document.canvas();
+            canvas.translate((page.margin, page.margin));
+            canvas.scale((scale, scale));
+            paint(canvas, font_collection, images_cache, paragraph_cache);
+            document.end_page().close()
+        }
+    };
+
+    Some(data.as_bytes().to_vec())
+}
@@ -3,26 +3,40 @@ pub use config::*;
 use dioxus_core::VirtualDom;
 use dioxus_native_core::NodeId;
 use freya_common::EventMessage;
+use freya_core::prelude::DomEvent;
 use freya_dom::prelude::SafeDOM;
 use std::sync::{Arc, Mutex};
-use tokio::sync::Notify;
+use tokio::sync::{broadcast, Notify};
 use winit::event_loop::EventLoopBuilder;
 
 pub use config::WindowConfig;
+pub use export::{export_node, find_export_node, find_node_by_reference};
+pub use freya_core::render_settings::{AntiAliasing, RenderSettings};
+pub use renderer::render_skia;
+pub use tray::{TrayAction, TrayConfig, TrayMenuItem};
 pub use window::WindowEnv;
 
 mod accessibility;
 mod app;
 mod config;
+mod dirty_region;
+mod drivers;
 mod elements;
 mod event_loop;
+mod export;
 mod renderer;
+mod stylesheet;
+mod tray;
 mod window;
+mod window_geometry;
 mod winit_waker;
 mod wireframe;
 
 pub type HoveredNode = Option<Arc<Mutex<Option<NodeId>>>>;
 
+/// Receives a copy of the events dispatched to the VirtualDOM, used by the devtools.
+pub type EventsLog = Option<broadcast::Sender<DomEvent>>;
+
 /// Desktop renderer for Freya.
 pub struct DesktopRenderer;
 
@@ -33,6 +47,7 @@ impl DesktopRenderer {
         config: LaunchConfig<T>,
         mutations_notifier: Option<Arc<Notify>>,
         hovered_node: HoveredNode,
+        events_log: EventsLog,
     ) {
         let rt = tokio::runtime::Builder::new_multi_thread()
             .enable_all()
@@ -66,13 +81,23 @@ impl DesktopRenderer {
             vdom,
             &proxy,
             mutations_notifier,
+            events_log,
             window_env,
             config.fonts,
             config.plugins,
         );
 
+        // Styles of the `class` attribute, the stylesheet file is reloaded when it changes
+        app.set_styles(config.styles);
+        if let Some(path) = config.stylesheet {
+            if let Ok(source) = std::fs::read_to_string(&path) {
+                app.set_stylesheet(&source);
+            }
+            stylesheet::watch_stylesheet(path, proxy.clone());
+        }
+
         app.init_doms();
         app.process_layout();
-        app.run(event_loop, proxy, hovered_node)
+        app.run(event_loop, proxy, hovered_node, config.tray)
     }
 }
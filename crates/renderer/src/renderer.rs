@@ -5,22 +5,53 @@ use dioxus_native_core::NodeId;
 use freya_core::prelude::*;
 use freya_dom::prelude::DioxusNode;
 use freya_engine::prelude::*;
-use freya_node_state::{Style, Transform};
+use freya_node_state::{CornerRadius, InteractionState, Style, Transform};
 use torin::geometry::Area;
 
 use crate::elements::{render_image, render_label, render_paragraph, render_rect, render_svg};
 
-fn clip_viewport(canvas: &Canvas, viewport: &Area) {
-    canvas.clip_rect(
-        Rect::new(
-            viewport.min_x(),
-            viewport.min_y(),
-            viewport.max_x(),
-            viewport.max_y(),
-        ),
-        ClipOp::Intersect,
-        true,
+/// Round the origin and the size of the area to whole pixels, separately so the size doesn't change while moving.
+fn snap_to_pixels(area: &Area) -> Area {
+    Area::new(area.origin.round(), area.size.round())
+}
+
+fn clip_viewport(
+    canvas: &Canvas,
+    viewport: &Area,
+    corner_radius: &CornerRadius,
+    render_settings: RenderSettings,
+) {
+    let viewport = &if render_settings.pixel_snapping {
+        snap_to_pixels(viewport)
+    } else {
+        *viewport
+    };
+    let anti_alias = render_settings.anti_aliasing.is_enabled();
+    let rect = Rect::new(
+        viewport.min_x(),
+        viewport.min_y(),
+        viewport.max_x(),
+        viewport.max_y(),
     );
+
+    if corner_radius.is_zero() {
+        canvas.clip_rect(rect, ClipOp::Intersect, anti_alias);
+        return;
+    }
+
+    // Clip with the same shape as the background of the viewport element
+    let rounded_rect = RRect::new_rect_radii(rect, &corner_radius.radii());
+    let mut path = Path::new();
+    if corner_radius.smoothing > 0.0 {
+        path.add_path(
+            &corner_radius.smoothed_path(rounded_rect),
+            (viewport.min_x(), viewport.min_y()),
+            None,
+        );
+    } else {
+        path.add_rrect(rounded_rect, None);
+    }
+    canvas.clip_path(&path, ClipOp::Intersect, anti_alias);
 }
 
 /// Render a node into the Skia canvas
@@ -29,17 +60,27 @@ pub fn render_skia(
     canvas: &Canvas,
     area: &Area,
     dioxus_node: &DioxusNode,
+    interaction: InteractionState,
     font_collection: &mut FontCollection,
     font_manager: &FontMgr,
     viewports: &Viewports,
     render_wireframe: bool,
     matrices: &mut Vec<(Matrix, Vec<NodeId>)>,
     opacities: &mut Vec<(f32, Vec<NodeId>)>,
+    images_cache: &mut ImagesCache,
+    paragraph_cache: &mut ParagraphCache,
+    render_settings: RenderSettings,
 ) {
     let node_type = &*dioxus_node.node_type();
     if let NodeType::Element(ElementNode { tag, .. }) = node_type {
         canvas.save();
 
+        let area = &if render_settings.pixel_snapping {
+            snap_to_pixels(area)
+        } else {
+            *area
+        };
+
         let node_transform = &*dioxus_node.get::<Transform>().unwrap();
         let node_style = &*dioxus_node.get::<Style>().unwrap();
 
@@ -90,38 +131,72 @@ pub fn render_skia(
             // It is the paragraph job to make sure they are clipped
             if tag.as_str() == "paragraph" {
                 if let Some(element_viewport) = element_viewport {
-                    clip_viewport(canvas, element_viewport);
+                    clip_viewport(
+                        canvas,
+                        element_viewport,
+                        &node_style.corner_radius,
+                        render_settings,
+                    );
                 }
             }
             for viewport_id in node_viewports {
                 let viewport = viewports.get(viewport_id).unwrap().0;
                 if let Some(viewport) = viewport {
-                    clip_viewport(canvas, &viewport);
+                    let corner_radius = dioxus_node
+                        .real_dom()
+                        .get(*viewport_id)
+                        .map(|viewport_node| viewport_node.get::<Style>().unwrap().corner_radius)
+                        .unwrap_or_default();
+                    clip_viewport(canvas, &viewport, &corner_radius, render_settings);
                 }
             }
         }
 
         match tag.as_str() {
             "rect" => {
-                render_rect(area, dioxus_node, canvas, font_collection);
+                render_rect(
+                    area,
+                    dioxus_node,
+                    canvas,
+                    font_collection,
+                    interaction,
+                    images_cache,
+                    render_settings,
+                );
             }
             "label" => {
-                render_label(area, dioxus_node, canvas, font_collection);
+                render_label(
+                    area,
+                    dioxus_node,
+                    canvas,
+                    font_collection,
+                    interaction,
+                    paragraph_cache,
+                    render_settings,
+                );
             }
             "paragraph" => {
-                render_paragraph(area, dioxus_node, canvas, font_collection);
+                render_paragraph(
+                    area,
+                    dioxus_node,
+                    canvas,
+                    font_collection,
+                    interaction,
+                    paragraph_cache,
+                    render_settings,
+                );
             }
             "svg" => {
-                render_svg(area, dioxus_node, canvas, font_manager);
+                render_svg(area, dioxus_node, canvas, font_manager, images_cache);
             }
             "image" => {
-                render_image(area, dioxus_node, canvas);
+                render_image(area, dioxus_node, canvas, images_cache, render_settings);
             }
             _ => {}
         }
 
         if render_wireframe {
-            crate::wireframe::render_wireframe(canvas, area);
+            crate::wireframe::render_wireframe(canvas, area, dioxus_node, font_collection);
         }
 
         canvas.restore();
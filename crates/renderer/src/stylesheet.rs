@@ -0,0 +1,44 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use freya_common::EventMessage;
+use winit::event_loop::EventLoopProxy;
+
+/// How often the stylesheet file is checked for changes.
+const STYLESHEET_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Send the content of the stylesheet file to the event loop every time it's modified.
+pub fn watch_stylesheet(path: PathBuf, proxy: EventLoopProxy<EventMessage>) {
+    tokio::spawn(async move {
+        let mut last_modified = modified_at(&path);
+        let mut interval = tokio::time::interval(STYLESHEET_POLL_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let modified = modified_at(&path);
+            if modified == last_modified {
+                continue;
+            }
+            last_modified = modified;
+
+            if let Ok(source) = std::fs::read_to_string(&path) {
+                // The event loop is closed
+                if proxy
+                    .send_event(EventMessage::UpdateStyleSheet(source))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        }
+    });
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    path.metadata()
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
@@ -0,0 +1,197 @@
+/// What to do when a [`TrayMenuItem`] is clicked.
+///
+/// The app is always notified about the clicks through `use_tray_menu`, regardless of the action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrayAction {
+    /// Show and focus the main window.
+    ShowWindow,
+    /// Hide the main window, the app keeps running in the background.
+    HideWindow,
+    /// Show the main window if hidden, or hide it otherwise.
+    ToggleWindow,
+    /// Exit the app.
+    Quit,
+    /// Only notify the app.
+    Custom,
+}
+
+/// An item of the system tray menu.
+#[derive(Clone, Debug)]
+pub struct TrayMenuItem {
+    pub id: String,
+    pub label: String,
+    pub action: TrayAction,
+}
+
+impl TrayMenuItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>, action: TrayAction) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            action,
+        }
+    }
+}
+
+/// Configuration of the system tray icon and its menu.
+///
+/// It requires the `tray` feature, without it or on platforms without tray support the app is launched without a tray.
+#[derive(Clone, Debug)]
+pub struct TrayConfig {
+    /// Encoded image (e.g PNG) of the icon.
+    pub icon: &'static [u8],
+    /// Text shown when hovering the icon.
+    pub tooltip: Option<String>,
+    /// Items of the menu.
+    pub items: Vec<TrayMenuItem>,
+}
+
+impl TrayConfig {
+    pub fn new(icon: &'static [u8]) -> Self {
+        Self {
+            icon,
+            tooltip: None,
+            items: Vec::new(),
+        }
+    }
+
+    /// Specify the text shown when hovering the icon.
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Add an item to the menu.
+    pub fn with_item(mut self, item: TrayMenuItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Get the action of the given menu item.
+    pub(crate) fn action(&self, id: &str) -> Option<TrayAction> {
+        self.items
+            .iter()
+            .find(|item| item.id == id)
+            .map(|item| item.action)
+    }
+}
+
+/// Keeps the tray icon alive.
+#[cfg_attr(not(feature = "tray"), allow(dead_code))]
+pub(crate) struct Tray {
+    #[cfg(all(feature = "tray", not(target_os = "linux")))]
+    _tray_icon: tray_icon::TrayIcon,
+}
+
+#[cfg(feature = "tray")]
+mod platform {
+    use std::sync::Mutex;
+
+    use freya_common::EventMessage;
+    use tray_icon::menu::{Menu, MenuEvent, MenuItem};
+    use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+    use winit::event_loop::EventLoopProxy;
+
+    use super::{Tray, TrayConfig};
+
+    fn build_tray_icon(config: &TrayConfig) -> Result<TrayIcon, String> {
+        let image = image::load_from_memory(config.icon)
+            .map_err(|err| err.to_string())?
+            .into_rgba8();
+        let (width, height) = image.dimensions();
+        let icon =
+            Icon::from_rgba(image.into_raw(), width, height).map_err(|err| err.to_string())?;
+
+        let menu = Menu::new();
+        for item in &config.items {
+            menu.append(&MenuItem::with_id(
+                item.id.as_str(),
+                &item.label,
+                true,
+                None,
+            ))
+            .map_err(|err| err.to_string())?;
+        }
+
+        let mut builder = TrayIconBuilder::new()
+            .with_icon(icon)
+            .with_menu(Box::new(menu));
+
+        if let Some(tooltip) = &config.tooltip {
+            builder = builder.with_tooltip(tooltip);
+        }
+
+        builder.build().map_err(|err| err.to_string())
+    }
+
+    /// Build the tray icon in a thread with a GTK event loop, as the tray needs one on Linux.
+    #[cfg(target_os = "linux")]
+    fn build_tray(config: &TrayConfig) -> Result<Tray, String> {
+        let config = config.clone();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            if gtk::init().is_err() {
+                sender
+                    .send(Err("Could not initialize GTK".to_string()))
+                    .ok();
+                return;
+            }
+            match build_tray_icon(&config) {
+                Ok(_tray_icon) => {
+                    sender.send(Ok(())).ok();
+                    gtk::main()
+                }
+                Err(err) => {
+                    sender.send(Err(err)).ok();
+                }
+            }
+        });
+
+        // Wait for the tray icon to be created so failures are not reported as a tray
+        match receiver.recv() {
+            Ok(Ok(())) => Ok(Tray {}),
+            Ok(Err(err)) => Err(err),
+            Err(_) => Err("The tray thread stopped".to_string()),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn build_tray(config: &TrayConfig) -> Result<Tray, String> {
+        build_tray_icon(config).map(|tray_icon| Tray {
+            _tray_icon: tray_icon,
+        })
+    }
+
+    pub fn create_tray(config: &TrayConfig, proxy: EventLoopProxy<EventMessage>) -> Option<Tray> {
+        let tray = match build_tray(config) {
+            Ok(tray) => tray,
+            Err(err) => {
+                tracing::warn!("Could not create the tray icon: {err}");
+                return None;
+            }
+        };
+
+        let proxy = Mutex::new(proxy);
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            proxy
+                .lock()
+                .unwrap()
+                .send_event(EventMessage::TrayMenuClicked(event.id.0))
+                .ok();
+        }));
+
+        Some(tray)
+    }
+}
+
+#[cfg(feature = "tray")]
+pub(crate) use platform::create_tray;
+
+#[cfg(not(feature = "tray"))]
+pub(crate) fn create_tray(
+    _config: &TrayConfig,
+    _proxy: winit::event_loop::EventLoopProxy<freya_common::EventMessage>,
+) -> Option<Tray> {
+    tracing::warn!("The `tray` feature is required to show a tray icon.");
+    None
+}
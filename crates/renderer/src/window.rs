@@ -1,234 +1,212 @@
+use std::time::Duration;
+
 use freya_common::EventMessage;
+use freya_core::render_settings::AntiAliasing;
 use freya_engine::prelude::*;
-use gl::{types::*, *};
-use glutin::context::GlProfile;
-use glutin::context::NotCurrentGlContext;
-use glutin::prelude::PossiblyCurrentGlContext;
-use glutin::{
-    config::{ConfigTemplateBuilder, GlConfig},
-    context::{ContextApi, ContextAttributesBuilder, PossiblyCurrentContext},
-    display::{GetGlDisplay, GlDisplay},
-    prelude::GlSurface,
-    surface::{Surface as GlutinSurface, SurfaceAttributesBuilder, WindowSurface},
-};
-use glutin_winit::DisplayBuilder;
-use raw_window_handle::HasRawWindowHandle;
-use std::ffi::CString;
-use std::num::NonZeroU32;
-
 use winit::dpi::{LogicalSize, PhysicalSize};
 use winit::{
-    event_loop::EventLoop,
+    event_loop::EventLoopWindowTarget,
     window::{Window, WindowBuilder},
 };
 
-use crate::config::WindowConfig;
+use torin::geometry::Area;
+
+use crate::config::{RendererBackend, WindowConfig};
+use crate::drivers::GraphicsDriver;
+use crate::window_geometry::WindowGeometry;
 
 /// Manager for a Window
 pub struct WindowEnv<State: Clone> {
-    pub(crate) gr_context: DirectContext,
-    pub(crate) surface: Surface,
-    pub(crate) gl_surface: GlutinSurface<WindowSurface>,
-    pub(crate) gl_context: PossiblyCurrentContext,
+    /// Offscreen surface where the app is painted, kept between frames to only repaint what changed.
+    pub(crate) app_surface: Surface,
+    // Declared before the Window so it's dropped first
+    pub(crate) driver: GraphicsDriver,
     pub(crate) window: Window,
-    pub(crate) fb_info: FramebufferInfo,
-    pub(crate) num_samples: usize,
-    pub(crate) stencil_size: usize,
     pub(crate) window_config: WindowConfig<State>,
+    pub(crate) background: Color,
 }
 
 impl<T: Clone> Drop for WindowEnv<T> {
     fn drop(&mut self) {
-        if !self.gl_context.is_current() && self.gl_context.make_current(&self.gl_surface).is_err()
-        {
-            self.gr_context.abandon();
-        }
+        // The app surface is released with the context of this Window
+        self.driver.make_current();
     }
 }
 
 impl<T: Clone> WindowEnv<T> {
     /// Setup the Window and related features
-    pub fn new(mut window_config: WindowConfig<T>, event_loop: &EventLoop<EventMessage>) -> Self {
+    pub fn new(
+        mut window_config: WindowConfig<T>,
+        event_loop: &EventLoopWindowTarget<EventMessage>,
+    ) -> Self {
         let mut window_builder = WindowBuilder::new()
             .with_visible(false)
             .with_title(window_config.title)
+            .with_resizable(window_config.resizable)
             .with_decorations(window_config.decorations)
             .with_transparent(window_config.transparent)
+            .with_blur(window_config.blur)
             .with_window_icon(window_config.icon.take())
             .with_inner_size(LogicalSize::<f64>::new(
                 window_config.width,
                 window_config.height,
             ));
 
-        if let Some(min_size) = window_config.min_width.zip(window_config.min_height) {
-            window_builder = window_builder.with_min_inner_size(LogicalSize::<f64>::from(min_size))
+        // The constraints can be set for a single axis
+        if window_config.min_width.is_some() || window_config.min_height.is_some() {
+            window_builder = window_builder.with_min_inner_size(LogicalSize::<f64>::new(
+                window_config.min_width.unwrap_or_default(),
+                window_config.min_height.unwrap_or_default(),
+            ))
         }
 
-        if let Some(max_size) = window_config.max_width.zip(window_config.max_height) {
-            window_builder = window_builder.with_max_inner_size(LogicalSize::<f64>::from(max_size))
+        if let Some(max_size) = max_inner_size(window_config.max_width, window_config.max_height) {
+            window_builder = window_builder.with_max_inner_size(max_size)
+        }
+
+        // Restore the geometry from the previous launch
+        let stored_geometry = window_config
+            .geometry_storage
+            .as_deref()
+            .and_then(WindowGeometry::load)
+            .filter(|geometry| geometry.is_on_monitors(event_loop.available_monitors()));
+
+        if let Some(geometry) = stored_geometry {
+            window_builder = geometry.apply(window_builder);
         }
 
         if let Some(with_window_builder) = &window_config.window_builder_hook {
             window_builder = (with_window_builder)(window_builder);
         }
 
-        let template = ConfigTemplateBuilder::new()
-            .with_alpha_size(8)
-            .with_transparency(window_config.transparent);
-
-        let display_builder = DisplayBuilder::new().with_window_builder(Some(window_builder));
-        let (window, gl_config) = display_builder
-            .build(event_loop, template, |configs| {
-                configs
-                    .reduce(|accum, config| {
-                        let transparency_check = config.supports_transparency().unwrap_or(false)
-                            & !accum.supports_transparency().unwrap_or(false);
-
-                        if transparency_check || config.num_samples() < accum.num_samples() {
-                            config
-                        } else {
-                            accum
-                        }
-                    })
-                    .unwrap()
-            })
-            .unwrap();
-
-        let mut window = window.expect("Could not create window with OpenGL context");
+        let (window, mut driver) = GraphicsDriver::new(
+            event_loop,
+            window_builder,
+            window_config.transparent,
+            window_config.backend,
+            window_config.render_settings.anti_aliasing == AntiAliasing::Multisampled,
+        );
         window.set_ime_allowed(true);
-        let raw_window_handle = window.raw_window_handle();
-
-        let context_attributes = ContextAttributesBuilder::new()
-            .with_profile(GlProfile::Core)
-            .build(Some(raw_window_handle));
-
-        let fallback_context_attributes = ContextAttributesBuilder::new()
-            .with_profile(GlProfile::Core)
-            .with_context_api(ContextApi::Gles(None))
-            .build(Some(raw_window_handle));
-
-        let not_current_gl_context = unsafe {
-            gl_config
-                .display()
-                .create_context(&gl_config, &context_attributes)
-                .unwrap_or_else(|_| {
-                    gl_config
-                        .display()
-                        .create_context(&gl_config, &fallback_context_attributes)
-                        .expect("failed to create context")
-                })
-        };
+        driver.set_vsync(window_config.vsync);
 
-        let (width, height): (u32, u32) = window.inner_size().into();
+        // Use a solid color if the Window can't be transparent
+        let background = if window_config.transparent && !driver.supports_transparency() {
+            window_config.fallback_background
+        } else {
+            window_config.background
+        };
 
-        let attrs = SurfaceAttributesBuilder::<WindowSurface>::new().build(
-            raw_window_handle,
-            NonZeroU32::new(width).unwrap(),
-            NonZeroU32::new(height).unwrap(),
-        );
+        let app_surface = create_app_surface(&mut driver, &window);
 
-        let gl_surface = unsafe {
-            gl_config
-                .display()
-                .create_window_surface(&gl_config, &attrs)
-                .expect("Could not create gl window surface")
+        let mut window_env = WindowEnv {
+            app_surface,
+            driver,
+            window,
+            window_config,
+            background,
         };
+        window_env.apply_scale_factor();
+        window_env
+    }
 
-        let gl_context = not_current_gl_context
-            .make_current(&gl_surface)
-            .expect("Could not make GL context current when setting up skia renderer");
-
-        load_with(|s| {
-            gl_config
-                .display()
-                .get_proc_address(CString::new(s).unwrap().as_c_str())
-        });
-        let interface = Interface::new_load_with(|name| {
-            if name == "eglGetCurrentDisplay" {
-                return std::ptr::null();
-            }
-            gl_config
-                .display()
-                .get_proc_address(CString::new(name).unwrap().as_c_str())
-        })
-        .expect("Could not create interface");
-
-        let mut gr_context =
-            DirectContext::new_gl(Some(interface), None).expect("Could not create direct context");
-
-        let fb_info = {
-            let mut fboid: GLint = 0;
-            unsafe { GetIntegerv(FRAMEBUFFER_BINDING, &mut fboid) };
-
-            FramebufferInfo {
-                fboid: fboid.try_into().unwrap(),
-                format: Format::RGBA8.into(),
-                ..Default::default()
-            }
-        };
+    /// Scale the canvases by the scale factor of the Window, so the app is painted in logical pixels.
+    fn apply_scale_factor(&mut self) {
+        let sf = self.window.scale_factor() as f32;
+        let canvas = self.driver.surface().canvas();
+        canvas.reset_matrix();
+        canvas.scale((sf, sf));
 
-        let num_samples = gl_config.num_samples() as usize;
-        let stencil_size = gl_config.stencil_size() as usize;
+        let app_canvas = self.app_surface.canvas();
+        app_canvas.reset_matrix();
+        app_canvas.scale((sf, sf));
+    }
 
-        let mut surface = create_surface(
-            &mut window,
-            fb_info,
-            &mut gr_context,
-            num_samples,
-            stencil_size,
-        );
+    /// Make the GL context of this Window the current one, needed when there are multiple windows.
+    pub fn make_current(&self) {
+        self.driver.make_current();
+    }
 
-        let sf = window.scale_factor() as f32;
-        surface.canvas().scale((sf, sf));
-
-        WindowEnv {
-            surface,
-            gl_surface,
-            gl_context,
-            gr_context,
-            fb_info,
-            num_samples,
-            stencil_size,
-            window,
-            window_config,
-        }
+    /// Minimum time between frames given the frame rate cap, if any.
+    pub fn frame_interval(&self) -> Option<Duration> {
+        self.window_config
+            .max_fps
+            .map(|max_fps| Duration::from_secs_f64(1.0 / max_fps as f64))
+    }
+
+    /// Backend used to render this Window.
+    pub fn backend(&self) -> RendererBackend {
+        self.driver.backend()
     }
 
     /// Get a reference to the Canvas.
     pub fn canvas(&mut self) -> &Canvas {
-        self.surface.canvas()
+        self.driver.surface().canvas()
     }
 
-    /// Clear the canvas.
-    pub fn clear(&mut self) {
-        let canvas = self.surface.canvas();
-        canvas.clear(self.window_config.background);
+    /// Get a reference to the Canvas where the app is painted.
+    pub fn app_canvas(&mut self) -> &Canvas {
+        self.app_surface.canvas()
+    }
+
+    /// Clear the app canvas, only inside the given area if any, so it can be repainted.
+    pub fn start_app_render(&mut self, dirty_area: Option<Area>) {
+        let scale_factor = self.window.scale_factor() as f32;
+        let canvas = self.app_surface.canvas();
+        canvas.save();
+        if let Some(dirty_area) = dirty_area {
+            // Round the area out to whole pixels so the cleared edges are not blended with the previous frame
+            canvas.clip_rect(
+                Rect::new(
+                    (dirty_area.min_x() * scale_factor).floor() / scale_factor,
+                    (dirty_area.min_y() * scale_factor).floor() / scale_factor,
+                    (dirty_area.max_x() * scale_factor).ceil() / scale_factor,
+                    (dirty_area.max_y() * scale_factor).ceil() / scale_factor,
+                ),
+                ClipOp::Intersect,
+                false,
+            );
+        }
+        canvas.clear(self.background);
+    }
+
+    /// Stop painting the app.
+    pub fn finish_app_render(&mut self) {
+        self.app_surface.canvas().restore();
+    }
+
+    /// Copy the last painted frame of the app into the Window canvas.
+    pub fn present_app(&mut self) {
+        let image = self.app_surface.image_snapshot();
+
+        let canvas = self.driver.surface().canvas();
+        canvas.save();
+        canvas.reset_matrix();
+        canvas.clear(Color::TRANSPARENT);
+        canvas.draw_image(&image, Point::new(0.0, 0.0), None);
+        canvas.restore();
+    }
+
+    /// Encode the last painted frame of the app as a PNG, reading it back from the GPU if needed.
+    pub fn screenshot(&mut self) -> Option<Vec<u8>> {
+        self.make_current();
+        let image = self.app_surface.image_snapshot();
+        let mut context = self.app_surface.direct_context();
+        let data = image.encode(context.as_mut(), EncodedImageFormat::PNG, None)?;
+        Some(data.as_bytes().to_vec())
     }
 
     /// Flush and submit the canvas.
     pub fn finish_render(&mut self) {
         self.window.pre_present_notify();
-        self.gr_context.flush_and_submit();
-        self.gl_surface.swap_buffers(&self.gl_context).unwrap();
+        self.driver.present();
     }
 
     /// Resize the Window
     pub fn resize(&mut self, size: PhysicalSize<u32>) {
-        self.surface = create_surface(
-            &mut self.window,
-            self.fb_info,
-            &mut self.gr_context,
-            self.num_samples,
-            self.stencil_size,
-        );
-
-        let (width, height): (u32, u32) = size.into();
-
-        self.gl_surface.resize(
-            &self.gl_context,
-            NonZeroU32::new(width.max(1)).unwrap(),
-            NonZeroU32::new(height.max(1)).unwrap(),
-        );
+        self.driver.resize(&self.window, size);
+        self.app_surface = create_app_surface(&mut self.driver, &self.window);
+        // The surfaces might have been recreated
+        self.apply_scale_factor();
 
         self.window.request_redraw();
     }
@@ -241,6 +219,13 @@ impl<T: Clone> WindowEnv<T> {
         }
     }
 
+    /// Persist the geometry of the Window if enabled in the launch config
+    pub fn save_geometry(&self) {
+        if let Some(path) = &self.window_config.geometry_storage {
+            WindowGeometry::save(&self.window, path);
+        }
+    }
+
     /// Run the `on_exit` callback that was passed to the launch function
     pub fn run_on_exit(&mut self) {
         let on_exit = self.window_config.on_exit.clone();
@@ -250,28 +235,35 @@ impl<T: Clone> WindowEnv<T> {
     }
 }
 
-/// Create the surface for Skia to render in
-fn create_surface(
-    window: &mut Window,
-    fb_info: FramebufferInfo,
-    gr_context: &mut DirectContext,
-    num_samples: usize,
-    stencil_size: usize,
-) -> Surface {
+/// Create an offscreen surface with the size of the Window, backed by the same context as the given surface.
+fn create_app_surface(driver: &mut GraphicsDriver, window: &Window) -> Surface {
     let size = window.inner_size();
-    let size = (
-        size.width.try_into().expect("Could not convert width"),
-        size.height.try_into().expect("Could not convert height"),
-    );
-    let backend_render_target =
-        backend_render_targets::make_gl(size, num_samples, stencil_size, fb_info);
-    wrap_backend_render_target(
-        gr_context,
-        &backend_render_target,
-        SurfaceOrigin::BottomLeft,
-        ColorType::RGBA8888,
-        None,
-        None,
-    )
-    .expect("Could not create skia surface")
+    let dimensions = (size.width.max(1) as i32, size.height.max(1) as i32);
+
+    if let Some(surface) = driver.surface().new_surface_with_dimensions(dimensions) {
+        return surface;
+    }
+
+    driver.fall_back_to_software(window, "Could not create the app surface");
+    driver
+        .surface()
+        .new_surface_with_dimensions(dimensions)
+        .expect("Could not create skia app surface")
+}
+
+/// Maximum size of the window, if there is any limit.
+///
+/// Winit only takes a limit for both axes, so the axis without a limit gets a size no window will reach.
+/// It must still fit in the platform sizes once scaled, `f64::MAX` overflows them.
+fn max_inner_size(max_width: Option<f64>, max_height: Option<f64>) -> Option<LogicalSize<f64>> {
+    const UNLIMITED_SIZE: f64 = i16::MAX as f64;
+
+    if max_width.is_none() && max_height.is_none() {
+        return None;
+    }
+
+    Some(LogicalSize::new(
+        max_width.unwrap_or(UNLIMITED_SIZE),
+        max_height.unwrap_or(UNLIMITED_SIZE),
+    ))
 }
@@ -0,0 +1,91 @@
+use std::fs;
+use std::path::Path;
+
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::monitor::MonitorHandle;
+use winit::window::{Window, WindowBuilder};
+
+/// Position, size and maximized state of a Window, persisted across launches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct WindowGeometry {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    maximized: bool,
+}
+
+impl WindowGeometry {
+    /// Read the geometry stored in the given file.
+    pub fn load(path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        let mut values = content.split_whitespace();
+
+        Some(Self {
+            x: values.next()?.parse().ok()?,
+            y: values.next()?.parse().ok()?,
+            width: values.next()?.parse().ok()?,
+            height: values.next()?.parse().ok()?,
+            maximized: values.next()?.parse().ok()?,
+        })
+    }
+
+    /// Store the geometry of the Window in the given file.
+    ///
+    /// The previous position and size are kept while maximized, so the Window
+    /// can be restored to them once it's not maximized anymore.
+    pub fn save(window: &Window, path: &Path) {
+        let maximized = window.is_maximized();
+
+        let geometry = match Self::load(path) {
+            Some(previous) if maximized => Self {
+                maximized,
+                ..previous
+            },
+            _ => {
+                let Ok(position) = window.outer_position() else {
+                    return;
+                };
+                let size = window.inner_size();
+                Self {
+                    x: position.x,
+                    y: position.y,
+                    width: size.width,
+                    height: size.height,
+                    maximized,
+                }
+            }
+        };
+
+        let content = format!(
+            "{} {} {} {} {}",
+            geometry.x, geometry.y, geometry.width, geometry.height, geometry.maximized
+        );
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).ok();
+        }
+
+        if let Err(err) = fs::write(path, content) {
+            tracing::warn!("Could not save the window geometry: {err}");
+        }
+    }
+
+    /// Check if the top-left corner of the Window is in any of the given monitors.
+    pub fn is_on_monitors(&self, mut monitors: impl Iterator<Item = MonitorHandle>) -> bool {
+        monitors.any(|monitor| {
+            let position = monitor.position();
+            let size = monitor.size();
+            (position.x..position.x + size.width as i32).contains(&self.x)
+                && (position.y..position.y + size.height as i32).contains(&self.y)
+        })
+    }
+
+    /// Apply the geometry to the Window that is about to be created.
+    pub fn apply(&self, window_builder: WindowBuilder) -> WindowBuilder {
+        window_builder
+            .with_position(PhysicalPosition::new(self.x, self.y))
+            .with_inner_size(PhysicalSize::new(self.width, self.height))
+            .with_maximized(self.maximized)
+    }
+}
@@ -2,19 +2,22 @@ use std::sync::Arc;
 
 use freya_common::EventMessage;
 use futures_task::{waker, ArcWake};
-use winit::event_loop::EventLoopProxy;
+use winit::{event_loop::EventLoopProxy, window::WindowId};
 
-pub fn winit_waker(proxy: &EventLoopProxy<EventMessage>) -> std::task::Waker {
-    struct DomHandle(EventLoopProxy<EventMessage>);
+pub fn winit_waker(proxy: &EventLoopProxy<EventMessage>, window_id: WindowId) -> std::task::Waker {
+    struct DomHandle(EventLoopProxy<EventMessage>, WindowId);
 
     unsafe impl Send for DomHandle {}
     unsafe impl Sync for DomHandle {}
 
     impl ArcWake for DomHandle {
         fn wake_by_ref(arc_self: &Arc<Self>) {
-            _ = arc_self.0.send_event(EventMessage::PollVDOM);
+            _ = arc_self.0.send_event(EventMessage::WindowMessage(
+                arc_self.1,
+                Box::new(EventMessage::PollVDOM),
+            ));
         }
     }
 
-    waker(Arc::new(DomHandle(proxy.clone())))
+    waker(Arc::new(DomHandle(proxy.clone(), window_id)))
 }
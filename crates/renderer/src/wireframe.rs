@@ -1,12 +1,98 @@
+use dioxus_native_core::real_dom::NodeImmutable;
+use freya_dom::prelude::DioxusNode;
 use freya_engine::prelude::*;
+use freya_node_state::LayoutState;
 use torin::geometry::Area;
+use torin::prelude::{AreaModel, Gaps};
+
+fn to_rect(area: &Area) -> Rect {
+    Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y())
+}
+
+/// Tint the space between the outer and inner areas.
+fn render_gaps(canvas: &Canvas, outer: &Area, inner: &Area, color: Color) {
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Fill);
+    paint.set_color(color);
+
+    canvas.save();
+    canvas.clip_rect(to_rect(inner), ClipOp::Difference, true);
+    canvas.draw_rect(to_rect(outer), &paint);
+    canvas.restore();
+}
+
+/// Area surrounding the given area by the given gaps.
+fn before_gaps(area: &Area, gaps: &Gaps) -> Area {
+    Area::new(
+        (area.min_x() - gaps.left(), area.min_y() - gaps.top()).into(),
+        (
+            area.width() + gaps.horizontal(),
+            area.height() + gaps.vertical(),
+        )
+            .into(),
+    )
+}
+
+/// Render the dimensions of the given area in a tag above it, or below if there is no space.
+fn render_dimensions(canvas: &Canvas, area: &Area, font_collection: &mut FontCollection) {
+    let mut text_style = TextStyle::new();
+    text_style.set_color(Color::WHITE);
+    text_style.set_font_size(12.0);
+
+    let mut paragraph_style = ParagraphStyle::default();
+    paragraph_style.set_text_style(&text_style);
+
+    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection.clone());
+    paragraph_builder.add_text(format!(
+        "{} × {}",
+        area.width().round(),
+        area.height().round()
+    ));
+    let mut paragraph = paragraph_builder.build();
+    paragraph.layout(f32::MAX);
+
+    let padding = 4.0;
+    let width = paragraph.max_intrinsic_width() + padding * 2.0;
+    let height = paragraph.height() + padding * 2.0;
+
+    let x = area.min_x();
+    let y = if area.min_y() - height - padding >= 0.0 {
+        area.min_y() - height - padding
+    } else {
+        area.max_y() + padding
+    };
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Fill);
+    paint.set_color(Color::from_rgb(40, 40, 40));
+
+    canvas.draw_rect(Rect::new(x, y, x + width, y + height), &paint);
+    paragraph.paint(canvas, (x + padding, y + padding));
+}
+
+/// Render a wireframe around the given node, with its margin, padding and content tinted and its dimensions.
+pub fn render_wireframe(
+    canvas: &Canvas,
+    area: &Area,
+    dioxus_node: &DioxusNode,
+    font_collection: &mut FontCollection,
+) {
+    let layout = &*dioxus_node.get::<LayoutState>().unwrap();
+    let margin_area = before_gaps(area, &layout.margin);
+    let content_area = area.after_gaps(&layout.padding);
+
+    render_gaps(canvas, &margin_area, area, Color::from_argb(102, 246, 178, 107));
+    render_gaps(canvas, area, &content_area, Color::from_argb(102, 147, 196, 125));
 
-/// Render a wireframe around the given node
-pub fn render_wireframe(canvas: &Canvas, area: &Area) {
     let mut paint = Paint::default();
 
     paint.set_anti_alias(true);
     paint.set_style(PaintStyle::Fill);
+    paint.set_color(Color::from_argb(102, 111, 168, 220));
+    canvas.draw_rect(to_rect(&content_area), &paint);
+
     paint.set_color(Color::MAGENTA);
 
     let x = area.min_x();
@@ -23,4 +109,6 @@ pub fn render_wireframe(canvas: &Canvas, area: &Area) {
     canvas.draw_line((x2, y), (x2, y2), &paint);
     canvas.draw_line((x2, y2), (x, y2), &paint);
     canvas.draw_line((x, y2), (x, y), &paint);
+
+    render_dimensions(canvas, area, font_collection);
 }
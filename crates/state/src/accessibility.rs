@@ -8,7 +8,7 @@ use dioxus_native_core::{
 };
 use dioxus_native_core_macro::partial_derive_state;
 
-use crate::CustomAttributeValues;
+use crate::{CustomAttributeValues, Parse};
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Component)]
 pub struct AccessibilityNodeState {
@@ -33,6 +33,7 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
             "role",
             "alt",
             "name",
+            "aria_label",
             "focusable",
         ]));
 
@@ -60,11 +61,9 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
                     }
                     "role" => {
                         if let OwnedAttributeValue::Text(attr) = attr.value {
-                            if let Ok(new_role) =
-                                serde_json::from_str::<Role>(&format!("\"{attr}\""))
-                            {
-                                accessibility.role = Some(new_role)
-                            }
+                            // Unknown roles fallback to a generic container
+                            accessibility.role =
+                                Some(Role::parse(attr).unwrap_or(Role::GenericContainer))
                         }
                     }
                     "alt" => {
@@ -73,6 +72,11 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
                         }
                     }
                     "name" => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.name.get_or_insert_with(|| attr.to_owned());
+                        }
+                    }
+                    "aria_label" => {
                         if let OwnedAttributeValue::Text(attr) = attr.value {
                             accessibility.name = Some(attr.to_owned())
                         }
@@ -6,8 +6,9 @@ use dioxus_native_core::{
 };
 use dioxus_native_core_macro::partial_derive_state;
 use freya_engine::prelude::*;
+use winit::window::CursorIcon;
 
-use crate::{CursorMode, CustomAttributeValues, Parse};
+use crate::{resolve_attributes, CursorMode, CustomAttributeValues, Parse};
 
 #[derive(Clone, Debug, PartialEq, Eq, Component)]
 pub struct CursorSettings {
@@ -17,6 +18,9 @@ pub struct CursorSettings {
     pub cursor_id: Option<usize>,
     pub highlights: Option<Vec<(usize, usize)>>,
     pub highlight_color: Color,
+    pub icon: Option<CursorIcon>,
+    pub window_drag: bool,
+    pub pointer_capture: bool,
 }
 
 impl Default for CursorSettings {
@@ -28,10 +32,27 @@ impl Default for CursorSettings {
             cursor_id: None,
             highlights: None,
             highlight_color: Color::from_rgb(87, 108, 188),
+            icon: None,
+            window_drag: false,
+            pointer_capture: false,
         }
     }
 }
 
+/// Attributes read by [`CursorSettings`].
+const CURSOR_ATTRIBUTES: &[&str] = &[
+    "cursor_index",
+    "cursor_color",
+    "cursor_mode",
+    "cursor_id",
+    "highlights",
+    "highlight_color",
+    "cursor",
+    "window_drag",
+    "pointer_capture",
+    "class",
+];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for CursorSettings {
     type ParentDependencies = (Self,);
@@ -41,14 +62,7 @@ impl State<CustomAttributeValues> for CursorSettings {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
-            "cursor_index",
-            "cursor_color",
-            "cursor_mode",
-            "cursor_id",
-            "highlights",
-            "highlight_color",
-        ]));
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(CURSOR_ATTRIBUTES));
 
     fn update<'a>(
         &mut self,
@@ -56,57 +70,76 @@ impl State<CustomAttributeValues> for CursorSettings {
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
-        _context: &SendAnyMap,
+        context: &SendAnyMap,
     ) -> bool {
         let mut cursor = parent.map(|(p,)| p.clone()).unwrap_or_default();
+        // The pointer capture is not inherited
+        cursor.pointer_capture = false;
 
-        if let Some(attributes) = node_view.attributes() {
-            for attr in attributes {
-                match attr.attribute.name.as_str() {
-                    "cursor_index" => {
-                        let value = attr.value.as_text().unwrap();
+        for attr in resolve_attributes(&node_view, context, CURSOR_ATTRIBUTES) {
+            match attr.attribute.name.as_str() {
+                "cursor_index" => {
+                    if let Some(value) = attr.value.as_text() {
                         if value != "none" {
-                            let new_cursor_index = value.parse().unwrap();
-                            cursor.position = Some(new_cursor_index);
+                            if let Ok(new_cursor_index) = value.parse() {
+                                cursor.position = Some(new_cursor_index);
+                            }
                         }
                     }
-                    "cursor_color" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(color) = Color::parse(value) {
-                                cursor.color = color;
-                            }
+                }
+                "cursor_color" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(color) = Color::parse(value) {
+                            cursor.color = color;
                         }
                     }
-                    "cursor_mode" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mode) = CursorMode::parse(value) {
-                                cursor.mode = mode;
-                            }
+                }
+                "cursor_mode" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mode) = CursorMode::parse(value) {
+                            cursor.mode = mode;
                         }
                     }
-                    "cursor_id" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(id) = value.parse() {
-                                cursor.cursor_id = Some(id);
-                            }
+                }
+                "cursor_id" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(id) = value.parse() {
+                            cursor.cursor_id = Some(id);
                         }
                     }
-                    "highlights" => {
-                        if let Some(CustomAttributeValues::TextHighlights(highlights)) =
-                            attr.value.as_custom()
-                        {
-                            cursor.highlights = Some(highlights.clone());
+                }
+                "highlights" => {
+                    if let Some(CustomAttributeValues::TextHighlights(highlights)) =
+                        attr.value.as_custom()
+                    {
+                        cursor.highlights = Some(highlights.clone());
+                    }
+                }
+                "highlight_color" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(highlight_color) = Color::parse(value) {
+                            cursor.highlight_color = highlight_color;
                         }
                     }
-                    "highlight_color" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(highlight_color) = Color::parse(value) {
-                                cursor.highlight_color = highlight_color;
-                            }
+                }
+                "cursor" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(icon) = CursorIcon::parse(value) {
+                            cursor.icon = Some(icon);
                         }
                     }
-                    _ => {}
                 }
+                "window_drag" => {
+                    if let Some(value) = attr.value.as_text() {
+                        cursor.window_drag = value == "true";
+                    }
+                }
+                "pointer_capture" => {
+                    if let Some(value) = attr.value.as_text() {
+                        cursor.pointer_capture = value == "true";
+                    }
+                }
+                _ => {}
             }
         }
         let changed = &cursor != self;
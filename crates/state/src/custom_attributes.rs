@@ -53,11 +53,13 @@ pub type CanvasRunner = dyn Fn(&Canvas, &mut FontCollection, Area) + Sync + Send
 #[derive(Clone)]
 pub struct CanvasReference {
     pub runner: Arc<Box<CanvasRunner>>,
+    /// Draw relative to the element, with `(0, 0)` being its top-left corner and clipped to its bounds.
+    pub local: bool,
 }
 
 impl PartialEq for CanvasReference {
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.runner, &other.runner)
+        Arc::ptr_eq(&self.runner, &other.runner) && self.local == other.local
     }
 }
 
@@ -158,6 +160,13 @@ pub fn bytes_to_data(bytes: &[u8]) -> AttributeValue {
     )))
 }
 
+/// Transform some shared bytes (e.g: raw image, raw svg) into attribute data, without copying them
+pub fn shared_bytes_to_data(bytes: Arc<Vec<u8>>) -> AttributeValue {
+    AttributeValue::any_value(CustomAttributeValues::Bytes(AttributesBytes::Dynamic(
+        bytes,
+    )))
+}
+
 /// Transform some static bytes (e.g: raw image, raw svg) into attribute data
 pub fn static_bytes_to_data(bytes: &'static [u8]) -> AttributeValue {
     AttributeValue::any_value(CustomAttributeValues::Bytes(AttributesBytes::Static(bytes)))
@@ -11,18 +11,23 @@ use freya_engine::prelude::*;
 use smallvec::{smallvec, SmallVec};
 use torin::torin::Torin;
 
-use crate::{CustomAttributeValues, ExtSplit, Parse, TextOverflow};
+use crate::{
+    resolve_attributes, CustomAttributeValues, ExtSplit, InteractionState, LineHeight, Parse,
+    TextOverflow,
+};
 
 #[derive(Debug, Clone, PartialEq, Component)]
 pub struct FontStyleState {
     pub color: Color,
+    pub color_hover: Option<Color>,
+    pub color_active: Option<Color>,
     pub text_shadows: Vec<TextShadow>,
     pub font_family: SmallVec<[String; 2]>,
     pub font_size: f32,
     pub font_slant: Slant,
     pub font_weight: Weight,
     pub font_width: Width,
-    pub line_height: f32, // https://developer.mozilla.org/en-US/docs/Web/CSS/line-height,
+    pub line_height: LineHeight, // https://developer.mozilla.org/en-US/docs/Web/CSS/line-height,
     pub decoration: Decoration,
     pub word_spacing: f32,
     pub letter_spacing: f32,
@@ -32,6 +37,23 @@ pub struct FontStyleState {
 }
 
 impl FontStyleState {
+    /// Get the text color to paint given the interaction state of the node.
+    pub fn current_color(&self, interaction: InteractionState) -> Color {
+        if interaction.pressed {
+            if let Some(color) = self.color_active {
+                return color;
+            }
+        }
+
+        if interaction.hovered {
+            if let Some(color) = self.color_hover {
+                return color;
+            }
+        }
+
+        self.color
+    }
+
     fn default_with_scale_factor(scale_factor: f32) -> Self {
         Self {
             font_size: 16.0 * scale_factor,
@@ -56,7 +78,7 @@ impl From<&FontStyleState> for TextStyle {
             .set_word_spacing(value.word_spacing)
             .set_letter_spacing(value.letter_spacing)
             .set_height_override(true)
-            .set_height(value.line_height);
+            .set_height(value.line_height.multiplier(value.font_size));
 
         for shadow in value.text_shadows.iter() {
             text_style.add_shadow(*shadow);
@@ -72,26 +94,51 @@ impl Default for FontStyleState {
     fn default() -> Self {
         Self {
             color: Color::BLACK,
+            color_hover: None,
+            color_active: None,
             text_shadows: Vec::new(),
             font_family: smallvec!["Fira Sans".to_string()],
             font_size: 16.0,
             font_weight: Weight::NORMAL,
             font_slant: Slant::Upright,
             font_width: Width::NORMAL,
-            line_height: 1.2,
+            line_height: LineHeight::default(),
             word_spacing: 0.0,
             letter_spacing: 0.0,
             decoration: Decoration {
                 thickness_multiplier: 1.0, // Defaults to 0.0, even though 0.0 won't render anything
                 ..Decoration::default()
             },
-            text_align: TextAlign::default(),
+            text_align: TextAlign::Start,
             max_lines: None,
             text_overflow: TextOverflow::default(),
         }
     }
 }
 
+/// Attributes read by [`FontStyleState`].
+const FONT_STYLE_ATTRIBUTES: &[&str] = &[
+    "color",
+    "color_hover",
+    "color_active",
+    "text_shadow",
+    "font_size",
+    "font_family",
+    "line_height",
+    "text_align",
+    "max_lines",
+    "font_style",
+    "font_weight",
+    "font_width",
+    "word_spacing",
+    "letter_spacing",
+    "decoration",
+    "decoration_color",
+    "decoration_style",
+    "text_overflow",
+    "class",
+];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for FontStyleState {
     type ParentDependencies = (Self,);
@@ -101,24 +148,7 @@ impl State<CustomAttributeValues> for FontStyleState {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
-            "color",
-            "text_shadow",
-            "font_size",
-            "font_family",
-            "line_height",
-            "text_align",
-            "max_lines",
-            "font_style",
-            "font_weight",
-            "font_width",
-            "word_spacing",
-            "letter_spacing",
-            "decoration",
-            "decoration_color",
-            "decoration_style",
-            "text_overflow",
-        ]));
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(FONT_STYLE_ATTRIBUTES));
 
     fn update<'a>(
         &mut self,
@@ -135,139 +165,164 @@ impl State<CustomAttributeValues> for FontStyleState {
             .map(|(v,)| v.clone())
             .unwrap_or_else(|| FontStyleState::default_with_scale_factor(*scale_factor));
 
-        if let Some(attributes) = node_view.attributes() {
-            for attr in attributes {
-                match attr.attribute.name.as_str() {
-                    "color" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(new_color) = Color::parse(value) {
-                                font_style.color = new_color;
-                            }
+        // The colors of the interaction states only apply to the node that declares them
+        font_style.color_hover = None;
+        font_style.color_active = None;
+
+        for attr in resolve_attributes(&node_view, context, FONT_STYLE_ATTRIBUTES) {
+            match attr.attribute.name.as_str() {
+                "color" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(new_color) = Color::parse(value) {
+                            font_style.color = new_color;
                         }
                     }
-                    "text_shadow" => {
-                        if let Some(value) = attr.value.as_text() {
-                            font_style.text_shadows = value
-                                .split_excluding_group(',', '(', ')')
-                                .map(|chunk| {
-                                    let mut shadow = TextShadow::parse(chunk).unwrap_or_default();
-                                    shadow.offset *= *scale_factor;
-                                    shadow.blur_sigma *= *scale_factor as f64;
-
-                                    shadow
-                                })
-                                .collect();
+                }
+                "color_hover" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(new_color) = Color::parse(value) {
+                            font_style.color_hover = Some(new_color);
                         }
                     }
-                    "font_family" => {
-                        if let Some(value) = attr.value.as_text() {
-                            let families = value.split(',');
-                            font_style.font_family = SmallVec::from(
-                                families
-                                    .into_iter()
-                                    .map(|f| f.trim().to_string())
-                                    .collect::<Vec<String>>(),
-                            );
+                }
+                "color_active" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(new_color) = Color::parse(value) {
+                            font_style.color_active = Some(new_color);
+                        }
+                    }
+                }
+                "text_shadow" => {
+                    if let Some(value) = attr.value.as_text() {
+                        font_style.text_shadows = value
+                            .split_excluding_group(',', '(', ')')
+                            .map(|chunk| {
+                                let mut shadow = TextShadow::parse(chunk).unwrap_or_default();
+                                shadow.offset *= *scale_factor;
+                                shadow.blur_sigma *= *scale_factor as f64;
+
+                                shadow
+                            })
+                            .collect();
+                    }
+                }
+                "font_family" => {
+                    if let Some(value) = attr.value.as_text() {
+                        // Families are tried in order, quotes around names are optional
+                        let families = value
+                            .split(',')
+                            .map(|f| f.trim().trim_matches(|c| c == '"' || c == '\'').trim())
+                            .filter(|f| !f.is_empty())
+                            .map(|f| f.to_string())
+                            .collect::<SmallVec<[String; 2]>>();
+
+                        if !families.is_empty() {
+                            font_style.font_family = families;
                         }
                     }
-                    "font_size" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(font_size) = value.parse::<f32>() {
-                                font_style.font_size = font_size * scale_factor;
-                            }
+                }
+                "font_size" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(font_size) = value.parse::<f32>() {
+                            font_style.font_size = font_size * scale_factor;
                         }
                     }
-                    "line_height" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(line_height) = value.parse() {
-                                font_style.line_height = line_height;
-                            }
+                }
+                "line_height" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(line_height) = LineHeight::parse(value) {
+                            font_style.line_height = match line_height {
+                                LineHeight::Pixels(pixels) => {
+                                    LineHeight::Pixels(pixels * scale_factor)
+                                }
+                                line_height => line_height,
+                            };
                         }
                     }
-                    "text_align" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(text_align) = TextAlign::parse(value) {
-                                font_style.text_align = text_align;
-                            }
+                }
+                "text_align" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(text_align) = TextAlign::parse(value) {
+                            font_style.text_align = text_align;
                         }
                     }
-                    "max_lines" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(max_lines) = value.parse() {
-                                font_style.max_lines = Some(max_lines);
-                            }
+                }
+                "max_lines" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(max_lines) = value.parse() {
+                            font_style.max_lines = Some(max_lines);
                         }
                     }
-                    "text_overflow" => {
-                        let value = attr.value.as_text();
-                        if let Some(value) = value {
-                            if let Ok(text_overflow) = TextOverflow::parse(value) {
-                                font_style.text_overflow = text_overflow;
-                            }
+                }
+                "text_overflow" => {
+                    let value = attr.value.as_text();
+                    if let Some(value) = value {
+                        if let Ok(text_overflow) = TextOverflow::parse(value) {
+                            font_style.text_overflow = text_overflow;
                         }
                     }
-                    "font_style" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(font_slant) = Slant::parse(value) {
-                                font_style.font_slant = font_slant;
-                            }
+                }
+                "font_style" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(font_slant) = Slant::parse(value) {
+                            font_style.font_slant = font_slant;
                         }
                     }
-                    "font_weight" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(font_weight) = Weight::parse(value) {
-                                font_style.font_weight = font_weight;
-                            }
+                }
+                "font_weight" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(font_weight) = Weight::parse(value) {
+                            font_style.font_weight = font_weight;
                         }
                     }
-                    "font_width" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(font_width) = Width::parse(value) {
-                                font_style.font_width = font_width;
-                            }
+                }
+                "font_width" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(font_width) = Width::parse(value) {
+                            font_style.font_width = font_width;
                         }
                     }
-                    "decoration" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(decoration) = TextDecoration::parse(value) {
-                                font_style.decoration.ty = decoration;
-                            }
+                }
+                "decoration" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(decoration) = TextDecoration::parse(value) {
+                            font_style.decoration.ty = decoration;
                         }
                     }
-                    "decoration_style" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(style) = TextDecorationStyle::parse(value) {
-                                font_style.decoration.style = style;
-                            }
+                }
+                "decoration_style" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(style) = TextDecorationStyle::parse(value) {
+                            font_style.decoration.style = style;
                         }
                     }
-                    "decoration_color" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(new_decoration_color) = Color::parse(value) {
-                                font_style.decoration.color = new_decoration_color;
-                            }
-                        } else {
-                            font_style.decoration.color = font_style.color;
+                }
+                "decoration_color" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(new_decoration_color) = Color::parse(value) {
+                            font_style.decoration.color = new_decoration_color;
                         }
+                    } else {
+                        font_style.decoration.color = font_style.color;
                     }
-                    "word_spacing" => {
-                        let value = attr.value.as_text();
-                        if let Some(value) = value {
-                            if let Ok(word_spacing) = value.parse() {
-                                font_style.word_spacing = word_spacing;
-                            }
+                }
+                "word_spacing" => {
+                    let value = attr.value.as_text();
+                    if let Some(value) = value {
+                        if let Ok(word_spacing) = value.parse::<f32>() {
+                            font_style.word_spacing = word_spacing * scale_factor;
                         }
                     }
-                    "letter_spacing" => {
-                        let value = attr.value.as_text();
-                        if let Some(value) = value {
-                            if let Ok(letter_spacing) = value.parse() {
-                                font_style.letter_spacing = letter_spacing;
-                            }
+                }
+                "letter_spacing" => {
+                    let value = attr.value.as_text();
+                    if let Some(value) = value {
+                        if let Ok(letter_spacing) = value.parse::<f32>() {
+                            font_style.letter_spacing = letter_spacing * scale_factor;
                         }
                     }
-                    _ => {}
                 }
+                _ => {}
             }
         }
 
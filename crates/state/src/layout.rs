@@ -10,7 +10,7 @@ use dioxus_native_core::{
 use dioxus_native_core_macro::partial_derive_state;
 use torin::prelude::*;
 
-use crate::{CustomAttributeValues, NodeReference, Parse};
+use crate::{resolve_attributes, CustomAttributeValues, NodeReference, Parse};
 
 #[derive(Default, Clone, Debug, Component, PartialEq)]
 pub struct LayoutState {
@@ -23,52 +23,64 @@ pub struct LayoutState {
     pub padding: Gaps,
     pub margin: Gaps,
     pub direction: DirectionMode,
+    pub layout_direction: LayoutDirection,
     pub node_id: NodeId,
     pub offset_y: Length,
     pub offset_x: Length,
     pub main_alignment: Alignment,
     pub cross_alignment: Alignment,
     pub position: Position,
+    pub content_visibility: ContentVisibility,
+    pub contain_intrinsic_size: Size2D,
+    pub display: DisplayMode,
     pub node_ref: Option<NodeReference>,
 }
 
+/// Attributes read by [`LayoutState`].
+const LAYOUT_ATTRIBUTES: &[&str] = &[
+    "width",
+    "height",
+    "min_height",
+    "min_width",
+    "max_height",
+    "max_width",
+    "padding",
+    "direction",
+    "direction_mode",
+    "offset_y",
+    "offset_x",
+    "main_align",
+    "cross_align",
+    "reference",
+    "margin",
+    "position",
+    "position_top",
+    "position_right",
+    "position_bottom",
+    "position_left",
+    "content_visibility",
+    "contain_intrinsic_size",
+    "display",
+    "class",
+];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for LayoutState {
-    type ParentDependencies = ();
+    type ParentDependencies = (Self,);
 
     type ChildDependencies = ();
 
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
-        .with_attrs(AttributeMaskBuilder::Some(&[
-            "width",
-            "height",
-            "min_height",
-            "min_width",
-            "max_height",
-            "max_width",
-            "padding",
-            "direction",
-            "offset_y",
-            "offset_x",
-            "main_align",
-            "cross_align",
-            "reference",
-            "margin",
-            "position",
-            "position_top",
-            "position_right",
-            "position_bottom",
-            "position_left",
-        ]))
+        .with_attrs(AttributeMaskBuilder::Some(LAYOUT_ATTRIBUTES))
         .with_tag();
 
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
-        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
     ) -> bool {
@@ -85,161 +97,195 @@ impl State<CustomAttributeValues> for LayoutState {
             } else {
                 DirectionMode::Vertical
             },
+            // The direction of the parent is inherited
+            layout_direction: parent
+                .map(|(parent,)| parent.layout_direction)
+                .unwrap_or_default(),
             ..Default::default()
         };
 
-        if let Some(attributes) = node_view.attributes() {
-            for attr in attributes {
-                match attr.attribute.name.as_str() {
-                    "width" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut width) = Size::parse(value) {
-                                width.scale(*scale_factor);
-                                layout.width = width;
-                            }
+        for attr in resolve_attributes(&node_view, context, LAYOUT_ATTRIBUTES) {
+            match attr.attribute.name.as_str() {
+                "width" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mut width) = Size::parse(value) {
+                            width.scale(*scale_factor);
+                            layout.width = width;
                         }
                     }
-                    "height" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut height) = Size::parse(value) {
-                                height.scale(*scale_factor);
-                                layout.height = height;
-                            }
+                }
+                "height" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mut height) = Size::parse(value) {
+                            height.scale(*scale_factor);
+                            layout.height = height;
                         }
                     }
-                    "min_height" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut min_height) = Size::parse(value) {
-                                min_height.scale(*scale_factor);
-                                layout.minimum_height = min_height;
-                            }
+                }
+                "min_height" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mut min_height) = Size::parse(value) {
+                            min_height.scale(*scale_factor);
+                            layout.minimum_height = min_height;
                         }
                     }
-                    "min_width" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut min_width) = Size::parse(value) {
-                                min_width.scale(*scale_factor);
-                                layout.minimum_width = min_width;
-                            }
+                }
+                "min_width" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mut min_width) = Size::parse(value) {
+                            min_width.scale(*scale_factor);
+                            layout.minimum_width = min_width;
                         }
                     }
-                    "max_height" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut max_height) = Size::parse(value) {
-                                max_height.scale(*scale_factor);
-                                layout.maximum_height = max_height;
-                            }
+                }
+                "max_height" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mut max_height) = Size::parse(value) {
+                            max_height.scale(*scale_factor);
+                            layout.maximum_height = max_height;
                         }
                     }
-                    "max_width" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut max_width) = Size::parse(value) {
-                                max_width.scale(*scale_factor);
-                                layout.maximum_width = max_width;
-                            }
+                }
+                "max_width" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mut max_width) = Size::parse(value) {
+                            max_width.scale(*scale_factor);
+                            layout.maximum_width = max_width;
                         }
                     }
-                    "padding" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut padding) = Gaps::parse(value) {
-                                padding.scale(*scale_factor);
-                                layout.padding = padding;
-                            }
+                }
+                "padding" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mut padding) = Gaps::parse(value) {
+                            padding.scale(*scale_factor);
+                            layout.padding = padding;
                         }
                     }
-                    "margin" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut margin) = Gaps::parse(value) {
-                                margin.scale(*scale_factor);
-                                layout.margin = margin;
-                            }
+                }
+                "margin" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mut margin) = Gaps::parse(value) {
+                            margin.scale(*scale_factor);
+                            layout.margin = margin;
                         }
                     }
-                    "direction" => {
-                        if let Some(value) = attr.value.as_text() {
-                            layout.direction = match value {
-                                "horizontal" => DirectionMode::Horizontal,
-                                _ => DirectionMode::Vertical,
-                            }
+                }
+                "direction" => {
+                    if let Some(value) = attr.value.as_text() {
+                        layout.direction = match value {
+                            "horizontal" => DirectionMode::Horizontal,
+                            _ => DirectionMode::Vertical,
                         }
                     }
-                    "offset_y" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(scroll) = value.parse::<f32>() {
-                                layout.offset_y = Length::new(scroll * scale_factor);
-                            }
+                }
+                "direction_mode" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(layout_direction) = LayoutDirection::parse(value) {
+                            layout.layout_direction = layout_direction;
                         }
                     }
-                    "offset_x" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(scroll) = value.parse::<f32>() {
-                                layout.offset_x = Length::new(scroll * scale_factor);
-                            }
+                }
+                "offset_y" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(scroll) = value.parse::<f32>() {
+                            layout.offset_y = Length::new(scroll * scale_factor);
                         }
                     }
-                    "main_align" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(alignment) = Alignment::parse(value) {
-                                layout.main_alignment = alignment;
-                            }
+                }
+                "offset_x" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(scroll) = value.parse::<f32>() {
+                            layout.offset_x = Length::new(scroll * scale_factor);
                         }
                     }
-                    "cross_align" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(alignment) = Alignment::parse(value) {
-                                layout.cross_alignment = alignment;
-                            }
+                }
+                "main_align" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(alignment) = Alignment::parse(value) {
+                            layout.main_alignment = alignment;
                         }
                     }
-                    "position" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(position) = Position::parse(value) {
-                                if layout.position.is_empty() {
-                                    layout.position = position;
-                                }
-                            }
+                }
+                "cross_align" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(alignment) = Alignment::parse(value) {
+                            layout.cross_alignment = alignment;
                         }
                     }
-                    "position_top" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(top) = value.parse::<f32>() {
-                                layout.position.set_top(top * scale_factor);
+                }
+                "position" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(position) = Position::parse(value) {
+                            if layout.position.is_empty() {
+                                layout.position = position;
                             }
                         }
                     }
-                    "position_right" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(right) = value.parse::<f32>() {
-                                layout.position.set_right(right * scale_factor);
-                            }
+                }
+                "position_top" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(top) = value.parse::<f32>() {
+                            layout.position.set_top(top * scale_factor);
                         }
                     }
-                    "position_bottom" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(bottom) = value.parse::<f32>() {
-                                layout.position.set_bottom(bottom * scale_factor);
-                            }
+                }
+                "position_right" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(right) = value.parse::<f32>() {
+                            layout.position.set_right(right * scale_factor);
                         }
                     }
-                    "position_left" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(left) = value.parse::<f32>() {
-                                layout.position.set_left(left * scale_factor);
-                            }
+                }
+                "position_bottom" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(bottom) = value.parse::<f32>() {
+                            layout.position.set_bottom(bottom * scale_factor);
                         }
                     }
-                    "reference" => {
-                        if let OwnedAttributeValue::Custom(CustomAttributeValues::Reference(
-                            reference,
-                        )) = attr.value
-                        {
-                            layout.node_ref = Some(reference.clone());
+                }
+                "position_left" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(left) = value.parse::<f32>() {
+                            layout.position.set_left(left * scale_factor);
                         }
                     }
-                    _ => {
-                        panic!("Unsupported attribute <{}>, this should not be happening, please report it.", attr.attribute.name);
+                }
+                "content_visibility" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(content_visibility) = ContentVisibility::parse(value) {
+                            layout.content_visibility = content_visibility;
+                        }
+                    }
+                }
+                "contain_intrinsic_size" => {
+                    if let Some(value) = attr.value.as_text() {
+                        let mut values = value.split_ascii_whitespace().map(str::parse::<f32>);
+                        if let Some(Ok(width)) = values.next() {
+                            // A single value is used for both dimensions
+                            let height = values.next().and_then(Result::ok).unwrap_or(width);
+                            layout.contain_intrinsic_size =
+                                Size2D::new(width, height) * *scale_factor;
+                        }
                     }
                 }
+                "display" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(display) = DisplayMode::parse(value) {
+                            layout.display = display;
+                        }
+                    }
+                }
+                "reference" => {
+                    if let OwnedAttributeValue::Custom(CustomAttributeValues::Reference(
+                        reference,
+                    )) = attr.value
+                    {
+                        layout.node_ref = Some(reference.clone());
+                    }
+                }
+                _ => {
+                    panic!("Unsupported attribute <{}>, this should not be happening, please report it.", attr.attribute.name);
+                }
             }
         }
 
@@ -252,11 +298,15 @@ impl State<CustomAttributeValues> for LayoutState {
             || (layout.padding != self.padding)
             || (node_view.node_id() != self.node_id)
             || (layout.direction != self.direction)
+            || (layout.layout_direction != self.layout_direction)
             || (layout.offset_x != self.offset_x)
             || (layout.offset_y != self.offset_y)
             || (layout.main_alignment != self.main_alignment)
             || (layout.cross_alignment != self.cross_alignment)
-            || (layout.position != self.position);
+            || (layout.position != self.position)
+            || (layout.content_visibility != self.content_visibility)
+            || (layout.contain_intrinsic_size != self.contain_intrinsic_size)
+            || (layout.display != self.display);
 
         if changed {
             torin_layout.lock().unwrap().invalidate(node_view.node_id());
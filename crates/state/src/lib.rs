@@ -6,6 +6,7 @@ mod layout;
 mod parsing;
 mod references;
 mod style;
+mod stylesheet;
 mod transform;
 mod values;
 
@@ -17,5 +18,6 @@ pub use layout::*;
 pub use parsing::*;
 pub use references::*;
 pub use style::*;
+pub use stylesheet::*;
 pub use transform::*;
 pub use values::*;
@@ -8,26 +8,99 @@ use dioxus_native_core::{
     SendAnyMap,
 };
 use dioxus_native_core_macro::partial_derive_state;
+use freya_engine::prelude::Color;
 use torin::scaled::Scaled;
 
 use crate::{
-    parsing::ExtSplit, AttributesBytes, Border, BorderAlignment, CornerRadius,
-    CustomAttributeValues, Fill, OverflowMode, Parse, Shadow,
+    parse_corner_smoothing, parsing::ExtSplit, resolve_attributes, AttributesBytes, Border,
+    BorderAlignment, CornerRadius, CustomAttributeValues, Fill, ImageFill, ImagePosition,
+    ImageRepeat, OverflowMode, Parse, Shadow, SvgColors, SvgFit, Visibility,
 };
 
+/// Pointer interaction state of a node, used to pick the values of the attributes
+/// that depend on it, e.g `background_hover`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InteractionState {
+    /// The cursor is over the node.
+    pub hovered: bool,
+    /// The node is being pressed.
+    pub pressed: bool,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Component)]
 pub struct Style {
     pub background: Fill,
+    pub background_hover: Option<Fill>,
+    pub background_active: Option<Fill>,
     pub relative_layer: i16,
     pub border: Border,
     pub shadows: Vec<Shadow>,
     pub corner_radius: CornerRadius,
     pub image_data: Option<AttributesBytes>,
     pub svg_data: Option<AttributesBytes>,
+    pub svg_colors: SvgColors,
+    pub svg_fit: Option<SvgFit>,
     pub overflow: OverflowMode,
     pub opacity: Option<f32>,
+    /// Inherited by the inner elements unless they specify their own.
+    pub visibility: Visibility,
 }
 
+impl Style {
+    /// Get the background to paint given the interaction state of the node.
+    pub fn current_background(&self, interaction: InteractionState) -> &Fill {
+        if interaction.pressed {
+            if let Some(background) = &self.background_active {
+                return background;
+            }
+        }
+
+        if interaction.hovered {
+            if let Some(background) = &self.background_hover {
+                return background;
+            }
+        }
+
+        &self.background
+    }
+}
+
+/// Parse a background, either from text like a color or from the bytes of an image.
+fn parse_background(value: &OwnedAttributeValue<CustomAttributeValues>) -> Option<Fill> {
+    match value {
+        OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) => {
+            Some(Fill::Image(ImageFill::new(bytes.clone())))
+        }
+        value => Fill::parse(value.as_text()?).ok(),
+    }
+}
+
+/// Attributes read by [`Style`].
+const STYLE_ATTRIBUTES: &[&str] = &[
+    "background",
+    "background_hover",
+    "background_active",
+    "background_repeat",
+    "background_position",
+    "layer",
+    "border",
+    "border_align",
+    "shadow",
+    "corner_radius",
+    "corner_smoothing",
+    "image_data",
+    "svg_data",
+    "svg_content",
+    "color",
+    "fill",
+    "stroke",
+    "fit",
+    "overflow",
+    "opacity",
+    "visibility",
+    "class",
+];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for Style {
     type ParentDependencies = (Self,);
@@ -37,134 +110,195 @@ impl State<CustomAttributeValues> for Style {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
-            "background",
-            "layer",
-            "border",
-            "border_align",
-            "shadow",
-            "corner_radius",
-            "corner_smoothing",
-            "image_data",
-            "svg_data",
-            "svg_content",
-            "overflow",
-            "opacity",
-        ]));
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(STYLE_ATTRIBUTES));
 
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
-        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
     ) -> bool {
-        let mut style = Style::default();
+        let mut style = Style {
+            visibility: parent
+                .map(|(parent,)| parent.visibility)
+                .unwrap_or_default(),
+            ..Default::default()
+        };
         let scale_factor = context.get::<f32>().unwrap();
+        let mut background_repeat = ImageRepeat::default();
+        let mut background_position = ImagePosition::default();
 
-        if let Some(attributes) = node_view.attributes() {
-            for attr in attributes {
-                match attr.attribute.name.as_str() {
-                    "background" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(background) = Fill::parse(value) {
-                                style.background = background;
-                            }
+        for attr in resolve_attributes(&node_view, context, STYLE_ATTRIBUTES) {
+            match attr.attribute.name.as_str() {
+                "background" => {
+                    if let Some(background) = parse_background(attr.value) {
+                        style.background = background;
+                    }
+                }
+                "background_hover" => {
+                    if let Some(background) = parse_background(attr.value) {
+                        style.background_hover = Some(background);
+                    }
+                }
+                "background_active" => {
+                    if let Some(background) = parse_background(attr.value) {
+                        style.background_active = Some(background);
+                    }
+                }
+                "background_repeat" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(repeat) = ImageRepeat::parse(value) {
+                            background_repeat = repeat;
+                        }
+                    }
+                }
+                "background_position" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(position) = ImagePosition::parse(value) {
+                            background_position = position;
                         }
                     }
-                    "layer" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(relative_layer) = value.parse::<i16>() {
-                                style.relative_layer = relative_layer;
-                            }
+                }
+                "layer" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(relative_layer) = value.parse::<i16>() {
+                            style.relative_layer = relative_layer;
                         }
                     }
-                    "border" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut border) = Border::parse(value) {
-                                border.alignment = style.border.alignment;
-                                border.scale(*scale_factor);
+                }
+                "border" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mut border) = Border::parse(value) {
+                            border.alignment = style.border.alignment;
+                            border.scale(*scale_factor);
 
-                                style.border = border;
-                            }
+                            style.border = border;
                         }
                     }
-                    "border_align" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(alignment) = BorderAlignment::parse(value) {
-                                style.border.alignment = alignment;
-                            }
+                }
+                "border_align" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(alignment) = BorderAlignment::parse(value) {
+                            style.border.alignment = alignment;
                         }
                     }
-                    "shadow" => {
-                        if let Some(value) = attr.value.as_text() {
-                            style.shadows = value
-                                .split_excluding_group(',', '(', ')')
-                                .map(|chunk| {
-                                    let mut shadow = Shadow::parse(chunk).unwrap_or_default();
-                                    shadow.scale(*scale_factor);
-                                    shadow
-                                })
-                                .collect();
+                }
+                "shadow" => {
+                    if let Some(value) = attr.value.as_text() {
+                        style.shadows = value
+                            .split_excluding_group(',', '(', ')')
+                            .map(|chunk| {
+                                let mut shadow = Shadow::parse(chunk).unwrap_or_default();
+                                shadow.scale(*scale_factor);
+                                shadow
+                            })
+                            .collect();
+                    }
+                }
+                "corner_radius" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(mut radius) = CornerRadius::parse(value) {
+                            radius.scale(*scale_factor);
+                            radius.smoothing = style.corner_radius.smoothing;
+                            style.corner_radius = radius;
                         }
                     }
-                    "corner_radius" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut radius) = CornerRadius::parse(value) {
-                                radius.scale(*scale_factor);
-                                radius.smoothing = style.corner_radius.smoothing;
-                                style.corner_radius = radius;
-                            }
+                }
+                "corner_smoothing" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(smoothing) = parse_corner_smoothing(value) {
+                            style.corner_radius.smoothing = smoothing;
                         }
                     }
-                    "corner_smoothing" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if value.ends_with('%') {
-                                if let Ok(smoothing) = value.replacen('%', "", 1).parse::<f32>() {
-                                    style.corner_radius.smoothing =
-                                        (smoothing / 100.0).clamp(0.0, 1.0);
-                                }
-                            }
+                }
+                "image_data" => {
+                    if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) =
+                        attr.value
+                    {
+                        style.image_data = Some(bytes.clone());
+                    }
+                }
+                "svg_data" => {
+                    if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) =
+                        attr.value
+                    {
+                        style.svg_data = Some(bytes.clone());
+                    }
+                }
+                "svg_content" => {
+                    let text = attr.value.as_text();
+                    style.svg_data =
+                        text.map(|v| AttributesBytes::Dynamic(Arc::new(v.as_bytes().to_owned())));
+                }
+                "color" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(color) = Color::parse(value) {
+                            style.svg_colors.color = Some(color);
                         }
                     }
-                    "image_data" => {
-                        if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) =
-                            attr.value
-                        {
-                            style.image_data = Some(bytes.clone());
+                }
+                "fill" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(fill) = Color::parse(value) {
+                            style.svg_colors.fill = Some(fill);
                         }
                     }
-                    "svg_data" => {
-                        if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) =
-                            attr.value
-                        {
-                            style.svg_data = Some(bytes.clone());
+                }
+                "stroke" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(stroke) = Color::parse(value) {
+                            style.svg_colors.stroke = Some(stroke);
                         }
                     }
-                    "svg_content" => {
-                        let text = attr.value.as_text();
-                        style.svg_data = text
-                            .map(|v| AttributesBytes::Dynamic(Arc::new(v.as_bytes().to_owned())));
+                }
+                "fit" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(fit) = SvgFit::parse(value) {
+                            style.svg_fit = Some(fit);
+                        }
                     }
-                    "overflow" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(overflow) = OverflowMode::parse(value) {
-                                style.overflow = overflow;
-                            }
+                }
+                "overflow" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(overflow) = OverflowMode::parse(value) {
+                            style.overflow = overflow;
                         }
                     }
-                    "opacity" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if let Ok(opacity) = value.parse::<f32>() {
-                                style.opacity = Some(opacity);
-                            }
+                }
+                "opacity" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(opacity) = value.parse::<f32>() {
+                            style.opacity = Some(opacity);
                         }
                     }
-                    _ => {
-                        panic!("Unsupported attribute <{}>, this should not be happening, please report it.", attr.attribute.name);
+                }
+                "visibility" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if let Ok(visibility) = Visibility::parse(value) {
+                            style.visibility = visibility;
+                        }
                     }
                 }
+                _ => {
+                    panic!("Unsupported attribute <{}>, this should not be happening, please report it.", attr.attribute.name);
+                }
+            }
+        }
+
+        // The repeat and position attributes can come before or after the backgrounds
+        for background in [
+            Some(&mut style.background),
+            style.background_hover.as_mut(),
+            style.background_active.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if let Fill::Image(image) = background {
+                image.repeat = background_repeat;
+                image.position = background_position;
             }
         }
 
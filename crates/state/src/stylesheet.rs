@@ -0,0 +1,228 @@
+use std::{collections::HashMap, fmt, sync::Arc};
+
+use dioxus_native_core::{
+    node::{OwnedAttributeDiscription, OwnedAttributeValue, OwnedAttributeView},
+    node_ref::NodeView,
+    SendAnyMap,
+};
+
+use crate::{CustomAttributeValues, Parse};
+
+type ClassAttribute = (
+    OwnedAttributeDiscription,
+    OwnedAttributeValue<CustomAttributeValues>,
+);
+
+/// Named sets of attributes that elements can reuse with the `class` attribute.
+///
+/// A StyleSheet is built with [`StyleSheet::with_class`] or parsed from a list of classes, each one with its attributes:
+///
+/// ```css
+/// /* A comment */
+/// card {
+///     background: white;
+///     padding: 12;
+///     corner_radius: 8;
+/// }
+/// ```
+#[derive(Default, Clone)]
+pub struct StyleSheet {
+    classes: HashMap<String, Vec<ClassAttribute>>,
+}
+
+impl StyleSheet {
+    /// Add a class with the given attributes, replacing the class with the same name.
+    ///
+    /// ```rust
+    /// # use freya_node_state::StyleSheet;
+    /// let stylesheet = StyleSheet::default()
+    ///     .with_class("card", [("background", "white"), ("padding", "12")])
+    ///     .with_class("primary", [("background", "rgb(0, 119, 182)"), ("color", "white")]);
+    /// ```
+    pub fn with_class<'a>(
+        mut self,
+        name: &str,
+        attributes: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let attributes = attributes
+            .into_iter()
+            .map(|(attribute, value)| class_attribute(attribute, value))
+            .collect();
+        self.classes.insert(name.to_string(), attributes);
+        self
+    }
+
+    /// Add the classes of another StyleSheet, replacing the classes with the same name.
+    pub fn extend(&mut self, other: StyleSheet) {
+        self.classes.extend(other.classes);
+    }
+
+    /// Get the attributes of the given class, in the order they were declared.
+    pub fn class(&self, name: &str) -> Option<impl Iterator<Item = (&str, &str)>> {
+        self.classes.get(name).map(|attributes| {
+            attributes
+                .iter()
+                .filter_map(|(attribute, value)| Some((attribute.name.as_str(), value.as_text()?)))
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseStyleSheetError {
+    /// A class is missing its name, e.g `{ padding: 8; }`.
+    MissingClassName,
+    /// The name of a class is not a single word, e.g `my card { ... }`.
+    InvalidClassName(String),
+    /// A class is not closed with `}`.
+    UnclosedClass(String),
+    /// A comment is not closed with `*/`.
+    UnclosedComment,
+    /// An attribute is not declared as `name: value`.
+    InvalidAttribute(String),
+}
+
+impl fmt::Display for ParseStyleSheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingClassName => f.write_str("missing class name"),
+            Self::InvalidClassName(name) => write!(f, "invalid class name `{name}`"),
+            Self::UnclosedClass(name) => write!(f, "class `{name}` is not closed"),
+            Self::UnclosedComment => f.write_str("comment is not closed"),
+            Self::InvalidAttribute(attribute) => write!(f, "invalid attribute `{attribute}`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseStyleSheetError {}
+
+impl Parse for StyleSheet {
+    type Err = ParseStyleSheetError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let source = strip_comments(value)?;
+        let mut classes = HashMap::new();
+        let mut rest = source.as_str();
+
+        while let Some(start) = rest.find('{') {
+            let name = rest[..start].trim();
+            if name.is_empty() {
+                return Err(ParseStyleSheetError::MissingClassName);
+            }
+            if name.contains(|c: char| c.is_whitespace() || c == '}') {
+                return Err(ParseStyleSheetError::InvalidClassName(name.to_string()));
+            }
+
+            let body = &rest[start + 1..];
+            let end = body
+                .find('}')
+                .ok_or_else(|| ParseStyleSheetError::UnclosedClass(name.to_string()))?;
+
+            let mut attributes = Vec::new();
+            for declaration in body[..end].split(';') {
+                let declaration = declaration.trim();
+                if declaration.is_empty() {
+                    continue;
+                }
+
+                let (attribute, value) = declaration
+                    .split_once(':')
+                    .map(|(attribute, value)| (attribute.trim(), value.trim()))
+                    .filter(|(attribute, value)| {
+                        !attribute.is_empty()
+                            && !value.is_empty()
+                            && !attribute.contains(char::is_whitespace)
+                    })
+                    .ok_or_else(|| {
+                        ParseStyleSheetError::InvalidAttribute(declaration.to_string())
+                    })?;
+
+                attributes.push(class_attribute(attribute, value));
+            }
+
+            classes.insert(name.to_string(), attributes);
+            rest = &body[end + 1..];
+        }
+
+        if !rest.trim().is_empty() {
+            return Err(ParseStyleSheetError::InvalidClassName(
+                rest.trim().to_string(),
+            ));
+        }
+
+        Ok(Self { classes })
+    }
+}
+
+fn class_attribute(attribute: &str, value: &str) -> ClassAttribute {
+    (
+        OwnedAttributeDiscription {
+            name: attribute.to_string(),
+            namespace: None,
+        },
+        OwnedAttributeValue::Text(value.to_string()),
+    )
+}
+
+/// Remove the `/* */` comments.
+fn strip_comments(value: &str) -> Result<String, ParseStyleSheetError> {
+    let mut source = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("/*") {
+        source.push_str(&rest[..start]);
+        let end = rest[start + 2..]
+            .find("*/")
+            .ok_or(ParseStyleSheetError::UnclosedComment)?;
+        rest = &rest[start + 2 + end + 2..];
+    }
+    source.push_str(rest);
+
+    Ok(source)
+}
+
+/// Get the attributes of a Node that a State reads, including the attributes of its classes.
+///
+/// The attributes of the classes come first so the Node's own attributes override them.
+pub fn resolve_attributes<'a>(
+    node_view: &'a NodeView<'a, CustomAttributeValues>,
+    context: &'a SendAnyMap,
+    state_attributes: &[&str],
+) -> Vec<OwnedAttributeView<'a, CustomAttributeValues>> {
+    let mut resolved = Vec::new();
+
+    let Some(attributes) = node_view.attributes() else {
+        return resolved;
+    };
+    let attributes = attributes.collect::<Vec<_>>();
+
+    let classes = attributes
+        .iter()
+        .find(|attr| attr.attribute.name == "class")
+        .and_then(|attr| attr.value.as_text());
+    let stylesheet = context.get::<Arc<StyleSheet>>();
+
+    if let (Some(classes), Some(stylesheet)) = (classes, stylesheet) {
+        for class in classes.split_ascii_whitespace() {
+            let Some(class_attributes) = stylesheet.classes.get(class) else {
+                continue;
+            };
+            resolved.extend(
+                class_attributes
+                    .iter()
+                    .filter(|(attribute, _)| {
+                        attribute.name != "class"
+                            && state_attributes.contains(&attribute.name.as_str())
+                    })
+                    .map(|(attribute, value)| OwnedAttributeView { attribute, value }),
+            );
+        }
+    }
+
+    resolved.extend(
+        attributes
+            .into_iter()
+            .filter(|attr| attr.attribute.name != "class"),
+    );
+
+    resolved
+}
@@ -6,13 +6,16 @@ use dioxus_native_core::{
 };
 use dioxus_native_core_macro::partial_derive_state;
 
-use crate::CustomAttributeValues;
+use crate::{resolve_attributes, CustomAttributeValues};
 
 #[derive(Default, Clone, Debug, Component, PartialEq)]
 pub struct Transform {
     pub rotate_degs: Option<f32>,
 }
 
+/// Attributes read by [`Transform`].
+const TRANSFORM_ATTRIBUTES: &[&str] = &["rotate", "class"];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for Transform {
     type ParentDependencies = (Self,);
@@ -22,7 +25,7 @@ impl State<CustomAttributeValues> for Transform {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&["rotate"]));
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(TRANSFORM_ATTRIBUTES));
 
     fn update<'a>(
         &mut self,
@@ -30,25 +33,23 @@ impl State<CustomAttributeValues> for Transform {
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
-        _context: &SendAnyMap,
+        context: &SendAnyMap,
     ) -> bool {
         let mut rotate_degs = None;
 
-        if let Some(attributes) = node_view.attributes() {
-            for attr in attributes {
-                match attr.attribute.name.as_str() {
-                    "rotate" => {
-                        if let Some(value) = attr.value.as_text() {
-                            if value.ends_with("deg") {
-                                if let Ok(degs) = value.replacen("deg", "", 1).parse::<f32>() {
-                                    rotate_degs = Some(degs)
-                                }
+        for attr in resolve_attributes(&node_view, context, TRANSFORM_ATTRIBUTES) {
+            match attr.attribute.name.as_str() {
+                "rotate" => {
+                    if let Some(value) = attr.value.as_text() {
+                        if value.ends_with("deg") {
+                            if let Ok(degs) = value.replacen("deg", "", 1).parse::<f32>() {
+                                rotate_degs = Some(degs)
                             }
                         }
                     }
-                    _ => {
-                        panic!("Unsupported attribute <{}>, this should not be happening, please report it.", attr.attribute.name);
-                    }
+                }
+                _ => {
+                    panic!("Unsupported attribute <{}>, this should not be happening, please report it.", attr.attribute.name);
                 }
             }
         }
@@ -7,36 +7,120 @@ pub trait DisplayColor {
     fn fmt_hsl(&self, f: &mut fmt::Formatter) -> fmt::Result;
 }
 
+/// Why a color couldn't be parsed.
 #[derive(Debug, PartialEq, Eq)]
-pub struct ParseColorError;
+pub enum ParseColorError {
+    /// The value is not a color name, a hex color or a color function.
+    UnknownColor(String),
+    /// A hex color that doesn't have 3, 4, 6 or 8 hexadecimal digits.
+    InvalidHex(String),
+    /// A color function like `rgb()` or `hsl()` with invalid arguments.
+    InvalidFunction(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownColor(value) => write!(
+                f,
+                "Unknown color `{value}`, expected a color name, a hex color, `rgb()` or `hsl()`"
+            ),
+            Self::InvalidHex(value) => write!(
+                f,
+                "Invalid hex color `{value}`, expected `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`"
+            ),
+            Self::InvalidFunction(value) => write!(f, "Invalid arguments in color `{value}`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
 
 impl Parse for Color {
     type Err = ParseColorError;
 
     fn parse(value: &str) -> Result<Self, Self::Err> {
-        match value {
-            "red" => Ok(Color::RED),
-            "green" => Ok(Color::GREEN),
-            "blue" => Ok(Color::BLUE),
-            "yellow" => Ok(Color::YELLOW),
-            "black" => Ok(Color::BLACK),
-            "gray" => Ok(Color::GRAY),
-            "white" => Ok(Color::WHITE),
-            "orange" => Ok(Color::from_rgb(255, 165, 0)),
-            "transparent" => Ok(Color::TRANSPARENT),
-            _ => {
-                if value.starts_with("hsl(") {
-                    parse_hsl(value)
-                } else if value.starts_with("rgb(") {
-                    parse_rgb(value)
-                } else {
-                    Err(ParseColorError)
-                }
+        let value = value.trim();
+
+        // An opacity suffix multiplies the alpha of the color, e.g `red opacity(0.5)`
+        if let Some((color, opacity)) = split_opacity(value) {
+            let color = Color::parse(color)?;
+            let opacity = parse_alpha(opacity)
+                .ok_or_else(|| ParseColorError::InvalidFunction(value.to_string()))?;
+            let alpha = (color.a() as f32 * opacity).round() as u8;
+            return Ok(color.with_a(alpha));
+        }
+
+        if let Some(hex) = value.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(|| ParseColorError::InvalidHex(value.to_string()));
+        }
+
+        let function = if value.starts_with("hsl(") || value.starts_with("hsla(") {
+            Some(parse_hsl(value))
+        } else if value.starts_with("rgba(") {
+            Some(parse_rgba(value))
+        } else if value.starts_with("rgb(") {
+            Some(parse_rgb(value))
+        } else {
+            None
+        };
+
+        match function {
+            Some(color) => color.ok_or_else(|| ParseColorError::InvalidFunction(value.to_string())),
+            None => {
+                parse_name(value).ok_or_else(|| ParseColorError::UnknownColor(value.to_string()))
             }
         }
     }
 }
 
+/// Split a color like `red opacity(0.5)` into the color and the opacity.
+fn split_opacity(value: &str) -> Option<(&str, &str)> {
+    let (color, opacity) = value.strip_suffix(')')?.rsplit_once("opacity(")?;
+    if !color.ends_with(char::is_whitespace) {
+        return None;
+    }
+    Some((color.trim_end(), opacity.trim()))
+}
+
+/// Find a color by its CSS name, ignoring the case.
+fn parse_name(name: &str) -> Option<Color> {
+    let name = name.to_ascii_lowercase();
+    match name.as_str() {
+        // These have always been different from CSS in Freya
+        "green" => Some(Color::GREEN),
+        "gray" | "grey" => Some(Color::GRAY),
+        "transparent" => Some(Color::TRANSPARENT),
+        _ => {
+            let index = NAMED_COLORS
+                .binary_search_by(|(color_name, _)| color_name.cmp(&name.as_str()))
+                .ok()?;
+            let (r, g, b) = NAMED_COLORS[index].1;
+            Some(Color::from_rgb(r, g, b))
+        }
+    }
+}
+
+/// Parse the digits of a `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` color.
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok();
+    // Short digits are repeated, e.g `#f80` is `#ff8800`
+    let short = |i: usize| digit(i).map(|d| d * 17);
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+
+    match hex.len() {
+        3 => Some(Color::from_rgb(short(0)?, short(1)?, short(2)?)),
+        4 => Some(Color::from_argb(short(3)?, short(0)?, short(1)?, short(2)?)),
+        6 => Some(Color::from_rgb(byte(0)?, byte(2)?, byte(4)?)),
+        8 => Some(Color::from_argb(byte(6)?, byte(0)?, byte(2)?, byte(4)?)),
+        _ => None,
+    }
+}
+
 impl DisplayColor for Color {
     fn fmt_rgb(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -70,116 +154,263 @@ impl DisplayColor for Color {
     }
 }
 
-fn parse_rgb(color: &str) -> Result<Color, ParseColorError> {
-    if !color.ends_with(')') {
-        return Err(ParseColorError);
-    }
-
-    let color = color.replacen("rgb(", "", 1).replacen(')', "", 1);
-
+fn parse_rgb(color: &str) -> Option<Color> {
+    let color = color.strip_prefix("rgb(")?.strip_suffix(')')?;
     let mut colors = color.split(',');
 
-    let r = colors
-        .next()
-        .ok_or(ParseColorError)?
-        .trim()
-        .parse::<u8>()
-        .map_err(|_| ParseColorError)?;
-    let g = colors
-        .next()
-        .ok_or(ParseColorError)?
-        .trim()
-        .parse::<u8>()
-        .map_err(|_| ParseColorError)?;
-    let b = colors
-        .next()
-        .ok_or(ParseColorError)?
-        .trim()
-        .parse::<u8>()
-        .map_err(|_| ParseColorError)?;
+    let r = colors.next()?.trim().parse::<u8>().ok()?;
+    let g = colors.next()?.trim().parse::<u8>().ok()?;
+    let b = colors.next()?.trim().parse::<u8>().ok()?;
     let a: Option<&str> = colors.next();
 
     // There should not be more than 4 components.
     if colors.next().is_some() {
-        return Err(ParseColorError);
+        return None;
     }
 
     if let Some(a) = a {
         let alpha_trimmed = a.trim();
         if let Ok(u8_alpha) = alpha_trimmed.parse::<u8>() {
-            Ok(Color::from_argb(u8_alpha, r, g, b))
-        } else if let Ok(f32_alpha) = alpha_trimmed.parse::<f32>() {
-            let a = (255.0 * f32_alpha).min(255.0).max(0.0).round() as u8;
-            Ok(Color::from_argb(a, r, g, b))
+            Some(Color::from_argb(u8_alpha, r, g, b))
         } else {
-            Err(ParseColorError)
+            let f32_alpha = alpha_trimmed.parse::<f32>().ok()?;
+            let a = (255.0 * f32_alpha).min(255.0).max(0.0).round() as u8;
+            Some(Color::from_argb(a, r, g, b))
         }
     } else {
-        Ok(Color::from_rgb(r, g, b))
+        Some(Color::from_rgb(r, g, b))
     }
 }
 
-fn parse_hsl(color: &str) -> Result<Color, ParseColorError> {
-    if !color.ends_with(')') {
-        return Err(ParseColorError);
+/// Parse a color like `rgba(0, 0, 0, 0.5)`, where the alpha is 0.0 - 1.0 or a percentage.
+fn parse_rgba(color: &str) -> Option<Color> {
+    let color = color.strip_prefix("rgba(")?.strip_suffix(')')?;
+    let mut colors = color.split(',');
+
+    let r = colors.next()?.trim().parse::<u8>().ok()?;
+    let g = colors.next()?.trim().parse::<u8>().ok()?;
+    let b = colors.next()?.trim().parse::<u8>().ok()?;
+    let a = parse_alpha(colors.next()?.trim())?;
+
+    // There should be exactly 4 components.
+    if colors.next().is_some() {
+        return None;
     }
 
-    let color = color.replacen("hsl(", "", 1).replacen(')', "", 1);
+    Some(Color::from_argb((a * 255.0).round() as u8, r, g, b))
+}
+
+/// Parse an alpha that can be a percentage, otherwise its 0.0 - 1.0
+fn parse_alpha(value: &str) -> Option<f32> {
+    if value.ends_with('%') {
+        parse_percentage(value)
+    } else {
+        Some(value.parse::<f32>().ok()?.clamp(0.0, 1.0))
+    }
+}
+
+/// Parse a percentage like `50%` as a fraction.
+fn parse_percentage(value: &str) -> Option<f32> {
+    let value = value.strip_suffix('%')?.trim().parse::<f32>().ok()?;
+    Some((value / 100.0).clamp(0.0, 1.0))
+}
+
+fn parse_hsl(color: &str) -> Option<Color> {
+    let color = color
+        .strip_prefix("hsla(")
+        .or_else(|| color.strip_prefix("hsl("))?
+        .strip_suffix(')')?;
     let mut colors = color.split(',');
 
     // Get each color component as a string
-    let h_str = colors.next().ok_or(ParseColorError)?.trim();
-    let s_str = colors.next().ok_or(ParseColorError)?.trim();
-    let l_str = colors.next().ok_or(ParseColorError)?.trim();
+    let h_str = colors.next()?.trim();
+    let s_str = colors.next()?.trim();
+    let l_str = colors.next()?.trim();
     let a_str: Option<&str> = colors.next();
 
-    // Ensure correct units and lengths.
-    if colors.next().is_some()
-        || !h_str.ends_with("deg")
-        || !s_str.ends_with('%')
-        || !l_str.ends_with('%')
-    {
-        return Err(ParseColorError);
+    // There should not be more than 4 components.
+    if colors.next().is_some() {
+        return None;
     }
 
-    // S, L and A can end in percentage, otherwise its 0.0 - 1.0
+    // The hue can be in degrees or unitless, S and L are percentages
     let h = h_str
-        .replacen("deg", "", 1)
-        .parse::<f32>()
-        .map_err(|_| ParseColorError)?;
-    let mut s = s_str
-        .replacen('%', "", 1)
-        .parse::<f32>()
-        .map_err(|_| ParseColorError)?
-        / 100.0;
-    let mut l = l_str
-        .replacen('%', "", 1)
+        .strip_suffix("deg")
+        .unwrap_or(h_str)
+        .trim()
         .parse::<f32>()
-        .map_err(|_| ParseColorError)?
-        / 100.0;
+        .ok()?;
+    let mut s = parse_percentage(s_str)?;
+    let mut l = parse_percentage(l_str)?;
 
     // HSL to HSV Conversion
     l *= 2.0;
     s *= if l <= 1.0 { l } else { 2.0 - l };
     let v = (l + s) / 2.0;
-    s = (2.0 * s) / (l + s);
-    let hsv = HSV::from((h, s, v));
-
-    // Handle alpha formatting and convert to ARGB
-    if let Some(a_str) = a_str {
-        if !s_str.ends_with('%') {
-            return Err(ParseColorError);
-        }
+    // Black has no saturation
+    s = if l + s > 0.0 {
+        (2.0 * s) / (l + s)
+    } else {
+        0.0
+    };
+    let hsv = HSV::from((h.rem_euclid(360.0), s, v));
 
-        let a = a_str
-            .trim()
-            .replace('%', "")
-            .parse::<f32>()
-            .map_err(|_| ParseColorError)?
-            / 100.0;
+    let a = match a_str {
+        Some(a_str) => parse_alpha(a_str.trim())?,
+        None => 1.0,
+    };
 
-        Ok(hsv.to_color((a * 255.0).round() as u8))
-    } else {
-        Ok(hsv.to_color(255))
-    }
+    Some(hsv.to_color((a * 255.0).round() as u8))
 }
+
+/// CSS color keywords, sorted by name.
+const NAMED_COLORS: &[(&str, (u8, u8, u8))] = &[
+    ("aliceblue", (240, 248, 255)),
+    ("antiquewhite", (250, 235, 215)),
+    ("aqua", (0, 255, 255)),
+    ("aquamarine", (127, 255, 212)),
+    ("azure", (240, 255, 255)),
+    ("beige", (245, 245, 220)),
+    ("bisque", (255, 228, 196)),
+    ("black", (0, 0, 0)),
+    ("blanchedalmond", (255, 235, 205)),
+    ("blue", (0, 0, 255)),
+    ("blueviolet", (138, 43, 226)),
+    ("brown", (165, 42, 42)),
+    ("burlywood", (222, 184, 135)),
+    ("cadetblue", (95, 158, 160)),
+    ("chartreuse", (127, 255, 0)),
+    ("chocolate", (210, 105, 30)),
+    ("coral", (255, 127, 80)),
+    ("cornflowerblue", (100, 149, 237)),
+    ("cornsilk", (255, 248, 220)),
+    ("crimson", (220, 20, 60)),
+    ("cyan", (0, 255, 255)),
+    ("darkblue", (0, 0, 139)),
+    ("darkcyan", (0, 139, 139)),
+    ("darkgoldenrod", (184, 134, 11)),
+    ("darkgray", (169, 169, 169)),
+    ("darkgreen", (0, 100, 0)),
+    ("darkgrey", (169, 169, 169)),
+    ("darkkhaki", (189, 183, 107)),
+    ("darkmagenta", (139, 0, 139)),
+    ("darkolivegreen", (85, 107, 47)),
+    ("darkorange", (255, 140, 0)),
+    ("darkorchid", (153, 50, 204)),
+    ("darkred", (139, 0, 0)),
+    ("darksalmon", (233, 150, 122)),
+    ("darkseagreen", (143, 188, 143)),
+    ("darkslateblue", (72, 61, 139)),
+    ("darkslategray", (47, 79, 79)),
+    ("darkslategrey", (47, 79, 79)),
+    ("darkturquoise", (0, 206, 209)),
+    ("darkviolet", (148, 0, 211)),
+    ("deeppink", (255, 20, 147)),
+    ("deepskyblue", (0, 191, 255)),
+    ("dimgray", (105, 105, 105)),
+    ("dimgrey", (105, 105, 105)),
+    ("dodgerblue", (30, 144, 255)),
+    ("firebrick", (178, 34, 34)),
+    ("floralwhite", (255, 250, 240)),
+    ("forestgreen", (34, 139, 34)),
+    ("fuchsia", (255, 0, 255)),
+    ("gainsboro", (220, 220, 220)),
+    ("ghostwhite", (248, 248, 255)),
+    ("gold", (255, 215, 0)),
+    ("goldenrod", (218, 165, 32)),
+    ("gray", (128, 128, 128)),
+    ("green", (0, 128, 0)),
+    ("greenyellow", (173, 255, 47)),
+    ("grey", (128, 128, 128)),
+    ("honeydew", (240, 255, 240)),
+    ("hotpink", (255, 105, 180)),
+    ("indianred", (205, 92, 92)),
+    ("indigo", (75, 0, 130)),
+    ("ivory", (255, 255, 240)),
+    ("khaki", (240, 230, 140)),
+    ("lavender", (230, 230, 250)),
+    ("lavenderblush", (255, 240, 245)),
+    ("lawngreen", (124, 252, 0)),
+    ("lemonchiffon", (255, 250, 205)),
+    ("lightblue", (173, 216, 230)),
+    ("lightcoral", (240, 128, 128)),
+    ("lightcyan", (224, 255, 255)),
+    ("lightgoldenrodyellow", (250, 250, 210)),
+    ("lightgray", (211, 211, 211)),
+    ("lightgreen", (144, 238, 144)),
+    ("lightgrey", (211, 211, 211)),
+    ("lightpink", (255, 182, 193)),
+    ("lightsalmon", (255, 160, 122)),
+    ("lightseagreen", (32, 178, 170)),
+    ("lightskyblue", (135, 206, 250)),
+    ("lightslategray", (119, 136, 153)),
+    ("lightslategrey", (119, 136, 153)),
+    ("lightsteelblue", (176, 196, 222)),
+    ("lightyellow", (255, 255, 224)),
+    ("lime", (0, 255, 0)),
+    ("limegreen", (50, 205, 50)),
+    ("linen", (250, 240, 230)),
+    ("magenta", (255, 0, 255)),
+    ("maroon", (128, 0, 0)),
+    ("mediumaquamarine", (102, 205, 170)),
+    ("mediumblue", (0, 0, 205)),
+    ("mediumorchid", (186, 85, 211)),
+    ("mediumpurple", (147, 112, 219)),
+    ("mediumseagreen", (60, 179, 113)),
+    ("mediumslateblue", (123, 104, 238)),
+    ("mediumspringgreen", (0, 250, 154)),
+    ("mediumturquoise", (72, 209, 204)),
+    ("mediumvioletred", (199, 21, 133)),
+    ("midnightblue", (25, 25, 112)),
+    ("mintcream", (245, 255, 250)),
+    ("mistyrose", (255, 228, 225)),
+    ("moccasin", (255, 228, 181)),
+    ("navajowhite", (255, 222, 173)),
+    ("navy", (0, 0, 128)),
+    ("oldlace", (253, 245, 230)),
+    ("olive", (128, 128, 0)),
+    ("olivedrab", (107, 142, 35)),
+    ("orange", (255, 165, 0)),
+    ("orangered", (255, 69, 0)),
+    ("orchid", (218, 112, 214)),
+    ("palegoldenrod", (238, 232, 170)),
+    ("palegreen", (152, 251, 152)),
+    ("paleturquoise", (175, 238, 238)),
+    ("palevioletred", (219, 112, 147)),
+    ("papayawhip", (255, 239, 213)),
+    ("peachpuff", (255, 218, 185)),
+    ("peru", (205, 133, 63)),
+    ("pink", (255, 192, 203)),
+    ("plum", (221, 160, 221)),
+    ("powderblue", (176, 224, 230)),
+    ("purple", (128, 0, 128)),
+    ("rebeccapurple", (102, 51, 153)),
+    ("red", (255, 0, 0)),
+    ("rosybrown", (188, 143, 143)),
+    ("royalblue", (65, 105, 225)),
+    ("saddlebrown", (139, 69, 19)),
+    ("salmon", (250, 128, 114)),
+    ("sandybrown", (244, 164, 96)),
+    ("seagreen", (46, 139, 87)),
+    ("seashell", (255, 245, 238)),
+    ("sienna", (160, 82, 45)),
+    ("silver", (192, 192, 192)),
+    ("skyblue", (135, 206, 235)),
+    ("slateblue", (106, 90, 205)),
+    ("slategray", (112, 128, 144)),
+    ("slategrey", (112, 128, 144)),
+    ("snow", (255, 250, 250)),
+    ("springgreen", (0, 255, 127)),
+    ("steelblue", (70, 130, 180)),
+    ("tan", (210, 180, 140)),
+    ("teal", (0, 128, 128)),
+    ("thistle", (216, 191, 216)),
+    ("tomato", (255, 99, 71)),
+    ("turquoise", (64, 224, 208)),
+    ("violet", (238, 130, 238)),
+    ("wheat", (245, 222, 179)),
+    ("white", (255, 255, 255)),
+    ("whitesmoke", (245, 245, 245)),
+    ("yellow", (255, 255, 0)),
+    ("yellowgreen", (154, 205, 50)),
+];
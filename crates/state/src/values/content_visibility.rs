@@ -0,0 +1,16 @@
+use crate::Parse;
+use torin::content_visibility::ContentVisibility;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseContentVisibilityError;
+
+impl Parse for ContentVisibility {
+    type Err = ParseContentVisibilityError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "auto" => ContentVisibility::Auto,
+            _ => ContentVisibility::Visible,
+        })
+    }
+}
@@ -11,6 +11,9 @@ pub struct CornerRadius {
     pub bottom_left: f32,
     pub bottom_right: f32,
     pub smoothing: f32,
+    /// Vertical radii of elliptical corners, in the same order as the fields.
+    /// The corners are circular when this is `None`.
+    pub vertical: Option<[f32; 4]>,
 }
 
 // https://www.figma.com/blog/desperately-seeking-squircles/
@@ -54,11 +57,19 @@ impl CornerRadius {
     pub fn fill_top(&mut self, value: f32) {
         self.top_left = value;
         self.top_right = value;
+        if let Some(vertical) = &mut self.vertical {
+            vertical[0] = value;
+            vertical[1] = value;
+        }
     }
 
     pub fn fill_bottom(&mut self, value: f32) {
         self.bottom_left = value;
         self.bottom_right = value;
+        if let Some(vertical) = &mut self.vertical {
+            vertical[2] = value;
+            vertical[3] = value;
+        }
     }
 
     pub fn fill_all(&mut self, value: f32) {
@@ -66,6 +77,31 @@ impl CornerRadius {
         self.fill_top(value);
     }
 
+    /// Check if all the corners are square.
+    pub fn is_zero(&self) -> bool {
+        self.top_left == 0.0
+            && self.top_right == 0.0
+            && self.bottom_left == 0.0
+            && self.bottom_right == 0.0
+    }
+
+    /// Horizontal and vertical radius of each corner, ordered as [`RRect`] expects them:
+    /// top left, top right, bottom right and bottom left.
+    pub fn radii(&self) -> [Point; 4] {
+        let [top_left_y, top_right_y, bottom_left_y, bottom_right_y] = self.vertical.unwrap_or([
+            self.top_left,
+            self.top_right,
+            self.bottom_left,
+            self.bottom_right,
+        ]);
+        [
+            (self.top_left, top_left_y).into(),
+            (self.top_right, top_right_y).into(),
+            (self.bottom_right, bottom_right_y).into(),
+            (self.bottom_left, bottom_left_y).into(),
+        ]
+    }
+
     // https://github.com/aloisdeniel/figma_squircle/blob/main/lib/src/path_smooth_corners.dart
     pub fn smoothed_path(&self, rect: RRect) -> Path {
         let mut path = Path::new();
@@ -194,84 +230,86 @@ impl CornerRadius {
 #[derive(Debug, PartialEq, Eq)]
 pub struct ParseCornerRadiusError;
 
+/// Parse the smoothing of the corners, either as a fraction (`0.6`) or as a percentage (`60%`).
+///
+/// The smoothing is clamped between `0` (circular corners) and `1` (fully smoothed corners).
+pub fn parse_corner_smoothing(value: &str) -> Result<f32, ParseCornerRadiusError> {
+    let smoothing = if let Some(percentage) = value.strip_suffix('%') {
+        percentage
+            .parse::<f32>()
+            .map_err(|_| ParseCornerRadiusError)?
+            / 100.0
+    } else {
+        value.parse::<f32>().map_err(|_| ParseCornerRadiusError)?
+    };
+    Ok(smoothing.clamp(0.0, 1.0))
+}
+
+/// Parse the radius of a corner, either circular (`8`) or elliptical (`8/4`).
+fn parse_corner(value: &str) -> Result<(f32, f32), ParseCornerRadiusError> {
+    let (x, y) = value.split_once('/').unwrap_or((value, value));
+    let x = x.parse::<f32>().map_err(|_| ParseCornerRadiusError)?;
+    let y = y.parse::<f32>().map_err(|_| ParseCornerRadiusError)?;
+    Ok((x, y))
+}
+
 impl Parse for CornerRadius {
     type Err = ParseCornerRadiusError;
 
     fn parse(value: &str) -> Result<Self, Self::Err> {
-        let mut radius = CornerRadius::default();
-        let mut values = value.split_ascii_whitespace();
+        let corners = value
+            .split_ascii_whitespace()
+            .map(parse_corner)
+            .collect::<Result<Vec<_>, _>>()?;
 
-        match values.clone().count() {
+        let [top_left, top_right, bottom_left, bottom_right] = match corners[..] {
             // Same in all corners
-            1 => {
-                radius.fill_all(
-                    values
-                        .next()
-                        .ok_or(ParseCornerRadiusError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseCornerRadiusError)?,
-                );
-            }
+            [all] => [all; 4],
             // By Top and Bottom
-            2 => {
-                // Top
-                radius.fill_top(
-                    values
-                        .next()
-                        .ok_or(ParseCornerRadiusError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseCornerRadiusError)?,
-                );
-
-                // Bottom
-                radius.fill_bottom(
-                    values
-                        .next()
-                        .ok_or(ParseCornerRadiusError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseCornerRadiusError)?,
-                )
-            }
+            [top, bottom] => [top, top, bottom, bottom],
             // Each corner
-            4 => {
-                radius = CornerRadius {
-                    top_left: values
-                        .next()
-                        .ok_or(ParseCornerRadiusError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseCornerRadiusError)?,
-                    top_right: values
-                        .next()
-                        .ok_or(ParseCornerRadiusError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseCornerRadiusError)?,
-                    bottom_left: values
-                        .next()
-                        .ok_or(ParseCornerRadiusError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseCornerRadiusError)?,
-                    bottom_right: values
-                        .next()
-                        .ok_or(ParseCornerRadiusError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseCornerRadiusError)?,
-                    ..Default::default()
-                }
+            [top_left, top_right, bottom_left, bottom_right] => {
+                [top_left, top_right, bottom_left, bottom_right]
             }
             _ => return Err(ParseCornerRadiusError),
-        }
-
-        Ok(radius)
+        };
+
+        let corners = [top_left, top_right, bottom_left, bottom_right];
+        let is_elliptical = corners.iter().any(|(x, y)| x != y);
+
+        Ok(CornerRadius {
+            top_left: top_left.0,
+            top_right: top_right.0,
+            bottom_left: bottom_left.0,
+            bottom_right: bottom_right.0,
+            smoothing: 0.0,
+            vertical: is_elliptical.then(|| corners.map(|(_, y)| y)),
+        })
     }
 }
 
 impl fmt::Display for CornerRadius {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{} {} {} {}",
-            self.top_left, self.top_right, self.bottom_left, self.bottom_right
-        )
+        if let Some([top_left_y, top_right_y, bottom_left_y, bottom_right_y]) = self.vertical {
+            write!(
+                f,
+                "{}/{} {}/{} {}/{} {}/{}",
+                self.top_left,
+                top_left_y,
+                self.top_right,
+                top_right_y,
+                self.bottom_left,
+                bottom_left_y,
+                self.bottom_right,
+                bottom_right_y
+            )
+        } else {
+            write!(
+                f,
+                "{} {} {} {}",
+                self.top_left, self.top_right, self.bottom_left, self.bottom_right
+            )
+        }
     }
 }
 
@@ -281,5 +319,10 @@ impl Scaled for CornerRadius {
         self.top_right *= scale;
         self.bottom_left *= scale;
         self.bottom_right *= scale;
+        if let Some(vertical) = &mut self.vertical {
+            for radius in vertical {
+                *radius *= scale;
+            }
+        }
     }
 }
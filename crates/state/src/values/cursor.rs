@@ -1,5 +1,6 @@
 use crate::Parse;
 use std::fmt;
+use winit::window::CursorIcon;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CursorMode {
@@ -29,3 +30,39 @@ impl fmt::Display for CursorMode {
         })
     }
 }
+
+impl Parse for CursorIcon {
+    type Err = ParseCursorError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "default" => CursorIcon::Default,
+            "pointer" => CursorIcon::Pointer,
+            "text" => CursorIcon::Text,
+            "vertical-text" => CursorIcon::VerticalText,
+            "crosshair" => CursorIcon::Crosshair,
+            "help" => CursorIcon::Help,
+            "wait" => CursorIcon::Wait,
+            "progress" => CursorIcon::Progress,
+            "context-menu" => CursorIcon::ContextMenu,
+            "cell" => CursorIcon::Cell,
+            "move" => CursorIcon::Move,
+            "grab" => CursorIcon::Grab,
+            "grabbing" => CursorIcon::Grabbing,
+            "copy" => CursorIcon::Copy,
+            "alias" => CursorIcon::Alias,
+            "no-drop" => CursorIcon::NoDrop,
+            "not-allowed" => CursorIcon::NotAllowed,
+            "all-scroll" => CursorIcon::AllScroll,
+            "zoom-in" => CursorIcon::ZoomIn,
+            "zoom-out" => CursorIcon::ZoomOut,
+            "col-resize" => CursorIcon::ColResize,
+            "row-resize" => CursorIcon::RowResize,
+            "ew-resize" => CursorIcon::EwResize,
+            "ns-resize" => CursorIcon::NsResize,
+            "nesw-resize" => CursorIcon::NeswResize,
+            "nwse-resize" => CursorIcon::NwseResize,
+            _ => return Err(ParseCursorError),
+        })
+    }
+}
@@ -0,0 +1,17 @@
+use crate::Parse;
+use torin::display::DisplayMode;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDisplayModeError;
+
+impl Parse for DisplayMode {
+    type Err = ParseDisplayModeError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "normal" => Ok(DisplayMode::Normal),
+            "none" => Ok(DisplayMode::None),
+            _ => Err(ParseDisplayModeError),
+        }
+    }
+}
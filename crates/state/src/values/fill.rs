@@ -1,4 +1,4 @@
-use crate::{DisplayColor, LinearGradient, Parse};
+use crate::{DisplayColor, ImageFill, LinearGradient, Parse};
 use freya_engine::prelude::Color;
 use std::fmt;
 
@@ -6,6 +6,7 @@ use std::fmt;
 pub enum Fill {
     Color(Color),
     LinearGradient(LinearGradient),
+    Image(ImageFill),
     // RadialGradient(RadialGradient),
     // ConicGradient(ConicGradient),
 }
@@ -42,6 +43,7 @@ impl fmt::Display for Fill {
         match self {
             Self::Color(color) => color.fmt_rgb(f),
             Self::LinearGradient(gradient) => gradient.fmt(f),
+            Self::Image(image) => image.fmt(f),
         }
     }
 }
@@ -15,7 +15,7 @@ impl Parse for TextAlign {
             "end" => TextAlign::End,
             "left" => TextAlign::Left,
             "right" => TextAlign::Right,
-            _ => TextAlign::default(),
+            _ => TextAlign::Start,
         })
     }
 }
@@ -31,7 +31,7 @@ impl Parse for Slant {
             "upright" => Slant::Upright,
             "italic" => Slant::Italic,
             "oblique" => Slant::Oblique,
-            _ => Slant::Upright,
+            _ => return Err(ParseSlantError),
         })
     }
 }
@@ -69,13 +69,14 @@ impl Parse for Weight {
     // CSS has one deviation from this spec, which uses the value "950" for extra_black.
     // skia_safe also has an "invisible" weight smaller than the thin weight, which could fall under CSS's interpretation of OpenType's
     // version. In this case it would be font_weight: "50".
+    // Any other number from 1 to 1000 is used as it is, for variable fonts.
     fn parse(value: &str) -> Result<Self, Self::Err> {
         Ok(match value {
             "invisible" => Weight::INVISIBLE,
             "thin" => Weight::THIN,
             "extra-light" => Weight::EXTRA_LIGHT,
             "light" => Weight::LIGHT,
-            "normal" => Weight::NORMAL,
+            "normal" | "regular" => Weight::NORMAL,
             "medium" => Weight::MEDIUM,
             "semi-bold" => Weight::SEMI_BOLD,
             "bold" => Weight::BOLD,
@@ -93,7 +94,10 @@ impl Parse for Weight {
             "800" => Weight::EXTRA_BOLD,
             "900" => Weight::BLACK,
             "950" => Weight::EXTRA_BLACK,
-            _ => Weight::NORMAL,
+            value => match value.parse::<i32>() {
+                Ok(weight) if (1..=1000).contains(&weight) => Weight::from(weight),
+                _ => return Err(ParseWeightError),
+            },
         })
     }
 }
@@ -110,7 +114,7 @@ impl TextOverflow {
     pub fn get_ellipsis(&self) -> Option<&str> {
         match self {
             Self::Clip => None,
-            Self::Ellipsis => Some("..."),
+            Self::Ellipsis => Some("…"),
             Self::Custom(custom) => Some(custom),
         }
     }
@@ -138,3 +142,58 @@ impl Parse for TextOverflow {
         })
     }
 }
+
+/// Height of the lines of text, see the `line_height` attribute.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineHeight {
+    /// Multiplier of the font size of every text span, e.g `1.5`.
+    Multiplier(f32),
+    /// Height in pixels, the same for any font size, e.g `24px`.
+    Pixels(f32),
+}
+
+impl Default for LineHeight {
+    fn default() -> Self {
+        Self::Multiplier(1.2)
+    }
+}
+
+impl LineHeight {
+    /// Get the line height as a multiplier of the given font size, like Skia expects it.
+    pub fn multiplier(&self, font_size: f32) -> f32 {
+        match self {
+            Self::Multiplier(multiplier) => *multiplier,
+            Self::Pixels(pixels) if font_size > 0.0 => pixels / font_size,
+            Self::Pixels(_) => 1.0,
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Multiplier(multiplier) => multiplier.to_string(),
+            Self::Pixels(pixels) => format!("{pixels}px"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseLineHeightError;
+
+impl Parse for LineHeight {
+    type Err = ParseLineHeightError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let line_height = if let Some(pixels) = value.trim().strip_suffix("px") {
+            LineHeight::Pixels(pixels.trim().parse().map_err(|_| ParseLineHeightError)?)
+        } else {
+            LineHeight::Multiplier(value.trim().parse().map_err(|_| ParseLineHeightError)?)
+        };
+
+        match line_height {
+            LineHeight::Multiplier(value) | LineHeight::Pixels(value) if value < 0.0 => {
+                Err(ParseLineHeightError)
+            }
+            line_height => Ok(line_height),
+        }
+    }
+}
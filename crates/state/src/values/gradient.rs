@@ -16,13 +16,16 @@ impl Parse for GradientStop {
     type Err = ParseGradientStopError;
 
     fn parse(value: &str) -> Result<Self, Self::Err> {
-        let mut split = value.split_ascii_whitespace_excluding_group('(', ')');
-        let color_str = split.next().ok_or(ParseGradientStopError)?;
+        let mut split = value
+            .split_ascii_whitespace_excluding_group('(', ')')
+            .collect::<Vec<_>>();
 
-        let offset_str = split.next().ok_or(ParseGradientStopError)?.trim();
-        if !offset_str.ends_with('%') || split.next().is_some() {
+        // The offset is the last value, the color can have several, e.g `red opacity(0.5) 50%`
+        let offset_str = split.pop().ok_or(ParseGradientStopError)?.trim();
+        if !offset_str.ends_with('%') || split.is_empty() {
             return Err(ParseGradientStopError);
         }
+        let color_str = split.join(" ");
 
         let offset = offset_str
             .replacen('%', "", 1)
@@ -31,7 +34,7 @@ impl Parse for GradientStop {
             / 100.0;
 
         Ok(GradientStop {
-            color: Color::parse(color_str).map_err(|_| ParseGradientStopError)?,
+            color: Color::parse(&color_str).map_err(|_| ParseGradientStopError)?,
             offset,
         })
     }
@@ -44,32 +47,84 @@ impl fmt::Display for GradientStop {
     }
 }
 
+/// Corner a gradient goes towards, e.g `to top right`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradientCorner {
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+
+impl GradientCorner {
+    /// Horizontal and vertical sign of the corner, from the center of the element.
+    fn signs(&self) -> (f32, f32) {
+        match self {
+            Self::TopLeft => (-1.0, -1.0),
+            Self::TopRight => (1.0, -1.0),
+            Self::BottomRight => (1.0, 1.0),
+            Self::BottomLeft => (-1.0, 1.0),
+        }
+    }
+}
+
+impl fmt::Display for GradientCorner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::TopLeft => "top left",
+            Self::TopRight => "top right",
+            Self::BottomRight => "bottom right",
+            Self::BottomLeft => "bottom left",
+        })
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct LinearGradient {
     pub stops: Vec<GradientStop>,
+    /// Direction of the gradient in radians, `0` goes to the bottom and `PI / 2` to the left.
     pub angle: f32,
+    /// When set, the gradient goes towards this corner instead of following `angle`.
+    pub corner: Option<GradientCorner>,
 }
 
 impl LinearGradient {
+    /// Get the angle of the gradient in the given bounds.
+    ///
+    /// Gradients towards a corner depend on the aspect ratio of the element,
+    /// so the opposite corners share the middle color.
+    pub fn angle_in(&self, bounds: Rect<f32, Measure>) -> f32 {
+        match self.corner {
+            Some(corner) => {
+                let (x, y) = corner.signs();
+                // Perpendicular to the diagonal between the two neighbouring corners
+                (-x * bounds.height()).atan2(y * bounds.width())
+            }
+            None => self.angle,
+        }
+    }
+
+    /// Get the start and end points of the gradient line in the given bounds.
+    ///
+    /// The line goes through the center of the element and is long enough
+    /// for the first and last colors to reach the farthest corners.
+    pub fn endpoints(&self, bounds: Rect<f32, Measure>) -> (Point, Point) {
+        let (sin, cos) = self.angle_in(bounds).sin_cos();
+        let length = (bounds.width() * sin).abs() + (bounds.height() * cos).abs();
+        let center = Point::new(
+            bounds.min_x() + bounds.width() / 2.0,
+            bounds.min_y() + bounds.height() / 2.0,
+        );
+        let half_line = Point::new(-sin, cos) * (length / 2.0);
+        (center - half_line, center + half_line)
+    }
+
     pub fn into_shader(&self, bounds: Rect<f32, Measure>) -> Option<Shader> {
         let colors: Vec<Color> = self.stops.iter().map(|stop| stop.color).collect();
         let offsets: Vec<f32> = self.stops.iter().map(|stop| stop.offset).collect();
 
-        let (dx, dy) = (-self.angle).sin_cos();
-        let farthest_corner = Point::new(
-            if dx > 0.0 { bounds.width() } else { 0.0 },
-            if dy > 0.0 { bounds.height() } else { 0.0 },
-        );
-        let delta = farthest_corner - Point::new(bounds.width(), bounds.height()) / 2.0;
-        let u = delta.x * dy - delta.y * dx;
-        let endpoint = farthest_corner + Point::new(-u * dy, u * dx);
-
-        let origin = Point::new(bounds.min_x(), bounds.min_y());
         Shader::linear_gradient(
-            (
-                Point::new(bounds.width(), bounds.height()) - endpoint + origin,
-                endpoint + origin,
-            ),
+            self.endpoints(bounds),
             GradientShaderColors::Colors(&colors[..]),
             Some(&offsets[..]),
             TileMode::Clamp,
@@ -79,6 +134,49 @@ impl LinearGradient {
     }
 }
 
+/// Parse a named direction like `to right` or `to bottom left`, returning the angle or the corner.
+fn parse_direction(value: &str) -> Result<(f32, Option<GradientCorner>), ParseLinearGradientError> {
+    let mut words = value.split_ascii_whitespace();
+    if words.next() != Some("to") {
+        return Err(ParseLinearGradientError);
+    }
+
+    let mut horizontal = None;
+    let mut vertical = None;
+    for word in words {
+        let (side, direction) = match word {
+            "top" => (&mut vertical, -1.0),
+            "bottom" => (&mut vertical, 1.0),
+            "left" => (&mut horizontal, -1.0),
+            "right" => (&mut horizontal, 1.0),
+            _ => return Err(ParseLinearGradientError),
+        };
+        // Each axis can only be used once, e.g `to left right` is invalid
+        if side.replace(direction).is_some() {
+            return Err(ParseLinearGradientError);
+        }
+    }
+
+    let angle = match (horizontal, vertical) {
+        (None, Some(y)) if y > 0.0 => 0.0,
+        (Some(x), None) if x < 0.0 => 90.0,
+        (None, Some(_)) => 180.0,
+        (Some(_), None) => 270.0,
+        (Some(x), Some(y)) => {
+            let corner = match (x > 0.0, y > 0.0) {
+                (false, false) => GradientCorner::TopLeft,
+                (true, false) => GradientCorner::TopRight,
+                (true, true) => GradientCorner::BottomRight,
+                (false, true) => GradientCorner::BottomLeft,
+            };
+            return Ok((0.0, Some(corner)));
+        }
+        (None, None) => return Err(ParseLinearGradientError),
+    };
+
+    Ok((f32::to_radians(angle), None))
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct ParseLinearGradientError;
 
@@ -102,6 +200,8 @@ impl Parse for LinearGradient {
             if let Ok(angle) = angle_or_first_stop.replacen("deg", "", 1).parse::<f32>() {
                 gradient.angle = angle.to_radians();
             }
+        } else if angle_or_first_stop.starts_with("to ") {
+            (gradient.angle, gradient.corner) = parse_direction(angle_or_first_stop)?;
         } else {
             gradient.stops.push(
                 GradientStop::parse(angle_or_first_stop).map_err(|_| ParseLinearGradientError)?,
@@ -120,10 +220,13 @@ impl Parse for LinearGradient {
 
 impl fmt::Display for LinearGradient {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.corner {
+            Some(corner) => write!(f, "linear-gradient(to {corner}, ")?,
+            None => write!(f, "linear-gradient({}deg, ", self.angle.to_degrees())?,
+        }
         write!(
             f,
-            "linear-gradient({}deg, {})",
-            self.angle.to_degrees(),
+            "{})",
             self.stops
                 .iter()
                 .map(|stop| stop.to_string())
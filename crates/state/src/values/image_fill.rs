@@ -0,0 +1,146 @@
+use crate::{AttributesBytes, Parse};
+use freya_engine::prelude::*;
+use std::fmt;
+use torin::{prelude::Measure, size::Rect};
+
+/// How an image fill covers an element.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ImageRepeat {
+    /// Tile the image in both directions.
+    #[default]
+    Repeat,
+    /// Tile the image horizontally.
+    RepeatX,
+    /// Tile the image vertically.
+    RepeatY,
+    /// Draw the image once.
+    NoRepeat,
+    /// Scale the image to the size of the element.
+    Stretch,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseImageRepeatError;
+
+impl Parse for ImageRepeat {
+    type Err = ParseImageRepeatError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "repeat" => Self::Repeat,
+            "repeat-x" => Self::RepeatX,
+            "repeat-y" => Self::RepeatY,
+            "no-repeat" => Self::NoRepeat,
+            "stretch" => Self::Stretch,
+            _ => return Err(ParseImageRepeatError),
+        })
+    }
+}
+
+impl fmt::Display for ImageRepeat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Repeat => "repeat",
+            Self::RepeatX => "repeat-x",
+            Self::RepeatY => "repeat-y",
+            Self::NoRepeat => "no-repeat",
+            Self::Stretch => "stretch",
+        })
+    }
+}
+
+/// Where an image fill starts, as a fraction of the free space in each axis:
+/// `0` is the start of the element, `0.5` the center and `1` the end.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ImagePosition {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseImagePositionError;
+
+fn parse_position(value: &str) -> Result<f32, ParseImagePositionError> {
+    Ok(match value {
+        "start" => 0.0,
+        "center" => 0.5,
+        "end" => 1.0,
+        _ => return Err(ParseImagePositionError),
+    })
+}
+
+impl Parse for ImagePosition {
+    type Err = ParseImagePositionError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut values = value.split_ascii_whitespace();
+        let x = parse_position(values.next().ok_or(ParseImagePositionError)?)?;
+        // The same position is used in both axis if there is only one
+        let y = values.next().map(parse_position).transpose()?.unwrap_or(x);
+
+        if values.next().is_some() {
+            return Err(ParseImagePositionError);
+        }
+
+        Ok(Self { x, y })
+    }
+}
+
+/// Image used as the fill of an element.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageFill {
+    /// Encoded image, e.g a PNG.
+    pub data: AttributesBytes,
+    pub repeat: ImageRepeat,
+    pub position: ImagePosition,
+}
+
+impl ImageFill {
+    pub fn new(data: AttributesBytes) -> Self {
+        Self {
+            data,
+            repeat: ImageRepeat::default(),
+            position: ImagePosition::default(),
+        }
+    }
+
+    /// Create a shader painting the decoded image in the given bounds.
+    pub fn into_shader(&self, image: &Image, bounds: Rect<f32, Measure>) -> Option<Shader> {
+        let (image_width, image_height) = (image.width() as f32, image.height() as f32);
+        if image_width <= 0.0 || image_height <= 0.0 {
+            return None;
+        }
+
+        if self.repeat == ImageRepeat::Stretch {
+            let mut matrix = Matrix::translate((bounds.min_x(), bounds.min_y()));
+            matrix.pre_scale(
+                (bounds.width() / image_width, bounds.height() / image_height),
+                None,
+            );
+            return image.to_shader(
+                (TileMode::Clamp, TileMode::Clamp),
+                FilterMode::Linear,
+                &matrix,
+            );
+        }
+
+        let tile_modes = match self.repeat {
+            ImageRepeat::RepeatX => (TileMode::Repeat, TileMode::Decal),
+            ImageRepeat::RepeatY => (TileMode::Decal, TileMode::Repeat),
+            ImageRepeat::NoRepeat => (TileMode::Decal, TileMode::Decal),
+            ImageRepeat::Repeat | ImageRepeat::Stretch => (TileMode::Repeat, TileMode::Repeat),
+        };
+
+        let matrix = Matrix::translate((
+            bounds.min_x() + (bounds.width() - image_width) * self.position.x,
+            bounds.min_y() + (bounds.height() - image_height) * self.position.y,
+        ));
+        image.to_shader(tile_modes, FilterMode::Linear, &matrix)
+    }
+}
+
+impl fmt::Display for ImageFill {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "image({})", self.repeat)
+    }
+}
@@ -0,0 +1,17 @@
+use crate::Parse;
+use torin::direction::LayoutDirection;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseLayoutDirectionError;
+
+impl Parse for LayoutDirection {
+    type Err = ParseLayoutDirectionError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ltr" => Ok(LayoutDirection::Ltr),
+            "rtl" => Ok(LayoutDirection::Rtl),
+            _ => Err(ParseLayoutDirectionError),
+        }
+    }
+}
@@ -1,31 +1,49 @@
 mod alignment;
 mod border;
 mod color;
+mod content_visibility;
 mod corner_radius;
 mod cursor;
 mod decoration;
+mod display_mode;
 mod fill;
 mod font;
 mod gaps;
 mod gradient;
+mod image_fill;
+mod layout_direction;
 mod overflow;
 mod position;
+mod role;
 mod shadow;
 mod size;
+mod svg_colors;
+mod svg_fit;
+mod svg_size;
 mod text_shadow;
+mod visibility;
 
 pub use alignment::*;
 pub use border::*;
 pub use color::*;
+pub use content_visibility::*;
 pub use corner_radius::*;
 pub use cursor::*;
 pub use decoration::*;
+pub use display_mode::*;
 pub use fill::*;
 pub use font::*;
 pub use gaps::*;
 pub use gradient::*;
+pub use image_fill::*;
+pub use layout_direction::*;
 pub use overflow::*;
 pub use position::*;
+pub use role::*;
 pub use shadow::*;
 pub use size::*;
+pub use svg_colors::*;
+pub use svg_fit::*;
+pub use svg_size::*;
 pub use text_shadow::*;
+pub use visibility::*;
@@ -0,0 +1,53 @@
+use crate::Parse;
+use accesskit::Role;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseRoleError;
+
+impl Parse for Role {
+    type Err = ParseRoleError;
+
+    /// Parse an [accesskit role](https://docs.rs/accesskit/latest/accesskit/enum.Role.html),
+    /// written in camelCase, snake_case, kebab-case or with its ARIA name.
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let role = match value {
+            "textbox" => Role::TextInput,
+            "searchbox" => Role::SearchInput,
+            "checkbox" => Role::CheckBox,
+            "combobox" => Role::ComboBox,
+            "spinbutton" => Role::SpinButton,
+            "img" => Role::Image,
+            "listitem" => Role::ListItem,
+            "menuitem" => Role::MenuItem,
+            "treeitem" => Role::TreeItem,
+            "tablist" => Role::TabList,
+            "tabpanel" => Role::TabPanel,
+            "radiogroup" => Role::RadioGroup,
+            "progressbar" => Role::ProgressIndicator,
+            "scrollbar" => Role::ScrollBar,
+            "gridcell" => Role::Cell,
+            "columnheader" => Role::ColumnHeader,
+            "rowheader" => Role::RowHeader,
+            "text" => Role::StaticText,
+            value => {
+                let mut camel_case = String::with_capacity(value.len());
+                let mut upper = false;
+                for c in value.chars() {
+                    if c == '_' || c == '-' {
+                        upper = true;
+                    } else if upper {
+                        camel_case.push(c.to_ascii_uppercase());
+                        upper = false;
+                    } else {
+                        camel_case.push(c);
+                    }
+                }
+
+                serde_json::from_str::<Role>(&format!("\"{camel_case}\""))
+                    .map_err(|_| ParseRoleError)?
+            }
+        };
+
+        Ok(role)
+    }
+}
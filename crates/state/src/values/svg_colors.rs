@@ -0,0 +1,184 @@
+use std::borrow::Cow;
+
+use freya_engine::prelude::Color;
+
+/// Colors that replace the ones of an SVG, so the same icon can be used with different colors, e.g in light and dark themes.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SvgColors {
+    /// Replaces `currentColor`, its alpha channel is ignored.
+    pub color: Option<Color>,
+    /// Replaces every fill except `none`, also used by the shapes without a fill.
+    /// Its alpha channel is set as the `fill-opacity` of the root element.
+    pub fill: Option<Color>,
+    /// Replaces every stroke except `none`.
+    /// Its alpha channel is set as the `stroke-opacity` of the root element.
+    pub stroke: Option<Color>,
+}
+
+impl SvgColors {
+    /// Whether no color is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.color.is_none() && self.fill.is_none() && self.stroke.is_none()
+    }
+
+    /// Restyle the given SVG source with these colors.
+    pub fn apply<'a>(&self, svg: &'a [u8]) -> Cow<'a, [u8]> {
+        if self.is_empty() {
+            return Cow::Borrowed(svg);
+        }
+
+        let Ok(source) = std::str::from_utf8(svg) else {
+            return Cow::Borrowed(svg);
+        };
+
+        let mut source = source.to_string();
+
+        if let Some(color) = self.color {
+            source = source.replace("currentColor", &svg_color(color));
+        }
+
+        if let Some(fill) = self.fill {
+            if fill.a() < u8::MAX {
+                set_root_property(&mut source, "fill-opacity", &svg_opacity(fill));
+            }
+
+            let fill = svg_color(fill);
+            source = replace_property(&source, "fill", &fill);
+
+            // Shapes without a fill are black by default, so they inherit it from the root instead
+            if let Some((start, end)) = root_tag(&source) {
+                if !has_property(&source[start..end], "fill") {
+                    source.insert_str(start + "<svg".len(), &format!(" fill=\"{fill}\""));
+                }
+            }
+        }
+
+        if let Some(stroke) = self.stroke {
+            if stroke.a() < u8::MAX {
+                set_root_property(&mut source, "stroke-opacity", &svg_opacity(stroke));
+            }
+
+            source = replace_property(&source, "stroke", &svg_color(stroke));
+        }
+
+        Cow::Owned(source.into_bytes())
+    }
+}
+
+/// Format a color for SVG, the alpha channel is ignored.
+fn svg_color(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
+}
+
+/// Format the alpha channel of a color as an SVG opacity.
+fn svg_opacity(color: Color) -> String {
+    let opacity = format!("{:.3}", color.a() as f32 / 255.0);
+    opacity
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Set the given attribute of the root `<svg ...>` tag, replacing its current value if any.
+fn set_root_property(source: &mut String, name: &str, value: &str) {
+    let Some((start, end)) = root_tag(source) else {
+        return;
+    };
+
+    let values = find_property(&source[start..end], name);
+    if values.is_empty() {
+        source.insert_str(start + "<svg".len(), &format!(" {name}=\"{value}\""));
+    } else {
+        for (value_start, value_end) in values.into_iter().rev() {
+            source.replace_range(start + value_start..start + value_end, value);
+        }
+    }
+}
+
+/// Find the start and end of the root `<svg ...>` tag.
+pub(crate) fn root_tag(source: &str) -> Option<(usize, usize)> {
+    let start = source.find("<svg")?;
+    let end = start + source[start..].find('>')?;
+    Some((start, end))
+}
+
+/// Find the values of the given attribute (`name="value"`) or style property (`name: value`),
+/// returning the range of each value.
+pub(crate) fn find_property(source: &str, name: &str) -> Vec<(usize, usize)> {
+    let bytes = source.as_bytes();
+    let mut values = Vec::new();
+    let mut offset = 0;
+
+    while let Some(found) = source[offset..].find(name) {
+        let start = offset + found;
+        let mut cursor = start + name.len();
+        offset = cursor;
+
+        // Skip properties that only end with the name, e.g `flood-fill`
+        let is_separated = start == 0
+            || matches!(
+                bytes[start - 1],
+                b' ' | b'\t' | b'\n' | b'\r' | b';' | b'"' | b'\'' | b'{'
+            );
+        if !is_separated {
+            continue;
+        }
+
+        while cursor < bytes.len() && bytes[cursor].is_ascii_whitespace() {
+            cursor += 1;
+        }
+
+        match bytes.get(cursor) {
+            // Attribute
+            Some(b'=') => {
+                cursor += 1;
+                while cursor < bytes.len() && bytes[cursor].is_ascii_whitespace() {
+                    cursor += 1;
+                }
+                let Some(quote @ (b'"' | b'\'')) = bytes.get(cursor).copied() else {
+                    continue;
+                };
+                let value_start = cursor + 1;
+                let Some(len) = source[value_start..].find(quote as char) else {
+                    continue;
+                };
+                values.push((value_start, value_start + len));
+                offset = value_start + len;
+            }
+            // Style property
+            Some(b':') => {
+                let value_start = cursor + 1;
+                let len = source[value_start..]
+                    .find([';', '"', '\'', '}'])
+                    .unwrap_or(source.len() - value_start);
+                values.push((value_start, value_start + len));
+                offset = value_start + len;
+            }
+            _ => {}
+        }
+    }
+
+    values
+}
+
+pub(crate) fn has_property(source: &str, name: &str) -> bool {
+    !find_property(source, name).is_empty()
+}
+
+/// Replace the values of the given attribute or style property, except the ones set to `none`.
+fn replace_property(source: &str, name: &str, value: &str) -> String {
+    let mut result = String::with_capacity(source.len());
+    let mut last = 0;
+
+    for (start, end) in find_property(source, name) {
+        if source[start..end].trim() == "none" {
+            continue;
+        }
+        result.push_str(&source[last..start]);
+        result.push_str(value);
+        last = end;
+    }
+
+    result.push_str(&source[last..]);
+    result
+}
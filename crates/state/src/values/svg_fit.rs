@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use super::svg_colors::{find_property, root_tag};
+use crate::Parse;
+
+/// How the content of an SVG fits in the area of the `svg` element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum SvgFit {
+    /// Show the whole SVG, keeping its aspect ratio.
+    #[default]
+    Contain,
+    /// Cover the whole area, keeping the aspect ratio and cropping the SVG if needed.
+    Cover,
+    /// Stretch the SVG to the area.
+    Fill,
+}
+
+impl SvgFit {
+    /// The `preserveAspectRatio` value of the SVG for this fit.
+    pub fn preserve_aspect_ratio(&self) -> &'static str {
+        match self {
+            Self::Contain => "xMidYMid meet",
+            Self::Cover => "xMidYMid slice",
+            Self::Fill => "none",
+        }
+    }
+
+    /// Set the `preserveAspectRatio` of the given SVG source.
+    pub fn apply<'a>(&self, svg: &'a [u8]) -> Cow<'a, [u8]> {
+        let Ok(source) = std::str::from_utf8(svg) else {
+            return Cow::Borrowed(svg);
+        };
+        let Some((start, end)) = root_tag(source) else {
+            return Cow::Borrowed(svg);
+        };
+
+        let value = self.preserve_aspect_ratio();
+        let mut source = source.to_string();
+
+        match find_property(&source[start..end], "preserveAspectRatio").first() {
+            Some((value_start, value_end)) => {
+                source.replace_range(start + value_start..start + value_end, value);
+            }
+            None => {
+                source.insert_str(
+                    start + "<svg".len(),
+                    &format!(" preserveAspectRatio=\"{value}\""),
+                );
+            }
+        }
+
+        Cow::Owned(source.into_bytes())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseSvgFitError;
+
+impl Parse for SvgFit {
+    type Err = ParseSvgFitError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "contain" => Self::Contain,
+            "cover" => Self::Cover,
+            "fill" => Self::Fill,
+            _ => return Err(ParseSvgFitError),
+        })
+    }
+}
+
+impl fmt::Display for SvgFit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Contain => "contain",
+            Self::Cover => "cover",
+            Self::Fill => "fill",
+        })
+    }
+}
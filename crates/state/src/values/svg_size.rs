@@ -0,0 +1,51 @@
+use super::svg_colors::{find_property, root_tag};
+
+/// Get the intrinsic size of an SVG from the `width` and `height` of its root tag,
+/// or from its `viewBox` when they are missing.
+pub fn svg_intrinsic_size(svg: &[u8]) -> Option<(f32, f32)> {
+    let source = std::str::from_utf8(svg).ok()?;
+    let (start, end) = root_tag(source)?;
+    let root = &source[start..end];
+
+    let attribute = |name: &str| {
+        find_property(root, name)
+            .first()
+            .map(|(start, end)| root[*start..*end].trim())
+    };
+
+    let width = attribute("width").and_then(parse_length);
+    let height = attribute("height").and_then(parse_length);
+    let view_box = attribute("viewBox").and_then(parse_view_box);
+
+    match (width, height, view_box) {
+        (Some(width), Some(height), _) => Some((width, height)),
+        (Some(width), None, Some((box_width, box_height))) => {
+            Some((width, width * box_height / box_width))
+        }
+        (None, Some(height), Some((box_width, box_height))) => {
+            Some((height * box_width / box_height, height))
+        }
+        (None, None, Some(view_box)) => Some(view_box),
+        _ => None,
+    }
+}
+
+/// Parse an absolute length like `24` or `24px`, relative lengths like `100%` are not intrinsic.
+fn parse_length(value: &str) -> Option<f32> {
+    let length = value.strip_suffix("px").unwrap_or(value).trim();
+    length.parse::<f32>().ok().filter(|length| *length > 0.0)
+}
+
+/// Parse the width and height of a `viewBox` like `0 0 24 24`.
+fn parse_view_box(value: &str) -> Option<(f32, f32)> {
+    let mut values = value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|value| !value.is_empty())
+        .skip(2)
+        .map(|value| value.parse::<f32>().ok());
+
+    let width = values.next()??;
+    let height = values.next()??;
+
+    (width > 0.0 && height > 0.0).then_some((width, height))
+}
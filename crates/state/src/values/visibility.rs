@@ -0,0 +1,41 @@
+use crate::Parse;
+use std::fmt;
+
+/// Whether an element is painted, it always keeps its space in the layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Visibility {
+    #[default]
+    Visible,
+    /// The element is neither painted nor hit by the pointer.
+    Hidden,
+}
+
+impl Visibility {
+    pub fn is_hidden(&self) -> bool {
+        *self == Self::Hidden
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseVisibilityError;
+
+impl Parse for Visibility {
+    type Err = ParseVisibilityError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "visible" => Ok(Visibility::Visible),
+            "hidden" => Ok(Visibility::Hidden),
+            _ => Err(ParseVisibilityError),
+        }
+    }
+}
+
+impl fmt::Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Visibility::Visible => "visible",
+            Visibility::Hidden => "hidden",
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use freya_engine::prelude::*;
-use freya_node_state::CornerRadius;
+use freya_node_state::{CornerRadius, Parse};
 
 #[test]
 fn smooth_corner_radius() {
@@ -9,6 +9,7 @@ fn smooth_corner_radius() {
         bottom_left: 5.,
         bottom_right: 3.,
         smoothing: 20.,
+        vertical: None,
     };
 
     let rounded_rect = RRect::new_rect_radii(
@@ -26,3 +27,19 @@ fn smooth_corner_radius() {
     assert!(path.is_valid());
     assert!(path.is_last_contour_closed());
 }
+
+#[test]
+fn clamp_corner_radius() {
+    let corner_radius = CornerRadius::parse("999 999/10 0 0").unwrap();
+
+    let rounded_rect =
+        RRect::new_rect_radii(Rect::new(0., 0., 100., 20.), &corner_radius.radii());
+
+    // The radii are scaled down proportionally so they fit in the rect
+    let top_left = rounded_rect.radii(Corner::UpperLeft);
+    let top_right = rounded_rect.radii(Corner::UpperRight);
+    assert!(top_left.y <= 20.);
+    assert!(top_left.x + top_right.x <= 100.);
+    assert!(top_right.x > top_right.y);
+    assert_eq!(rounded_rect.radii(Corner::LowerLeft), Point::new(0., 0.));
+}
@@ -1,5 +1,5 @@
 use freya_engine::prelude::*;
-use freya_node_state::Parse;
+use freya_node_state::{Parse, ParseColorError};
 
 #[test]
 fn parse_manual_color() {
@@ -54,3 +54,84 @@ fn invalid_colors() {
     assert_eq!(extra_ending_commas.is_err(), true);
     assert_eq!(bad_unit.is_err(), true);
 }
+
+#[test]
+fn parse_css_named_colors() {
+    assert_eq!(
+        Color::parse("rebeccapurple"),
+        Ok(Color::from_rgb(102, 51, 153))
+    );
+    assert_eq!(Color::parse("SteelBlue"), Ok(Color::from_rgb(70, 130, 180)));
+    assert_eq!(Color::parse("green"), Ok(Color::GREEN));
+    assert_eq!(Color::parse("transparent"), Ok(Color::TRANSPARENT));
+}
+
+#[test]
+fn parse_hex_colors() {
+    assert_eq!(Color::parse("#ff8800"), Ok(Color::from_rgb(255, 136, 0)));
+    assert_eq!(Color::parse("#F80"), Ok(Color::from_rgb(255, 136, 0)));
+    assert_eq!(
+        Color::parse("#f808"),
+        Ok(Color::from_argb(136, 255, 136, 0))
+    );
+}
+
+#[test]
+fn parse_hsla_color() {
+    assert_eq!(
+        Color::parse("hsla(120, 100%, 50%, 0.5)"),
+        Color::parse("hsl(120deg, 100%, 50%, 50%)")
+    );
+    assert_eq!(Color::parse("hsl(0, 0%, 0%)"), Ok(Color::BLACK));
+}
+
+#[test]
+fn invalid_color_errors() {
+    assert_eq!(
+        Color::parse("blurple"),
+        Err(ParseColorError::UnknownColor("blurple".to_string()))
+    );
+    assert_eq!(
+        Color::parse("#12345"),
+        Err(ParseColorError::InvalidHex("#12345".to_string()))
+    );
+    assert_eq!(
+        Color::parse("rgb(300, 0, 0)"),
+        Err(ParseColorError::InvalidFunction(
+            "rgb(300, 0, 0)".to_string()
+        ))
+    );
+}
+
+#[test]
+fn parse_rgba_color() {
+    let color = Color::parse("rgba(0, 0, 0, 0.5)").unwrap();
+    assert_eq!(color.a(), 128);
+    assert_eq!(color, Color::from_argb(128, 0, 0, 0));
+    assert_eq!(
+        Color::parse("rgba(91, 123, 57, 25%)"),
+        Ok(Color::from_argb(64, 91, 123, 57))
+    );
+    assert!(Color::parse("rgba(0, 0, 0)").is_err());
+}
+
+#[test]
+fn parse_hex8_color() {
+    assert_eq!(
+        Color::parse("#5b7b3980"),
+        Ok(Color::from_argb(128, 91, 123, 57))
+    );
+}
+
+#[test]
+fn parse_opacity_suffix() {
+    assert_eq!(
+        Color::parse("red opacity(0.5)"),
+        Ok(Color::from_argb(128, 255, 0, 0))
+    );
+    assert_eq!(
+        Color::parse("rgba(0, 0, 0, 0.5) opacity(50%)"),
+        Ok(Color::from_argb(64, 0, 0, 0))
+    );
+    assert!(Color::parse("red opacity(half)").is_err());
+}
@@ -1,4 +1,4 @@
-use freya_node_state::{CornerRadius, Parse};
+use freya_node_state::{parse_corner_smoothing, CornerRadius, Parse};
 
 #[test]
 fn parse_basic_corner_radius() {
@@ -9,7 +9,8 @@ fn parse_basic_corner_radius() {
             top_right: 3.0,
             bottom_left: 3.0,
             bottom_right: 3.0,
-            smoothing: 0.0
+            smoothing: 0.0,
+            vertical: None,
         })
     );
 }
@@ -23,6 +24,7 @@ fn parse_two_value_radius() {
             bottom_left: 4.0,
             bottom_right: 4.0,
             smoothing: 0.0,
+            vertical: None,
         })
     );
 }
@@ -37,6 +39,7 @@ fn parse_four_value_radius() {
             bottom_left: 3.0,
             bottom_right: 1.0,
             smoothing: 0.0,
+            vertical: None,
         })
     );
 }
@@ -53,3 +56,68 @@ fn invalid_radius() {
     assert_eq!(bad_unit.is_err(), true);
     assert_eq!(incorrect_separator.is_err(), true);
 }
+
+#[test]
+fn parse_top_corners_radius() {
+    assert_eq!(
+        CornerRadius::parse("8 8 0 0"),
+        Ok(CornerRadius {
+            top_left: 8.0,
+            top_right: 8.0,
+            bottom_left: 0.0,
+            bottom_right: 0.0,
+            smoothing: 0.0,
+            vertical: None,
+        })
+    );
+}
+
+#[test]
+fn parse_elliptical_radius() {
+    assert_eq!(
+        CornerRadius::parse("20/10"),
+        Ok(CornerRadius {
+            top_left: 20.0,
+            top_right: 20.0,
+            bottom_left: 20.0,
+            bottom_right: 20.0,
+            smoothing: 0.0,
+            vertical: Some([10.0; 4]),
+        })
+    );
+
+    assert_eq!(
+        CornerRadius::parse("20/10 5 0 3/6"),
+        Ok(CornerRadius {
+            top_left: 20.0,
+            top_right: 5.0,
+            bottom_left: 0.0,
+            bottom_right: 3.0,
+            smoothing: 0.0,
+            vertical: Some([10.0, 5.0, 0.0, 6.0]),
+        })
+    );
+}
+
+#[test]
+fn invalid_elliptical_radius() {
+    let missing_vertical = CornerRadius::parse("4/");
+    let extra_vertical = CornerRadius::parse("4/3/2");
+
+    assert_eq!(missing_vertical.is_err(), true);
+    assert_eq!(extra_vertical.is_err(), true);
+}
+
+#[test]
+fn parse_smoothing() {
+    assert_eq!(parse_corner_smoothing("0.6"), Ok(0.6));
+    assert_eq!(parse_corner_smoothing("75%"), Ok(0.75));
+    assert_eq!(parse_corner_smoothing("0"), Ok(0.0));
+
+    // Out of range values are clamped
+    assert_eq!(parse_corner_smoothing("150%"), Ok(1.0));
+    assert_eq!(parse_corner_smoothing("-2"), Ok(0.0));
+
+    assert_eq!(parse_corner_smoothing("smooth").is_err(), true);
+    assert_eq!(parse_corner_smoothing("%").is_err(), true);
+}
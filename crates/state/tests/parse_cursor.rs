@@ -0,0 +1,20 @@
+use freya_node_state::Parse;
+use winit::window::CursorIcon;
+
+#[test]
+fn parse_pointer_cursor() {
+    let cursor = CursorIcon::parse("pointer");
+    assert_eq!(cursor, Ok(CursorIcon::Pointer));
+}
+
+#[test]
+fn parse_resize_cursor() {
+    let cursor = CursorIcon::parse("col-resize");
+    assert_eq!(cursor, Ok(CursorIcon::ColResize));
+}
+
+#[test]
+fn parse_invalid_cursor() {
+    let cursor = CursorIcon::parse("Hello, World!");
+    assert!(cursor.is_err());
+}
@@ -0,0 +1,20 @@
+use freya_node_state::{Parse, ParseDisplayModeError};
+use torin::display::DisplayMode;
+
+#[test]
+fn parse_none_display_mode() {
+    let none = DisplayMode::parse("none");
+    assert_eq!(none, Ok(DisplayMode::None));
+}
+
+#[test]
+fn parse_normal_display_mode() {
+    let normal = DisplayMode::parse("normal");
+    assert_eq!(normal, Ok(DisplayMode::Normal));
+}
+
+#[test]
+fn parse_invalid_display_mode() {
+    let invalid = DisplayMode::parse("hidden");
+    assert_eq!(invalid, Err(ParseDisplayModeError));
+}
@@ -0,0 +1,30 @@
+use freya_engine::prelude::*;
+use freya_node_state::Parse;
+
+#[test]
+fn parse_named_font_weight() {
+    assert_eq!(Weight::parse("bold"), Ok(Weight::BOLD));
+    assert_eq!(Weight::parse("regular"), Ok(Weight::NORMAL));
+    assert_eq!(Weight::parse("extra-black"), Ok(Weight::EXTRA_BLACK));
+}
+
+#[test]
+fn parse_numeric_font_weight() {
+    assert_eq!(Weight::parse("700"), Ok(Weight::BOLD));
+    assert_eq!(Weight::parse("950"), Ok(Weight::EXTRA_BLACK));
+    assert_eq!(Weight::parse("350"), Ok(Weight::from(350)));
+}
+
+#[test]
+fn parse_invalid_font_weight() {
+    assert!(Weight::parse("0").is_err());
+    assert!(Weight::parse("1200").is_err());
+    assert!(Weight::parse("heavy-ish").is_err());
+}
+
+#[test]
+fn parse_font_style() {
+    assert_eq!(Slant::parse("italic"), Ok(Slant::Italic));
+    assert_eq!(Slant::parse("oblique"), Ok(Slant::Oblique));
+    assert!(Slant::parse("slanted").is_err());
+}
@@ -1,5 +1,6 @@
 use freya_engine::prelude::*;
-use freya_node_state::{GradientStop, LinearGradient, Parse};
+use freya_node_state::{GradientCorner, GradientStop, LinearGradient, Parse};
+use torin::prelude::{Area, Point2D, Size2D};
 
 #[test]
 fn parse_basic_gradient() {
@@ -7,6 +8,7 @@ fn parse_basic_gradient() {
         LinearGradient::parse("linear-gradient(red 0%, blue 100%)"),
         Ok(LinearGradient {
             angle: 0.0,
+            corner: None,
             stops: vec![
                 GradientStop {
                     color: Color::RED,
@@ -27,6 +29,7 @@ fn parse_rgb_hsl_gradient() {
         LinearGradient::parse("linear-gradient(0deg, rgb(255, 0, 0) 0%, blue 100%)"),
         Ok(LinearGradient {
             angle: 0.0,
+            corner: None,
             stops: vec![
                 GradientStop {
                     color: Color::from_rgb(255, 0, 0),
@@ -47,6 +50,7 @@ fn parse_gradient_angle() {
         LinearGradient::parse("linear-gradient(45deg, red 0%, blue 100%)"),
         Ok(LinearGradient {
             angle: f32::to_radians(45.0),
+            corner: None,
             stops: vec![
                 GradientStop {
                     color: Color::from_rgb(255, 0, 0),
@@ -88,3 +92,113 @@ fn invalid_gradients() {
     assert_eq!(missing_color.is_err(), true);
     assert_eq!(missing_offset.is_err(), true);
 }
+
+#[test]
+fn parse_gradient_opacity() {
+    assert_eq!(
+        LinearGradient::parse("linear-gradient(red opacity(0.5) 0%, #0000ff80 100%)"),
+        Ok(LinearGradient {
+            angle: 0.0,
+            corner: None,
+            stops: vec![
+                GradientStop {
+                    color: Color::from_argb(128, 255, 0, 0),
+                    offset: 0.0,
+                },
+                GradientStop {
+                    color: Color::from_argb(128, 0, 0, 255),
+                    offset: 1.0,
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn parse_gradient_named_directions() {
+    let gradient = LinearGradient::parse("linear-gradient(to left, red 0%, blue 100%)").unwrap();
+    assert_eq!(gradient.angle, f32::to_radians(90.0));
+    assert_eq!(gradient.corner, None);
+
+    let gradient = LinearGradient::parse("linear-gradient(to bottom, red 0%, blue 100%)").unwrap();
+    assert_eq!(gradient.angle, 0.0);
+
+    let gradient = LinearGradient::parse("linear-gradient(to right, red 0%, blue 100%)").unwrap();
+    assert_eq!(gradient.angle, f32::to_radians(270.0));
+
+    let gradient =
+        LinearGradient::parse("linear-gradient(to bottom left, red 0%, blue 100%)").unwrap();
+    assert_eq!(gradient.corner, Some(GradientCorner::BottomLeft));
+
+    let gradient =
+        LinearGradient::parse("linear-gradient(to right top, red 0%, blue 100%)").unwrap();
+    assert_eq!(gradient.corner, Some(GradientCorner::TopRight));
+
+    assert!(LinearGradient::parse("linear-gradient(to left right, red 0%, blue 100%)").is_err());
+    assert!(LinearGradient::parse("linear-gradient(to, red 0%, blue 100%)").is_err());
+    assert!(LinearGradient::parse("linear-gradient(to middle, red 0%, blue 100%)").is_err());
+}
+
+fn endpoints(gradient: &str, area: Area) -> ((f32, f32), (f32, f32)) {
+    let (start, end) = LinearGradient::parse(gradient).unwrap().endpoints(area);
+    let round = |value: f32| (value * 100.0).round() / 100.0;
+    (
+        (round(start.x), round(start.y)),
+        (round(end.x), round(end.y)),
+    )
+}
+
+#[test]
+fn gradient_angle_endpoints() {
+    let square = Area::new(Point2D::new(10.0, 10.0), Size2D::new(100.0, 100.0));
+
+    // 0deg goes to the bottom and the angle turns clockwise, so 90deg goes to the left
+    assert_eq!(
+        endpoints("linear-gradient(0deg, red 0%, blue 100%)", square),
+        ((60.0, 10.0), (60.0, 110.0))
+    );
+    assert_eq!(
+        endpoints("linear-gradient(45deg, red 0%, blue 100%)", square),
+        ((110.0, 10.0), (10.0, 110.0))
+    );
+    assert_eq!(
+        endpoints("linear-gradient(90deg, red 0%, blue 100%)", square),
+        ((110.0, 60.0), (10.0, 60.0))
+    );
+    assert_eq!(
+        endpoints("linear-gradient(135deg, red 0%, blue 100%)", square),
+        ((110.0, 110.0), (10.0, 10.0))
+    );
+    assert_eq!(
+        endpoints("linear-gradient(180deg, red 0%, blue 100%)", square),
+        ((60.0, 110.0), (60.0, 10.0))
+    );
+    // Without a direction it goes to the bottom
+    assert_eq!(
+        endpoints("linear-gradient(red 0%, blue 100%)", square),
+        ((60.0, 10.0), (60.0, 110.0))
+    );
+}
+
+#[test]
+fn gradient_corner_endpoints() {
+    let wide = Area::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 100.0));
+
+    // The gradient line is perpendicular to the other diagonal, so its center color
+    // touches the top left and bottom right corners of the wide element
+    let ((start_x, start_y), (end_x, end_y)) =
+        endpoints("linear-gradient(to top right, red 0%, blue 100%)", wide);
+    let center = (100.0, 50.0);
+    let direction = (end_x - start_x, end_y - start_y);
+    let dot = |(x, y): (f32, f32)| (x - center.0) * direction.0 + (y - center.1) * direction.1;
+    assert!(dot((0.0, 0.0)).abs() < 0.01);
+    assert!(dot((200.0, 100.0)).abs() < 0.01);
+    // And it goes towards the top right corner
+    assert!(end_x > start_x && end_y < start_y);
+
+    // A 225deg angle, towards the top right in a square, is not the same in a wide element
+    assert_ne!(
+        endpoints("linear-gradient(to top right, red 0%, blue 100%)", wide),
+        endpoints("linear-gradient(225deg, red 0%, blue 100%)", wide)
+    );
+}
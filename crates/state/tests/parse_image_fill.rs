@@ -0,0 +1,26 @@
+use freya_node_state::{ImagePosition, ImageRepeat, Parse};
+
+#[test]
+fn parse_image_repeat() {
+    assert_eq!(ImageRepeat::parse("repeat"), Ok(ImageRepeat::Repeat));
+    assert_eq!(ImageRepeat::parse("repeat-x"), Ok(ImageRepeat::RepeatX));
+    assert_eq!(ImageRepeat::parse("repeat-y"), Ok(ImageRepeat::RepeatY));
+    assert_eq!(ImageRepeat::parse("no-repeat"), Ok(ImageRepeat::NoRepeat));
+    assert_eq!(ImageRepeat::parse("stretch"), Ok(ImageRepeat::Stretch));
+    assert!(ImageRepeat::parse("tile").is_err());
+}
+
+#[test]
+fn parse_image_position() {
+    assert_eq!(
+        ImagePosition::parse("center"),
+        Ok(ImagePosition { x: 0.5, y: 0.5 })
+    );
+    assert_eq!(
+        ImagePosition::parse("start end"),
+        Ok(ImagePosition { x: 0.0, y: 1.0 })
+    );
+    assert!(ImagePosition::parse("").is_err());
+    assert!(ImagePosition::parse("center top").is_err());
+    assert!(ImagePosition::parse("start center end").is_err());
+}
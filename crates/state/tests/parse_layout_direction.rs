@@ -0,0 +1,20 @@
+use freya_node_state::{Parse, ParseLayoutDirectionError};
+use torin::direction::LayoutDirection;
+
+#[test]
+fn parse_rtl_layout_direction() {
+    let rtl = LayoutDirection::parse("rtl");
+    assert_eq!(rtl, Ok(LayoutDirection::Rtl));
+}
+
+#[test]
+fn parse_ltr_layout_direction() {
+    let ltr = LayoutDirection::parse("ltr");
+    assert_eq!(ltr, Ok(LayoutDirection::Ltr));
+}
+
+#[test]
+fn parse_invalid_layout_direction() {
+    let invalid = LayoutDirection::parse("up");
+    assert_eq!(invalid, Err(ParseLayoutDirectionError));
+}
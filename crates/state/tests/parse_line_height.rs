@@ -0,0 +1,19 @@
+use freya_node_state::{LineHeight, Parse};
+
+#[test]
+fn parse_multiplier_line_height() {
+    let line_height = LineHeight::parse("1.5");
+    assert_eq!(line_height, Ok(LineHeight::Multiplier(1.5)));
+}
+
+#[test]
+fn parse_pixels_line_height() {
+    let line_height = LineHeight::parse("24px");
+    assert_eq!(line_height, Ok(LineHeight::Pixels(24.0)));
+}
+
+#[test]
+fn parse_invalid_line_height() {
+    assert!(LineHeight::parse("-2").is_err());
+    assert!(LineHeight::parse("big").is_err());
+}
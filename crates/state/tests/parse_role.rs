@@ -0,0 +1,26 @@
+use accesskit::Role;
+use freya_node_state::Parse;
+
+#[test]
+fn parse_camel_case_role() {
+    let role = Role::parse("textInput");
+    assert_eq!(role, Ok(Role::TextInput));
+}
+
+#[test]
+fn parse_snake_case_role() {
+    let role = Role::parse("list_item");
+    assert_eq!(role, Ok(Role::ListItem));
+}
+
+#[test]
+fn parse_aria_role() {
+    let role = Role::parse("checkbox");
+    assert_eq!(role, Ok(Role::CheckBox));
+}
+
+#[test]
+fn parse_invalid_role() {
+    let role = Role::parse("Hello, World!");
+    assert!(role.is_err());
+}
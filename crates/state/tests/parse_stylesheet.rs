@@ -0,0 +1,82 @@
+use freya_node_state::{Parse, ParseStyleSheetError, StyleSheet};
+
+fn class(stylesheet: &StyleSheet, name: &str) -> Option<Vec<(String, String)>> {
+    stylesheet.class(name).map(|attributes| {
+        attributes
+            .map(|(attribute, value)| (attribute.to_string(), value.to_string()))
+            .collect()
+    })
+}
+
+#[test]
+fn parse_stylesheet() {
+    let stylesheet = StyleSheet::parse(
+        "
+        /* Cards */
+        card {
+            background: rgb(255, 255, 255);
+            padding: 8 12;
+        }
+
+        primary { color: white; background: blue }
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(
+        class(&stylesheet, "card"),
+        Some(vec![
+            ("background".to_string(), "rgb(255, 255, 255)".to_string()),
+            ("padding".to_string(), "8 12".to_string()),
+        ])
+    );
+    assert_eq!(
+        class(&stylesheet, "primary"),
+        Some(vec![
+            ("color".to_string(), "white".to_string()),
+            ("background".to_string(), "blue".to_string()),
+        ])
+    );
+    assert_eq!(class(&stylesheet, "secondary"), None);
+}
+
+#[test]
+fn parse_empty_stylesheet() {
+    let stylesheet = StyleSheet::parse("  /* Nothing yet */ ").unwrap();
+    assert_eq!(class(&stylesheet, "card"), None);
+
+    let stylesheet = StyleSheet::parse("empty {}").unwrap();
+    assert_eq!(class(&stylesheet, "empty"), Some(vec![]));
+}
+
+#[test]
+fn invalid_stylesheets() {
+    assert_eq!(
+        StyleSheet::parse("{ padding: 8; }").err(),
+        Some(ParseStyleSheetError::MissingClassName)
+    );
+    assert_eq!(
+        StyleSheet::parse("my card { padding: 8; }").err(),
+        Some(ParseStyleSheetError::InvalidClassName("my card".to_string()))
+    );
+    assert_eq!(
+        StyleSheet::parse("card { padding: 8;").err(),
+        Some(ParseStyleSheetError::UnclosedClass("card".to_string()))
+    );
+    assert_eq!(
+        StyleSheet::parse("card { padding 8; }").err(),
+        Some(ParseStyleSheetError::InvalidAttribute("padding 8".to_string()))
+    );
+    assert_eq!(
+        StyleSheet::parse("card { padding: ; }").err(),
+        Some(ParseStyleSheetError::InvalidAttribute("padding:".to_string()))
+    );
+    assert_eq!(
+        StyleSheet::parse("/* card { padding: 8; }").err(),
+        Some(ParseStyleSheetError::UnclosedComment)
+    );
+    assert_eq!(
+        StyleSheet::parse("card { padding: 8; } title").err(),
+        Some(ParseStyleSheetError::InvalidClassName("title".to_string()))
+    );
+}
@@ -0,0 +1,8 @@
+use freya_node_state::{Parse, ParseVisibilityError, Visibility};
+
+#[test]
+fn parse_visibility() {
+    assert_eq!(Visibility::parse("visible"), Ok(Visibility::Visible));
+    assert_eq!(Visibility::parse("hidden"), Ok(Visibility::Hidden));
+    assert_eq!(Visibility::parse("none"), Err(ParseVisibilityError));
+}
@@ -0,0 +1,82 @@
+use freya_engine::prelude::*;
+use freya_node_state::SvgColors;
+
+fn apply(colors: SvgColors, svg: &str) -> String {
+    String::from_utf8(colors.apply(svg.as_bytes()).into_owned()).unwrap()
+}
+
+#[test]
+fn no_colors() {
+    let svg = r#"<svg><path fill="currentColor"/></svg>"#;
+    assert_eq!(apply(SvgColors::default(), svg), svg);
+}
+
+#[test]
+fn current_color() {
+    let colors = SvgColors {
+        color: Some(Color::RED),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        apply(
+            colors,
+            r#"<svg><path fill="currentColor" stroke="currentColor"/></svg>"#
+        ),
+        r##"<svg><path fill="#ff0000" stroke="#ff0000"/></svg>"##
+    );
+}
+
+#[test]
+fn fill() {
+    let colors = SvgColors {
+        fill: Some(Color::BLUE),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        apply(
+            colors,
+            r#"<svg><path fill="black"/><rect fill="none" style="fill: red; flood-fill: red"/></svg>"#
+        ),
+        r##"<svg fill="#0000ff"><path fill="#0000ff"/><rect fill="none" style="fill:#0000ff; flood-fill: red"/></svg>"##
+    );
+
+    assert_eq!(
+        apply(colors, r#"<svg fill="black"><path/></svg>"#),
+        r##"<svg fill="#0000ff"><path/></svg>"##
+    );
+}
+
+#[test]
+fn stroke() {
+    let colors = SvgColors {
+        stroke: Some(Color::GREEN),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        apply(
+            colors,
+            r#"<svg><path stroke="black" stroke-width="2"/><path stroke="none"/></svg>"#
+        ),
+        r##"<svg><path stroke="#00ff00" stroke-width="2"/><path stroke="none"/></svg>"##
+    );
+}
+
+#[test]
+fn transparent_colors() {
+    let colors = SvgColors {
+        fill: Some(Color::from_argb(128, 0, 0, 255)),
+        stroke: Some(Color::from_argb(51, 0, 255, 0)),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        apply(
+            colors,
+            r#"<svg fill-opacity="1"><path stroke="black"/></svg>"#
+        ),
+        r##"<svg stroke-opacity="0.2" fill="#0000ff" fill-opacity="0.502"><path stroke="#00ff00"/></svg>"##
+    );
+}
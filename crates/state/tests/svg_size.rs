@@ -0,0 +1,57 @@
+use freya_node_state::{svg_intrinsic_size, Parse, ParseSvgFitError, SvgFit};
+
+#[test]
+fn intrinsic_size_from_dimensions() {
+    let size = svg_intrinsic_size(br#"<svg width="24px" height="12" viewBox="0 0 10 10"></svg>"#);
+    assert_eq!(size, Some((24.0, 12.0)));
+}
+
+#[test]
+fn intrinsic_size_from_view_box() {
+    let size = svg_intrinsic_size(br#"<svg viewBox="0 0 48 24"><path/></svg>"#);
+    assert_eq!(size, Some((48.0, 24.0)));
+
+    let size = svg_intrinsic_size(br#"<svg viewBox="0,0,48,24" width="100%"></svg>"#);
+    assert_eq!(size, Some((48.0, 24.0)));
+}
+
+#[test]
+fn intrinsic_size_from_one_dimension() {
+    let size = svg_intrinsic_size(br#"<svg width="96" viewBox="0 0 48 24"></svg>"#);
+    assert_eq!(size, Some((96.0, 48.0)));
+
+    let size = svg_intrinsic_size(br#"<svg height="96" viewBox="0 0 48 24"></svg>"#);
+    assert_eq!(size, Some((192.0, 96.0)));
+}
+
+#[test]
+fn no_intrinsic_size() {
+    assert_eq!(
+        svg_intrinsic_size(br#"<svg><rect width="10" height="10"/></svg>"#),
+        None
+    );
+    assert_eq!(svg_intrinsic_size(b"Hello, World!"), None);
+}
+
+#[test]
+fn parse_svg_fit() {
+    assert_eq!(SvgFit::parse("contain"), Ok(SvgFit::Contain));
+    assert_eq!(SvgFit::parse("cover"), Ok(SvgFit::Cover));
+    assert_eq!(SvgFit::parse("fill"), Ok(SvgFit::Fill));
+    assert_eq!(SvgFit::parse("Hello, World!"), Err(ParseSvgFitError));
+}
+
+#[test]
+fn apply_svg_fit() {
+    let svg = SvgFit::Fill.apply(br#"<svg viewBox="0 0 10 10"></svg>"#);
+    assert_eq!(
+        &*svg,
+        br#"<svg preserveAspectRatio="none" viewBox="0 0 10 10"></svg>"#
+    );
+
+    let svg = SvgFit::Cover.apply(br#"<svg preserveAspectRatio="none" viewBox="0 0 10 10"></svg>"#);
+    assert_eq!(
+        &*svg,
+        br#"<svg preserveAspectRatio="xMidYMid slice" viewBox="0 0 10 10"></svg>"#
+    );
+}
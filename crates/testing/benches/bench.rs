@@ -0,0 +1,68 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_testing::*;
+
+fn labels_app() -> Element {
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            for i in 0..200 {
+                label {
+                    key: "{i}",
+                    width: "100%",
+                    "Label number {i}, with some text to shape"
+                }
+            }
+            paragraph {
+                width: "100%",
+                for i in 0..50 {
+                    text {
+                        key: "{i}",
+                        font_size: "{10 + i % 10}",
+                        "Text span number {i} "
+                    }
+                }
+            }
+        }
+    )
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let mut group = c.benchmark_group("render");
+    group.sample_size(20);
+
+    for cached in [false, true] {
+        let name = if cached {
+            "Render texts (cached paragraphs)"
+        } else {
+            "Render texts (first frame)"
+        };
+
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || {
+                    let mut utils = launch_test(labels_app);
+                    runtime.block_on(utils.wait_for_update());
+                    if cached {
+                        utils.render_to_image();
+                    }
+                    utils
+                },
+                |mut utils| utils.render_to_image(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
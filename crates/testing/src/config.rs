@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use freya_core::prelude::RenderSettings;
 use torin::geometry::Size2D;
 
 /// Configuration for [`crate::test_handler::TestingHandler`].
@@ -8,6 +9,11 @@ pub struct TestingConfig {
     pub vdom_timeout: Duration,
     pub size: Size2D,
     pub event_loop_ticker: bool,
+    pub scale_factor: f64,
+    /// Custom fonts to register, as `(family name, TTF or OTF data)`.
+    pub fonts: &'static [(&'static str, &'static [u8])],
+    /// Anti-aliasing, text hinting and pixel snapping used to measure and render.
+    pub render_settings: RenderSettings,
 }
 
 impl Default for TestingConfig {
@@ -16,6 +22,9 @@ impl Default for TestingConfig {
             vdom_timeout: Duration::from_millis(16),
             size: Size2D::from((500.0, 500.0)),
             event_loop_ticker: true,
+            scale_factor: 1.0,
+            fonts: &[],
+            render_settings: RenderSettings::default(),
         }
     }
 }
@@ -30,6 +39,21 @@ impl TestingConfig {
         self.size
     }
 
+    /// Get the scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    /// Get the custom fonts.
+    pub fn fonts(&self) -> &'static [(&'static str, &'static [u8])] {
+        self.fonts
+    }
+
+    /// Get the render settings.
+    pub fn render_settings(&self) -> RenderSettings {
+        self.render_settings
+    }
+
     /// Get the VirtualDOM polling timeout.
     pub fn vdom_timeout(&self) -> Duration {
         self.vdom_timeout
@@ -6,7 +6,7 @@ use freya_common::EventMessage;
 use freya_core::prelude::*;
 use freya_dom::prelude::{FreyaDOM, SafeDOM};
 use freya_engine::prelude::*;
-use freya_hooks::{use_init_accessibility, PlatformInformation};
+use freya_hooks::{use_init_accessibility, HotkeysManager, PlatformInformation};
 use std::sync::{Arc, Mutex};
 use tokio::sync::broadcast;
 use tokio::sync::mpsc::unbounded_channel;
@@ -33,14 +33,18 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
     let (platform_event_emitter, platform_event_receiver) = unbounded_channel::<EventMessage>();
     let layers = Arc::default();
     let viewports = Arc::default();
-    let mut font_collection = FontCollection::new();
-    font_collection.set_dynamic_font_manager(FontMgr::default());
+    let def_mgr = FontMgr::default();
+    let font_mgr = create_custom_font_mgr(config.fonts.iter().copied(), &def_mgr);
+    let font_collection = create_font_collection(font_mgr.clone(), def_mgr, None);
 
     let mut handler = TestingHandler {
         vdom,
         events_queue: EventsQueue::new(),
         nodes_state: NodesState::default(),
         font_collection,
+        font_mgr,
+        images_cache: ImagesCache::default(),
+        paragraph_cache: ParagraphCache::default(),
         event_emitter,
         event_receiver,
         utils: TestUtils {
@@ -54,8 +58,14 @@ pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> Tes
         accessibility_manager: AccessibilityManager::new(ACCESSIBILITY_ROOT_ID).wrap(),
         ticker_sender: broadcast::channel(5).0,
         navigation_state: NavigatorState::new(NavigationMode::NotKeyboard),
-        platform_information: Arc::new(Mutex::new(PlatformInformation::new(config.size))),
+        platform_information: Arc::new(Mutex::new(PlatformInformation::new(
+            config.size,
+            config.scale_factor,
+        ))),
         cursor_icon: CursorIcon::default(),
+        hotkeys: HotkeysManager::default(),
+        window_drags: 0,
+        needs_redraw: false,
     };
 
     handler.init_dom();
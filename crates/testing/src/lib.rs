@@ -4,8 +4,6 @@ mod test_handler;
 mod test_node;
 mod test_utils;
 
-const SCALE_FACTOR: f64 = 1.0;
-
 pub use config::*;
 pub use freya_core::prelude::*;
 pub use freya_elements::events::*;
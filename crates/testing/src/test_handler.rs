@@ -1,22 +1,31 @@
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use accesskit::NodeId as AccessibilityId;
+use accesskit::{Node as AccessibilityNode, NodeId as AccessibilityId};
 use dioxus_core::VirtualDom;
-use freya_common::EventMessage;
+use dioxus_native_core::real_dom::NodeImmutable;
+use dioxus_native_core::NodeId;
+use freya_common::{EventMessage, ExportFormat, NodeReferenceLayout};
 use freya_core::prelude::*;
 use freya_dom::prelude::SafeDOM;
-use freya_engine::prelude::FontCollection;
-use freya_hooks::PlatformInformation;
-use tokio::sync::broadcast;
+use freya_elements::events::keyboard::{Code, Key, Modifiers};
+use freya_elements::events::KeyboardData;
+use freya_engine::prelude::{
+    surfaces, Canvas, Color, EncodedImageFormat, FontCollection, FontMgr, Matrix,
+};
+use freya_hooks::{HotkeysManager, PlatformInformation};
+use freya_node_state::{InteractionState, StyleSheet};
+use freya_renderer::{export_node, find_export_node, render_skia};
+use tokio::sync::{broadcast, watch};
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
-use tokio::time::{interval, timeout};
-use torin::geometry::{Area, Size2D};
+use tokio::time::{advance, interval, timeout, Instant};
+use torin::geometry::{Area, CursorPoint, Size2D};
+use winit::event::MouseButton;
 use winit::window::CursorIcon;
 
-use crate::test_node::TestNode;
+use crate::test_node::{TestNode, TestNodes};
 use crate::test_utils::TestUtils;
-use crate::{TestingConfig, SCALE_FACTOR};
+use crate::TestingConfig;
 
 /// Manages the lifecycle of your tests.
 pub struct TestingHandler {
@@ -29,12 +38,18 @@ pub struct TestingHandler {
     pub(crate) events_queue: EventsQueue,
     pub(crate) nodes_state: NodesState,
     pub(crate) font_collection: FontCollection,
+    pub(crate) font_mgr: FontMgr,
     pub(crate) accessibility_manager: SharedAccessibilityManager,
     pub(crate) config: TestingConfig,
     pub(crate) ticker_sender: broadcast::Sender<()>,
     pub(crate) navigation_state: NavigatorState,
     pub(crate) platform_information: Arc<Mutex<PlatformInformation>>,
     pub(crate) cursor_icon: CursorIcon,
+    pub(crate) hotkeys: HotkeysManager,
+    pub(crate) window_drags: usize,
+    pub(crate) needs_redraw: bool,
+    pub(crate) images_cache: ImagesCache,
+    pub(crate) paragraph_cache: ParagraphCache,
 }
 
 impl TestingHandler {
@@ -43,7 +58,12 @@ impl TestingHandler {
         self.provide_vdom_contexts();
         let sdom = self.utils.sdom();
         let mut fdom = sdom.get();
-        fdom.init_dom(&mut self.vdom, SCALE_FACTOR as f32);
+        fdom.init_dom(&mut self.vdom, self.config.scale_factor as f32);
+    }
+
+    /// Replace the [`StyleSheet`] used by the `class` attribute, it's applied with the next update.
+    pub fn set_stylesheet(&mut self, stylesheet: StyleSheet) {
+        self.utils.sdom().get_mut().set_stylesheet(stylesheet);
     }
 
     /// Get a mutable reference to the current [`TestingConfig`].
@@ -61,10 +81,17 @@ impl TestingHandler {
             .insert_any_root_context(Box::new(self.navigation_state.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.platform_information.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.hotkeys.clone()));
+        self.vdom.insert_any_root_context(Box::new(TextMeasurer::new(
+            self.font_collection.clone(),
+            self.config.render_settings,
+        )));
     }
 
     /// Wait and apply new changes
     pub async fn wait_for_update(&mut self) -> (bool, bool) {
+        self.needs_redraw = false;
         self.wait_for_work(self.config.size());
 
         let mut ticker = if self.config.event_loop_ticker {
@@ -85,6 +112,7 @@ impl TestingHandler {
             if let Ok(ev) = platform_ev {
                 match ev {
                     EventMessage::RequestRerender => {
+                        self.needs_redraw = true;
                         if let Some(ticker) = ticker.as_mut() {
                             ticker.tick().await;
                             self.ticker_sender.send(()).unwrap();
@@ -102,6 +130,16 @@ impl TestingHandler {
                     EventMessage::SetCursorIcon(icon) => {
                         self.cursor_icon = icon;
                     }
+                    EventMessage::ReleasePointerCapture => {
+                        self.nodes_state.release_pointer_capture();
+                    }
+                    EventMessage::Screenshot(sender) => {
+                        _ = sender.send(Some(self.render_to_image()));
+                    }
+                    EventMessage::ExportNode(request) => {
+                        let data = self.export_node(request.reference.as_ref(), request.format);
+                        _ = request.sender.send(data);
+                    }
                     _ => {}
                 }
             }
@@ -121,7 +159,11 @@ impl TestingHandler {
             .utils
             .sdom()
             .get_mut()
-            .render_mutations(&mut self.vdom, SCALE_FACTOR as f32);
+            .render_mutations(&mut self.vdom, self.config.scale_factor as f32);
+
+        if must_repaint || must_relayout {
+            self.needs_redraw = true;
+        }
 
         self.wait_for_work(self.config.size());
 
@@ -130,6 +172,26 @@ impl TestingHandler {
         (must_repaint, must_relayout)
     }
 
+    /// Advance the clock by the given duration, one frame at a time, applying the changes after every frame.
+    ///
+    /// Timers, intervals and animations run on the Tokio clock, so this lets them fire
+    /// predictably without real sleeps. The clock must be paused,
+    /// e.g with `#[tokio::test(start_paused = true)]`.
+    pub async fn advance_time(&mut self, duration: Duration) {
+        let frame = Duration::from_millis(16);
+        let deadline = Instant::now() + duration;
+
+        // Applying the changes might also move the paused clock, so step until the deadline is reached
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            advance(remaining.min(frame)).await;
+            self.wait_for_update().await;
+        }
+    }
+
     /// Wait for layout and events to be processed
     pub fn wait_for_work(&mut self, size: Size2D) {
         // Clear cached results
@@ -143,7 +205,8 @@ impl TestingHandler {
                 size,
             },
             &mut self.font_collection,
-            SCALE_FACTOR as f32,
+            self.config.scale_factor as f32,
+            self.config.render_settings,
         );
 
         *self.utils.layers().lock().unwrap() = layers;
@@ -151,6 +214,10 @@ impl TestingHandler {
 
         let dom = &self.utils.sdom().get_mut();
 
+        // Drop the paragraphs of the removed nodes
+        self.paragraph_cache
+            .retain(|node_id| dom.rdom().get(node_id).is_some());
+
         process_accessibility(
             &self.utils.layers().lock().unwrap(),
             &dom.layout(),
@@ -158,22 +225,119 @@ impl TestingHandler {
             &mut self.accessibility_manager.lock().unwrap(),
         );
 
-        process_events(
+        let processed_events = process_events(
             dom,
             &self.utils.layers().lock().unwrap(),
             &mut self.events_queue,
             &self.event_emitter,
             &mut self.nodes_state,
             &self.utils.viewports().lock().unwrap(),
-            SCALE_FACTOR,
+            self.config.scale_factor,
         );
+
+        if let Some(cursor_icon) = processed_events.cursor_icon {
+            self.cursor_icon = cursor_icon;
+        }
+
+        if processed_events.drag_window {
+            self.window_drags += 1;
+        }
+
+        if processed_events.interaction_changed {
+            self.needs_redraw = true;
+        }
     }
 
     /// Push an event to the events queue
     pub fn push_event(&mut self, event: PlatformEvent) {
+        // Let the hotkeys know about the key press, they might prevent the keydown event
+        if let PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key,
+            code,
+            modifiers,
+        } = &event
+        {
+            let data = KeyboardData::new(key.clone(), *code, *modifiers);
+            if self.hotkeys.process(&data) {
+                return;
+            }
+        }
+
         self.events_queue.push(event);
     }
 
+    /// Type the given text into the focused element, pushing a key down and key up event for every character
+    /// and applying the changes after each one.
+    ///
+    /// New lines are typed as the `Enter` key.
+    pub async fn type_text(&mut self, text: &str) {
+        for character in text.chars() {
+            let (key, code) = match character {
+                '\n' => (Key::Enter, Code::Enter),
+                ' ' => (Key::Character(character.to_string()), Code::Space),
+                c if c.is_ascii_alphabetic() => (
+                    Key::Character(c.to_string()),
+                    format!("Key{}", c.to_ascii_uppercase())
+                        .parse()
+                        .unwrap_or(Code::Unidentified),
+                ),
+                c if c.is_ascii_digit() => (
+                    Key::Character(c.to_string()),
+                    format!("Digit{c}").parse().unwrap_or(Code::Unidentified),
+                ),
+                c => (Key::Character(c.to_string()), Code::Unidentified),
+            };
+            let modifiers = if character.is_uppercase() {
+                Modifiers::SHIFT
+            } else {
+                Modifiers::default()
+            };
+
+            for name in [EventName::KeyDown, EventName::KeyUp] {
+                self.push_event(PlatformEvent::Keyboard {
+                    name,
+                    key: key.clone(),
+                    code,
+                    modifiers,
+                });
+            }
+            self.wait_for_update().await;
+        }
+    }
+
+    /// Click with the left button at the given position, pushing a mouse down and a click (release) event
+    /// and applying the changes after each one.
+    ///
+    /// Returns the top-most node under the position, which is the one receiving the events.
+    /// The layout is only computed after a [`TestingHandler::wait_for_update`].
+    pub async fn click_at(&mut self, x: f64, y: f64) -> Option<TestNode> {
+        let cursor = CursorPoint::new(x, y);
+
+        let hit_node_id = {
+            let sdom = self.utils.sdom();
+            let fdom = sdom.get();
+            measure_hit_node(
+                &self.utils.layers().lock().unwrap(),
+                &self.utils.viewports().lock().unwrap(),
+                &fdom,
+                cursor,
+            )
+        };
+        let hit_node = hit_node_id.map(|node_id| self.utils.get_node_by_id(node_id));
+
+        for name in [EventName::MouseDown, EventName::Click] {
+            self.push_event(PlatformEvent::Mouse {
+                name,
+                cursor,
+                button: Some(MouseButton::Left),
+            });
+            self.wait_for_update().await;
+        }
+
+        hit_node
+    }
+
     /// Get the root node
     pub fn root(&mut self) -> TestNode {
         let root_id = {
@@ -186,15 +350,147 @@ impl TestingHandler {
         self.utils.get_node_by_id(root_id)
     }
 
+    /// Find the nodes whose text content is the given text, see [`TestNode::query_by_text`].
+    pub fn query_by_text(&mut self, text: &str) -> TestNodes {
+        self.root().query_by_text(text)
+    }
+
+    /// Find the nodes that have the given attribute value, see [`TestNode::query_by_attr`].
+    pub fn query_by_attr(&mut self, name: &str, value: &str) -> TestNodes {
+        self.root().query_by_attr(name, value)
+    }
+
     /// Get the current [AccessibilityId].
     pub fn focus_id(&self) -> AccessibilityId {
         self.accessibility_manager.lock().unwrap().focused_id
     }
 
+    /// Get the Accessibility Nodes exposed to assistive technologies.
+    pub fn accessibility_nodes(&self) -> Vec<(AccessibilityId, AccessibilityNode)> {
+        self.accessibility_manager.lock().unwrap().nodes.clone()
+    }
+
     /// Resize the simulated canvas.
     pub fn resize(&mut self, size: Size2D) {
         self.config.size = size;
         self.platform_information.lock().unwrap().window_size = size;
+        // The renderer ticks after rendering the resized window
+        self.ticker_sender.send(()).ok();
+    }
+
+    /// Usage of the cache of decoded images and rasterized SVGs used by [`TestingHandler::render_to_image`].
+    pub fn images_cache_stats(&self) -> ImagesCacheStats {
+        self.images_cache.stats()
+    }
+
+    /// Usage of the cache of laid out paragraphs used by [`TestingHandler::render_to_image`].
+    pub fn paragraph_cache_stats(&self) -> ParagraphCacheStats {
+        self.paragraph_cache.stats()
+    }
+
+    /// Paint the app into an offscreen surface and encode it as a PNG, e.g for golden-file tests.
+    ///
+    /// The image has the size of the canvas and uses the configured scale factor, so it doesn't depend
+    /// on the machine's display. Text is shaped with the fonts installed in the machine unless custom ones
+    /// are registered in the [`TestingConfig`], so snapshots with text might differ across CI machines.
+    ///
+    /// The layout is only computed after a [`TestingHandler::wait_for_update`].
+    pub fn render_to_image(&mut self) -> Vec<u8> {
+        let size = self.config.size();
+        let mut surface =
+            surfaces::raster_n32_premul((size.width as i32, size.height as i32))
+                .expect("Could not create the offscreen surface");
+        surface.canvas().clear(Color::WHITE);
+
+        self.render_to_canvas(surface.canvas());
+
+        surface
+            .image_snapshot()
+            .encode(None, EncodedImageFormat::PNG, None)
+            .expect("Could not encode the snapshot")
+            .as_bytes()
+            .to_vec()
+    }
+
+    /// Paint the app into the given canvas, e.g one of a GPU surface.
+    ///
+    /// The layout is only computed after a [`TestingHandler::wait_for_update`].
+    pub fn render_to_canvas(&mut self, canvas: &Canvas) {
+        let sdom = self.utils.sdom();
+        let fdom = sdom.get();
+        let layers = self.utils.layers().lock().unwrap();
+        let viewports = self.utils.viewports().lock().unwrap();
+
+        let mut matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
+        let mut opacities: Vec<(f32, Vec<NodeId>)> = Vec::default();
+        let render_settings = self.config.render_settings;
+
+        process_render(
+            &viewports,
+            &fdom,
+            &mut self.font_collection,
+            &layers,
+            &mut (
+                canvas,
+                &mut matrices,
+                &mut opacities,
+                &mut self.images_cache,
+                &mut self.paragraph_cache,
+            ),
+            |dom,
+             node_id,
+             area,
+             font_collection,
+             viewports,
+             (canvas, matrices, opacities, images_cache, paragraph_cache)| {
+                if let Some(dioxus_node) = dom.rdom().get(*node_id) {
+                    render_skia(
+                        canvas,
+                        area,
+                        &dioxus_node,
+                        self.nodes_state.interaction(node_id),
+                        font_collection,
+                        &self.font_mgr,
+                        viewports,
+                        false,
+                        matrices,
+                        opacities,
+                        images_cache,
+                        paragraph_cache,
+                        render_settings,
+                    );
+                }
+            },
+        );
+    }
+
+    /// Export the Node with the given `reference` and its descendants to a vector document, like `use_node_export` does.
+    ///
+    /// The whole app is exported if there is no `reference`.
+    pub fn export_node(
+        &mut self,
+        reference: Option<&Arc<watch::Sender<NodeReferenceLayout>>>,
+        format: ExportFormat,
+    ) -> Option<Vec<u8>> {
+        let sdom = self.utils.sdom();
+        let fdom = sdom.get();
+        let layers = self.utils.layers().lock().unwrap();
+        let viewports = self.utils.viewports().lock().unwrap();
+
+        let node_id = find_export_node(&fdom, reference)?;
+        export_node(
+            node_id,
+            format,
+            &fdom,
+            &layers,
+            &viewports,
+            &self.nodes_state,
+            &mut self.font_collection,
+            &self.font_mgr,
+            &mut self.images_cache,
+            &mut self.paragraph_cache,
+            self.config.render_settings,
+        )
     }
 
     /// Get the current [CursorIcon].
@@ -202,6 +498,22 @@ impl TestingHandler {
         self.cursor_icon
     }
 
+    /// Get how many times the window was requested to be dragged, see the `window_drag` attribute.
+    pub fn window_drags(&self) -> usize {
+        self.window_drags
+    }
+
+    /// Get if the last update asked for a new frame, because of a change in the DOM,
+    /// an interaction that changes how an element looks or an animation. Idle apps don't render new frames.
+    pub fn needs_redraw(&self) -> bool {
+        self.needs_redraw
+    }
+
+    /// Get if the given node is hovered or pressed, see attributes like `background_hover`.
+    pub fn interaction(&self, node: &TestNode) -> InteractionState {
+        self.nodes_state.interaction(&node.node_id)
+    }
+
     /// Get the [SafeDOM]
     pub fn sdom(&self) -> &SafeDOM {
         self.utils.sdom()
@@ -1,4 +1,5 @@
-use dioxus_native_core::prelude::TextNode;
+use dioxus_native_core::node::OwnedAttributeValue;
+use dioxus_native_core::prelude::{ElementNode, TextNode};
 use dioxus_native_core::NodeId;
 use dioxus_native_core::{node::NodeType, real_dom::NodeImmutable};
 use freya_core::node::NodeState;
@@ -42,12 +43,72 @@ impl TestNode {
         }
     }
 
+    /// Get the text of the Node's direct text children, e.g the content of a `label`.
+    pub fn text_content(&self) -> Option<String> {
+        let mut content: Option<String> = None;
+        for child_id in &self.children_ids {
+            let child = self.utils.get_node_by_id(*child_id);
+            if let Some(text) = child.text() {
+                content.get_or_insert_with(String::new).push_str(text);
+            }
+        }
+        content
+    }
+
+    /// Get the value of the given attribute, if it's set and holds a text, number or boolean.
+    pub fn attribute(&self, name: &str) -> Option<String> {
+        let NodeType::Element(ElementNode { attributes, .. }) = &self.node_type else {
+            return None;
+        };
+        attributes
+            .iter()
+            .find(|(attr, _)| attr.name == name)
+            .and_then(|(_, value)| match value {
+                OwnedAttributeValue::Text(text) => Some(text.clone()),
+                OwnedAttributeValue::Float(float) => Some(float.to_string()),
+                OwnedAttributeValue::Int(int) => Some(int.to_string()),
+                OwnedAttributeValue::Bool(bool) => Some(bool.to_string()),
+                _ => None,
+            })
+    }
+
+    /// Find the descendants of this Node that match the given predicate, in tree order.
+    pub fn query(&self, predicate: impl Fn(&TestNode) -> bool) -> TestNodes {
+        let mut matches = Vec::new();
+        let mut stack = self.children_ids.iter().rev().copied().collect::<Vec<_>>();
+
+        while let Some(node_id) = stack.pop() {
+            let node = self.utils.get_node_by_id(node_id);
+            stack.extend(node.children_ids.iter().rev().copied());
+            if predicate(&node) {
+                matches.push(node);
+            }
+        }
+
+        TestNodes {
+            nodes: matches.into_iter(),
+        }
+    }
+
+    /// Find the descendants of this Node whose text content is the given text, see [`TestNode::text_content`].
+    pub fn query_by_text(&self, text: &str) -> TestNodes {
+        self.query(|node| node.text_content().as_deref() == Some(text))
+    }
+
+    /// Find the descendants of this Node that have the given attribute value, e.g `query_by_attr("role", "button")`.
+    pub fn query_by_attr(&self, name: &str, value: &str) -> TestNodes {
+        self.query(|node| node.attribute(name).as_deref() == Some(value))
+    }
+
     /// Get the Node state
     pub fn state(&self) -> &NodeState {
         &self.state
     }
 
-    /// Get the Node layout
+    /// Get the Node layout.
+    ///
+    /// The layout is only computed after a [`wait_for_update`](crate::TestingHandler::wait_for_update),
+    /// so this returns `None` before that.
     pub fn layout(&self) -> Option<NodeAreas> {
         self.utils()
             .sdom()
@@ -57,7 +118,7 @@ impl TestNode {
             .cloned()
     }
 
-    /// Get the Node layout Area
+    /// Get the position and size computed for the Node, see [`TestNode::layout`].
     pub fn area(&self) -> Option<Area> {
         self.layout().map(|l| l.area)
     }
@@ -94,8 +155,12 @@ impl TestNode {
         self.height
     }
 
-    /// Check if the Node is visible given it's viewports.
+    /// Check if the Node is visible given it's viewports and its `visibility`, see [`TestNode::layout`].
     pub fn is_visible(&self) -> bool {
+        if self.style().visibility.is_hidden() {
+            return false;
+        }
+
         let viewports = self.utils.viewports().lock().unwrap();
         let node_viewports = viewports.get(&self.node_id);
         let Some(area) = self.area() else {
@@ -117,8 +182,36 @@ impl TestNode {
         true
     }
 
+    /// Check if the Node is visible once laid out, see [`TestNode::is_visible`].
+    ///
+    /// Returns `None` before the layout is computed in a [`wait_for_update`](crate::TestingHandler::wait_for_update).
+    pub fn visible(&self) -> Option<bool> {
+        self.layout()?;
+        Some(self.is_visible())
+    }
+
     /// Get the IDs of this Node children.
     pub fn children_ids(&self) -> Vec<NodeId> {
         self.children_ids.clone()
     }
 }
+
+/// Nodes matched by a query, see [`TestNode::query`].
+pub struct TestNodes {
+    nodes: std::vec::IntoIter<TestNode>,
+}
+
+impl TestNodes {
+    /// Get the first match, if any.
+    pub fn first(mut self) -> Option<TestNode> {
+        self.nodes.next()
+    }
+}
+
+impl Iterator for TestNodes {
+    type Item = TestNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nodes.next()
+    }
+}
@@ -1,8 +1,14 @@
+use std::time::Duration;
+
 use dioxus::prelude::*;
 use freya_core::events::{EventName, PlatformEvent};
 use freya_elements::elements as dioxus_elements;
 use freya_elements::events::mouse::MouseButton;
-use freya_testing::launch_test;
+use freya_hooks::use_interval;
+use freya_node_state::static_bytes_to_data;
+use freya_testing::{
+    launch_test, launch_test_with_config, AntiAliasing, RenderSettings, TestingConfig,
+};
 
 #[tokio::test]
 async fn no_state() {
@@ -110,3 +116,452 @@ async fn simulate_events() {
 
     assert_eq!(text.text(), Some("Is enabled? true"));
 }
+
+#[tokio::test(start_paused = true)]
+async fn advance_time() {
+    fn interval_app() -> Element {
+        let mut count = use_signal(|| 0);
+        use_interval(Duration::from_secs(1), move || {
+            count += 1;
+        });
+
+        rsx!(
+            label {
+                "{count}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(interval_app);
+    let label = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    utils.advance_time(Duration::from_millis(2500)).await;
+
+    assert_eq!(label.get(0).text(), Some("2"));
+
+    utils.advance_time(Duration::from_secs(1)).await;
+
+    assert_eq!(label.get(0).text(), Some("3"));
+}
+
+#[tokio::test]
+async fn query_nodes() {
+    fn query_app() -> Element {
+        rsx!(
+            rect {
+                label {
+                    "Hello"
+                }
+                rect {
+                    role: "button",
+                    label {
+                        "Click me"
+                    }
+                }
+                rect {
+                    role: "button",
+                    label {
+                        "Hello"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(query_app);
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.query_by_text("Hello").count(), 2);
+    assert!(utils.query_by_text("Goodbye").first().is_none());
+
+    let buttons = utils.query_by_attr("role", "button").collect::<Vec<_>>();
+    assert_eq!(buttons.len(), 2);
+
+    assert!(buttons[0].query_by_text("Click me").first().is_some());
+    assert!(buttons[0].query_by_text("Hello").first().is_none());
+    assert!(buttons[1].query_by_text("Hello").first().is_some());
+}
+
+#[tokio::test]
+async fn computed_layout() {
+    fn layout_app() -> Element {
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                main_align: "center",
+                cross_align: "center",
+                rect {
+                    width: "100",
+                    height: "50",
+                    rect {
+                        width: "100%",
+                        height: "100%",
+                        visibility: "hidden",
+                    }
+                }
+            }
+            rect {
+                width: "100",
+                height: "100",
+            }
+        )
+    }
+
+    let mut utils = launch_test(layout_app);
+    let centered = utils.root().get(0).get(0);
+    let outside = utils.root().get(1);
+    let hidden = centered.get(0);
+
+    assert!(centered.area().is_none());
+    assert_eq!(centered.visible(), None);
+
+    utils.wait_for_update().await;
+
+    let area = centered.area().unwrap();
+    assert_eq!(area.min_x(), 200.0);
+    assert_eq!(area.min_y(), 225.0);
+    assert_eq!(area.width(), 100.0);
+    assert_eq!(area.height(), 50.0);
+    assert!(centered.is_visible());
+    assert_eq!(centered.visible(), Some(true));
+
+    assert_eq!(outside.area().unwrap().min_y(), 500.0);
+    assert_eq!(hidden.visible(), Some(false));
+}
+
+#[tokio::test]
+async fn svg_intrinsic_size() {
+    static WIDE: &[u8] = br#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 40 20"></svg>"#;
+
+    fn svg_app() -> Element {
+        rsx!(
+            svg {
+                svg_data: static_bytes_to_data(WIDE),
+            }
+            svg {
+                width: "100",
+                svg_data: static_bytes_to_data(WIDE),
+            }
+            svg {
+                height: "100",
+                svg_data: static_bytes_to_data(WIDE),
+            }
+        )
+    }
+
+    let mut utils = launch_test(svg_app);
+    utils.wait_for_update().await;
+
+    let unsized_svg = utils.root().get(0).area().unwrap();
+    assert_eq!(unsized_svg.size, (40.0, 20.0).into());
+
+    let width_svg = utils.root().get(1).area().unwrap();
+    assert_eq!(width_svg.size, (100.0, 50.0).into());
+
+    let height_svg = utils.root().get(2).area().unwrap();
+    assert_eq!(height_svg.size, (200.0, 100.0).into());
+}
+
+#[tokio::test]
+async fn render_to_image() {
+    fn image_app() -> Element {
+        rsx!(rect {
+            width: "50%",
+            height: "50%",
+            background: "red",
+        })
+    }
+
+    let mut utils = launch_test(image_app);
+    utils.wait_for_update().await;
+
+    let image = utils.render_to_image();
+
+    // PNG signature
+    assert_eq!(&image[..8], &[137, 80, 78, 71, 13, 10, 26, 10]);
+    // The same frame is always painted the same way
+    assert_eq!(image, utils.render_to_image());
+}
+
+#[tokio::test]
+async fn render_reuses_images_cache() {
+    static SQUARE: &[u8] = br#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10"><rect width="10" height="10" fill="blue"/></svg>"#;
+
+    fn svg_app() -> Element {
+        rsx!(svg {
+            width: "50",
+            height: "50",
+            svg_data: static_bytes_to_data(SQUARE),
+        })
+    }
+
+    let mut utils = launch_test(svg_app);
+    utils.wait_for_update().await;
+
+    let image = utils.render_to_image();
+    let stats = utils.images_cache_stats();
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.entries, 1);
+
+    // The rasterized SVG is reused in the next frame
+    assert_eq!(image, utils.render_to_image());
+    let stats = utils.images_cache_stats();
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.hits, 1);
+}
+
+#[tokio::test]
+async fn render_recolored_svg() {
+    static ICON: &[u8] = br#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10"><rect width="10" height="10" fill="currentColor"/></svg>"#;
+    static RED_ICON: &[u8] = br#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10"><rect width="10" height="10" fill="red"/></svg>"#;
+
+    fn red_app() -> Element {
+        rsx!(svg {
+            width: "50",
+            height: "50",
+            color: "red",
+            svg_data: static_bytes_to_data(ICON),
+        })
+    }
+
+    fn blue_app() -> Element {
+        rsx!(svg {
+            width: "50",
+            height: "50",
+            color: "blue",
+            svg_data: static_bytes_to_data(ICON),
+        })
+    }
+
+    fn original_app() -> Element {
+        rsx!(svg {
+            width: "50",
+            height: "50",
+            svg_data: static_bytes_to_data(RED_ICON),
+        })
+    }
+
+    let mut red = launch_test(red_app);
+    red.wait_for_update().await;
+    let mut blue = launch_test(blue_app);
+    blue.wait_for_update().await;
+    let mut original = launch_test(original_app);
+    original.wait_for_update().await;
+
+    let red_image = red.render_to_image();
+
+    // The same icon is painted in different colors
+    assert_ne!(red_image, blue.render_to_image());
+    // And the recolored icon is painted like one with that color
+    assert_eq!(red_image, original.render_to_image());
+
+    // The recolored icon is cached until its colors change
+    red.render_to_image();
+    let stats = red.images_cache_stats();
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.hits, 1);
+}
+
+#[tokio::test]
+async fn render_reuses_paragraph_cache() {
+    fn label_app() -> Element {
+        rsx!(label {
+            width: "100%",
+            "Hello, World!"
+        })
+    }
+
+    let mut utils = launch_test(label_app);
+    utils.wait_for_update().await;
+
+    utils.render_to_image();
+    let stats = utils.paragraph_cache_stats();
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.entries, 1);
+
+    // The paragraph is reused in the next frame
+    utils.render_to_image();
+    let stats = utils.paragraph_cache_stats();
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.hits, 1);
+
+    // Changing the width lays out the paragraph again
+    utils.resize((300.0, 300.0).into());
+    utils.wait_for_update().await;
+    utils.render_to_image();
+    let stats = utils.paragraph_cache_stats();
+    assert_eq!(stats.misses, 2);
+    assert_eq!(stats.entries, 1);
+}
+
+#[tokio::test]
+async fn render_without_anti_aliasing() {
+    fn app() -> Element {
+        rsx!(rect {
+            width: "50",
+            height: "50",
+            corner_radius: "25",
+            background: "black",
+        })
+    }
+
+    async fn render(anti_aliasing: AntiAliasing) -> Vec<u8> {
+        let mut utils = launch_test_with_config(
+            app,
+            TestingConfig {
+                render_settings: RenderSettings {
+                    anti_aliasing,
+                    ..RenderSettings::default()
+                },
+                ..TestingConfig::default()
+            },
+        );
+        utils.wait_for_update().await;
+        utils.render_to_image()
+    }
+
+    // The edges of the circle are only smoothed with anti-aliasing
+    assert_ne!(
+        render(AntiAliasing::Enabled).await,
+        render(AntiAliasing::Disabled).await
+    );
+}
+
+#[tokio::test]
+async fn render_with_pixel_snapping() {
+    fn app(margin: &str) -> Element {
+        rsx!(rect {
+            margin: "{margin}",
+            width: "50",
+            height: "50",
+            border: "1 solid black",
+        })
+    }
+
+    async fn render(root: fn() -> Element, pixel_snapping: bool) -> Vec<u8> {
+        let mut utils = launch_test_with_config(
+            root,
+            TestingConfig {
+                render_settings: RenderSettings {
+                    pixel_snapping,
+                    ..RenderSettings::default()
+                },
+                ..TestingConfig::default()
+            },
+        );
+        utils.wait_for_update().await;
+        utils.render_to_image()
+    }
+
+    let aligned = render(|| app("10"), false).await;
+
+    // The border between two pixels is blurry without snapping
+    assert_ne!(render(|| app("10.4"), false).await, aligned);
+    // And painted in the closest whole pixel with snapping
+    assert_eq!(render(|| app("10.4"), true).await, aligned);
+}
+
+#[tokio::test]
+async fn click_at() {
+    fn overlapping_app() -> Element {
+        let mut clicked = use_signal(|| "none");
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                rect {
+                    width: "200",
+                    height: "200",
+                    background: "red",
+                    onclick: move |_| clicked.set("bottom"),
+                }
+                rect {
+                    position: "absolute",
+                    position_top: "50",
+                    position_left: "50",
+                    layer: "-1",
+                    width: "100",
+                    height: "100",
+                    background: "blue",
+                    onclick: move |_| clicked.set("top"),
+                }
+                label {
+                    "{clicked}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(overlapping_app);
+    let container = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    // The overlapping node is on top
+    let hit = utils.click_at(75.0, 75.0).await.unwrap();
+    assert_eq!(hit.attribute("background").as_deref(), Some("blue"));
+    assert_eq!(container.get(2).get(0).text(), Some("top"));
+
+    // Outside the overlapping node
+    let hit = utils.click_at(10.0, 10.0).await.unwrap();
+    assert_eq!(hit.attribute("background").as_deref(), Some("red"));
+    assert_eq!(container.get(2).get(0).text(), Some("bottom"));
+}
+
+#[tokio::test]
+async fn render_image_fill() {
+    static RED_SQUARE: &[u8] = include_bytes!("./red_square.png");
+
+    fn tiled_app() -> Element {
+        rsx!(rect {
+            width: "95",
+            height: "95",
+            background: static_bytes_to_data(RED_SQUARE),
+            background_repeat: "repeat",
+        })
+    }
+
+    fn stretched_app() -> Element {
+        rsx!(rect {
+            width: "95",
+            height: "95",
+            background: static_bytes_to_data(RED_SQUARE),
+            background_repeat: "stretch",
+        })
+    }
+
+    fn single_app() -> Element {
+        rsx!(rect {
+            width: "95",
+            height: "95",
+            background: static_bytes_to_data(RED_SQUARE),
+            background_repeat: "no-repeat",
+        })
+    }
+
+    fn color_app() -> Element {
+        rsx!(rect {
+            width: "95",
+            height: "95",
+            background: "red",
+        })
+    }
+
+    async fn render(app: fn() -> Element) -> Vec<u8> {
+        let mut utils = launch_test(app);
+        utils.wait_for_update().await;
+        utils.render_to_image()
+    }
+
+    let color = render(color_app).await;
+
+    // The tiles cover the whole rect, even the last partial tiles
+    assert_eq!(render(tiled_app).await, color);
+    assert_eq!(render(stretched_app).await, color);
+    // A single tile doesn't
+    assert_ne!(render(single_app).await, color);
+}
@@ -12,4 +12,10 @@ pub trait LayoutMeasurer<Key: NodeKey> {
         parent_area: &Area,
         available_parent_area: &Area,
     ) -> Option<Size2D>;
+
+    /// Whether the measured size of this Node is an intrinsic size whose aspect ratio must be kept
+    /// when only one of its dimensions is set, e.g SVGs.
+    fn keeps_aspect_ratio(&mut self, _node_id: Key) -> bool {
+        false
+    }
 }
@@ -6,7 +6,7 @@ use crate::{
     geometry::{Area, Size2D},
     measure_mode::MeasureMode,
     node::Node,
-    prelude::{AlignmentDirection, AreaModel, LayoutMetadata, Torin},
+    prelude::{AlignmentDirection, AreaModel, ContentVisibility, LayoutMetadata, Torin},
     size::Size,
 };
 
@@ -31,6 +31,8 @@ pub fn measure_node<Key: NodeKey>(
 
     invalidated_tree: bool,
 ) -> (bool, NodeAreas) {
+    layout.visited_nodes += 1;
+
     let must_revalidate = invalidated_tree
         || layout.dirty.contains(&node_id)
         || !layout.results.contains_key(&node_id);
@@ -60,14 +62,52 @@ pub fn measure_node<Key: NodeKey>(
             layout_metadata.root_area.height(),
         );
 
+        // 2.1. Skip the inner Nodes when the content is far from the viewport, using its estimated size instead
+        let skip_content = node.content_visibility == ContentVisibility::Auto && {
+            let estimated_size = estimated_content_size(&node_id, node, layout, area_size);
+            let estimated_origin =
+                node.position
+                    .get_origin(available_parent_area, parent_area, &estimated_size);
+
+            // Nodes closer than one viewport are laid out so they are ready when scrolled into view
+            let root_area = layout_metadata.root_area;
+            let viewport = root_area.inflate(root_area.width(), root_area.height());
+            let is_far = !Rect::new(estimated_origin, estimated_size).intersects(&viewport);
+            if is_far {
+                area_size = estimated_size;
+            }
+            is_far
+        };
+
         // 3. If available, run a custom layout measure function
         // This is useful when you use third-party libraries (e.g. rust-skia, cosmic-text) to measure text layouts
         // When a Node is measured by a custom measurer function the inner children will be skipped
-        let measure_inner_children = if let Some(measurer) = measurer {
+        let measure_inner_children = if skip_content {
+            false
+        } else if let Some(measurer) = measurer {
             let custom_size = measurer.measure(node_id, node, parent_area, available_parent_area);
 
             // 3.1. Compute the width and height again using the new custom area sizes
-            if let Some(custom_size) = custom_size {
+            if let Some(mut custom_size) = custom_size {
+                // 3.1.1. Scale the intrinsic size to the dimension that is set, keeping its aspect ratio
+                if measurer.keeps_aspect_ratio(node_id)
+                    && custom_size.width > 0.0
+                    && custom_size.height > 0.0
+                {
+                    let aspect_ratio = custom_size.width / custom_size.height;
+                    match (Size::Inner == node.width, Size::Inner == node.height) {
+                        (true, false) => {
+                            custom_size.width =
+                                (area_size.height - node.margin.vertical()) * aspect_ratio;
+                        }
+                        (false, true) => {
+                            custom_size.height =
+                                (area_size.width - node.margin.horizontal()) / aspect_ratio;
+                        }
+                        _ => {}
+                    }
+                }
+
                 if Size::Inner == node.width {
                     area_size.width = node.width.min_max(
                         custom_size.width,
@@ -143,6 +183,15 @@ pub fn measure_node<Key: NodeKey>(
 
         let mut inner_sizes = Size2D::default();
 
+        if skip_content {
+            // Forget the outdated results of the inner Nodes so they are not painted
+            if layout.skipped_contents.insert(node_id) {
+                remove_inner_results(&node_id, layout, dom_adapter);
+            }
+        } else {
+            layout.skipped_contents.remove(&node_id);
+        }
+
         if measure_inner_children {
             // 6. Create an area containing the available space inside the inner area
             let mut available_area = inner_area;
@@ -183,6 +232,20 @@ pub fn measure_node<Key: NodeKey>(
     } else {
         let areas = layout.get(node_id).unwrap().clone();
 
+        // The inner Nodes are not laid out while the content is far from the viewport
+        if layout.skipped_contents.contains(&node_id) {
+            return (false, areas);
+        }
+
+        // Nothing changed inside this Node, so its cached subtree can be reused as it is
+        if !layout.dirty_ancestors.contains(&node_id) {
+            debug_assert!(
+                !has_dirty_descendants(&node_id, layout, dom_adapter),
+                "Skipped the measurement of a subtree with dirty nodes"
+            );
+            return (false, areas);
+        }
+
         let mut inner_sizes = areas.inner_sizes;
         let mut available_area = areas.inner_area;
 
@@ -210,6 +273,54 @@ pub fn measure_node<Key: NodeKey>(
     }
 }
 
+/// Estimate the size of a Node whose inner Nodes are skipped.
+/// The last measured size is kept to avoid jumps while scrolling, otherwise the `contain_intrinsic_size` is used.
+fn estimated_content_size<Key: NodeKey>(
+    node_id: &Key,
+    node: &Node,
+    layout: &Torin<Key>,
+    area_size: Size2D,
+) -> Size2D {
+    let last_size = layout.get(*node_id).map(|areas| areas.area.size);
+    let mut estimated_size = area_size;
+
+    if Size::Inner == node.width {
+        estimated_size.width = last_size
+            .map(|size| size.width)
+            .unwrap_or(area_size.width + node.contain_intrinsic_size.width);
+    }
+    if Size::Inner == node.height {
+        estimated_size.height = last_size
+            .map(|size| size.height)
+            .unwrap_or(area_size.height + node.contain_intrinsic_size.height);
+    }
+
+    estimated_size
+}
+
+/// Remove the layout results of all the descendants of the given Node
+fn remove_inner_results<Key: NodeKey>(
+    node_id: &Key,
+    layout: &mut Torin<Key>,
+    dom_adapter: &mut impl DOMAdapter<Key>,
+) {
+    for child_id in dom_adapter.children_of(node_id) {
+        layout.raw_remove(child_id);
+        remove_inner_results(&child_id, layout, dom_adapter);
+    }
+}
+
+/// Check whether any descendant of the given Node is dirty
+fn has_dirty_descendants<Key: NodeKey>(
+    node_id: &Key,
+    layout: &Torin<Key>,
+    dom_adapter: &mut impl DOMAdapter<Key>,
+) -> bool {
+    dom_adapter.children_of(node_id).iter().any(|child_id| {
+        layout.dirty.contains(child_id) || has_dirty_descendants(child_id, layout, dom_adapter)
+    })
+}
+
 /// Measure the children layouts of a Node
 #[allow(clippy::too_many_arguments)]
 #[inline(always)]
@@ -235,7 +346,9 @@ pub fn measure_inner_nodes<Key: NodeKey>(
     let mut measure_children = |mode: &mut MeasureMode,
                                 available_area: &mut Area,
                                 inner_sizes: &mut Size2D,
-                                must_cache_inner_nodes: bool| {
+                                must_cache_inner_nodes: bool,
+                                // Area in which the children are mirrored when laying out from right to left
+                                mirror_area: Option<&Area>| {
         let children = dom_adapter.children_of(parent_node_id);
 
         for child_id in children {
@@ -243,7 +356,18 @@ pub fn measure_inner_nodes<Key: NodeKey>(
 
             let child_data = dom_adapter.get_node(&child_id).unwrap();
 
+            // Hidden Nodes take no space, so their outdated results are forgotten instead of being measured
+            if child_data.display.is_none() {
+                if layout.get(child_id).is_some() {
+                    layout.raw_remove(child_id);
+                    remove_inner_results(&child_id, layout, dom_adapter);
+                }
+                continue;
+            }
+
             let mut adapted_available_area = *available_area;
+            let mut adapted_inner_area = inner_area;
+            let mut first_measure_size = None;
 
             if parent_node.cross_alignment.is_not_start() {
                 // 1. First measure: Cross axis is not aligned
@@ -268,6 +392,38 @@ pub fn measure_inner_nodes<Key: NodeKey>(
                     &parent_node.direction,
                     AlignmentDirection::Cross,
                 );
+
+                first_measure_size = Some(child_areas.area.size);
+            }
+
+            // 2.1. Mirror the child horizontally inside the parent when laying out from right to left
+            let mut mirror_offset = 0.0;
+            if let Some(mirror_area) = mirror_area {
+                let size = first_measure_size.unwrap_or_else(|| {
+                    let (_, child_areas) = measure_node(
+                        child_id,
+                        &child_data,
+                        layout,
+                        &inner_area,
+                        &adapted_available_area,
+                        measurer,
+                        false,
+                        dom_adapter,
+                        layout_metadata,
+                        invalidated_tree,
+                    );
+                    child_areas.area.size
+                });
+
+                let origin =
+                    child_data
+                        .position
+                        .get_origin(&adapted_available_area, &inner_area, &size);
+                let mirrored_x = mirror_area.min_x() + mirror_area.max_x() - origin.x - size.width;
+                mirror_offset = mirrored_x - origin.x;
+
+                adapted_available_area.origin.x += mirror_offset;
+                adapted_inner_area.origin.x += mirror_offset;
             }
 
             // 3. Second measure
@@ -275,7 +431,7 @@ pub fn measure_inner_nodes<Key: NodeKey>(
                 child_id,
                 &child_data,
                 layout,
-                &inner_area,
+                &adapted_inner_area,
                 &adapted_available_area,
                 measurer,
                 must_cache_inner_nodes,
@@ -284,11 +440,13 @@ pub fn measure_inner_nodes<Key: NodeKey>(
                 invalidated_tree,
             );
 
-            // Stack the child into its parent
+            // Stack the child into its parent as if it wasn't mirrored
+            let mut stacked_area = child_areas.area;
+            stacked_area.origin.x -= mirror_offset;
             mode.stack_into_node(
                 parent_node,
                 available_area,
-                &child_areas.area,
+                &stacked_area,
                 inner_sizes,
                 &child_data,
             );
@@ -300,6 +458,8 @@ pub fn measure_inner_nodes<Key: NodeKey>(
         }
     };
 
+    let mut mirror_area = None;
+
     {
         // This is no the final measure, hence we make a temporary measurement mode
         // so the affected values are not reused by the final measurement
@@ -307,13 +467,22 @@ pub fn measure_inner_nodes<Key: NodeKey>(
         let mut alignment_mode = alignment_mode.to_mut();
         let mut inner_sizes = *inner_sizes;
 
-        if parent_node.main_alignment.is_not_start() || parent_node.cross_alignment.is_not_start() {
+        // The final width is needed to mirror the children of unsized Nodes laid out from right to left
+        let must_fit_mirror_area = parent_node.layout_direction.is_rtl()
+            && Size::Inner == parent_node.width
+            && matches!(alignment_mode, MeasureMode::ParentIsNotCached { .. });
+
+        if parent_node.main_alignment.is_not_start()
+            || parent_node.cross_alignment.is_not_start()
+            || must_fit_mirror_area
+        {
             // 1. First measure: Main axis is not aligned
             measure_children(
                 &mut alignment_mode,
                 &mut available_area.clone(),
                 &mut inner_sizes,
                 false,
+                None,
             );
         }
 
@@ -343,8 +512,29 @@ pub fn measure_inner_nodes<Key: NodeKey>(
                 AlignmentDirection::Main,
             );
         }
+
+        if parent_node.layout_direction.is_rtl() {
+            let mut area = *alignment_mode.inner_area();
+            if let MeasureMode::ParentIsNotCached {
+                area: parent_area, ..
+            } = &alignment_mode
+            {
+                if must_fit_mirror_area {
+                    area.size.width = parent_area.width()
+                        - parent_node.padding.horizontal()
+                        - parent_node.margin.horizontal();
+                }
+            }
+            mirror_area = Some(area);
+        }
     }
 
     // 5. Second measure
-    measure_children(mode, available_area, inner_sizes, must_cache_inner_nodes);
+    measure_children(
+        mode,
+        available_area,
+        inner_sizes,
+        must_cache_inner_nodes,
+        mirror_area.as_ref(),
+    );
 }
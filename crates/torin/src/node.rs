@@ -1,8 +1,14 @@
 pub use euclid::Rect;
 
 use crate::{
-    alignment::Alignment, direction::DirectionMode, gaps::Gaps, geometry::Length,
-    prelude::Position, size::Size,
+    alignment::Alignment,
+    content_visibility::ContentVisibility,
+    direction::{DirectionMode, LayoutDirection},
+    display::DisplayMode,
+    gaps::Gaps,
+    geometry::{Length, Size2D},
+    prelude::Position,
+    size::Size,
 };
 
 /// Node layout configuration
@@ -37,10 +43,22 @@ pub struct Node {
     /// Direction in which it's inner Nodes will be stacked
     pub direction: DirectionMode,
 
+    /// Whether the inner Nodes are placed from left to right or mirrored from right to left
+    pub layout_direction: LayoutDirection,
+
     pub position: Position,
 
     /// A Node might depend on inner sizes but have a fixed position, like scroll views.
     pub has_layout_references: bool,
+
+    /// Skip the layout of the inner Nodes while this Node is far from the viewport
+    pub content_visibility: ContentVisibility,
+
+    /// Estimated size of the inner Nodes used while they are skipped and were never measured
+    pub contain_intrinsic_size: Size2D,
+
+    /// Remove the Node and its inner Nodes from the layout
+    pub display: DisplayMode,
 }
 
 impl Node {
@@ -166,5 +184,6 @@ impl Node {
             || self.has_layout_references
             || self.cross_alignment.is_not_start()
             || self.main_alignment.is_not_start()
+            || self.layout_direction.is_rtl()
     }
 }
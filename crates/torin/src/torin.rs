@@ -2,7 +2,7 @@ use std::{collections::HashMap, mem};
 
 pub use euclid::Rect;
 use rustc_hash::{FxHashMap, FxHashSet};
-use tracing::info;
+use tracing::{info, trace};
 
 use crate::{
     custom_measurer::LayoutMeasurer,
@@ -61,6 +61,15 @@ pub struct Torin<Key: NodeKey> {
 
     /// Best Root node candidate from where to start measuring
     pub root_node_candidate: RootNodeCandidate<Key>,
+
+    /// Ancestors of the dirty nodes, the subtrees of the other cached nodes are skipped while measuring
+    pub dirty_ancestors: FxHashSet<Key>,
+
+    /// Nodes visited in the last measurement, either measured again or reusing their cached results
+    pub visited_nodes: usize,
+
+    /// Nodes with `content_visibility: auto` whose inner Nodes were skipped because they are far from the viewport
+    pub skipped_contents: FxHashSet<Key>,
 }
 
 impl<Key: NodeKey> Default for Torin<Key> {
@@ -76,6 +85,9 @@ impl<Key: NodeKey> Torin<Key> {
             results: HashMap::default(),
             dirty: FxHashSet::default(),
             root_node_candidate: RootNodeCandidate::None,
+            dirty_ancestors: FxHashSet::default(),
+            visited_nodes: 0,
+            skipped_contents: FxHashSet::default(),
         }
     }
 
@@ -88,6 +100,8 @@ impl<Key: NodeKey> Torin<Key> {
         self.root_node_candidate = RootNodeCandidate::None;
         self.results.clear();
         self.dirty.clear();
+        self.dirty_ancestors.clear();
+        self.skipped_contents.clear();
     }
 
     /// Read the HashSet of dirty nodes
@@ -95,10 +109,21 @@ impl<Key: NodeKey> Torin<Key> {
         &self.dirty
     }
 
+    /// Amount of Nodes visited in the last measurement, useful to verify that only the affected subtrees were measured
+    pub fn visited_nodes(&self) -> usize {
+        self.visited_nodes
+    }
+
+    /// Whether the inner Nodes of this Node were skipped in the last measurement because it is far from the viewport
+    pub fn is_content_skipped(&self, node_id: Key) -> bool {
+        self.skipped_contents.contains(&node_id)
+    }
+
     /// Remove a Node's result and data
     pub fn raw_remove(&mut self, node_id: Key) {
         self.results.remove(&node_id);
         self.dirty.remove(&node_id);
+        self.skipped_contents.remove(&node_id);
         if let RootNodeCandidate::Valid(id) = self.root_node_candidate {
             if id == node_id {
                 self.root_node_candidate = RootNodeCandidate::None
@@ -205,6 +230,20 @@ impl<Key: NodeKey> Torin<Key> {
         }
     }
 
+    /// Check if a Node can be measured on its own, which is not the case
+    /// for hidden Nodes and Nodes whose parent has no results, e.g inside a hidden Node.
+    fn is_measurable_root(&self, node_id: &Key, dom_adapter: &mut impl DOMAdapter<Key>) -> bool {
+        let is_hidden = dom_adapter
+            .get_node(node_id)
+            .map(|node| node.display.is_none())
+            .unwrap_or(true);
+        let has_parent_results = dom_adapter
+            .parent_of(node_id)
+            .map(|parent_id| self.results.contains_key(&parent_id))
+            .unwrap_or(true);
+        !is_hidden && has_parent_results
+    }
+
     /// Measure dirty Nodes
     pub fn measure(
         &mut self,
@@ -221,10 +260,9 @@ impl<Key: NodeKey> Torin<Key> {
         }
 
         // Try the Root candidate otherwise use the provided Root
-        let root_id = if let RootNodeCandidate::Valid(id) = self.root_node_candidate.take() {
-            id
-        } else {
-            suggested_root_id
+        let root_id = match self.root_node_candidate.take() {
+            RootNodeCandidate::Valid(id) if self.is_measurable_root(&id, dom_adapter) => id,
+            _ => suggested_root_id,
         };
         let root_parent = dom_adapter.parent_of(&root_id);
         let areas = root_parent
@@ -245,6 +283,19 @@ impl<Key: NodeKey> Torin<Key> {
             root_height
         );
 
+        // Only the subtrees containing dirty nodes need to be visited
+        self.dirty_ancestors.clear();
+        for dirty in self.dirty.iter() {
+            let mut parent = dom_adapter.parent_of(dirty);
+            while let Some(parent_id) = parent {
+                if !self.dirty_ancestors.insert(parent_id) {
+                    break;
+                }
+                parent = dom_adapter.parent_of(&parent_id);
+            }
+        }
+        self.visited_nodes = 0;
+
         let metadata = LayoutMetadata { root_area };
 
         let (root_revalidated, root_areas) = measure_node(
@@ -265,7 +316,10 @@ impl<Key: NodeKey> Torin<Key> {
             self.cache_node(root_id, root_areas);
         }
 
+        trace!("Visited {} nodes", self.visited_nodes);
+
         self.dirty.clear();
+        self.dirty_ancestors.clear();
         self.root_node_candidate = RootNodeCandidate::None;
     }
 
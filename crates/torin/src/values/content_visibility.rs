@@ -0,0 +1,18 @@
+/// Whether the content of a Node is laid out and painted while it is far from the viewport.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub enum ContentVisibility {
+    /// The content is always laid out.
+    #[default]
+    Visible,
+    /// The content is skipped while the Node is far from the viewport, using its estimated size instead.
+    Auto,
+}
+
+impl ContentVisibility {
+    pub fn pretty(&self) -> String {
+        match self {
+            ContentVisibility::Visible => "visible".to_string(),
+            ContentVisibility::Auto => "auto".to_string(),
+        }
+    }
+}
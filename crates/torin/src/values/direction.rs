@@ -13,3 +13,26 @@ impl DirectionMode {
         }
     }
 }
+
+/// Horizontal direction in which the inner Nodes are placed, inherited by the inner Nodes.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum LayoutDirection {
+    /// Left to right.
+    #[default]
+    Ltr,
+    /// Right to left, the inner Nodes are mirrored horizontally.
+    Rtl,
+}
+
+impl LayoutDirection {
+    pub fn is_rtl(&self) -> bool {
+        *self == Self::Rtl
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            LayoutDirection::Ltr => "ltr".to_string(),
+            LayoutDirection::Rtl => "rtl".to_string(),
+        }
+    }
+}
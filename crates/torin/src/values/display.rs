@@ -0,0 +1,22 @@
+/// Whether a Node takes part in the layout.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum DisplayMode {
+    /// The Node is laid out as usual.
+    #[default]
+    Normal,
+    /// The Node and its inner Nodes take no space and are not laid out, so they are not painted either.
+    None,
+}
+
+impl DisplayMode {
+    pub fn is_none(&self) -> bool {
+        *self == Self::None
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            DisplayMode::Normal => "normal".to_string(),
+            DisplayMode::None => "none".to_string(),
+        }
+    }
+}
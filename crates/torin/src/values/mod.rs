@@ -1,12 +1,16 @@
 pub mod alignment;
+pub mod content_visibility;
 pub mod direction;
+pub mod display;
 pub mod gaps;
 pub mod position;
 pub mod size;
 
 pub mod prelude {
     pub use crate::alignment::*;
+    pub use crate::content_visibility::*;
     pub use crate::direction::*;
+    pub use crate::display::*;
     pub use crate::gaps::*;
     pub use crate::position::*;
     pub use crate::size::*;
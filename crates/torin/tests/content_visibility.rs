@@ -0,0 +1,142 @@
+use euclid::Length;
+use torin::{prelude::*, test_utils::*};
+
+fn item(content_visibility: ContentVisibility) -> Node {
+    Node {
+        width: Size::Percentage(Length::new(100.0)),
+        height: Size::Inner,
+        content_visibility,
+        contain_intrinsic_size: Size2D::new(0.0, 100.0),
+        ..Default::default()
+    }
+}
+
+/// A scroll view with 30 items of 100px each, the items are `1..=30` and their contents `101..=130`
+fn scroll_view(offset_y: f32) -> TestingDOM {
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        (1..=30).collect(),
+        Node::from_size_and_scroll(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Length::new(0.0),
+            Length::new(offset_y),
+        ),
+    );
+    for node_id in 1..=30 {
+        mocked_dom.add(
+            node_id,
+            Some(0),
+            vec![node_id + 100],
+            item(ContentVisibility::Auto),
+        );
+        mocked_dom.add(
+            node_id + 100,
+            Some(node_id),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Percentage(Length::new(100.0)),
+                Size::Pixels(Length::new(100.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+    mocked_dom
+}
+
+#[test]
+pub fn content_visibility_skips_far_contents() {
+    let (mut layout, mut measurer) = test_utils();
+    let mut mocked_dom = scroll_view(0.0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Items closer than one viewport are laid out
+    assert!(!layout.is_content_skipped(20));
+    assert_eq!(
+        layout.get(120).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 1900.0), Size2D::new(1000.0, 100.0)),
+    );
+
+    // The farther ones use their estimated size
+    assert!(layout.is_content_skipped(21));
+    assert!(layout.get(121).is_none());
+    assert_eq!(
+        layout.get(30).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 2900.0), Size2D::new(1000.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn content_visibility_lays_out_scrolled_contents() {
+    let (mut layout, mut measurer) = test_utils();
+    let mut mocked_dom = scroll_view(0.0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Scroll to the end
+    mocked_dom.set_node(
+        0,
+        Node::from_size_and_scroll(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Length::new(0.0),
+            Length::new(-2000.0),
+        ),
+    );
+    layout.invalidate(0);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The last item is now laid out
+    assert!(!layout.is_content_skipped(30));
+    assert_eq!(
+        layout.get(130).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 900.0), Size2D::new(1000.0, 100.0)),
+    );
+
+    // The first one is far now, it keeps its last size and its content is forgotten
+    assert!(layout.is_content_skipped(1));
+    assert!(layout.get(101).is_none());
+    assert_eq!(
+        layout.get(1).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, -2000.0), Size2D::new(1000.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn content_visibility_visible_is_always_laid_out() {
+    let (mut layout, mut measurer) = test_utils();
+    let mut mocked_dom = scroll_view(0.0);
+    mocked_dom.set_node(30, item(ContentVisibility::Visible));
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert!(!layout.is_content_skipped(30));
+    assert_eq!(
+        layout.get(130).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 2900.0), Size2D::new(1000.0, 100.0)),
+    );
+}
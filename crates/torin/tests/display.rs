@@ -0,0 +1,122 @@
+use torin::{prelude::*, test_utils::*};
+
+fn item(display: DisplayMode) -> Node {
+    Node {
+        width: Size::Percentage(Length::new(100.0)),
+        height: Size::Pixels(Length::new(100.0)),
+        display,
+        ..Default::default()
+    }
+}
+
+/// A column with the items `1..=3`, the item `2` has an inner Node `4`
+fn column(display: DisplayMode) -> TestingDOM {
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Inner,
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(1, Some(0), vec![], item(DisplayMode::Normal));
+    mocked_dom.add(2, Some(0), vec![4], item(display));
+    mocked_dom.add(3, Some(0), vec![], item(DisplayMode::Normal));
+    mocked_dom.add(4, Some(2), vec![], item(DisplayMode::Normal));
+    mocked_dom
+}
+
+#[test]
+pub fn display_none_takes_no_space() {
+    let (mut layout, mut measurer) = test_utils();
+    let mut mocked_dom = column(DisplayMode::None);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert!(layout.get(2).is_none());
+    assert!(layout.get(4).is_none());
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 200.0)),
+    );
+}
+
+#[test]
+pub fn display_none_toggle() {
+    let (mut layout, mut measurer) = test_utils();
+    let mut mocked_dom = column(DisplayMode::Normal);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 200.0), Size2D::new(200.0, 100.0)),
+    );
+
+    // Hide the item, its outdated results are forgotten
+    mocked_dom.set_node(2, item(DisplayMode::None));
+    layout.invalidate(2);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert!(layout.get(2).is_none());
+    assert!(layout.get(4).is_none());
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(200.0, 100.0)),
+    );
+
+    // Changes inside the hidden item are not laid out
+    layout.invalidate(4);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert!(layout.get(4).is_none());
+
+    // Show it again
+    mocked_dom.set_node(2, item(DisplayMode::Normal));
+    layout.invalidate(2);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 200.0), Size2D::new(200.0, 100.0)),
+    );
+}
@@ -389,3 +389,107 @@ pub fn deep_tree() {
 
     assert_eq!(layout.get_root_candidate(), RootNodeCandidate::None);
 }
+
+#[test]
+pub fn layout_skips_clean_subtrees() {
+    let (mut layout, mut measurer) = test_utils();
+
+    //        0
+    //       / \
+    //      1   2
+    //     /     \
+    //  3..12   13-14
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(1000.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        (3..=12).collect(),
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(500.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    for node_id in 3..=12 {
+        mocked_dom.add(
+            node_id,
+            Some(1),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Percentage(Length::new(100.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![13, 14],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(500.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    for node_id in 13..=14 {
+        mocked_dom.add(
+            node_id,
+            Some(2),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Percentage(Length::new(100.0)),
+                Size::Pixels(Length::new(50.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(layout.visited_nodes(), 15);
+
+    // Only the subtree of Node 2 is visited, the children of Node 1 are not affected
+    mocked_dom.set_node(
+        14,
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    layout.invalidate(14);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(layout.visited_nodes(), 3);
+    assert_eq!(
+        layout.get(14).unwrap().area,
+        Rect::new(Point2D::new(0.0, 550.0), Size2D::new(1000.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(12).unwrap().area,
+        Rect::new(Point2D::new(0.0, 450.0), Size2D::new(1000.0, 50.0)),
+    );
+}
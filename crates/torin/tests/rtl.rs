@@ -0,0 +1,240 @@
+use euclid::Length;
+use torin::{prelude::*, test_utils::*};
+
+fn rtl(node: Node) -> Node {
+    Node {
+        layout_direction: LayoutDirection::Rtl,
+        ..node
+    }
+}
+
+fn fixed(width: f32, height: f32) -> Node {
+    Node::from_size_and_direction(
+        Size::Pixels(Length::new(width)),
+        Size::Pixels(Length::new(height)),
+        DirectionMode::Vertical,
+    )
+}
+
+#[test]
+pub fn rtl_horizontal_stacking() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        rtl(Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Horizontal,
+        )),
+    );
+    mocked_dom.add(1, Some(0), vec![], fixed(100.0, 100.0));
+    mocked_dom.add(2, Some(0), vec![], fixed(200.0, 100.0));
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The first Node starts from the right
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(900.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(700.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+
+    // Resizing a Node moves its siblings
+    mocked_dom.set_node(1, fixed(150.0, 100.0));
+    layout.invalidate(1);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(850.0, 0.0), Size2D::new(150.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(650.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn rtl_vertical_stacking() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        rtl(Node::from_size_and_padding(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Gaps::new(0.0, 0.0, 0.0, 20.0),
+        )),
+    );
+    mocked_dom.add(1, Some(0), vec![], fixed(100.0, 100.0));
+    mocked_dom.add(2, Some(0), vec![], fixed(200.0, 100.0));
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The Nodes are aligned to the right, the padding stays in its side
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(900.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(800.0, 100.0), Size2D::new(200.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn rtl_alignments() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        rtl(Node::from_size_and_alignments_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Alignment::End,
+            Alignment::Center,
+            DirectionMode::Horizontal,
+        )),
+    );
+    mocked_dom.add(1, Some(0), vec![], fixed(100.0, 100.0));
+    mocked_dom.add(2, Some(0), vec![], fixed(200.0, 100.0));
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The end of the main axis is the left side
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(200.0, 450.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 450.0), Size2D::new(200.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn rtl_nested_inner_size() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        rtl(Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Vertical,
+        )),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3],
+        rtl(Node::from_size_and_direction(
+            Size::Inner,
+            Size::Inner,
+            DirectionMode::Horizontal,
+        )),
+    );
+    mocked_dom.add(2, Some(1), vec![], fixed(50.0, 50.0));
+    mocked_dom.add(3, Some(1), vec![], fixed(100.0, 50.0));
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(850.0, 0.0), Size2D::new(150.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(950.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(850.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn rtl_absolute_position() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        rtl(Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Vertical,
+        )),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_position(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Position::Absolute(Box::new(AbsolutePosition {
+                top: Some(10.0),
+                left: Some(30.0),
+                ..Default::default()
+            })),
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The left offset is applied from the right side
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(870.0, 10.0), Size2D::new(100.0, 100.0)),
+    );
+}
@@ -641,3 +641,104 @@ pub fn root_percentage() {
         Rect::new(Point2D::new(0.0, 0.0), Size2D::new(750.0, 100.0)),
     );
 }
+
+/// Measures every Node as a 40x20 image.
+struct ImageMeasurer;
+
+impl LayoutMeasurer<usize> for ImageMeasurer {
+    fn measure(
+        &mut self,
+        node_id: usize,
+        _node: &Node,
+        _parent_area: &Area,
+        _available_parent_area: &Area,
+    ) -> Option<Size2D> {
+        (node_id != 0).then_some(Size2D::new(40.0, 20.0))
+    }
+
+    fn keeps_aspect_ratio(&mut self, _node_id: usize) -> bool {
+        true
+    }
+}
+
+#[test]
+pub fn intrinsic_aspect_ratio() {
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = Some(ImageMeasurer);
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(Size::Inner, Size::Inner, DirectionMode::Vertical),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Inner,
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Inner,
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        4,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Unsized, the intrinsic size is used
+    assert_eq!(
+        layout.get(1).unwrap().visible_area().size,
+        Size2D::new(40.0, 20.0),
+    );
+    // Only the width is set, the height keeps the aspect ratio
+    assert_eq!(
+        layout.get(2).unwrap().visible_area().size,
+        Size2D::new(100.0, 50.0),
+    );
+    // Only the height is set, the width keeps the aspect ratio
+    assert_eq!(
+        layout.get(3).unwrap().visible_area().size,
+        Size2D::new(200.0, 100.0),
+    );
+    // Both are set, the aspect ratio is ignored
+    assert_eq!(
+        layout.get(4).unwrap().visible_area().size,
+        Size2D::new(100.0, 100.0),
+    );
+}
@@ -3,22 +3,21 @@
     windows_subsystem = "windows"
 )]
 
-use std::str::FromStr;
-
 use freya::prelude::*;
-use reqwest::Url;
 
 fn main() {
     launch(app);
 }
 
+static RUST_LOGO: &[u8] = include_bytes!("./rust_logo.png");
+
 fn app() -> Element {
     let mut focus_a = use_focus();
     let mut focus_b = use_focus();
     let mut focus_c = use_focus();
     let mut focus_d = use_focus();
 
-    let url = Url::from_str("https://upload.wikimedia.org/wikipedia/commons/thumb/4/47/PNG_transparency_demonstration_1.png/420px-PNG_transparency_demonstration_1.png").unwrap();
+    let image_data = static_bytes_to_data(RUST_LOGO);
 
     rsx!(
         rect {
@@ -63,12 +62,11 @@ fn app() -> Element {
                 color: "white",
                 "Hello, World! This is an example."
             }
-            NetworkImage {
-                url,
-                theme: theme_with!(NetworkImageTheme {
-                    width: "100".into(),
-                    height: "100".into(),
-                }),
+            image {
+                image_data,
+                width: "100",
+                height: "100",
+                role: "image",
                 alt: "This is an image"
             }
         }
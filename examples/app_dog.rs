@@ -3,19 +3,30 @@
     windows_subsystem = "windows"
 )]
 
+#[cfg(feature = "network_image")]
 use freya::prelude::*;
+#[cfg(feature = "network_image")]
 use reqwest::Url;
+#[cfg(feature = "network_image")]
 use serde::Deserialize;
 
+#[cfg(not(feature = "network_image"))]
+fn main() {
+    panic!("Run with the 'network_image' feature");
+}
+
+#[cfg(feature = "network_image")]
 fn main() {
     launch(app);
 }
 
+#[cfg(feature = "network_image")]
 #[derive(Deserialize)]
 struct DogApiResponse {
     message: String,
 }
 
+#[cfg(feature = "network_image")]
 async fn fetch_random_dog() -> Option<Url> {
     let res = reqwest::get("https://dog.ceo/api/breeds/image/random")
         .await
@@ -24,6 +35,7 @@ async fn fetch_random_dog() -> Option<Url> {
     data.message.parse().ok()
 }
 
+#[cfg(feature = "network_image")]
 fn app() -> Element {
     use_init_theme(DARK_THEME);
     let mut dog_url = use_signal(|| None);
@@ -0,0 +1,59 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+#[cfg(feature = "use_audio")]
+use freya::prelude::*;
+
+#[cfg(not(feature = "use_audio"))]
+fn main() {
+    panic!("Run with the 'use_audio' feature");
+}
+
+#[cfg(feature = "use_audio")]
+fn main() {
+    launch(app);
+}
+
+#[cfg(feature = "use_audio")]
+fn app() -> Element {
+    let audio = use_audio();
+    let mut error = use_signal(|| None);
+
+    let play = move |_| {
+        audio.set_looping(true);
+        if let Err(err) = audio.play("./examples/music.ogg") {
+            error.set(Some(err.to_string()));
+        }
+    };
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            direction: "horizontal",
+            Button {
+                onclick: play,
+                label { "Play" }
+            }
+            Button {
+                onclick: move |_| audio.pause(),
+                label { "Pause" }
+            }
+            Button {
+                onclick: move |_| audio.resume(),
+                label { "Resume" }
+            }
+            Button {
+                onclick: move |_| audio.stop(),
+                label { "Stop" }
+            }
+            if let Some(err) = &*error.read() {
+                label { "{err}" }
+            }
+        }
+    )
+}
@@ -0,0 +1,41 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Breadcrumb", (500.0, 300.0));
+}
+
+fn app() -> Element {
+    let mut path = use_signal(|| {
+        vec![
+            "Home".to_string(),
+            "Documents".to_string(),
+            "Projects".to_string(),
+            "Freya".to_string(),
+            "examples".to_string(),
+        ]
+    });
+
+    let items = path
+        .read()
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            BreadcrumbItem::new(segment.clone()).onclick(move |_| path.write().truncate(i + 1))
+        })
+        .collect::<Vec<_>>();
+
+    rsx!(
+        rect {
+            padding: "20",
+            Breadcrumb {
+                max_items: 3,
+                items
+            }
+        }
+    )
+}
@@ -17,7 +17,8 @@ fn main() {
 }
 #[cfg(feature = "use_camera")]
 fn app() -> Element {
-    let (image_reference, camera_error) = use_camera(CameraSettings::default());
+    let camera = use_camera_stream(CameraSettings::default());
+    let camera_error = camera.error();
 
     rsx!(
         rect {
@@ -30,10 +31,9 @@ fn app() -> Element {
                     "{err}"
                 }
             } else {
-                image {
-                    width: "100%",
-                    height: "100%",
-                    image_reference
+                CameraView {
+                    camera,
+                    mirror: true,
                 }
             }
         }
@@ -0,0 +1,51 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+use winit::window::CursorIcon;
+
+static FREYA_ICON: &[u8] = include_bytes!("./freya_icon.png");
+
+fn main() {
+    launch_with_props(app, "Custom Cursor", (400.0, 350.0));
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+
+    let onmouseenter = move |_| {
+        platform.set_custom_cursor(
+            CustomCursor::new(FREYA_ICON)
+                .hotspot(16.0, 16.0)
+                .fallback(CursorIcon::Crosshair),
+        );
+    };
+
+    let onmouseleave = move |_| {
+        platform.remove_custom_cursor();
+    };
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            rect {
+                onmouseenter,
+                onmouseleave,
+                width: "250",
+                height: "200",
+                corner_radius: "8",
+                background: "rgb(225, 225, 225)",
+                main_align: "center",
+                cross_align: "center",
+                label {
+                    "Hover me"
+                }
+            }
+        }
+    )
+}
@@ -206,7 +206,7 @@ fn Editor() -> Element {
                     margin: "0 10",
                     Slider {
                         value: *font_size_percentage.read(),
-                        onmoved: move |p| {
+                        onchange: move |p| {
                             font_size_percentage.set(p);
                         }
                     }
@@ -220,7 +220,7 @@ fn Editor() -> Element {
                     margin: "0 10",
                     Slider {
                         value: *line_height_percentage.read(),
-                        onmoved: move |p| {
+                        onchange: move |p| {
                             line_height_percentage.set(p);
                         }
                     }
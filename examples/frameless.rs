@@ -0,0 +1,65 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::builder()
+            .with_width(500.0)
+            .with_height(350.0)
+            .with_title("Frameless")
+            .with_decorations(false)
+            .with_transparency(true)
+            .with_blur(true)
+            .build(),
+    );
+}
+
+fn app() -> Element {
+    let window = use_window();
+
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            corner_radius: "12",
+            background: "rgb(240, 240, 240, 0.85)",
+            border: "1 solid rgb(200, 200, 200)",
+            rect {
+                height: "40",
+                width: "100%",
+                direction: "horizontal",
+                cross_align: "center",
+                padding: "0 8",
+                window_drag: "true",
+                label {
+                    width: "fill",
+                    "Drag me, double click to maximize"
+                }
+                rect {
+                    direction: "horizontal",
+                    window_drag: "false",
+                    Button {
+                        onclick: move |_| window.minimize(),
+                        label { "-" }
+                    }
+                    Button {
+                        onclick: move |_| window.close(),
+                        label { "x" }
+                    }
+                }
+            }
+            rect {
+                height: "fill",
+                width: "100%",
+                main_align: "center",
+                cross_align: "center",
+                label { "Hello, World!" }
+            }
+        }
+    )
+}
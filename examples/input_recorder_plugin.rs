@@ -0,0 +1,90 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    let recorder = InputRecorderPlugin::default();
+
+    launch_cfg(
+        app,
+        LaunchConfig::builder()
+            .with_title("Input Recorder Plugin")
+            .with_plugin(recorder.clone())
+            .with_state(recorder)
+            .build(),
+    )
+}
+
+fn recording_path() -> std::path::PathBuf {
+    std::env::temp_dir().join("freya-input.recording")
+}
+
+fn app() -> Element {
+    let recorder = use_context::<InputRecorderPlugin>();
+    let mut is_recording = use_signal(|| false);
+    let mut count = use_signal(|| 0);
+    let mut text = use_signal(String::new);
+
+    let toggle_recording = {
+        let recorder = recorder.clone();
+        move |_| {
+            if let Some(recording) = recorder.stop_recording() {
+                if let Err(err) = recording.save(recording_path()) {
+                    println!("Failed to save the recording: {err}");
+                }
+            } else {
+                recorder.start_recording();
+            }
+            is_recording.set(recorder.is_recording());
+        }
+    };
+
+    let replay = move |speed: ReplaySpeed| {
+        let recorder = recorder.clone();
+        move |_| match Recording::load(recording_path()) {
+            Ok(recording) => {
+                count.set(0);
+                text.set(String::new());
+                recorder.replay(recording, speed);
+            }
+            Err(err) => println!("Failed to load the recording: {err}"),
+        }
+    };
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "20",
+            rect {
+                direction: "horizontal",
+                margin: "0 0 10 0",
+                Button {
+                    onclick: toggle_recording,
+                    label {
+                        if is_recording() { "Stop recording" } else { "Record" }
+                    }
+                }
+                Button {
+                    onclick: replay(ReplaySpeed::Original),
+                    label { "Replay" }
+                }
+                Button {
+                    onclick: replay(ReplaySpeed::AsFastAsPossible),
+                    label { "Replay fast" }
+                }
+            }
+            Button {
+                onclick: move |_| count += 1,
+                label { "Clicked {count} times" }
+            }
+            Input {
+                value: text(),
+                onchange: move |value| text.set(value),
+            }
+        }
+    )
+}
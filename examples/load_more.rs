@@ -0,0 +1,53 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::time::Duration;
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Load more", (400.0, 500.0));
+}
+
+fn app() -> Element {
+    let mut items = use_signal(|| 20);
+    let mut loading = use_signal(|| false);
+
+    // Load more items when the end of the list is visible
+    let (reference, _) = use_on_visible(0.0, move |visible| {
+        if visible && !*loading.peek() {
+            loading.set(true);
+            spawn(async move {
+                // Pretend the items come from a server
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                *items.write() += 20;
+                loading.set(false);
+            });
+        }
+    });
+
+    rsx!(
+        ScrollView {
+            for i in 0..*items.read() {
+                rect {
+                    key: "{i}",
+                    width: "100%",
+                    height: "50",
+                    padding: "15",
+                    background: if i % 2 == 0 { "rgb(235, 235, 235)" } else { "white" },
+                    label { "Item {i}" }
+                }
+            }
+            rect {
+                reference,
+                width: "100%",
+                height: "50",
+                main_align: "center",
+                cross_align: "center",
+                Loader { }
+            }
+        }
+    )
+}
@@ -0,0 +1,57 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Multiple Windows", (400.0, 350.0));
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+    let mut count = use_signal(|| 0);
+
+    let onclick = move |_| {
+        count += 1;
+        platform.new_window(
+            NewWindow::new(secondary_window)
+                .with_title("Secondary window")
+                .with_size(300.0, 200.0)
+                .with_context(*count.peek()),
+        );
+    };
+
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            main_align: "center",
+            cross_align: "center",
+            Button {
+                onclick,
+                label { "Open window" }
+            }
+        }
+    )
+}
+
+fn secondary_window() -> Element {
+    let number = use_context::<i32>();
+    let window = use_window();
+
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            main_align: "center",
+            cross_align: "center",
+            label { "Window number {number}" }
+            Button {
+                onclick: move |_| window.close(),
+                label { "Close" }
+            }
+        }
+    )
+}
@@ -37,7 +37,7 @@ fn app() -> Element {
             Slider {
                 width: "100",
                 value: *opacity.read(),
-                onmoved: move |p| {
+                onchange: move |p| {
                     opacity.set(p);
                 }
             }
@@ -0,0 +1,35 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Pagination", (500.0, 300.0));
+}
+
+const PAGES: usize = 25;
+
+fn app() -> Element {
+    let mut page = use_signal(|| 0);
+    let current_page = *page.read() + 1;
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            label {
+                margin: "0 0 10 0",
+                "Showing page {current_page} of {PAGES}"
+            }
+            Pagination {
+                page: *page.read(),
+                pages: PAGES,
+                onchange: move |p| page.set(p)
+            }
+        }
+    )
+}
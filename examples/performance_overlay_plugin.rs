@@ -12,7 +12,7 @@ fn main() {
             .with_title("Performance Overlay Plugin")
             .with_width(700.)
             .with_height(500.)
-            .with_plugin(PerformanceOverlayPlugin::default())
+            .with_plugin(PerformanceOverlayPlugin::default().with_corner(OverlayCorner::TopRight))
             .build(),
     )
 }
@@ -3,15 +3,47 @@
     windows_subsystem = "windows"
 )]
 
+use std::time::Instant;
+
 use freya::prelude::*;
-use freya_core::plugins::{FreyaPlugin, PluginEvent};
+use freya_core::plugins::{FreyaPlugin, PluginEvent, PluginHandle, RenderContext};
+use winit::window::Window;
+
+/// Logs how long it takes to measure the layout and render every frame.
+#[derive(Default)]
+struct FrameTimePlugin {
+    started_layout: Option<Instant>,
+    started_render: Option<Instant>,
+}
+
+impl FreyaPlugin for FrameTimePlugin {
+    fn on_setup(&mut self, window: &Window, _handle: &PluginHandle) {
+        println!("Logging the frame times of '{}'", window.title());
+    }
 
-struct DummyPlugin;
+    fn on_before_render(&mut self, _context: &RenderContext) {
+        self.started_render = Some(Instant::now());
+    }
+
+    fn on_after_render(&mut self, context: &RenderContext) {
+        if let Some(started_render) = self.started_render.take() {
+            println!(
+                "Rendered {} nodes in {:?}",
+                context.freya_dom.rdom().tree_ref().len(),
+                started_render.elapsed()
+            );
+        }
+    }
 
-impl FreyaPlugin for DummyPlugin {
     fn on_event(&mut self, event: &PluginEvent) {
-        if let PluginEvent::AfterRender { .. } = event {
-            println!("The app just got rendered to the canvas.");
+        match event {
+            PluginEvent::StartedLayout(_) => self.started_layout = Some(Instant::now()),
+            PluginEvent::FinishedLayout(_) => {
+                if let Some(started_layout) = self.started_layout.take() {
+                    println!("Measured the layout in {:?}", started_layout.elapsed());
+                }
+            }
+            _ => {}
         }
     }
 }
@@ -20,7 +52,7 @@ fn main() {
     launch_cfg(
         app,
         LaunchConfig::<()>::builder()
-            .with_plugin(DummyPlugin)
+            .with_plugin(FrameTimePlugin::default())
             .with_width(250.0)
             .with_height(200.0)
             .build(),
@@ -25,7 +25,7 @@ fn app() -> Element {
         animation.read().start();
     };
 
-    let onmoved = move |value: f64| {
+    let onchange = move |value: f64| {
         start_origin.set(value as f32);
     };
 
@@ -85,7 +85,7 @@ fn app() -> Element {
         Slider {
             width: "300",
             value: progress as f64,
-            onmoved: onmoved
+            onchange: onchange
         }
         Button {
             onclick: set_to_max,
@@ -3,7 +3,7 @@
     windows_subsystem = "windows"
 )]
 
-use dioxus_router::prelude::{Outlet, Routable, Router};
+use dioxus_router::prelude::{Routable, Router};
 use freya::prelude::*;
 
 fn main() {
@@ -14,7 +14,7 @@ fn app() -> Element {
     rsx!(Router::<Route> {})
 }
 
-#[derive(Routable, Clone)]
+#[derive(Routable, Clone, PartialEq)]
 #[rustfmt::skip]
 pub enum Route {
     #[layout(AppSidebar)]
@@ -61,7 +61,9 @@ fn AppSidebar() -> Element {
                     cross_align: "center",
                     width: "100%",
                     height: "100%",
-                    Outlet::<Route> {  }
+                    AnimatedOutlet::<Route> {
+                        transition: RouteTransition::Fade,
+                    }
                 }
             }
         }
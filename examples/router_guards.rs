@@ -0,0 +1,137 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use dioxus_router::prelude::{Outlet, Routable, Router};
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Router Guards", (550.0, 400.0));
+}
+
+fn app() -> Element {
+    rsx!(Router::<Route> {})
+}
+
+#[derive(Routable, Clone, PartialEq)]
+#[rustfmt::skip]
+pub enum Route {
+    #[layout(AppSidebar)]
+        #[route("/")]
+        Home,
+        #[route("/editor")]
+        Editor,
+}
+
+#[allow(non_snake_case)]
+fn AppSidebar() -> Element {
+    let navigation = use_navigation();
+
+    rsx!(
+        Sidebar {
+            sidebar: rsx!(
+                Link {
+                    to: Route::Home,
+                    SidebarItem {
+                        label {
+                            "Home"
+                        }
+                    },
+                },
+                Link {
+                    to: Route::Editor,
+                    SidebarItem {
+                        label {
+                            "Editor"
+                        }
+                    },
+                },
+                SidebarItem {
+                    onclick: move |_| navigation.go_back(),
+                    label {
+                        "Back"
+                    }
+                },
+                SidebarItem {
+                    onclick: move |_| navigation.go_forward(),
+                    label {
+                        "Forward"
+                    }
+                },
+                label {
+                    "History: {navigation.history_len()}"
+                }
+            ),
+            Body {
+                rect {
+                    main_align: "center",
+                    cross_align: "center",
+                    width: "100%",
+                    height: "100%",
+                    Outlet::<Route> {  }
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn Home() -> Element {
+    rsx!(
+        label {
+            "Nothing to edit here"
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn Editor() -> Element {
+    let mut value = use_signal(String::new);
+    let mut confirm = use_signal(|| None::<tokio::sync::oneshot::Sender<bool>>);
+
+    use_navigation_guard(move |_| async move {
+        if value.peek().is_empty() {
+            return NavigationDecision::Allow;
+        }
+        // Wait for the user to confirm
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        confirm.set(Some(tx));
+        match rx.await {
+            Ok(true) => NavigationDecision::Allow,
+            _ => NavigationDecision::Cancel,
+        }
+    });
+
+    let mut answer = move |leave: bool| {
+        if let Some(tx) = confirm.write().take() {
+            let _ = tx.send(leave);
+        }
+    };
+
+    rsx!(
+        Input {
+            value: value.read().clone(),
+            onchange: move |e| value.set(e),
+        }
+        if confirm.read().is_some() {
+            label {
+                "You have unsaved changes, leave anyway?"
+            }
+            Button {
+                onclick: move |_| answer(true),
+                label {
+                    "Leave"
+                }
+            }
+            Button {
+                onclick: move |_| answer(false),
+                label {
+                    "Stay"
+                }
+            }
+        }
+    )
+}
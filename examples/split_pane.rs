@@ -0,0 +1,60 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Split Pane", (700.0, 500.0));
+}
+
+fn app() -> Element {
+    let mut ratio = use_signal(|| 0.3);
+    let percentage = (*ratio.read() * 100.0).round();
+
+    rsx!(
+        SplitPane {
+            ratio: *ratio.read(),
+            first_min_size: 120.0,
+            second_min_size: 200.0,
+            onresize: move |r| ratio.set(r),
+            first: rsx!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    padding: "10",
+                    label {
+                        "Sidebar ({percentage}%)"
+                    }
+                }
+            ),
+            second: rsx!(
+                SplitPane {
+                    direction: "vertical",
+                    ratio: 0.6,
+                    first: rsx!(
+                        rect {
+                            width: "100%",
+                            height: "100%",
+                            padding: "10",
+                            label {
+                                "Editor"
+                            }
+                        }
+                    ),
+                    second: rsx!(
+                        rect {
+                            width: "100%",
+                            height: "100%",
+                            padding: "10",
+                            label {
+                                "Terminal"
+                            }
+                        }
+                    ),
+                }
+            ),
+        }
+    )
+}
@@ -3,35 +3,22 @@
     windows_subsystem = "windows"
 )]
 
-use std::fmt::Display;
+use std::collections::HashSet;
 
 use freya::prelude::*;
-use itertools::{Either, Itertools};
 
 fn main() {
     launch(app);
 }
 
-#[derive(PartialEq, Clone)]
-enum OrderBy {
-    Name,
-    OtherName,
-    MoreData,
-}
-
-impl Display for OrderBy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            OrderBy::Name => f.write_str("Name"),
-            OrderBy::OtherName => f.write_str("Other Name"),
-            OrderBy::MoreData => f.write_str("More Data"),
-        }
-    }
-}
+const COLUMNS: [&str; 3] = ["Name", "Other Name", "More Data"];
 
 fn app() -> Element {
-    let mut order_direction = use_signal(|| OrderDirection::Down);
-    let mut order = use_signal(|| OrderBy::Name);
+    let mut sort = use_signal(|| TableSort {
+        column: 0,
+        direction: OrderDirection::Down,
+    });
+    let mut selected = use_signal(HashSet::<usize>::new);
     let data = use_signal(|| {
         vec![
             vec!["aaaa".to_string(), "bbbb".to_string(), "111".to_string()],
@@ -45,60 +32,47 @@ fn app() -> Element {
             vec!["rrrr".to_string(), "333".to_string(), "888".to_string()],
         ]
     });
-    let columns = use_hook(|| {
-        vec![
-            ("Name", OrderBy::Name),
-            ("OtherName", OrderBy::OtherName),
-            ("MoreData", OrderBy::MoreData),
-        ]
-    });
-    let data = data.read();
 
-    let filtered_data = {
-        let filtered_data = data.iter().sorted_by(|a, b| match *order.read() {
-            OrderBy::Name => Ord::cmp(&a[0].to_lowercase(), &b[0].to_lowercase()),
-            OrderBy::OtherName => Ord::cmp(&a[1].to_lowercase(), &b[1].to_lowercase()),
-            OrderBy::MoreData => Ord::cmp(&a[2].to_lowercase(), &b[2].to_lowercase()),
-        });
+    let mut rows = data.read().iter().cloned().enumerate().collect::<Vec<_>>();
+    sort.read().sort_by(&mut rows, |(_, a), (_, b), column| {
+        Ord::cmp(&a[column].to_lowercase(), &b[column].to_lowercase())
+    });
 
-        if *order_direction.read() == OrderDirection::Down {
-            Either::Left(filtered_data)
-        } else {
-            Either::Right(filtered_data.rev())
-        }
-    };
-
-    let mut on_column_head_click = move |column_order: &OrderBy| {
-        // Change order diection
-        if &*order.read() == column_order {
-            if *order_direction.read() == OrderDirection::Up {
-                order_direction.set(OrderDirection::Down)
-            } else {
-                order_direction.set(OrderDirection::Up)
-            }
-        // Change order column
-        } else {
-            order.set(column_order.clone());
-            order_direction.set(OrderDirection::default())
-        }
-    };
+    let total = rows.len();
+    let selected_count = selected.read().len();
+    let all_selected = selected_count == total;
+    let column_name = COLUMNS[sort.read().column];
 
     rsx!(
         rect {
             padding: "10",
             label {
                 height: "25",
-                "Ordering by {order}"
+                "Ordering by {column_name}, {selected_count} selected"
             }
             Table {
+                theme: theme_with!(TableTheme {
+                    height: "300".into(),
+                }),
                 columns: 3,
+                sticky_header: true,
+                resizable_columns: true,
+                sort: *sort.read(),
+                onsort: move |s| sort.set(s),
                 TableHead {
                     TableRow {
-                        for (n, (text, order_by)) in columns.into_iter().enumerate() {
+                        selected: all_selected,
+                        onselect: move |select_all| {
+                            if select_all {
+                                selected.set((0..total).collect());
+                            } else {
+                                selected.write().clear();
+                            }
+                        },
+                        for (n, text) in COLUMNS.into_iter().enumerate() {
                             TableCell {
                                 key: "{n}",
-                                order_direction: if *order.read() == order_by { Some(*order_direction.read()) } else { None },
-                                onclick: move |_| on_column_head_click(&order_by),
+                                column: n,
                                 label {
                                     "{text}"
                                 }
@@ -107,19 +81,26 @@ fn app() -> Element {
                     }
                 }
                 TableBody {
-                    ScrollView {
-                        for (i, items) in filtered_data.enumerate() {
-                            TableRow {
-                                key: "{i}",
-                                alternate_colors: i % 2 == 0,
-                                for (n, item) in items.iter().enumerate() {
-                                    TableCell {
-                                        key: "{n}",
-                                        label {
-                                            width: "100%",
-                                            text_align: "right",
-                                            "{item}"
-                                        }
+                    for (i, (id, items)) in rows.into_iter().enumerate() {
+                        TableRow {
+                            key: "{id}",
+                            alternate_colors: i % 2 == 0,
+                            selected: selected.read().contains(&id),
+                            onselect: move |select| {
+                                if select {
+                                    selected.write().insert(id);
+                                } else {
+                                    selected.write().remove(&id);
+                                }
+                            },
+                            for (n, item) in items.iter().enumerate() {
+                                TableCell {
+                                    key: "{n}",
+                                    column: n,
+                                    label {
+                                        width: "100%",
+                                        text_align: "right",
+                                        "{item}"
                                     }
                                 }
                             }
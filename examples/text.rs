@@ -33,7 +33,7 @@ fn app() -> Element {
                 }
                 Slider {
                     value: *percentage.read(),
-                    onmoved: move |p| {
+                    onchange: move |p| {
                         percentage.set(p);
                     }
                 }
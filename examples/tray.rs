@@ -0,0 +1,47 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+static FREYA_ICON: &[u8] = include_bytes!("./freya_icon.png");
+
+// Run with `cargo run --example tray --features tray`
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::builder()
+            .with_width(400.0)
+            .with_height(300.0)
+            .with_title("Tray")
+            .with_tray(
+                TrayConfig::new(FREYA_ICON)
+                    .with_tooltip("Freya")
+                    .with_item(TrayMenuItem::new("toggle", "Show/Hide", TrayAction::ToggleWindow))
+                    .with_item(TrayMenuItem::new("ping", "Ping", TrayAction::Custom))
+                    .with_item(TrayMenuItem::new("quit", "Quit", TrayAction::Quit)),
+            )
+            .build(),
+    );
+}
+
+fn app() -> Element {
+    let mut pings = use_signal(|| 0);
+
+    use_tray_menu(move |id| {
+        if id == "ping" {
+            pings += 1;
+        }
+    });
+
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            main_align: "center",
+            cross_align: "center",
+            label { "Pinged {pings} times from the tray" }
+        }
+    )
+}
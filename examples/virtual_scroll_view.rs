@@ -10,12 +10,14 @@ fn main() {
 }
 
 fn app() -> Element {
-    let values = use_signal(|| ["Hello, World!"].repeat(300));
+    let mut values = use_signal(|| ["Hello, World!"].repeat(300));
 
     rsx!(VirtualScrollView {
         length: values.read().len(),
         item_size: 25.0,
         direction: "vertical",
+        // Load more items when getting close to the end
+        onreachend: move |_| values.write().extend(["Hello, World!"].repeat(100)),
         builder: move |index, _: &Option<()>| {
             let value = values.read()[index];
             let background = if index % 2 == 0 {